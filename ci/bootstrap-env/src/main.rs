@@ -73,6 +73,7 @@ fn main() -> Result<()> {
                     initial_dao_balance: None,
                 },
                 active_threshold: None,
+                power_cap: None,
             })?,
             funds: vec![],
             admin: Some(Admin::CoreModule {}),
@@ -114,6 +115,8 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -56,6 +56,7 @@ pub fn create_dao(
                     initial_dao_balance: None,
                 },
                 active_threshold: None,
+                power_cap: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             label: "DAO DAO Voting Module".to_string(),
@@ -73,6 +74,8 @@ pub fn create_dao(
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: chain.orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -252,6 +255,8 @@ pub fn create_proposal(
                     description: "desc".to_string(),
                     msgs,
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             key,
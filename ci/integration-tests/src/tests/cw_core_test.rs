@@ -42,6 +42,7 @@ fn execute_execute_admin_msgs(chain: &mut Chain) {
                 contract_addr: dao.addr,
                 msg: to_json_binary(&dao_interface::msg::ExecuteMsg::Pause {
                     duration: Duration::Time(100),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -59,7 +60,7 @@ fn execute_execute_admin_msgs(chain: &mut Chain) {
         .unwrap();
     let res: PauseInfoResponse = res.data().unwrap();
 
-    assert_eq!(res, PauseInfoResponse::Unpaused {});
+    assert_eq!(res, PauseInfoResponse::Unpaused { scopes: vec![] });
 
     // if you are the admin you can execute admin msgs:
     let res = create_dao(
@@ -81,6 +82,7 @@ fn execute_execute_admin_msgs(chain: &mut Chain) {
                     contract_addr: dao.addr,
                     msg: to_json_binary(&dao_interface::msg::ExecuteMsg::Pause {
                         duration: Duration::Height(100),
+                        scope: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -97,7 +99,7 @@ fn execute_execute_admin_msgs(chain: &mut Chain) {
         .unwrap();
 
     let res: PauseInfoResponse = res.data().unwrap();
-    assert_ne!(res, PauseInfoResponse::Unpaused {});
+    assert_ne!(res, PauseInfoResponse::Unpaused { scopes: vec![] });
 }
 
 #[test_context(Chain)]
@@ -214,7 +216,10 @@ fn instantiate_with_no_admin(chain: &mut Chain) {
 
     // ensure the dao is the admin:
     assert_eq!(dao.state.admin, dao.addr);
-    assert_eq!(dao.state.pause_info, PauseInfoResponse::Unpaused {});
+    assert_eq!(
+        dao.state.pause_info,
+        PauseInfoResponse::Unpaused { scopes: vec![] }
+    );
     assert_eq!(
         dao.state.config,
         dao_interface::state::Config {
@@ -248,7 +253,10 @@ fn instantiate_with_admin(chain: &mut Chain) {
 
     // general dao info is valid:
     assert_eq!(dao.state.admin, user_addr);
-    assert_eq!(dao.state.pause_info, PauseInfoResponse::Unpaused {});
+    assert_eq!(
+        dao.state.pause_info,
+        PauseInfoResponse::Unpaused { scopes: vec![] }
+    );
     assert_eq!(
         dao.state.config,
         dao_interface::state::Config {
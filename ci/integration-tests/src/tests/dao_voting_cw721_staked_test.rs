@@ -53,6 +53,7 @@ fn setup_test(
                 },
                 unstaking_duration,
                 active_threshold: None,
+                power_cap: None,
             },
             key,
             None,
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{
+    from_json, Addr, Api, BankMsg, BlockInfo, CosmosMsg, Empty, Order, StdResult, Storage, Uint128,
+    WasmMsg,
+};
+use dao_interface::authorization::{Authorization, AuthorizationResult, SpendUsage};
+use serde::de::IgnoredAny;
+
+use crate::state::{AUTHORIZATIONS, AUTHORIZATION_USAGE};
+
+/// Validates the addresses in an authorization, returning the
+/// authorization with them normalized.
+pub(crate) fn validate_authorization(
+    api: &dyn Api,
+    authorization: Authorization,
+) -> StdResult<Authorization> {
+    Ok(match authorization {
+        Authorization::Any {} => Authorization::Any {},
+        Authorization::BankSend {
+            denom,
+            recipients,
+            limit,
+        } => Authorization::BankSend {
+            denom,
+            recipients: recipients
+                .map(|recipients| {
+                    recipients
+                        .into_iter()
+                        .map(|r| api.addr_validate(&r).map(Addr::into_string))
+                        .collect::<StdResult<Vec<_>>>()
+                })
+                .transpose()?,
+            limit,
+        },
+        Authorization::WasmExecute { contract, msgs } => Authorization::WasmExecute {
+            contract: api.addr_validate(&contract)?.into_string(),
+            msgs,
+        },
+    })
+}
+
+/// Removes all spending recorded against a module's authorizations.
+pub(crate) fn clear_usage(storage: &mut dyn Storage, module: &Addr) -> StdResult<()> {
+    let indexes = AUTHORIZATION_USAGE
+        .prefix(module)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u32>>>()?;
+    for index in indexes {
+        AUTHORIZATION_USAGE.remove(storage, (module, index));
+    }
+    Ok(())
+}
+
+/// Checks `msgs` in order against `module`'s authorizations. Returns
+/// the result for each message and the spending that executing all
+/// permitted messages would record, keyed by authorization index.
+pub(crate) fn authorize_msgs(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    module: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+) -> StdResult<(Vec<AuthorizationResult>, BTreeMap<u32, SpendUsage>)> {
+    let mut usage = BTreeMap::new();
+    let authorizations = match AUTHORIZATIONS.may_load(storage, module)? {
+        Some(authorizations) => authorizations,
+        None => {
+            return Ok((
+                vec![AuthorizationResult::Unrestricted {}; msgs.len()],
+                usage,
+            ))
+        }
+    };
+
+    let mut results = Vec::with_capacity(msgs.len());
+    for msg in msgs {
+        let mut reasons = vec![];
+        let mut allowed = None;
+        for (index, authorization) in authorizations.iter().enumerate() {
+            let index = index as u32;
+            let amount = match check_authorization(authorization, msg) {
+                Ok(amount) => amount,
+                Err(reason) => {
+                    reasons.push(format!("authorization {index}: {reason}"));
+                    continue;
+                }
+            };
+            if let Authorization::BankSend {
+                limit: Some(limit), ..
+            } = authorization
+            {
+                let current = match usage.get(&index) {
+                    Some(current) => Some(current.clone()),
+                    None => AUTHORIZATION_USAGE.may_load(storage, (module, index))?,
+                };
+                let current = match current {
+                    Some(current) if !current.period_end.is_expired(block) => current,
+                    _ => SpendUsage {
+                        spent: Uint128::zero(),
+                        period_end: limit.period.after(block),
+                    },
+                };
+                let spent = current.spent.checked_add(amount)?;
+                if spent > limit.amount {
+                    reasons.push(format!(
+                        "authorization {index}: sending {amount} would exceed the limit of {} per period ({} already spent)",
+                        limit.amount, current.spent
+                    ));
+                    continue;
+                }
+                usage.insert(
+                    index,
+                    SpendUsage {
+                        spent,
+                        period_end: current.period_end,
+                    },
+                );
+            }
+            allowed = Some(index);
+            break;
+        }
+        results.push(match allowed {
+            Some(index) => AuthorizationResult::Allowed { index },
+            None => AuthorizationResult::Denied { reasons },
+        });
+    }
+
+    Ok((results, usage))
+}
+
+/// Checks if an authorization applies to a message, ignoring spend
+/// limits. Returns the amount the message spends if it does, and the
+/// reason it does not otherwise.
+fn check_authorization(
+    authorization: &Authorization,
+    msg: &CosmosMsg<Empty>,
+) -> Result<Uint128, String> {
+    match (authorization, msg) {
+        (Authorization::Any {}, _) => Ok(Uint128::zero()),
+        (
+            Authorization::BankSend {
+                denom, recipients, ..
+            },
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }),
+        ) => {
+            if let Some(recipients) = recipients {
+                if !recipients.contains(to_address) {
+                    return Err(format!("recipient {to_address} is not permitted"));
+                }
+            }
+            amount.iter().try_fold(Uint128::zero(), |total, coin| {
+                if &coin.denom != denom {
+                    Err(format!("sends {} which is not {denom}", coin.denom))
+                } else {
+                    total
+                        .checked_add(coin.amount)
+                        .map_err(|_| "amount overflows".to_string())
+                }
+            })
+        }
+        (Authorization::BankSend { .. }, _) => Err("not a bank send".to_string()),
+        (
+            Authorization::WasmExecute { contract, msgs },
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }),
+        ) => {
+            if contract_addr != contract {
+                return Err(format!("contract {contract_addr} is not {contract}"));
+            }
+            if !funds.is_empty() {
+                return Err("attaches funds".to_string());
+            }
+            if let Some(msgs) = msgs {
+                let variant = from_json::<BTreeMap<String, IgnoredAny>>(msg)
+                    .ok()
+                    .filter(|m| m.len() == 1)
+                    .and_then(|m| m.into_keys().next())
+                    .ok_or_else(|| "message has no variant".to_string())?;
+                if !msgs.contains(&variant) {
+                    return Err(format!("message {variant} is not permitted"));
+                }
+            }
+            Ok(Uint128::zero())
+        }
+        (Authorization::WasmExecute { .. }, _) => Err("not a wasm execute".to_string()),
+    }
+}
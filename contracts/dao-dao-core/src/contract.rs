@@ -1,31 +1,53 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
-    Order, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
+    from_json, to_json_binary, Addr, Binary, BlockInfo, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_paginate_storage::{paginate_map, paginate_map_keys, paginate_map_values};
-use cw_storage_plus::Map;
-use cw_utils::{parse_reply_instantiate_data, Duration};
+use cw_storage_plus::{Bound, Map};
+use cw_utils::{parse_reply_instantiate_data, Duration, Expiration};
 use dao_interface::{
-    msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
+    authorization::{Authorization, AuthorizationResult, ModuleAuthorizationsResponse},
+    msg::{
+        ExecuteMsg, GuardianInfo, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg, ScheduleInfo,
+    },
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, SubDao,
+        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, ScheduledMsgsResponse,
+        SubDao,
+    },
+    ratification::{
+        PendingRatification, PendingRatificationResponse, Ratification, RatificationRule,
     },
+    spending::{SpendDenom, SpendingCap, SpendingCapResponse},
     state::{
-        Admin, Config, ModuleInstantiateCallback, ModuleInstantiateInfo, ProposalModule,
-        ProposalModuleStatus,
+        Admin, Config, Guardians, ModuleInstantiateCallback, ModuleInstantiateInfo, PauseScope,
+        ProposalModule, ProposalModuleStatus, ScheduledMsgs, ScopedPause,
     },
     voting,
 };
+use std::cmp::Ordering;
 
+use crate::authorization::{authorize_msgs, clear_usage, validate_authorization};
 use crate::error::ContractError;
+use crate::spending::{current_spending, enforce_spending_caps, is_treasury_transfer, total_spent};
+#[cfg(feature = "staking")]
+use crate::staking::{
+    compound_rewards, execute_claim_staking_rewards, execute_delegate, execute_redelegate,
+    execute_register_slash, execute_undelegate,
+};
+use crate::staking::{execute_update_staking_config, query_stake, query_unbondings};
 use crate::state::{
-    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CW20_LIST, CW721_LIST, ITEMS, NOMINATED_ADMIN,
-    PAUSED, PROPOSAL_MODULES, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
+    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, AUTHORIZATIONS, AUTHORIZATION_USAGE, CONFIG, CW20_LIST,
+    CW721_LIST, DROPPED_RATIFICATIONS, EXECUTING_MODULES, GUARDIANS, ITEMS, NOMINATED_ADMIN,
+    PAUSED, PAUSED_SCOPES, PENDING_RATIFICATIONS, PROPOSAL_MODULES, RATIFICATION,
+    RATIFICATION_COUNT, SCHEDULED, SCHEDULED_BY_PROPOSAL, SCHEDULED_COUNT, SPENDING_CAPS,
+    SPENDING_CAP_OVERRIDE_MODULES, SPENDS, STAKING_CONFIG, SUBDAO_LIST, SUBDAO_RATIFICATION,
+    TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
 };
+use crate::treasury::query_treasury;
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-dao-core";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -33,6 +55,11 @@ pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const PROPOSAL_MODULE_REPLY_ID: u64 = 0;
 const VOTE_MODULE_INSTANTIATE_REPLY_ID: u64 = 1;
 const VOTE_MODULE_UPDATE_REPLY_ID: u64 = 2;
+const MODULE_EXECUTION_REPLY_ID: u64 = 3;
+/// Reply ID for withdrawals of staking rewards that are being
+/// auto-compounded.
+#[cfg(feature = "staking")]
+pub(crate) const COMPOUND_REWARDS_REPLY_ID: u64 = 4;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -109,9 +136,16 @@ pub fn execute(
     // Check if the DAO is paused
     if let Some(expiration) = PAUSED.may_load(deps.storage)? {
         if !expiration.is_expired(&env.block) {
-            // If paused, then only allow messages from the Admin or DAO itself
+            // If paused, then only allow messages from the Admin or DAO
+            // itself, and guardians responding to the cause of the pause.
+            let guardian_action = matches!(
+                msg,
+                ExecuteMsg::DisableProposalModule { .. } | ExecuteMsg::CancelScheduled { .. }
+            ) && active_guardians(deps.as_ref(), &env, &info.sender)?
+                .is_some();
             if info.sender != env.contract.address
                 && info.sender.clone() != ADMIN.load(deps.storage)?
+                && !guardian_action
             {
                 return Err(ContractError::Paused {});
             }
@@ -119,14 +153,14 @@ pub fn execute(
     }
 
     match msg {
-        ExecuteMsg::ExecuteAdminMsgs { msgs } => {
-            execute_admin_msgs(deps.as_ref(), info.sender, msgs)
-        }
+        ExecuteMsg::ExecuteAdminMsgs { msgs } => execute_admin_msgs(deps, env, info.sender, msgs),
         ExecuteMsg::ExecuteProposalHook { msgs } => {
-            execute_proposal_hook(deps.as_ref(), info.sender, msgs)
+            execute_proposal_hook(deps, env, info.sender, msgs)
+        }
+        ExecuteMsg::Pause { duration, scope } => {
+            execute_pause(deps, env, info.sender, duration, scope)
         }
-        ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
-        ExecuteMsg::Unpause {} => execute_unpause(deps, info.sender),
+        ExecuteMsg::Unpause { scope } => execute_unpause(deps, info.sender, scope),
         ExecuteMsg::Receive(_) => execute_receive_cw20(deps, info.sender),
         ExecuteMsg::ReceiveNft(_) => execute_receive_cw721(deps, info.sender),
         ExecuteMsg::RemoveItem { key } => execute_remove_item(deps, env, info.sender, key),
@@ -141,11 +175,14 @@ pub fn execute(
             execute_update_cw721_list(deps, env, info.sender, to_add, to_remove)
         }
         ExecuteMsg::UpdateVotingModule { module } => {
-            execute_update_voting_module(env, info.sender, module)
+            execute_update_voting_module(deps, env, info.sender, module)
         }
         ExecuteMsg::UpdateProposalModules { to_add, to_disable } => {
             execute_update_proposal_modules(deps, env, info.sender, to_add, to_disable)
         }
+        ExecuteMsg::DisableProposalModule { address } => {
+            execute_disable_proposal_module(deps, env, info.sender, address)
+        }
         ExecuteMsg::NominateAdmin { admin } => {
             execute_nominate_admin(deps, env, info.sender, admin)
         }
@@ -156,31 +193,165 @@ pub fn execute(
         ExecuteMsg::UpdateSubDaos { to_add, to_remove } => {
             execute_update_sub_daos_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::Schedule {
+            proposal_module,
+            proposal_id,
+            schedule,
+        } => execute_schedule(
+            deps,
+            env,
+            info.sender,
+            proposal_module,
+            proposal_id,
+            schedule,
+        ),
+        ExecuteMsg::CancelScheduled {
+            proposal_module,
+            proposal_id,
+        } => execute_cancel_scheduled(deps, env, info.sender, proposal_module, proposal_id),
+        ExecuteMsg::ExecuteScheduled { id } => execute_execute_scheduled(deps, env, id),
+        ExecuteMsg::SetModuleAuthorizations {
+            module,
+            authorizations,
+        } => execute_set_module_authorizations(deps, env, info.sender, module, authorizations),
+        ExecuteMsg::RemoveModuleAuthorizations { module } => {
+            execute_remove_module_authorizations(deps, env, info.sender, module)
+        }
+        ExecuteMsg::UpdateSpendingCaps { to_set, to_remove } => {
+            execute_update_spending_caps(deps, env, info.sender, to_set, to_remove)
+        }
+        ExecuteMsg::UpdateSpendingCapOverrideModules { to_add, to_remove } => {
+            execute_update_spending_cap_override_modules(deps, env, info.sender, to_add, to_remove)
+        }
+        ExecuteMsg::UpdateGuardians { guardians } => {
+            execute_update_guardians(deps, env, info.sender, guardians)
+        }
+        ExecuteMsg::SetRatification { rules } => execute_set_ratification(deps, info.sender, rules),
+        ExecuteMsg::RemoveRatification {} => execute_remove_ratification(deps, info.sender),
+        ExecuteMsg::Ratify { id } => execute_ratify(deps, env, info.sender, id),
+        ExecuteMsg::RejectRatification { id } => execute_reject_ratification(deps, info.sender, id),
+        ExecuteMsg::UpdateStakingConfig { config } => {
+            execute_update_staking_config(deps, env, info.sender, config)
+        }
+        #[cfg(feature = "staking")]
+        ExecuteMsg::Delegate { validator, amount } => {
+            execute_delegate(deps, env, info.sender, validator, amount)
+        }
+        #[cfg(feature = "staking")]
+        ExecuteMsg::Redelegate {
+            src_validator,
+            dst_validator,
+            amount,
+        } => execute_redelegate(deps, env, info.sender, src_validator, dst_validator, amount),
+        #[cfg(feature = "staking")]
+        ExecuteMsg::Undelegate { validator, amount } => {
+            execute_undelegate(deps, env, info.sender, validator, amount)
+        }
+        #[cfg(feature = "staking")]
+        ExecuteMsg::ClaimStakingRewards { validators } => {
+            execute_claim_staking_rewards(deps, env, validators)
+        }
+        #[cfg(feature = "staking")]
+        ExecuteMsg::RegisterSlash {
+            validator,
+            time,
+            amount,
+            during_unbonding,
+        } => execute_register_slash(
+            deps,
+            env,
+            info.sender,
+            validator,
+            time,
+            amount,
+            during_unbonding,
+        ),
+        #[cfg(not(feature = "staking"))]
+        ExecuteMsg::Delegate { .. }
+        | ExecuteMsg::Redelegate { .. }
+        | ExecuteMsg::Undelegate { .. }
+        | ExecuteMsg::ClaimStakingRewards { .. }
+        | ExecuteMsg::RegisterSlash { .. } => Err(ContractError::StakingNotSupported {}),
     }
 }
 
+/// Returns the DAO's guardians if `sender` is one of them and their
+/// powers have not expired.
+fn active_guardians(deps: Deps, env: &Env, sender: &Addr) -> StdResult<Option<Guardians>> {
+    Ok(GUARDIANS.may_load(deps.storage)?.filter(|guardians| {
+        !guardians.expiration.is_expired(&env.block) && guardians.guardians.contains(sender)
+    }))
+}
+
 pub fn execute_pause(
     deps: DepsMut,
     env: Env,
     sender: Addr,
     pause_duration: Duration,
+    scope: Option<PauseScope>,
 ) -> Result<Response, ContractError> {
-    // Only the core contract may call this method.
+    let mut until = pause_duration.after(&env.block);
+    let scope = scope
+        .map(|scope| validate_pause_scope(deps.as_ref(), scope))
+        .transpose()?;
+
+    // Only the core contract and guardians may call this method.
     if sender != env.contract.address {
-        return Err(ContractError::Unauthorized {});
+        let guardians = active_guardians(deps.as_ref(), &env, &sender)?
+            .ok_or(ContractError::Unauthorized {})?;
+        // A guardian pause may not outlast the guardians, otherwise
+        // guardians could pause the DAO indefinitely. Expirations in
+        // different units can not be compared and are rejected.
+        if !matches!(
+            until.partial_cmp(&guardians.expiration),
+            Some(Ordering::Less | Ordering::Equal)
+        ) {
+            return Err(ContractError::GuardianPauseTooLong {
+                expiration: guardians.expiration,
+            });
+        }
+        // Only the DAO may shorten a pause, so a guardian's pause
+        // never ends an existing one early.
+        let existing = match &scope {
+            Some(scope) => PAUSED_SCOPES
+                .may_load(deps.storage, scope.key())?
+                .map(|paused| paused.expiration),
+            None => PAUSED.may_load(deps.storage)?,
+        };
+        if let Some(existing) = existing {
+            if !existing.is_expired(&env.block)
+                && until.partial_cmp(&existing) != Some(Ordering::Greater)
+            {
+                until = existing;
+            }
+        }
     }
 
-    let until = pause_duration.after(&env.block);
-
-    PAUSED.save(deps.storage, &until)?;
+    let scope_key = pause_scope_key(&scope);
+    match scope {
+        Some(scope) => PAUSED_SCOPES.save(
+            deps.storage,
+            scope.key(),
+            &ScopedPause {
+                scope,
+                expiration: until,
+            },
+        )?,
+        None => PAUSED.save(deps.storage, &until)?,
+    }
 
     Ok(Response::new()
         .add_attribute("action", "execute_pause")
         .add_attribute("sender", sender)
+        .add_attribute("scope", scope_key)
         .add_attribute("until", until.to_string()))
 }
 
-pub fn execute_unpause(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+pub fn execute_unpause(
+    deps: DepsMut,
+    sender: Addr,
+    scope: Option<PauseScope>,
+) -> Result<Response, ContractError> {
     let admin = ADMIN.load(deps.storage)?;
 
     // Only the admin can unpause
@@ -188,15 +359,86 @@ pub fn execute_unpause(deps: DepsMut, sender: Addr) -> Result<Response, Contract
         return Err(ContractError::Unauthorized {});
     }
 
-    PAUSED.remove(deps.storage);
+    let scope = scope
+        .map(|scope| validate_pause_scope(deps.as_ref(), scope))
+        .transpose()?;
+    match &scope {
+        Some(scope) => PAUSED_SCOPES.remove(deps.storage, scope.key()),
+        None => PAUSED.remove(deps.storage),
+    }
 
     Ok(Response::new()
         .add_attribute("action", "execute_unpause")
-        .add_attribute("sender", sender))
+        .add_attribute("sender", sender)
+        .add_attribute("scope", pause_scope_key(&scope)))
+}
+
+/// Validates the address of a proposal module scope, so that scopes
+/// are always stored under the same key.
+fn validate_pause_scope(deps: Deps, scope: PauseScope) -> StdResult<PauseScope> {
+    Ok(match scope {
+        PauseScope::ProposalModule { address } => PauseScope::ProposalModule {
+            address: deps.api.addr_validate(&address)?.into_string(),
+        },
+        scope => scope,
+    })
+}
+
+fn pause_scope_key(scope: &Option<PauseScope>) -> String {
+    scope
+        .as_ref()
+        .map(PauseScope::key)
+        .unwrap_or_else(|| "all".to_string())
+}
+
+/// Whether `scope` is currently paused.
+fn is_scope_paused(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    scope: &PauseScope,
+) -> StdResult<bool> {
+    Ok(matches!(
+        PAUSED_SCOPES.may_load(storage, scope.key())?,
+        Some(paused) if !paused.expiration.is_expired(block)
+    ))
+}
+
+/// Errors if `msgs` move tokens out of the treasury while treasury
+/// transfers are paused.
+fn assert_transfers_not_paused(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<(), ContractError> {
+    if msgs.iter().any(is_treasury_transfer)
+        && is_scope_paused(storage, block, &PauseScope::TreasuryTransfers {})?
+    {
+        return Err(ContractError::TreasuryTransfersPaused {});
+    }
+    Ok(())
+}
+
+/// Errors if messages from `module` may not be executed because the
+/// module is paused.
+fn assert_module_not_paused(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    module: &Addr,
+) -> Result<(), ContractError> {
+    let scope = PauseScope::ProposalModule {
+        address: module.to_string(),
+    };
+    if is_scope_paused(storage, block, &scope)? {
+        return Err(ContractError::ProposalModulePaused {
+            address: module.clone(),
+        });
+    }
+    Ok(())
 }
 
 pub fn execute_admin_msgs(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> Result<Response, ContractError> {
@@ -207,13 +449,21 @@ pub fn execute_admin_msgs(
         return Err(ContractError::Unauthorized {});
     }
 
+    // A DAO that is its own admin may reach this via a proposal, so
+    // spending caps must apply here too.
+    if sender == env.contract.address {
+        assert_transfers_not_paused(deps.storage, &env.block, &msgs)?;
+        enforce_spending_caps(deps.storage, &env.block, &msgs)?;
+    }
+
     Ok(Response::default()
         .add_attribute("action", "execute_admin_msgs")
         .add_messages(msgs))
 }
 
 pub fn execute_proposal_hook(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> Result<Response, ContractError> {
@@ -225,10 +475,490 @@ pub fn execute_proposal_hook(
     if module.status != ProposalModuleStatus::Enabled {
         return Err(ContractError::ModuleDisabledCannotExecute { address: sender });
     }
+    assert_module_not_paused(deps.storage, &env.block, &sender)?;
+    assert_transfers_not_paused(deps.storage, &env.block, &msgs)?;
+
+    authorize_module_msgs(deps.storage, &env.block, &sender, &msgs)?;
+    if let Some(id) = hold_for_ratification(deps.storage, &sender, &msgs)? {
+        return Ok(Response::default()
+            .add_attribute("action", "execute_proposal_hook")
+            .add_attribute("pending_ratification", id.to_string()));
+    }
+    if !SPENDING_CAP_OVERRIDE_MODULES.has(deps.storage, &sender) {
+        enforce_spending_caps(deps.storage, &env.block, &msgs)?;
+    }
 
     Ok(Response::default()
         .add_attribute("action", "execute_proposal_hook")
-        .add_messages(msgs))
+        .add_submessages(execute_as_module(deps.storage, &sender, msgs)?))
+}
+
+/// Checks `msgs` against `module`'s authorizations and records the
+/// usage of any authorizations they consume.
+fn authorize_module_msgs(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    module: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<(), ContractError> {
+    let (results, usage) = authorize_msgs(storage, block, module, msgs)?;
+    for (index, result) in results.into_iter().enumerate() {
+        if let AuthorizationResult::Denied { reasons } = result {
+            return Err(ContractError::MsgNotAuthorized {
+                index: index as u32,
+                reasons: reasons.join("; "),
+            });
+        }
+    }
+    for (index, usage) in usage {
+        AUTHORIZATION_USAGE.save(storage, (module, index), &usage)?;
+    }
+    Ok(())
+}
+
+/// Marks `module` as executing `msgs` until the last of them has
+/// run. Messages scheduled in the meantime are attributed to it.
+fn execute_as_module(
+    storage: &mut dyn Storage,
+    module: &Addr,
+    mut msgs: Vec<CosmosMsg<Empty>>,
+) -> StdResult<Vec<SubMsg>> {
+    let last = match msgs.pop() {
+        Some(last) => last,
+        None => return Ok(vec![]),
+    };
+    let mut executing = EXECUTING_MODULES.may_load(storage)?.unwrap_or_default();
+    executing.push(module.clone());
+    EXECUTING_MODULES.save(storage, &executing)?;
+
+    Ok(msgs
+        .into_iter()
+        .map(SubMsg::new)
+        .chain(std::iter::once(SubMsg::reply_on_success(
+            last,
+            MODULE_EXECUTION_REPLY_ID,
+        )))
+        .collect())
+}
+
+/// Holds `msgs` until they are ratified if the DAO's ratification
+/// rules apply to them. Returns the ID the messages are held under.
+fn hold_for_ratification(
+    storage: &mut dyn Storage,
+    proposal_module: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+) -> StdResult<Option<u64>> {
+    let ratification = match RATIFICATION.may_load(storage)? {
+        Some(ratification) => ratification,
+        None => return Ok(None),
+    };
+    if !ratification
+        .rules
+        .iter()
+        .any(|rule| rule.applies(|denom| total_spent(msgs, denom)))
+    {
+        return Ok(None);
+    }
+
+    let id = RATIFICATION_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    RATIFICATION_COUNT.save(storage, &id)?;
+    PENDING_RATIFICATIONS.save(
+        storage,
+        id,
+        &PendingRatification {
+            proposal_module: proposal_module.clone(),
+            msgs: msgs.to_vec(),
+        },
+    )?;
+    Ok(Some(id))
+}
+
+pub fn execute_set_ratification(
+    deps: DepsMut,
+    sender: Addr,
+    rules: Vec<RatificationRule>,
+) -> Result<Response, ContractError> {
+    if sender != ADMIN.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    if rules.is_empty() {
+        return Err(ContractError::NoRatificationRules {});
+    }
+    for rule in rules.iter() {
+        if let RatificationRule::SpendAbove {
+            denom: SpendDenom::Cw20(address),
+            ..
+        } = rule
+        {
+            deps.api.addr_validate(address)?;
+        }
+    }
+
+    RATIFICATION.save(
+        deps.storage,
+        &Ratification {
+            ratifier: sender.clone(),
+            rules,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_ratification")
+        .add_attribute("ratifier", sender))
+}
+
+pub fn execute_remove_ratification(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+    let ratification = RATIFICATION.may_load(deps.storage)?;
+    let is_ratifier = matches!(&ratification, Some(r) if r.ratifier == sender);
+    if !is_ratifier && sender != ADMIN.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Drop pending messages in constant time by marking every ID
+    // issued so far as dropped, rather than removing each of them.
+    RATIFICATION.remove(deps.storage);
+    DROPPED_RATIFICATIONS.save(
+        deps.storage,
+        &RATIFICATION_COUNT
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_ratification")
+        .add_attribute("sender", sender))
+}
+
+/// Loads the messages pending ratification with `id`, erroring if
+/// `sender` is not the ratifier.
+fn load_pending_ratification(
+    deps: Deps,
+    sender: &Addr,
+    id: u64,
+) -> Result<PendingRatification, ContractError> {
+    let ratification = RATIFICATION
+        .may_load(deps.storage)?
+        .ok_or(ContractError::Unauthorized {})?;
+    if ratification.ratifier != *sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if id
+        <= DROPPED_RATIFICATIONS
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+    {
+        return Err(ContractError::NoSuchPendingRatification { id });
+    }
+    PENDING_RATIFICATIONS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::NoSuchPendingRatification { id })
+}
+
+pub fn execute_ratify(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending = load_pending_ratification(deps.as_ref(), &sender, id)?;
+
+    assert_module_not_paused(deps.storage, &env.block, &pending.proposal_module)?;
+    assert_transfers_not_paused(deps.storage, &env.block, &pending.msgs)?;
+    if !SPENDING_CAP_OVERRIDE_MODULES.has(deps.storage, &pending.proposal_module) {
+        enforce_spending_caps(deps.storage, &env.block, &pending.msgs)?;
+    }
+    PENDING_RATIFICATIONS.remove(deps.storage, id);
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_ratify")
+        .add_attribute("id", id.to_string())
+        .add_attribute("proposal_module", pending.proposal_module.clone())
+        .add_submessages(execute_as_module(
+            deps.storage,
+            &pending.proposal_module,
+            pending.msgs,
+        )?))
+}
+
+pub fn execute_reject_ratification(
+    deps: DepsMut,
+    sender: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending = load_pending_ratification(deps.as_ref(), &sender, id)?;
+    PENDING_RATIFICATIONS.remove(deps.storage, id);
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_reject_ratification")
+        .add_attribute("id", id.to_string())
+        .add_attribute("proposal_module", pending.proposal_module))
+}
+
+pub fn execute_set_module_authorizations(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    module: String,
+    authorizations: Vec<Authorization>,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    if authorizations.is_empty() {
+        return Err(ContractError::NoAuthorizations {});
+    }
+
+    let module = deps.api.addr_validate(&module)?;
+    if !PROPOSAL_MODULES.has(deps.storage, module.clone()) {
+        return Err(ContractError::ProposalModuleDoesNotExist { address: module });
+    }
+
+    let authorizations = authorizations
+        .into_iter()
+        .map(|a| validate_authorization(deps.api, a))
+        .collect::<StdResult<Vec<_>>>()?;
+    AUTHORIZATIONS.save(deps.storage, &module, &authorizations)?;
+    clear_usage(deps.storage, &module)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_module_authorizations")
+        .add_attribute("module", module)
+        .add_attribute("count", authorizations.len().to_string()))
+}
+
+pub fn execute_remove_module_authorizations(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    module: String,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let module = deps.api.addr_validate(&module)?;
+    AUTHORIZATIONS.remove(deps.storage, &module);
+    clear_usage(deps.storage, &module)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_module_authorizations")
+        .add_attribute("module", module))
+}
+
+/// Errors if spending caps may not be changed by the DAO. Changes
+/// made while a proposal module's messages are executing must come
+/// from a module exempt from the caps, so that a capped module can not
+/// lift its own caps in the same proposal that spends past them.
+fn assert_may_update_spending_caps(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if *sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    let executing = EXECUTING_MODULES.may_load(storage)?.unwrap_or_default();
+    match executing.last() {
+        Some(module) if !SPENDING_CAP_OVERRIDE_MODULES.has(storage, module) => {
+            Err(ContractError::CappedModuleCanNotUpdateCaps {
+                module: module.clone(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn execute_update_spending_caps(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_set: Vec<SpendingCap>,
+    to_remove: Vec<SpendDenom>,
+) -> Result<Response, ContractError> {
+    assert_may_update_spending_caps(deps.storage, &env, &sender)?;
+
+    for denom in to_remove {
+        SPENDING_CAPS.remove(deps.storage, denom.key());
+        SPENDS.remove(deps.storage, denom.key());
+    }
+    for mut cap in to_set {
+        if let SpendDenom::Cw20(address) = &cap.denom {
+            cap.denom = SpendDenom::Cw20(deps.api.addr_validate(address)?.into_string());
+        }
+        SPENDING_CAPS.save(deps.storage, cap.denom.key(), &cap)?;
+    }
+
+    Ok(Response::default().add_attribute("action", "execute_update_spending_caps"))
+}
+
+pub fn execute_update_spending_cap_override_modules(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    assert_may_update_spending_caps(deps.storage, &env, &sender)?;
+
+    for module in to_add {
+        let module = deps.api.addr_validate(&module)?;
+        if !PROPOSAL_MODULES.has(deps.storage, module.clone()) {
+            return Err(ContractError::ProposalModuleDoesNotExist { address: module });
+        }
+        SPENDING_CAP_OVERRIDE_MODULES.save(deps.storage, &module, &Empty {})?;
+    }
+    for module in to_remove {
+        let module = deps.api.addr_validate(&module)?;
+        SPENDING_CAP_OVERRIDE_MODULES.remove(deps.storage, &module);
+    }
+
+    Ok(Response::default().add_attribute("action", "execute_update_spending_cap_override_modules"))
+}
+
+pub fn execute_schedule(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_module: String,
+    proposal_id: u64,
+    schedule: Vec<ScheduleInfo>,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Scheduled messages are held to the limits of the module they
+    // are attributed to, so only the module whose messages are being
+    // executed may schedule them.
+    let proposal_module = deps.api.addr_validate(&proposal_module)?;
+    let executing = EXECUTING_MODULES
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if executing.last() != Some(&proposal_module) {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !PROPOSAL_MODULES.has(deps.storage, proposal_module.clone()) {
+        return Err(ContractError::ProposalModuleDoesNotExist {
+            address: proposal_module,
+        });
+    }
+
+    let mut count = SCHEDULED_COUNT.may_load(deps.storage)?.unwrap_or_default();
+    let mut ids = Vec::with_capacity(schedule.len());
+    for ScheduleInfo { execute_at, msgs } in schedule {
+        if matches!(execute_at, Expiration::Never {}) {
+            return Err(ContractError::NeverExecutes {});
+        }
+        count += 1;
+        SCHEDULED.save(
+            deps.storage,
+            count,
+            &ScheduledMsgs {
+                proposal_module: proposal_module.clone(),
+                proposal_id,
+                execute_at,
+                msgs,
+            },
+        )?;
+        SCHEDULED_BY_PROPOSAL.save(
+            deps.storage,
+            (&proposal_module, proposal_id, count),
+            &Empty {},
+        )?;
+        ids.push(count.to_string());
+    }
+    SCHEDULED_COUNT.save(deps.storage, &count)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_schedule")
+        .add_attribute("proposal_module", proposal_module)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("ids", ids.join(",")))
+}
+
+pub fn execute_cancel_scheduled(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_module: String,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address && active_guardians(deps.as_ref(), &env, &sender)?.is_none() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let proposal_module = deps.api.addr_validate(&proposal_module)?;
+    let ids = SCHEDULED_BY_PROPOSAL
+        .prefix((&proposal_module, proposal_id))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for id in ids.iter() {
+        SCHEDULED.remove(deps.storage, *id);
+        SCHEDULED_BY_PROPOSAL.remove(deps.storage, (&proposal_module, proposal_id, *id));
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_cancel_scheduled")
+        .add_attribute("proposal_module", proposal_module)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("cancelled", ids.len().to_string()))
+}
+
+pub fn execute_execute_scheduled(
+    deps: DepsMut,
+    env: Env,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let scheduled = SCHEDULED
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::NoSuchScheduled { id })?;
+    if !scheduled.execute_at.is_expired(&env.block) {
+        return Err(ContractError::ScheduledNotReady {
+            id,
+            execute_at: scheduled.execute_at,
+        });
+    }
+
+    assert_module_not_paused(deps.storage, &env.block, &scheduled.proposal_module)?;
+    assert_transfers_not_paused(deps.storage, &env.block, &scheduled.msgs)?;
+
+    SCHEDULED.remove(deps.storage, id);
+    SCHEDULED_BY_PROPOSAL.remove(
+        deps.storage,
+        (&scheduled.proposal_module, scheduled.proposal_id, id),
+    );
+
+    // Scheduled messages are subject to authorizations and
+    // ratification when they are executed, as their contents are not
+    // known when scheduled.
+    authorize_module_msgs(
+        deps.storage,
+        &env.block,
+        &scheduled.proposal_module,
+        &scheduled.msgs,
+    )?;
+    if let Some(pending) =
+        hold_for_ratification(deps.storage, &scheduled.proposal_module, &scheduled.msgs)?
+    {
+        return Ok(Response::default()
+            .add_attribute("action", "execute_execute_scheduled")
+            .add_attribute("id", id.to_string())
+            .add_attribute("pending_ratification", pending.to_string()));
+    }
+    if !SPENDING_CAP_OVERRIDE_MODULES.has(deps.storage, &scheduled.proposal_module) {
+        enforce_spending_caps(deps.storage, &env.block, &scheduled.msgs)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_execute_scheduled")
+        .add_attribute("id", id.to_string())
+        .add_attribute("proposal_module", scheduled.proposal_module.clone())
+        .add_attribute("proposal_id", scheduled.proposal_id.to_string())
+        .add_submessages(execute_as_module(
+            deps.storage,
+            &scheduled.proposal_module,
+            scheduled.msgs,
+        )?))
 }
 
 pub fn execute_nominate_admin(
@@ -333,6 +1063,7 @@ pub fn execute_update_config(
 }
 
 pub fn execute_update_voting_module(
+    deps: DepsMut,
     env: Env,
     sender: Addr,
     module: ModuleInstantiateInfo,
@@ -340,6 +1071,9 @@ pub fn execute_update_voting_module(
     if env.contract.address != sender {
         return Err(ContractError::Unauthorized {});
     }
+    if is_scope_paused(deps.storage, &env.block, &PauseScope::ModuleUpdates {})? {
+        return Err(ContractError::ModuleUpdatesPaused {});
+    }
 
     let wasm = module.into_wasm_msg(env.contract.address);
     let submessage = SubMsg::reply_on_success(wasm, VOTE_MODULE_UPDATE_REPLY_ID);
@@ -359,6 +1093,9 @@ pub fn execute_update_proposal_modules(
     if env.contract.address != sender {
         return Err(ContractError::Unauthorized {});
     }
+    if is_scope_paused(deps.storage, &env.block, &PauseScope::ModuleUpdates {})? {
+        return Err(ContractError::ModuleUpdatesPaused {});
+    }
 
     let disable_count = to_disable.len() as u32;
     for addr in to_disable {
@@ -400,6 +1137,85 @@ pub fn execute_update_proposal_modules(
         .add_submessages(to_add))
 }
 
+pub fn execute_disable_proposal_module(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address && active_guardians(deps.as_ref(), &env, &sender)?.is_none() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut module = PROPOSAL_MODULES
+        .load(deps.storage, address.clone())
+        .map_err(|_| ContractError::ProposalModuleDoesNotExist {
+            address: address.clone(),
+        })?;
+    if module.status == ProposalModuleStatus::Disabled {
+        return Err(ContractError::ModuleAlreadyDisabled { address });
+    }
+    module.status = ProposalModuleStatus::Disabled {};
+    PROPOSAL_MODULES.save(deps.storage, address.clone(), &module)?;
+
+    ACTIVE_PROPOSAL_MODULE_COUNT.update(deps.storage, |count| {
+        if count <= 1 {
+            return Err(ContractError::NoActiveProposalModules {});
+        }
+        Ok(count - 1)
+    })?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_disable_proposal_module")
+        .add_attribute("sender", sender)
+        .add_attribute("address", address))
+}
+
+pub fn execute_update_guardians(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    guardians: Option<GuardianInfo>,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let GuardianInfo {
+        guardians,
+        expiration,
+    } = match guardians {
+        Some(info) => info,
+        None => {
+            GUARDIANS.remove(deps.storage);
+            return Ok(Response::default()
+                .add_attribute("action", "execute_update_guardians")
+                .add_attribute("guardians", "none"));
+        }
+    };
+    if matches!(expiration, Expiration::Never {}) {
+        return Err(ContractError::GuardiansMustExpire {});
+    }
+    let guardians = guardians
+        .into_iter()
+        .map(|addr| deps.api.addr_validate(&addr))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let count = guardians.len();
+    GUARDIANS.save(
+        deps.storage,
+        &Guardians {
+            guardians,
+            expiration,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_guardians")
+        .add_attribute("guardians", count.to_string())
+        .add_attribute("expiration", expiration.to_string()))
+}
+
 /// Updates a set of addresses in state applying VERIFY to each item
 /// that will be added.
 fn do_update_addr_list(
@@ -522,19 +1338,51 @@ pub fn execute_update_sub_daos_list(
         return Err(ContractError::Unauthorized {});
     }
 
+    // Ratification rules are installed on and removed from SubDAOs
+    // as they change. This DAO must be the admin of SubDAOs it
+    // requires ratification of.
+    let mut msgs: Vec<WasmMsg> = vec![];
     for addr in to_remove {
         let addr = deps.api.addr_validate(&addr)?;
         SUBDAO_LIST.remove(deps.storage, &addr);
+        if SUBDAO_RATIFICATION.has(deps.storage, &addr) {
+            SUBDAO_RATIFICATION.remove(deps.storage, &addr);
+            msgs.push(WasmMsg::Execute {
+                contract_addr: addr.into_string(),
+                msg: to_json_binary(&ExecuteMsg::RemoveRatification {})?,
+                funds: vec![],
+            });
+        }
     }
 
     for subdao in to_add {
         let addr = deps.api.addr_validate(&subdao.addr)?;
         SUBDAO_LIST.save(deps.storage, &addr, &subdao.charter)?;
+        let current = SUBDAO_RATIFICATION.may_load(deps.storage, &addr)?;
+        if current == subdao.ratification {
+            continue;
+        }
+        let msg = match subdao.ratification {
+            Some(rules) => {
+                SUBDAO_RATIFICATION.save(deps.storage, &addr, &rules)?;
+                ExecuteMsg::SetRatification { rules }
+            }
+            None => {
+                SUBDAO_RATIFICATION.remove(deps.storage, &addr);
+                ExecuteMsg::RemoveRatification {}
+            }
+        };
+        msgs.push(WasmMsg::Execute {
+            contract_addr: addr.into_string(),
+            msg: to_json_binary(&msg)?,
+            funds: vec![],
+        });
     }
 
     Ok(Response::default()
         .add_attribute("action", "execute_update_sub_daos_list")
-        .add_attribute("sender", sender))
+        .add_attribute("sender", sender)
+        .add_messages(msgs))
 }
 
 pub fn execute_receive_cw20(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
@@ -595,6 +1443,43 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query_list_sub_daos(deps, start_after, limit)
         }
         QueryMsg::DaoURI {} => query_dao_uri(deps),
+        QueryMsg::ListScheduled { start_after, limit } => {
+            query_list_scheduled(deps, start_after, limit)
+        }
+        QueryMsg::ProposalScheduled {
+            proposal_module,
+            proposal_id,
+            start_after,
+            limit,
+        } => query_proposal_scheduled(deps, proposal_module, proposal_id, start_after, limit),
+        QueryMsg::ModuleAuthorizations { module } => query_module_authorizations(deps, module),
+        QueryMsg::ListModuleAuthorizations { start_after, limit } => {
+            query_list_module_authorizations(deps, start_after, limit)
+        }
+        QueryMsg::CheckAuthorizations { module, msgs } => {
+            query_check_authorizations(deps, env, module, msgs)
+        }
+        QueryMsg::SpendingCaps { start_after, limit } => {
+            query_spending_caps(deps, env, start_after, limit)
+        }
+        QueryMsg::SpendingCapOverrideModules { start_after, limit } => {
+            query_spending_cap_override_modules(deps, start_after, limit)
+        }
+        QueryMsg::Guardians {} => to_json_binary(&GUARDIANS.may_load(deps.storage)?),
+        QueryMsg::Ratification {} => to_json_binary(&RATIFICATION.may_load(deps.storage)?),
+        QueryMsg::PendingRatifications { start_after, limit } => {
+            query_pending_ratifications(deps, start_after, limit)
+        }
+        QueryMsg::Treasury {
+            kind,
+            start_after,
+            limit,
+        } => to_json_binary(&query_treasury(deps, env, kind, start_after, limit)?),
+        QueryMsg::StakingConfig {} => to_json_binary(&STAKING_CONFIG.may_load(deps.storage)?),
+        QueryMsg::Stake(msg) => query_stake(deps, msg),
+        QueryMsg::Unbondings { start_after, limit } => {
+            query_unbondings(deps, env, start_after, limit)
+        }
     }
 }
 
@@ -676,15 +1561,23 @@ pub fn query_active_proposal_modules(
 }
 
 fn get_pause_info(deps: Deps, env: Env) -> StdResult<PauseInfoResponse> {
+    let scopes = PAUSED_SCOPES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|kv| match kv {
+            Ok((_, paused)) => !paused.expiration.is_expired(&env.block),
+            Err(_) => true,
+        })
+        .map(|kv| Ok(kv?.1))
+        .collect::<StdResult<Vec<ScopedPause>>>()?;
     Ok(match PAUSED.may_load(deps.storage)? {
         Some(expiration) => {
             if expiration.is_expired(&env.block) {
-                PauseInfoResponse::Unpaused {}
+                PauseInfoResponse::Unpaused { scopes }
             } else {
-                PauseInfoResponse::Paused { expiration }
+                PauseInfoResponse::Paused { expiration, scopes }
             }
         }
-        None => PauseInfoResponse::Unpaused {},
+        None => PauseInfoResponse::Unpaused { scopes },
     })
 }
 
@@ -845,15 +1738,183 @@ pub fn query_list_sub_daos(
 
     let subdaos: Vec<SubDao> = subdaos
         .into_iter()
-        .map(|(address, charter)| SubDao {
-            addr: address.into_string(),
-            charter,
+        .map(|(address, charter)| {
+            Ok(SubDao {
+                ratification: SUBDAO_RATIFICATION.may_load(deps.storage, &address)?,
+                addr: address.into_string(),
+                charter,
+            })
         })
-        .collect();
+        .collect::<StdResult<Vec<SubDao>>>()?;
 
     to_json_binary(&subdaos)
 }
 
+pub fn query_list_scheduled(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let scheduled: Vec<ScheduledMsgsResponse> =
+        paginate_map(deps, &SCHEDULED, start_after, limit, Order::Ascending)?
+            .into_iter()
+            .map(|(id, scheduled)| ScheduledMsgsResponse { id, scheduled })
+            .collect();
+
+    to_json_binary(&scheduled)
+}
+
+pub fn query_pending_ratifications(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let dropped = DROPPED_RATIFICATIONS.may_load(deps.storage)?;
+    let pending: Vec<PendingRatificationResponse> = paginate_map(
+        deps,
+        &PENDING_RATIFICATIONS,
+        start_after.max(dropped),
+        limit,
+        Order::Ascending,
+    )?
+    .into_iter()
+    .map(|(id, pending)| PendingRatificationResponse { id, pending })
+    .collect();
+
+    to_json_binary(&pending)
+}
+
+pub fn query_proposal_scheduled(
+    deps: Deps,
+    proposal_module: String,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let proposal_module = deps.api.addr_validate(&proposal_module)?;
+    let ids = SCHEDULED_BY_PROPOSAL
+        .prefix((&proposal_module, proposal_id))
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.map(|l| l as usize).unwrap_or(usize::MAX))
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    let scheduled = ids
+        .into_iter()
+        .map(|id| {
+            Ok(ScheduledMsgsResponse {
+                id,
+                scheduled: SCHEDULED.load(deps.storage, id)?,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&scheduled)
+}
+
+fn module_authorizations(
+    deps: Deps,
+    module: Addr,
+    authorizations: Vec<Authorization>,
+) -> StdResult<ModuleAuthorizationsResponse> {
+    let usage = (0..authorizations.len() as u32)
+        .map(|index| AUTHORIZATION_USAGE.may_load(deps.storage, (&module, index)))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(ModuleAuthorizationsResponse {
+        module,
+        authorizations,
+        usage,
+    })
+}
+
+pub fn query_module_authorizations(deps: Deps, module: String) -> StdResult<Binary> {
+    let module = deps.api.addr_validate(&module)?;
+    let authorizations = AUTHORIZATIONS
+        .may_load(deps.storage, &module)?
+        .unwrap_or_default();
+    to_json_binary(&module_authorizations(deps, module, authorizations)?)
+}
+
+pub fn query_list_module_authorizations(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let authorizations = paginate_map(
+        deps,
+        &AUTHORIZATIONS,
+        start_after.as_ref(),
+        limit,
+        Order::Ascending,
+    )?
+    .into_iter()
+    .map(|(module, authorizations)| module_authorizations(deps, module, authorizations))
+    .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&authorizations)
+}
+
+pub fn query_check_authorizations(
+    deps: Deps,
+    env: Env,
+    module: String,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> StdResult<Binary> {
+    let module = deps.api.addr_validate(&module)?;
+    let (results, _) = authorize_msgs(deps.storage, &env.block, &module, &msgs)?;
+    to_json_binary(&results)
+}
+
+pub fn query_spending_caps(
+    deps: Deps,
+    env: Env,
+    start_after: Option<SpendDenom>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let caps = paginate_map_values(
+        deps,
+        &SPENDING_CAPS,
+        start_after.map(|denom| denom.key()),
+        limit,
+        Order::Ascending,
+    )?
+    .into_iter()
+    .map(|cap| {
+        let spent = current_spending(deps.storage, &env.block, &cap.denom)
+            .map_err(|e| StdError::generic_err(e.to_string()))?
+            .map(|(_, spent)| spent)
+            .unwrap_or_default();
+        Ok(SpendingCapResponse { cap, spent })
+    })
+    .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&caps)
+}
+
+pub fn query_spending_cap_override_modules(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    to_json_binary(&paginate_map_keys(
+        deps,
+        &SPENDING_CAP_OVERRIDE_MODULES,
+        start_after.as_ref(),
+        limit,
+        Order::Ascending,
+    )?)
+}
+
 pub fn query_dao_uri(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_json_binary(&DaoURIResponse {
@@ -950,7 +2011,8 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+#[cfg_attr(not(feature = "staking"), allow(unused_variables))]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
         PROPOSAL_MODULE_REPLY_ID => {
             let res = parse_reply_instantiate_data(msg)?;
@@ -1017,6 +2079,14 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 
             Ok(Response::default().add_attribute("voting_module", vote_module_addr))
         }
+        MODULE_EXECUTION_REPLY_ID => {
+            let mut executing = EXECUTING_MODULES.load(deps.storage)?;
+            executing.pop();
+            EXECUTING_MODULES.save(deps.storage, &executing)?;
+            Ok(Response::default())
+        }
+        #[cfg(feature = "staking")]
+        COMPOUND_REWARDS_REPLY_ID => compound_rewards(deps, env),
         _ => Err(ContractError::UnknownReplyID {}),
     }
 }
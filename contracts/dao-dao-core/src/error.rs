@@ -1,5 +1,5 @@
-use cosmwasm_std::{Addr, StdError};
-use cw_utils::ParseReplyError;
+use cosmwasm_std::{Addr, StdError, Uint128};
+use cw_utils::{Expiration, ParseReplyError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -16,6 +16,15 @@ pub enum ContractError {
     #[error("The contract is paused.")]
     Paused {},
 
+    #[error("Proposal module ({address}) is paused.")]
+    ProposalModulePaused { address: Addr },
+
+    #[error("Treasury transfers are paused.")]
+    TreasuryTransfersPaused {},
+
+    #[error("Module updates are paused.")]
+    ModuleUpdatesPaused {},
+
     #[error("No voting module provided.")]
     NoVotingModule {},
 
@@ -56,4 +65,65 @@ pub enum ContractError {
 
     #[error("Can not migrate. Current version is up to date.")]
     AlreadyMigrated {},
+
+    #[error("Message {index} is not authorized for this proposal module: {reasons}")]
+    MsgNotAuthorized { index: u32, reasons: String },
+
+    #[error("At least one authorization must be provided.")]
+    NoAuthorizations {},
+
+    #[error("Spending {amount} {denom} would exceed the cap of {cap} ({spent} already spent in the current window).")]
+    SpendingCapExceeded {
+        denom: String,
+        cap: Uint128,
+        spent: Uint128,
+        amount: Uint128,
+    },
+
+    #[error(
+        "Message {index} is a Stargate message, which can not be checked against spending caps."
+    )]
+    UncheckableSpend { index: u32 },
+
+    #[error("Proposal module ({module}) is subject to spending caps and can not change them.")]
+    CappedModuleCanNotUpdateCaps { module: Addr },
+
+    #[error("Scheduled messages must have a finite execution time.")]
+    NeverExecutes {},
+
+    #[error("No scheduled messages with ID ({id}).")]
+    NoSuchScheduled { id: u64 },
+
+    #[error("Scheduled messages with ID ({id}) can not be executed until {execute_at}.")]
+    ScheduledNotReady { id: u64, execute_at: Expiration },
+
+    #[error("At least one ratification rule must be provided.")]
+    NoRatificationRules {},
+
+    #[error("No messages pending ratification with ID ({id}).")]
+    NoSuchPendingRatification { id: u64 },
+
+    #[error("Guardian powers must expire.")]
+    GuardiansMustExpire {},
+
+    #[error("Pauses by guardians must end before {expiration}, when guardian powers expire.")]
+    GuardianPauseTooLong { expiration: Expiration },
+
+    #[error("Staking is not supported on this chain.")]
+    StakingNotSupported {},
+
+    #[error("Staking has not been configured. Set a config with UpdateStakingConfig.")]
+    StakingNotConfigured {},
+
+    #[error("No delegation to validator ({validator}).")]
+    NoDelegation { validator: String },
+
+    #[error("Only {max} tokens may be redelegated immediately.")]
+    NonImmediateRedelegate { max: Uint128 },
+
+    #[error("Slashes can not be registered in the future.")]
+    FutureSlash {},
+
+    #[error("Slash amount must be non-zero.")]
+    NoSlash {},
 }
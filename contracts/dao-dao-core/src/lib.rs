@@ -1,8 +1,12 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+mod authorization;
 pub mod contract;
 mod error;
+mod spending;
+mod staking;
 pub mod state;
+mod treasury;
 
 #[cfg(test)]
 mod tests;
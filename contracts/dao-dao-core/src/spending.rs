@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{
+    from_json, BankMsg, BlockInfo, Coin, CosmosMsg, Empty, IbcMsg, Order, Storage, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw721::Cw721ExecuteMsg;
+use dao_interface::spending::{SpendDenom, SpendRecord};
+
+use crate::error::ContractError;
+use crate::state::{SPENDING_CAPS, SPENDS};
+
+/// Returns the tokens spent by a message that spending caps apply to:
+/// native tokens sent, burned, transferred over IBC or attached to a
+/// contract message, and cw20 tokens transferred, sent, burned or
+/// approved for spending.
+fn spent_by(msg: &CosmosMsg<Empty>) -> Vec<(SpendDenom, Uint128)> {
+    let native = |coins: &[Coin]| -> Vec<(SpendDenom, Uint128)> {
+        coins
+            .iter()
+            .map(|coin| (SpendDenom::Native(coin.denom.clone()), coin.amount))
+            .collect()
+    };
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { amount, .. } | BankMsg::Burn { amount }) => native(amount),
+        CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => native(std::slice::from_ref(amount)),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let mut spent = native(funds);
+            if let Ok(
+                Cw20ExecuteMsg::Transfer { amount, .. }
+                | Cw20ExecuteMsg::Send { amount, .. }
+                | Cw20ExecuteMsg::Burn { amount }
+                | Cw20ExecuteMsg::IncreaseAllowance { amount, .. },
+            ) = from_json(msg)
+            {
+                spent.push((SpendDenom::Cw20(contract_addr.clone()), amount));
+            }
+            spent
+        }
+        CosmosMsg::Wasm(
+            WasmMsg::Instantiate { funds, .. } | WasmMsg::Instantiate2 { funds, .. },
+        ) => native(funds),
+        _ => vec![],
+    }
+}
+
+/// The total amount of `denom` spent by `msgs`.
+pub(crate) fn total_spent(msgs: &[CosmosMsg<Empty>], denom: &SpendDenom) -> Uint128 {
+    msgs.iter()
+        .flat_map(spent_by)
+        .filter(|(spent, _)| spent == denom)
+        .fold(Uint128::zero(), |total, (_, amount)| {
+            total.saturating_add(amount)
+        })
+}
+
+/// Whether a message may move tokens out of the treasury. Covers the
+/// messages spending caps apply to, cw721 transfers and sends, and
+/// Stargate messages, whose effects can not be inspected.
+pub(crate) fn is_treasury_transfer(msg: &CosmosMsg<Empty>) -> bool {
+    if !spent_by(msg).is_empty() {
+        return true;
+    }
+    match msg {
+        CosmosMsg::Stargate { .. } => true,
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => matches!(
+            from_json(msg),
+            Ok(Cw721ExecuteMsg::TransferNft { .. } | Cw721ExecuteMsg::SendNft { .. })
+        ),
+        _ => false,
+    }
+}
+
+/// Sums the spending in the current window of the cap for `denom`,
+/// pruning spends that have left the window. Returns None if the
+/// denom has no cap.
+pub(crate) fn current_spending(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    denom: &SpendDenom,
+) -> Result<Option<(Vec<SpendRecord>, Uint128)>, ContractError> {
+    let cap = match SPENDING_CAPS.may_load(storage, denom.key())? {
+        Some(cap) => cap,
+        None => return Ok(None),
+    };
+    let records: Vec<SpendRecord> = SPENDS
+        .may_load(storage, denom.key())?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|record| record.in_window(&cap.window, block))
+        .collect();
+    let spent = records
+        .iter()
+        .try_fold(Uint128::zero(), |total, record| {
+            total.checked_add(record.amount)
+        })
+        .map_err(|_| ContractError::Overflow {})?;
+    Ok(Some((records, spent)))
+}
+
+/// Records the tokens spent by `msgs`, erroring if doing so would
+/// exceed any spending cap. As the tokens Stargate messages spend can
+/// not be known, they are rejected while any cap is set.
+pub(crate) fn enforce_spending_caps(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<(), ContractError> {
+    if let Some(index) = msgs
+        .iter()
+        .position(|msg| matches!(msg, CosmosMsg::Stargate { .. }))
+    {
+        if SPENDING_CAPS
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+        {
+            return Err(ContractError::UncheckableSpend {
+                index: index as u32,
+            });
+        }
+    }
+
+    let mut spends: BTreeMap<String, (SpendDenom, Uint128)> = BTreeMap::new();
+    for (denom, amount) in msgs.iter().flat_map(spent_by) {
+        let entry = spends
+            .entry(denom.key())
+            .or_insert((denom, Uint128::zero()));
+        entry.1 = entry
+            .1
+            .checked_add(amount)
+            .map_err(|_| ContractError::Overflow {})?;
+    }
+
+    for (key, (denom, amount)) in spends {
+        let (mut records, spent) = match current_spending(storage, block, &denom)? {
+            Some(current) => current,
+            None => continue,
+        };
+        let cap = SPENDING_CAPS.load(storage, key.clone())?;
+        if spent
+            .checked_add(amount)
+            .map_err(|_| ContractError::Overflow {})?
+            > cap.amount
+        {
+            return Err(ContractError::SpendingCapExceeded {
+                denom: denom.to_string(),
+                cap: cap.amount,
+                spent,
+                amount,
+            });
+        }
+        records.push(SpendRecord {
+            amount,
+            height: block.height,
+            time: block.time,
+        });
+        SPENDS.save(storage, key, &records)?;
+    }
+    Ok(())
+}
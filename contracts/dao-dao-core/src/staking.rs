@@ -0,0 +1,342 @@
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, DepsMut, Env, Order, Response, StdResult};
+#[cfg(feature = "staking")]
+use cosmwasm_std::{
+    Coin, DelegationResponse, DistributionMsg, StakingMsg, StakingQuery, Storage, SubMsg,
+    Timestamp, Uint128,
+};
+use cw_stake_tracker::StakeTrackerQuery;
+use cw_storage_plus::Bound;
+#[cfg(feature = "staking")]
+use dao_interface::staking::Unbonding;
+use dao_interface::staking::{StakingConfig, UnbondingResponse};
+
+#[cfg(feature = "staking")]
+use crate::contract::COMPOUND_REWARDS_REPLY_ID;
+use crate::error::ContractError;
+#[cfg(feature = "staking")]
+use crate::state::{Compounding, COMPOUNDING, UNBONDING_COUNT};
+use crate::state::{STAKE, STAKING_CONFIG, UNBONDINGS};
+
+pub(crate) fn execute_update_staking_config(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    config: StakingConfig,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    STAKING_CONFIG.save(deps.storage, &config)?;
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_staking_config")
+        .add_attribute(
+            "unbonding_duration_seconds",
+            config.unbonding_duration_seconds.to_string(),
+        )
+        .add_attribute("auto_compound", config.auto_compound.to_string()))
+}
+
+/// Checks that `sender` is the DAO and that staking has been
+/// configured. Returns the config.
+#[cfg(feature = "staking")]
+fn assert_can_stake(deps: Deps, env: &Env, sender: &Addr) -> Result<StakingConfig, ContractError> {
+    if *sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    STAKING_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::StakingNotConfigured {})
+}
+
+#[cfg(feature = "staking")]
+pub(crate) fn execute_delegate(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    validator: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    assert_can_stake(deps.as_ref(), &env, &sender)?;
+
+    let denom = deps.querier.query_bonded_denom()?;
+    STAKE.on_delegate(deps.storage, env.block.time, validator.clone(), amount)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_delegate")
+        .add_attribute("validator", validator.clone())
+        .add_attribute("amount", amount)
+        .add_message(StakingMsg::Delegate {
+            validator,
+            amount: Coin { denom, amount },
+        }))
+}
+
+#[cfg(feature = "staking")]
+pub(crate) fn execute_redelegate(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    src_validator: String,
+    dst_validator: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    assert_can_stake(deps.as_ref(), &env, &sender)?;
+
+    let resp: DelegationResponse = deps.querier.query(
+        &StakingQuery::Delegation {
+            delegator: env.contract.address.to_string(),
+            validator: src_validator.clone(),
+        }
+        .into(),
+    )?;
+    let delegation = resp.delegation.ok_or(ContractError::NoDelegation {
+        validator: src_validator.clone(),
+    })?;
+    // The stake tracker only supports redelegation of tokens that
+    // can be redelegated immediately.
+    if delegation.can_redelegate.amount < amount {
+        return Err(ContractError::NonImmediateRedelegate {
+            max: delegation.can_redelegate.amount,
+        });
+    }
+
+    STAKE.on_redelegate(
+        deps.storage,
+        env.block.time,
+        src_validator.clone(),
+        dst_validator.clone(),
+        amount,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_redelegate")
+        .add_attribute("src_validator", src_validator.clone())
+        .add_attribute("dst_validator", dst_validator.clone())
+        .add_attribute("amount", amount)
+        .add_message(StakingMsg::Redelegate {
+            src_validator,
+            dst_validator,
+            amount: Coin {
+                denom: delegation.amount.denom,
+                amount,
+            },
+        }))
+}
+
+#[cfg(feature = "staking")]
+pub(crate) fn execute_undelegate(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    validator: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = assert_can_stake(deps.as_ref(), &env, &sender)?;
+
+    remove_completed_unbondings(deps.storage, env.block.time)?;
+
+    let denom = deps.querier.query_bonded_denom()?;
+    STAKE.on_undelegate(
+        deps.storage,
+        env.block.time,
+        validator.clone(),
+        amount,
+        config.unbonding_duration_seconds,
+    )?;
+    let id = UNBONDING_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    UNBONDING_COUNT.save(deps.storage, &id)?;
+    UNBONDINGS.save(
+        deps.storage,
+        id,
+        &Unbonding {
+            validator: validator.clone(),
+            amount,
+            completes_at: env
+                .block
+                .time
+                .plus_seconds(config.unbonding_duration_seconds),
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_undelegate")
+        .add_attribute("validator", validator.clone())
+        .add_attribute("amount", amount)
+        .add_attribute("unbonding_id", id.to_string())
+        .add_message(StakingMsg::Undelegate {
+            validator,
+            amount: Coin { denom, amount },
+        }))
+}
+
+/// Removes unbondings that have completed. Unbondings are stored in
+/// the order they were made, so this stops at the first one that has
+/// not completed.
+#[cfg(feature = "staking")]
+fn remove_completed_unbondings(storage: &mut dyn Storage, now: Timestamp) -> StdResult<()> {
+    let completed = UNBONDINGS
+        .range(storage, None, None, Order::Ascending)
+        .take_while(|entry| match entry {
+            Ok((_, unbonding)) => unbonding.completes_at <= now,
+            Err(_) => true,
+        })
+        .map(|entry| entry.map(|(id, _)| id))
+        .collect::<StdResult<Vec<u64>>>()?;
+    for id in completed {
+        UNBONDINGS.remove(storage, id);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "staking")]
+pub(crate) fn execute_claim_staking_rewards(
+    deps: DepsMut,
+    env: Env,
+    validators: Vec<String>,
+) -> Result<Response, ContractError> {
+    let auto_compound = matches!(
+        STAKING_CONFIG.may_load(deps.storage)?,
+        Some(StakingConfig {
+            auto_compound: true,
+            ..
+        })
+    );
+
+    let withdrawals = validators.iter().map(|validator| {
+        let msg = DistributionMsg::WithdrawDelegatorReward {
+            validator: validator.clone(),
+        };
+        if auto_compound {
+            SubMsg::reply_on_success(msg, COMPOUND_REWARDS_REPLY_ID)
+        } else {
+            SubMsg::new(msg)
+        }
+    });
+    let response = Response::default()
+        .add_attribute("action", "execute_claim_staking_rewards")
+        .add_attribute("validators", validators.len().to_string())
+        .add_attribute("auto_compound", auto_compound.to_string())
+        .add_submessages(withdrawals);
+
+    if auto_compound && !validators.is_empty() {
+        let denom = deps.querier.query_bonded_denom()?;
+        let baseline = deps
+            .querier
+            .query_balance(&env.contract.address, denom)?
+            .amount;
+        COMPOUNDING.save(
+            deps.storage,
+            &Compounding {
+                baseline,
+                validators,
+            },
+        )?;
+    }
+
+    Ok(response)
+}
+
+/// Handles the reply to a reward withdrawal made by
+/// `ClaimStakingRewards` while auto-compounding. Rewards are the
+/// amount the DAO's balance has grown by since the withdrawals began,
+/// and are delegated back to the validator they were withdrawn from
+/// before the next withdrawal, so the balance returns to its baseline.
+#[cfg(feature = "staking")]
+pub(crate) fn compound_rewards(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let mut compounding = COMPOUNDING.load(deps.storage)?;
+    let validator = compounding.validators.remove(0);
+    if compounding.validators.is_empty() {
+        COMPOUNDING.remove(deps.storage);
+    } else {
+        COMPOUNDING.save(deps.storage, &compounding)?;
+    }
+
+    let denom = deps.querier.query_bonded_denom()?;
+    let balance = deps
+        .querier
+        .query_balance(&env.contract.address, denom.clone())?
+        .amount;
+    let rewards = balance.saturating_sub(compounding.baseline);
+
+    let response = Response::default()
+        .add_attribute("action", "compound_rewards")
+        .add_attribute("validator", validator.clone())
+        .add_attribute("amount", rewards);
+    if rewards.is_zero() {
+        return Ok(response);
+    }
+
+    STAKE.on_delegate(deps.storage, env.block.time, validator.clone(), rewards)?;
+    Ok(response.add_message(StakingMsg::Delegate {
+        validator,
+        amount: Coin {
+            denom,
+            amount: rewards,
+        },
+    }))
+}
+
+#[cfg(feature = "staking")]
+pub(crate) fn execute_register_slash(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    validator: String,
+    time: Timestamp,
+    amount: Uint128,
+    during_unbonding: bool,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    if time > env.block.time {
+        return Err(ContractError::FutureSlash {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::NoSlash {});
+    }
+
+    if during_unbonding {
+        STAKE.on_unbonding_slash(deps.storage, time, validator.clone(), amount)?;
+    } else {
+        STAKE.on_bonded_slash(deps.storage, time, validator.clone(), amount)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_register_slash")
+        .add_attribute("validator", validator)
+        .add_attribute("time", time.to_string())
+        .add_attribute("amount", amount)
+        .add_attribute("during_unbonding", during_unbonding.to_string()))
+}
+
+pub(crate) fn query_stake(deps: Deps, msg: StakeTrackerQuery) -> StdResult<Binary> {
+    STAKE.query(deps.storage, msg)
+}
+
+pub(crate) fn query_unbondings(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let unbondings = UNBONDINGS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .filter(|entry| match entry {
+            Ok((_, unbonding)) => unbonding.completes_at > env.block.time,
+            Err(_) => true,
+        })
+        .map(|entry| entry.map(|(id, unbonding)| UnbondingResponse { id, unbonding }));
+    let unbondings = match limit {
+        Some(limit) => unbondings
+            .take(limit as usize)
+            .collect::<StdResult<Vec<_>>>()?,
+        None => unbondings.collect::<StdResult<Vec<_>>>()?,
+    };
+    to_json_binary(&unbondings)
+}
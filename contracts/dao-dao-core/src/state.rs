@@ -1,7 +1,15 @@
-use cosmwasm_std::{Addr, Empty};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_stake_tracker::StakeTracker;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Expiration;
-use dao_interface::state::{Config, ProposalModule};
+use dao_interface::{
+    authorization::{Authorization, SpendUsage},
+    ratification::{PendingRatification, Ratification, RatificationRule},
+    spending::{SpendRecord, SpendingCap},
+    staking::{StakingConfig, Unbonding},
+    state::{Config, Guardians, ProposalModule, ScheduledMsgs, ScopedPause},
+};
 
 /// The admin of the contract. Typically a DAO. The contract admin may
 /// unilaterally execute messages on this contract.
@@ -27,6 +35,10 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 /// the DAO has never been paused.
 pub const PAUSED: Item<Expiration> = Item::new("paused");
 
+/// Pauses of parts of the DAO, keyed by `PauseScope::key`. Entries
+/// are removed when unpaused and ignored once expired.
+pub const PAUSED_SCOPES: Map<String, ScopedPause> = Map::new("paused_scopes");
+
 /// The voting module associated with this contract.
 pub const VOTING_MODULE: Item<Addr> = Item::new("voting_module");
 
@@ -53,3 +65,99 @@ pub const CW721_LIST: Map<Addr, Empty> = Map::new("cw721s");
 
 /// List of SubDAOs associated to this DAO. Each SubDAO has an optional charter.
 pub const SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
+
+/// Messages scheduled by proposals for later execution, keyed by an
+/// incrementing ID. Entries are removed once executed or cancelled.
+pub const SCHEDULED: Map<u64, ScheduledMsgs> = Map::new("scheduled");
+
+/// The number of message batches that have ever been scheduled. Used
+/// to assign IDs to new entries in `SCHEDULED`.
+pub const SCHEDULED_COUNT: Item<u64> = Item::new("scheduled_count");
+
+/// Proposal modules whose messages are currently being executed by
+/// the DAO, innermost last. Pushed before a module's messages are
+/// dispatched and popped once the last of them has run, so that
+/// messages scheduled during execution are attributed to the module
+/// that actually executed them.
+pub const EXECUTING_MODULES: Item<Vec<Addr>> = Item::new("executing_modules");
+
+/// Index of pending scheduled messages by (proposal module, proposal
+/// ID, scheduled ID). Allows all of a proposal's scheduled messages
+/// to be found and cancelled.
+pub const SCHEDULED_BY_PROPOSAL: Map<(&Addr, u64, u64), Empty> = Map::new("scheduled_by_proposal");
+
+/// Authorizations restricting the messages a proposal module may
+/// execute. Modules without an entry may execute any message.
+pub const AUTHORIZATIONS: Map<&Addr, Vec<Authorization>> = Map::new("authorizations");
+
+/// Spending recorded against a module's authorizations, keyed by
+/// (module, authorization index).
+pub const AUTHORIZATION_USAGE: Map<(&Addr, u32), SpendUsage> = Map::new("authorization_usage");
+
+/// Caps on the amount of a token proposal modules may spend over a
+/// rolling window, keyed by `SpendDenom::key`.
+pub const SPENDING_CAPS: Map<String, SpendingCap> = Map::new("spending_caps");
+
+/// Spends counted towards each spending cap that may still be within
+/// the cap's window, keyed by `SpendDenom::key`.
+pub const SPENDS: Map<String, Vec<SpendRecord>> = Map::new("spends");
+
+/// Proposal modules that are exempt from spending caps.
+pub const SPENDING_CAP_OVERRIDE_MODULES: Map<&Addr, Empty> =
+    Map::new("spending_cap_override_modules");
+
+/// Addresses that may take emergency actions on behalf of the DAO
+/// until their powers expire. Not set if the DAO has no guardians.
+pub const GUARDIANS: Item<Guardians> = Item::new("guardians");
+
+/// The ratification required of this DAO by its parent. Not set if
+/// no ratification is required.
+pub const RATIFICATION: Item<Ratification> = Item::new("ratification");
+
+/// Messages held until ratified by the parent DAO, keyed by an
+/// incrementing ID. Entries are removed once ratified or rejected.
+pub const PENDING_RATIFICATIONS: Map<u64, PendingRatification> = Map::new("pending_ratifications");
+
+/// Messages pending ratification with IDs up to and including this
+/// one were dropped when ratification was last removed. They are left
+/// in `PENDING_RATIFICATIONS` so that removal does not need to iterate
+/// over them, but may no longer be ratified, rejected or listed.
+pub const DROPPED_RATIFICATIONS: Item<u64> = Item::new("dropped_ratifications");
+
+/// The number of message batches that have ever been held for
+/// ratification. Used to assign IDs to new entries in
+/// `PENDING_RATIFICATIONS`.
+pub const RATIFICATION_COUNT: Item<u64> = Item::new("ratification_count");
+
+/// Ratification rules this DAO has installed on its SubDAOs.
+pub const SUBDAO_RATIFICATION: Map<&Addr, Vec<RatificationRule>> = Map::new("subdao_ratification");
+
+/// How the DAO stakes its native tokens. Not set if the DAO has not
+/// configured staking.
+pub const STAKING_CONFIG: Item<StakingConfig> = Item::new("staking_config");
+
+/// The DAO's bonded and unbonding native tokens.
+pub const STAKE: StakeTracker =
+    StakeTracker::new("stake_total", "stake_validators", "stake_cardinality");
+
+/// Tokens being unbonded, keyed by an incrementing ID. Entries are
+/// removed once the tokens are returned.
+pub const UNBONDINGS: Map<u64, Unbonding> = Map::new("unbondings");
+
+/// The number of undelegations that have ever been made. Used to
+/// assign IDs to new entries in `UNBONDINGS`.
+pub const UNBONDING_COUNT: Item<u64> = Item::new("unbonding_count");
+
+/// Rewards being withdrawn for auto-compounding.
+#[cw_serde]
+pub struct Compounding {
+    /// The DAO's balance of the staking denom before rewards were
+    /// withdrawn.
+    pub baseline: Uint128,
+    /// The validators rewards are still to be withdrawn from, in the
+    /// order they will be withdrawn.
+    pub validators: Vec<String>,
+}
+
+/// Set while `ClaimStakingRewards` is auto-compounding rewards.
+pub const COMPOUNDING: Item<Compounding> = Item::new("compounding");
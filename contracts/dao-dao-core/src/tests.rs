@@ -1,20 +1,37 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    from_json,
+    coin, coins, from_json,
     testing::{mock_dependencies, mock_env},
-    to_json_binary, Addr, CosmosMsg, Empty, Storage, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Empty, IbcMsg, IbcTimeout, Storage,
+    Timestamp, Uint128, Validator, WasmMsg,
 };
 use cw2::{set_contract_version, ContractVersion};
-use cw_multi_test::{App, Executor};
+use cw_multi_test::{App, AppBuilder, BankSudo, Executor, StakingInfo};
+use cw_stake_tracker::StakeTrackerQuery;
 use cw_storage_plus::{Item, Map};
 use cw_utils::{Duration, Expiration};
 use dao_interface::{
-    msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
+    authorization::{
+        Authorization, AuthorizationResult, ModuleAuthorizationsResponse, SpendLimit, SpendUsage,
+    },
+    msg::{
+        ExecuteMsg, GuardianInfo, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg, ScheduleInfo,
+    },
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, SubDao,
+        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, ScheduledMsgsResponse,
+        SubDao,
+    },
+    ratification::{
+        PendingRatification, PendingRatificationResponse, Ratification, RatificationRule,
+    },
+    spending::{SpendDenom, SpendingCap, SpendingCapResponse},
+    staking::{StakingConfig, Unbonding, UnbondingResponse},
+    state::{
+        Admin, Config, Guardians, ModuleInstantiateInfo, PauseScope, ProposalModule,
+        ProposalModuleStatus, ScheduledMsgs, ScopedPause,
     },
-    state::{Admin, Config, ModuleInstantiateInfo, ProposalModule, ProposalModuleStatus},
+    treasury::{TreasuryAsset, TreasuryItem, TreasuryKind, TreasuryResponse},
     voting::{InfoResponse, VotingPowerAtHeightResponse},
 };
 use dao_testing::contracts::{
@@ -24,7 +41,7 @@ use dao_testing::contracts::{
 
 use crate::{
     contract::{derive_proposal_module_prefix, migrate, CONTRACT_NAME, CONTRACT_VERSION},
-    state::PROPOSAL_MODULES,
+    state::{EXECUTING_MODULES, PROPOSAL_MODULES},
 };
 use dao_dao_core::ContractError;
 
@@ -919,7 +936,10 @@ fn test_permissions() {
 }
 
 fn do_standard_instantiate(auto_add: bool, admin: Option<String>) -> (Addr, App) {
-    let mut app = App::default();
+    instantiate_with_app(App::default(), auto_add, admin)
+}
+
+fn instantiate_with_app(mut app: App, auto_add: bool, admin: Option<String>) -> (Addr, App) {
     let govmod_id = app.store_code(dao_proposal_sudo_contract());
     let voting_id = app.store_code(dao_voting_cw20_balance_contract());
     let gov_id = app.store_code(dao_dao_core_contract());
@@ -1010,6 +1030,7 @@ fn test_admin_permissions() {
                 contract_addr: core_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1029,6 +1050,7 @@ fn test_admin_permissions() {
                 contract_addr: core_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1083,6 +1105,7 @@ fn test_admin_permissions() {
                 contract_addr: core_with_admin_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1099,6 +1122,7 @@ fn test_admin_permissions() {
         core_with_admin_addr.clone(),
         &ExecuteMsg::Pause {
             duration: Duration::Height(10),
+            scope: None,
         },
         &[],
     );
@@ -1110,6 +1134,7 @@ fn test_admin_permissions() {
         core_with_admin_addr.clone(),
         &ExecuteMsg::Pause {
             duration: Duration::Height(10),
+            scope: None,
         },
         &[],
     );
@@ -1124,6 +1149,7 @@ fn test_admin_permissions() {
                 contract_addr: core_with_admin_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1142,7 +1168,8 @@ fn test_admin_permissions() {
     assert_eq!(
         paused,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
+            expiration: Expiration::AtHeight(start_height + 10),
+            scopes: vec![],
         }
     );
 
@@ -1154,7 +1181,7 @@ fn test_admin_permissions() {
         .wrap()
         .query_wasm_smart(core_with_admin_addr.clone(), &QueryMsg::PauseInfo {})
         .unwrap();
-    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    assert_eq!(paused, PauseInfoResponse::Unpaused { scopes: vec![] });
 
     // Admin pauses DAO again
     let res = app.execute_contract(
@@ -1165,6 +1192,7 @@ fn test_admin_permissions() {
                 contract_addr: core_with_admin_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1179,7 +1207,7 @@ fn test_admin_permissions() {
     let res = app.execute_contract(
         core_with_admin_addr.clone(),
         core_with_admin_addr.clone(),
-        &ExecuteMsg::Unpause {},
+        &ExecuteMsg::Unpause { scope: None },
         &[],
     );
     assert!(res.is_err());
@@ -1188,7 +1216,7 @@ fn test_admin_permissions() {
     let res = app.execute_contract(
         Addr::unchecked("random"),
         core_with_admin_addr.clone(),
-        &ExecuteMsg::Unpause {},
+        &ExecuteMsg::Unpause { scope: None },
         &[],
     );
     assert!(res.is_err());
@@ -1197,7 +1225,7 @@ fn test_admin_permissions() {
     let res = app.execute_contract(
         Addr::unchecked("admin"),
         core_with_admin_addr.clone(),
-        &ExecuteMsg::Unpause {},
+        &ExecuteMsg::Unpause { scope: None },
         &[],
     );
     assert!(res.is_ok());
@@ -1207,7 +1235,7 @@ fn test_admin_permissions() {
         .wrap()
         .query_wasm_smart(core_with_admin_addr.clone(), &QueryMsg::PauseInfo {})
         .unwrap();
-    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    assert_eq!(paused, PauseInfoResponse::Unpaused { scopes: vec![] });
 
     // Admin can nominate a new admin.
     let res = app.execute_contract(
@@ -1430,6 +1458,7 @@ fn test_admin_nomination() {
                     contract_addr: core_addr.to_string(),
                     msg: to_json_binary(&ExecuteMsg::Pause {
                         duration: Duration::Height(10),
+                        scope: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -1451,6 +1480,7 @@ fn test_admin_nomination() {
                 contract_addr: core_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1468,7 +1498,8 @@ fn test_admin_nomination() {
     assert_eq!(
         paused,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
+            expiration: Expiration::AtHeight(start_height + 10),
+            scopes: vec![],
         }
     );
 
@@ -2359,12 +2390,15 @@ fn test_pause() {
         .wrap()
         .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
         .unwrap();
-    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    assert_eq!(paused, PauseInfoResponse::Unpaused { scopes: vec![] });
     let all_state: DumpStateResponse = app
         .wrap()
         .query_wasm_smart(core_addr.clone(), &QueryMsg::DumpState {})
         .unwrap();
-    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
+    assert_eq!(
+        all_state.pause_info,
+        PauseInfoResponse::Unpaused { scopes: vec![] }
+    );
 
     // DAO is not paused. Check that we can execute things.
     //
@@ -2396,6 +2430,7 @@ fn test_pause() {
             core_addr.clone(),
             &ExecuteMsg::Pause {
                 duration: Duration::Height(10),
+                scope: None,
             },
             &[],
         )
@@ -2415,6 +2450,7 @@ fn test_pause() {
                 contract_addr: core_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -2432,7 +2468,8 @@ fn test_pause() {
     assert_eq!(
         paused,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
+            expiration: Expiration::AtHeight(start_height + 10),
+            scopes: vec![],
         }
     );
     let all_state: DumpStateResponse = app
@@ -2442,7 +2479,8 @@ fn test_pause() {
     assert_eq!(
         all_state.pause_info,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 10)
+            expiration: Expiration::AtHeight(start_height + 10),
+            scopes: vec![],
         }
     );
 
@@ -2473,6 +2511,7 @@ fn test_pause() {
                     contract_addr: core_addr.to_string(),
                     msg: to_json_binary(&ExecuteMsg::Pause {
                         duration: Duration::Height(10),
+                        scope: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -2499,6 +2538,7 @@ fn test_pause() {
                     contract_addr: core_addr.to_string(),
                     msg: to_json_binary(&ExecuteMsg::Pause {
                         duration: Duration::Height(10),
+                        scope: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -2519,12 +2559,15 @@ fn test_pause() {
         .wrap()
         .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
         .unwrap();
-    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    assert_eq!(paused, PauseInfoResponse::Unpaused { scopes: vec![] });
     let all_state: DumpStateResponse = app
         .wrap()
         .query_wasm_smart(core_addr.clone(), &QueryMsg::DumpState {})
         .unwrap();
-    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
+    assert_eq!(
+        all_state.pause_info,
+        PauseInfoResponse::Unpaused { scopes: vec![] }
+    );
 
     // Now its unpaused so we should be able to pause again.
     app.execute_contract(
@@ -2535,6 +2578,7 @@ fn test_pause() {
                 contract_addr: core_addr.to_string(),
                 msg: to_json_binary(&ExecuteMsg::Pause {
                     duration: Duration::Height(10),
+                    scope: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -2552,7 +2596,8 @@ fn test_pause() {
     assert_eq!(
         paused,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 20)
+            expiration: Expiration::AtHeight(start_height + 20),
+            scopes: vec![],
         }
     );
     let all_state: DumpStateResponse = app
@@ -2562,7 +2607,8 @@ fn test_pause() {
     assert_eq!(
         all_state.pause_info,
         PauseInfoResponse::Paused {
-            expiration: Expiration::AtHeight(start_height + 20)
+            expiration: Expiration::AtHeight(start_height + 20),
+            scopes: vec![],
         }
     );
 }
@@ -2588,7 +2634,10 @@ fn test_dump_state_proposal_modules() {
         .wrap()
         .query_wasm_smart(core_addr, &QueryMsg::DumpState {})
         .unwrap();
-    assert_eq!(all_state.pause_info, PauseInfoResponse::Unpaused {});
+    assert_eq!(
+        all_state.pause_info,
+        PauseInfoResponse::Unpaused { scopes: vec![] }
+    );
     assert_eq!(all_state.proposal_modules.len(), 1);
     assert_eq!(all_state.proposal_modules[0], proposal_module);
 }
@@ -3030,18 +3079,22 @@ fn test_add_remove_subdaos() {
         SubDao {
             addr: "subdao001".to_string(),
             charter: None,
+            ratification: None,
         },
         SubDao {
             addr: "subdao002".to_string(),
             charter: Some("cool charter bro".to_string()),
+            ratification: None,
         },
         SubDao {
             addr: "subdao005".to_string(),
             charter: None,
+            ratification: None,
         },
         SubDao {
             addr: "subdao007".to_string(),
             charter: None,
+            ratification: None,
         },
     ];
     let to_remove: Vec<String> = vec![];
@@ -3096,6 +3149,7 @@ fn test_add_remove_subdaos() {
     let test_res: SubDao = SubDao {
         addr: "subdao002".to_string(),
         charter: Some("cool charter bro".to_string()),
+        ratification: None,
     };
 
     assert_eq!(res[1], test_res);
@@ -3104,14 +3158,17 @@ fn test_add_remove_subdaos() {
         SubDao {
             addr: "subdao001".to_string(),
             charter: None,
+            ratification: None,
         },
         SubDao {
             addr: "subdao002".to_string(),
             charter: Some("cool charter bro".to_string()),
+            ratification: None,
         },
         SubDao {
             addr: "subdao007".to_string(),
             charter: None,
+            ratification: None,
         },
     ];
 
@@ -3145,3 +3202,2436 @@ fn test_query_info() {
         }
     )
 }
+
+#[test]
+fn test_scheduled_execution() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let set_item = |key: &str| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetItem {
+                key: key.to_string(),
+                value: "value".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+    let schedule = |proposal_id: u64, execute_at: Expiration, key: &str| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::Schedule {
+                proposal_module: proposal_module.address.to_string(),
+                proposal_id,
+                schedule: vec![ScheduleInfo {
+                    execute_at,
+                    msgs: vec![set_item(key)],
+                }],
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+
+    // Only the DAO may schedule messages.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Schedule {
+                proposal_module: proposal_module.address.to_string(),
+                proposal_id: 1,
+                schedule: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let height = app.block_info().height;
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![
+                schedule(1, Expiration::AtHeight(height + 5), "one"),
+                schedule(2, Expiration::AtHeight(height + 5), "two"),
+            ],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let scheduled: Vec<ScheduledMsgsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ListScheduled {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        scheduled,
+        vec![
+            ScheduledMsgsResponse {
+                id: 1,
+                scheduled: ScheduledMsgs {
+                    proposal_module: proposal_module.address.clone(),
+                    proposal_id: 1,
+                    execute_at: Expiration::AtHeight(height + 5),
+                    msgs: vec![set_item("one")],
+                },
+            },
+            ScheduledMsgsResponse {
+                id: 2,
+                scheduled: ScheduledMsgs {
+                    proposal_module: proposal_module.address.clone(),
+                    proposal_id: 2,
+                    execute_at: Expiration::AtHeight(height + 5),
+                    msgs: vec![set_item("two")],
+                },
+            },
+        ]
+    );
+
+    // Scheduled messages may not be executed early.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteScheduled { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ScheduledNotReady {
+            id: 1,
+            execute_at: Expiration::AtHeight(height + 5),
+        }
+    );
+
+    // Cancel the messages scheduled by the second proposal.
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::CancelScheduled {
+                    proposal_module: proposal_module.address.to_string(),
+                    proposal_id: 2,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let scheduled: Vec<ScheduledMsgsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalScheduled {
+                proposal_module: proposal_module.address.to_string(),
+                proposal_id: 2,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(scheduled.is_empty());
+
+    app.update_block(|b| b.height += 5);
+
+    // Anyone may execute scheduled messages once they are due.
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteScheduled { id: 1 },
+        &[],
+    )
+    .unwrap();
+    let item: GetItemResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::GetItem {
+                key: "one".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(item.item, Some("value".to_string()));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteScheduled { id: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoSuchScheduled { id: 2 });
+
+    let scheduled: Vec<ScheduledMsgsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::ListScheduled {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(scheduled.is_empty());
+}
+
+#[test]
+fn test_scheduled_execution_attributed_to_executing_module() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(
+        BankSudo::Mint {
+            to_address: core_addr.to_string(),
+            amount: coins(1000, "ujuno"),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    // Add a second proposal module that is not spending capped.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModules {
+            to_add: vec![ModuleInstantiateInfo {
+                code_id: 1,
+                msg: to_json_binary(&dao_proposal_sudo::msg::InstantiateMsg {
+                    root: CREATOR_ADDR.to_string(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "override module".to_string(),
+            }],
+            to_disable: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let capped = proposal_modules[0].address.clone();
+    let uncapped = proposal_modules[1].address.clone();
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendingCaps {
+            to_set: vec![SpendingCap {
+                denom: SpendDenom::Native("ujuno".to_string()),
+                amount: Uint128::new(100),
+                window: Duration::Height(10),
+            }],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendingCapOverrideModules {
+            to_add: vec![uncapped.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let send = |to: &str, amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: to.to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let execute_at = Expiration::AtHeight(app.block_info().height + 1);
+    let schedule = |module: &Addr, msgs: Vec<CosmosMsg>| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::Schedule {
+                proposal_module: module.to_string(),
+                proposal_id: 1,
+                schedule: vec![ScheduleInfo { execute_at, msgs }],
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+
+    // The capped module may not schedule messages in the name of the
+    // uncapped one.
+    let msg = schedule(&uncapped, vec![send("recipient", 500)]);
+    let err: ContractError = app
+        .execute_contract(
+            capped.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook { msgs: vec![msg] },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Nor may messages be scheduled outside of a module's execution.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Schedule {
+                proposal_module: uncapped.to_string(),
+                proposal_id: 1,
+                schedule: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Messages the capped module schedules are capped, and checked
+    // against its authorizations when executed.
+    let msg = schedule(&capped, vec![send("recipient", 500), send("payee", 50)]);
+    app.execute_contract(
+        capped.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook { msgs: vec![msg] },
+        &[],
+    )
+    .unwrap();
+    let msg = schedule(&capped, vec![send("payee", 50)]);
+    app.execute_contract(
+        capped.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook { msgs: vec![msg] },
+        &[],
+    )
+    .unwrap();
+    let msg = schedule(&uncapped, vec![send("recipient", 500)]);
+    app.execute_contract(
+        uncapped.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook { msgs: vec![msg] },
+        &[],
+    )
+    .unwrap();
+    app.update_block(|b| b.height += 1);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteScheduled { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::SpendingCapExceeded { .. }));
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::SetModuleAuthorizations {
+            module: capped.to_string(),
+            authorizations: vec![Authorization::BankSend {
+                denom: "ujuno".to_string(),
+                recipients: Some(vec!["other".to_string()]),
+                limit: None,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteScheduled { id: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::MsgNotAuthorized { index: 0, .. }
+    ));
+
+    // The uncapped module's scheduled messages are not capped.
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        core_addr,
+        &ExecuteMsg::ExecuteScheduled { id: 3 },
+        &[],
+    )
+    .unwrap();
+    let balance = app.wrap().query_balance("recipient", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(500));
+}
+
+#[test]
+fn test_module_authorizations() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(
+        BankSudo::Mint {
+            to_address: core_addr.to_string(),
+            amount: coins(1000, "ujuno"),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let send = |to: &str, amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: to.to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let authorizations = vec![
+        Authorization::WasmExecute {
+            contract: core_addr.to_string(),
+            msgs: Some(vec!["set_item".to_string()]),
+        },
+        Authorization::BankSend {
+            denom: "ujuno".to_string(),
+            recipients: Some(vec!["payee".to_string()]),
+            limit: Some(SpendLimit {
+                amount: Uint128::new(100),
+                period: Duration::Height(10),
+            }),
+        },
+    ];
+
+    // Only the DAO may set authorizations.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::SetModuleAuthorizations {
+                module: proposal_module.address.to_string(),
+                authorizations: authorizations.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Modules without authorizations may execute anything.
+    let results: Vec<AuthorizationResult> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::CheckAuthorizations {
+                module: proposal_module.address.to_string(),
+                msgs: vec![send("other", 1000)],
+            },
+        )
+        .unwrap();
+    assert_eq!(results, vec![AuthorizationResult::Unrestricted {}]);
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::SetModuleAuthorizations {
+            module: proposal_module.address.to_string(),
+            authorizations: authorizations.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let results: Vec<AuthorizationResult> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::CheckAuthorizations {
+                module: proposal_module.address.to_string(),
+                msgs: vec![send("payee", 60), send("payee", 60), send("other", 1)],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            AuthorizationResult::Allowed { index: 1 },
+            AuthorizationResult::Denied {
+                reasons: vec![
+                    "authorization 0: not a wasm execute".to_string(),
+                    "authorization 1: sending 60 would exceed the limit of 100 per period (60 already spent)".to_string(),
+                ]
+            },
+            AuthorizationResult::Denied {
+                reasons: vec![
+                    "authorization 0: not a wasm execute".to_string(),
+                    "authorization 1: recipient other is not permitted".to_string(),
+                ]
+            },
+        ]
+    );
+
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![send("payee", 60)],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![send("payee", 60)],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::MsgNotAuthorized { index: 0, .. }
+    ));
+
+    // Configuration messages other than `SetItem` are not permitted.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::RemoveModuleAuthorizations {
+                        module: proposal_module.address.to_string(),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::MsgNotAuthorized { index: 0, .. }
+    ));
+
+    let response: ModuleAuthorizationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ModuleAuthorizations {
+                module: proposal_module.address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response.authorizations, authorizations);
+    assert_eq!(
+        response.usage,
+        vec![
+            None,
+            Some(SpendUsage {
+                spent: Uint128::new(60),
+                period_end: Expiration::AtHeight(app.block_info().height + 10),
+            })
+        ]
+    );
+
+    // The limit resets once the period has passed.
+    app.update_block(|b| b.height += 10);
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![send("payee", 100)],
+        },
+        &[],
+    )
+    .unwrap();
+    let balance = app.wrap().query_balance("payee", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(160));
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::RemoveModuleAuthorizations {
+            module: proposal_module.address.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![send("other", 500)],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let modules: Vec<ModuleAuthorizationsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::ListModuleAuthorizations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(modules.is_empty());
+}
+
+#[test]
+fn test_spending_caps() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(
+        BankSudo::Mint {
+            to_address: core_addr.to_string(),
+            amount: coins(1000, "ujuno"),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let cap = SpendingCap {
+        denom: SpendDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(100),
+        window: Duration::Height(10),
+    };
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendingCaps {
+            to_set: vec![cap.clone()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![send(60)],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![send(20), send(30)],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SpendingCapExceeded {
+            denom: "native:ujuno".to_string(),
+            cap: Uint128::new(100),
+            spent: Uint128::new(60),
+            amount: Uint128::new(50),
+        }
+    );
+
+    // Proposals that make the DAO execute admin messages on itself
+    // are capped as well.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::ExecuteAdminMsgs {
+                        msgs: vec![send(50)],
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::SpendingCapExceeded { .. }));
+
+    let caps: Vec<SpendingCapResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::SpendingCaps {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        caps,
+        vec![SpendingCapResponse {
+            cap: cap.clone(),
+            spent: Uint128::new(60),
+        }]
+    );
+
+    // Spends leave the window once it has passed.
+    app.update_block(|b| b.height += 10);
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![send(100)],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Override modules are not capped.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendingCapOverrideModules {
+            to_add: vec![proposal_module.address.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![send(500)],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance = app.wrap().query_balance("recipient", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(660));
+
+    let overrides: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::SpendingCapOverrideModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(overrides, vec![proposal_module.address]);
+}
+
+#[test]
+fn test_capped_module_can_not_update_caps() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let proposal_module = get_active_modules(&app, core_addr.clone())[0]
+        .address
+        .clone();
+    let cap = SpendingCap {
+        denom: SpendDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(100),
+        window: Duration::Height(10),
+    };
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendingCaps {
+            to_set: vec![cap.clone()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let execute_on_core = |app: &mut App, msg: &ExecuteMsg| {
+        app.execute_contract(
+            proposal_module.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_json_binary(msg).unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+            },
+            &[],
+        )
+    };
+    let unauthorized = ContractError::CappedModuleCanNotUpdateCaps {
+        module: proposal_module.clone(),
+    };
+
+    let err: ContractError = execute_on_core(
+        &mut app,
+        &ExecuteMsg::UpdateSpendingCaps {
+            to_set: vec![SpendingCap {
+                amount: Uint128::new(1000),
+                ..cap.clone()
+            }],
+            to_remove: vec![],
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, unauthorized);
+    let err: ContractError = execute_on_core(
+        &mut app,
+        &ExecuteMsg::UpdateSpendingCaps {
+            to_set: vec![],
+            to_remove: vec![cap.denom.clone()],
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, unauthorized);
+    let err: ContractError = execute_on_core(
+        &mut app,
+        &ExecuteMsg::UpdateSpendingCapOverrideModules {
+            to_add: vec![proposal_module.to_string()],
+            to_remove: vec![],
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, unauthorized);
+
+    // Nor by scheduling the change.
+    execute_on_core(
+        &mut app,
+        &ExecuteMsg::Schedule {
+            proposal_module: proposal_module.to_string(),
+            proposal_id: 1,
+            schedule: vec![ScheduleInfo {
+                execute_at: Expiration::AtHeight(app.block_info().height + 1),
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::UpdateSpendingCaps {
+                        to_set: vec![],
+                        to_remove: vec![cap.denom.clone()],
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+            }],
+        },
+    )
+    .unwrap();
+    app.update_block(|b| b.height += 1);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteScheduled { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, unauthorized);
+
+    // Once exempt, the module may change the caps.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendingCapOverrideModules {
+            to_add: vec![proposal_module.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    execute_on_core(
+        &mut app,
+        &ExecuteMsg::UpdateSpendingCaps {
+            to_set: vec![],
+            to_remove: vec![cap.denom],
+        },
+    )
+    .unwrap();
+    let caps: Vec<SpendingCapResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::SpendingCaps {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(caps, vec![]);
+}
+
+#[test]
+fn test_spending_caps_cover_all_spends() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateSpendingCaps {
+            to_set: vec![
+                SpendingCap {
+                    denom: SpendDenom::Native("ujuno".to_string()),
+                    amount: Uint128::new(100),
+                    window: Duration::Height(10),
+                },
+                SpendingCap {
+                    denom: SpendDenom::Cw20("cw20".to_string()),
+                    amount: Uint128::new(100),
+                    window: Duration::Height(10),
+                },
+            ],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let cw20_msg = |msg: cw20::Cw20ExecuteMsg| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: "cw20".to_string(),
+            msg: to_json_binary(&msg).unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+    let over_cap: Vec<(&str, CosmosMsg)> = vec![
+        (
+            "native:ujuno",
+            BankMsg::Burn {
+                amount: coins(101, "ujuno"),
+            }
+            .into(),
+        ),
+        (
+            "native:ujuno",
+            WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::Receive(cw20::Cw20ReceiveMsg {
+                    sender: "sender".to_string(),
+                    amount: Uint128::one(),
+                    msg: Binary::default(),
+                }))
+                .unwrap(),
+                funds: coins(101, "ujuno"),
+            }
+            .into(),
+        ),
+        (
+            "native:ujuno",
+            WasmMsg::Instantiate {
+                admin: None,
+                code_id: 1,
+                msg: Binary::default(),
+                funds: coins(101, "ujuno"),
+                label: "label".to_string(),
+            }
+            .into(),
+        ),
+        (
+            "native:ujuno",
+            WasmMsg::Instantiate2 {
+                admin: None,
+                code_id: 1,
+                msg: Binary::default(),
+                funds: coins(101, "ujuno"),
+                label: "label".to_string(),
+                salt: Binary::from(b"salt"),
+            }
+            .into(),
+        ),
+        (
+            "native:ujuno",
+            IbcMsg::Transfer {
+                channel_id: "channel-0".to_string(),
+                to_address: "recipient".to_string(),
+                amount: coin(101, "ujuno"),
+                timeout: IbcTimeout::with_timestamp(Timestamp::from_seconds(1)),
+            }
+            .into(),
+        ),
+        (
+            "cw20:cw20",
+            cw20_msg(cw20::Cw20ExecuteMsg::Burn {
+                amount: Uint128::new(101),
+            }),
+        ),
+        (
+            "cw20:cw20",
+            cw20_msg(cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                spender: "spender".to_string(),
+                amount: Uint128::new(101),
+                expires: None,
+            }),
+        ),
+    ];
+
+    for (denom, msg) in over_cap {
+        let err: ContractError = app
+            .execute_contract(
+                proposal_module.address.clone(),
+                core_addr.clone(),
+                &ExecuteMsg::ExecuteProposalHook { msgs: vec![msg] },
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(
+            err,
+            ContractError::SpendingCapExceeded {
+                denom: denom.to_string(),
+                cap: Uint128::new(100),
+                spent: Uint128::zero(),
+                amount: Uint128::new(101),
+            }
+        );
+    }
+
+    // The tokens spent by Stargate messages can not be known, so
+    // capped modules may not execute them.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![CosmosMsg::Stargate {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: Binary::default(),
+                }],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UncheckableSpend { index: 0 });
+}
+
+#[test]
+fn test_guardians() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let guardian = Addr::unchecked("guardian");
+    let start_height = app.block_info().height;
+
+    // Add a second proposal module so that one may be disabled.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModules {
+            to_add: vec![ModuleInstantiateInfo {
+                code_id: 1,
+                msg: to_json_binary(&dao_proposal_sudo::msg::InstantiateMsg {
+                    root: CREATOR_ADDR.to_string(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "emergency module".to_string(),
+            }],
+            to_disable: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposal_modules.len(), 2);
+
+    // Guardians must be set by the DAO and must expire.
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(5),
+                scope: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateGuardians {
+                guardians: Some(GuardianInfo {
+                    guardians: vec![guardian.to_string()],
+                    expiration: Expiration::Never {},
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::GuardiansMustExpire {});
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateGuardians {
+                guardians: Some(GuardianInfo {
+                    guardians: vec![guardian.to_string()],
+                    expiration: Expiration::AtHeight(start_height + 20),
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateGuardians {
+            guardians: Some(GuardianInfo {
+                guardians: vec![guardian.to_string()],
+                expiration: Expiration::AtHeight(start_height + 20),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+    let guardians: Option<Guardians> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::Guardians {})
+        .unwrap();
+    assert_eq!(
+        guardians,
+        Some(Guardians {
+            guardians: vec![guardian.clone()],
+            expiration: Expiration::AtHeight(start_height + 20),
+        })
+    );
+
+    // Guardians may disable proposal modules, but not the last
+    // active one.
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::DisableProposalModule {
+            address: proposal_modules[0].address.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::DisableProposalModule {
+                address: proposal_modules[0].address.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ModuleAlreadyDisabled {
+            address: proposal_modules[0].address.clone()
+        }
+    );
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::DisableProposalModule {
+                address: proposal_modules[1].address.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoActiveProposalModules {});
+
+    // Guardians may cancel scheduled messages.
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::CancelScheduled {
+            proposal_module: proposal_modules[1].address.to_string(),
+            proposal_id: 1,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Guardians may not move funds.
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteAdminMsgs {
+                msgs: vec![BankMsg::Send {
+                    to_address: guardian.to_string(),
+                    amount: coins(1, "ujuno"),
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Guardian pauses may not outlast the guardians.
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(21),
+                scope: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::GuardianPauseTooLong {
+            expiration: Expiration::AtHeight(start_height + 20)
+        }
+    );
+    let err: ContractError = app
+        .execute_contract(
+            guardian.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Time(10),
+                scope: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::GuardianPauseTooLong {
+            expiration: Expiration::AtHeight(start_height + 20)
+        }
+    );
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+            scope: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(
+        paused,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(start_height + 10),
+            scopes: vec![],
+        }
+    );
+
+    // Once expired, guardians have no powers.
+    app.update_block(|block| block.height += 20);
+    let err: ContractError = app
+        .execute_contract(
+            guardian,
+            core_addr,
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(1),
+                scope: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn test_guardian_can_not_shorten_pause() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let guardian = Addr::unchecked("guardian");
+    let start_height = app.block_info().height;
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateGuardians {
+            guardians: Some(GuardianInfo {
+                guardians: vec![guardian.to_string()],
+                expiration: Expiration::AtHeight(start_height + 20),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+    let pause = |app: &mut App, sender: &Addr, height: u64| {
+        app.execute_contract(
+            sender.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(height),
+                scope: Some(PauseScope::TreasuryTransfers {}),
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    let paused_until = |app: &App| -> Expiration {
+        let info: PauseInfoResponse = app
+            .wrap()
+            .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+            .unwrap();
+        match info {
+            PauseInfoResponse::Unpaused { scopes } => scopes[0].expiration,
+            PauseInfoResponse::Paused { .. } => panic!("expected a scoped pause"),
+        }
+    };
+
+    // The DAO's pause is not cut short by a guardian.
+    pause(&mut app, &core_addr, 15);
+    pause(&mut app, &guardian, 5);
+    assert_eq!(paused_until(&app), Expiration::AtHeight(start_height + 15));
+
+    // Guardians may extend it.
+    pause(&mut app, &guardian, 18);
+    assert_eq!(paused_until(&app), Expiration::AtHeight(start_height + 18));
+
+    // The DAO may shorten it.
+    pause(&mut app, &core_addr, 5);
+    assert_eq!(paused_until(&app), Expiration::AtHeight(start_height + 5));
+}
+
+#[test]
+fn test_guardians_act_while_paused() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let guardian = Addr::unchecked("guardian");
+    let start_height = app.block_info().height;
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModules {
+            to_add: vec![ModuleInstantiateInfo {
+                code_id: 1,
+                msg: to_json_binary(&dao_proposal_sudo::msg::InstantiateMsg {
+                    root: CREATOR_ADDR.to_string(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "emergency module".to_string(),
+            }],
+            to_disable: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateGuardians {
+            guardians: Some(GuardianInfo {
+                guardians: vec![guardian.to_string()],
+                expiration: Expiration::AtHeight(start_height + 20),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The exploited module schedules messages before the DAO is
+    // paused.
+    app.execute_contract(
+        proposal_modules[0].address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::Schedule {
+                    proposal_module: proposal_modules[0].address.to_string(),
+                    proposal_id: 1,
+                    schedule: vec![ScheduleInfo {
+                        execute_at: Expiration::AtHeight(start_height + 1),
+                        msgs: vec![BankMsg::Send {
+                            to_address: "attacker".to_string(),
+                            amount: coins(1, "ujuno"),
+                        }
+                        .into()],
+                    }],
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+            scope: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // While paused, guardians may still disable the module and cancel
+    // its scheduled messages.
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::DisableProposalModule {
+            address: proposal_modules[0].address.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        guardian.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::CancelScheduled {
+            proposal_module: proposal_modules[0].address.to_string(),
+            proposal_id: 1,
+        },
+        &[],
+    )
+    .unwrap();
+    let scheduled: Vec<ScheduledMsgsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ListScheduled {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(scheduled.is_empty());
+
+    // Other guardian messages, and anyone else's, remain paused.
+    let err: ContractError = app
+        .execute_contract(
+            guardian,
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(5),
+                scope: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Paused {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr,
+            &ExecuteMsg::CancelScheduled {
+                proposal_module: proposal_modules[1].address.to_string(),
+                proposal_id: 1,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Paused {});
+}
+
+#[test]
+fn test_scoped_pause() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(
+        BankSudo::Mint {
+            to_address: core_addr.to_string(),
+            amount: coins(1000, "ujuno"),
+        }
+        .into(),
+    )
+    .unwrap();
+    let start_height = app.block_info().height;
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let send: CosmosMsg = BankMsg::Send {
+        to_address: "recipient".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into();
+    let set_item: CosmosMsg = WasmMsg::Execute {
+        contract_addr: core_addr.to_string(),
+        msg: to_json_binary(&ExecuteMsg::SetItem {
+            key: "k".to_string(),
+            value: "v".to_string(),
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into();
+    let execute = |app: &mut App, msgs: Vec<CosmosMsg>| -> Result<(), ContractError> {
+        app.execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook { msgs },
+            &[],
+        )
+        .map(|_| ())
+        .map_err(|e| e.downcast().unwrap())
+    };
+
+    // Pausing treasury transfers leaves other activity alone.
+    execute(
+        &mut app,
+        vec![WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::Pause {
+                duration: Duration::Height(10),
+                scope: Some(PauseScope::TreasuryTransfers {}),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap();
+    let err = execute(&mut app, vec![set_item.clone(), send.clone()]).unwrap_err();
+    assert_eq!(err, ContractError::TreasuryTransfersPaused {});
+    for msg in [
+        IbcMsg::Transfer {
+            channel_id: "channel-0".to_string(),
+            to_address: "recipient".to_string(),
+            amount: coin(1, "ujuno"),
+            timeout: IbcTimeout::with_timestamp(Timestamp::from_seconds(1)),
+        }
+        .into(),
+        CosmosMsg::Stargate {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: Binary::default(),
+        },
+    ] {
+        let err = execute(&mut app, vec![msg]).unwrap_err();
+        assert_eq!(err, ContractError::TreasuryTransfersPaused {});
+    }
+    execute(&mut app, vec![set_item.clone()]).unwrap();
+
+    // Pausing module updates blocks the DAO from changing modules.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(5),
+            scope: Some(PauseScope::ModuleUpdates {}),
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateProposalModules {
+                to_add: vec![],
+                to_disable: vec![proposal_module.address.to_string()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ModuleUpdatesPaused {});
+
+    // Pausing a proposal module stops it executing anything.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(5),
+            scope: Some(PauseScope::ProposalModule {
+                address: proposal_module.address.to_string(),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = execute(&mut app, vec![set_item.clone()]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ProposalModulePaused {
+            address: proposal_module.address.clone()
+        }
+    );
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(
+        paused,
+        PauseInfoResponse::Unpaused {
+            scopes: vec![
+                ScopedPause {
+                    scope: PauseScope::ModuleUpdates {},
+                    expiration: Expiration::AtHeight(start_height + 5),
+                },
+                ScopedPause {
+                    scope: PauseScope::ProposalModule {
+                        address: proposal_module.address.to_string(),
+                    },
+                    expiration: Expiration::AtHeight(start_height + 5),
+                },
+                ScopedPause {
+                    scope: PauseScope::TreasuryTransfers {},
+                    expiration: Expiration::AtHeight(start_height + 10),
+                },
+            ]
+        }
+    );
+
+    // The admin may lift a single scope.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Unpause {
+            scope: Some(PauseScope::ProposalModule {
+                address: proposal_module.address.to_string(),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+    execute(&mut app, vec![set_item]).unwrap();
+
+    // Scoped pauses end when they expire.
+    app.update_block(|block| block.height += 10);
+    execute(&mut app, vec![send]).unwrap();
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr, &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(paused, PauseInfoResponse::Unpaused { scopes: vec![] });
+}
+
+#[test]
+fn test_ratification() {
+    let (parent, mut app) = do_standard_instantiate(false, None);
+    app.sudo(
+        BankSudo::Mint {
+            to_address: parent.to_string(),
+            amount: coins(1000, "ujuno"),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    // Create a SubDAO administered by the parent, reusing the code
+    // stored by `do_standard_instantiate`.
+    let child = instantiate_gov(
+        &mut app,
+        3,
+        InstantiateMsg {
+            dao_uri: None,
+            admin: Some(parent.to_string()),
+            name: "SubDAO".to_string(),
+            description: "A SubDAO.".to_string(),
+            image_url: None,
+            automatically_add_cw20s: false,
+            automatically_add_cw721s: false,
+            voting_module_instantiate_info: ModuleInstantiateInfo {
+                code_id: 2,
+                msg: to_json_binary(&dao_voting_cw20_balance::msg::InstantiateMsg {
+                    token_info: dao_voting_cw20_balance::msg::TokenInfo::New {
+                        code_id: 4,
+                        label: "SubDAO voting".to_string(),
+                        name: "SubDAO".to_string(),
+                        symbol: "SUB".to_string(),
+                        decimals: 6,
+                        initial_balances: vec![cw20::Cw20Coin {
+                            address: CREATOR_ADDR.to_string(),
+                            amount: Uint128::from(2u64),
+                        }],
+                        marketing: None,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "voting module".to_string(),
+            },
+            proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+                code_id: 1,
+                msg: to_json_binary(&dao_proposal_sudo::msg::InstantiateMsg {
+                    root: CREATOR_ADDR.to_string(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "governance module".to_string(),
+            }],
+            initial_items: None,
+        },
+    );
+    app.sudo(
+        BankSudo::Mint {
+            to_address: child.to_string(),
+            amount: coins(1000, "ujuno"),
+        }
+        .into(),
+    )
+    .unwrap();
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            child.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap().address;
+
+    let rules = vec![RatificationRule::SpendAbove {
+        denom: SpendDenom::Native("ujuno".to_string()),
+        threshold: Uint128::new(100),
+    }];
+
+    // Only the admin may require ratification.
+    let err: ContractError = app
+        .execute_contract(
+            child.clone(),
+            child.clone(),
+            &ExecuteMsg::SetRatification {
+                rules: rules.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Listing the SubDAO with ratification rules installs them.
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![SubDao {
+                addr: child.to_string(),
+                charter: None,
+                ratification: Some(rules.clone()),
+            }],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let ratification: Option<Ratification> = app
+        .wrap()
+        .query_wasm_smart(child.clone(), &QueryMsg::Ratification {})
+        .unwrap();
+    assert_eq!(
+        ratification,
+        Some(Ratification {
+            ratifier: parent.clone(),
+            rules: rules.clone(),
+        })
+    );
+    let sub_daos: Vec<SubDao> = app
+        .wrap()
+        .query_wasm_smart(
+            parent.clone(),
+            &QueryMsg::ListSubDaos {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(sub_daos[0].ratification, Some(rules));
+
+    let send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let execute = |app: &mut App, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            proposal_module.clone(),
+            child.clone(),
+            &ExecuteMsg::ExecuteProposalHook { msgs },
+            &[],
+        )
+        .unwrap();
+    };
+    let balance = |app: &App| -> Uint128 {
+        app.wrap()
+            .query_balance("recipient", "ujuno")
+            .unwrap()
+            .amount
+    };
+
+    // Spending under the threshold executes immediately.
+    execute(&mut app, vec![send(100)]);
+    assert_eq!(balance(&app), Uint128::new(100));
+
+    // Spending over the threshold is held.
+    execute(&mut app, vec![send(60), send(60)]);
+    assert_eq!(balance(&app), Uint128::new(100));
+    let pending: Vec<PendingRatificationResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            child.clone(),
+            &QueryMsg::PendingRatifications {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pending,
+        vec![PendingRatificationResponse {
+            id: 1,
+            pending: PendingRatification {
+                proposal_module: proposal_module.clone(),
+                msgs: vec![send(60), send(60)],
+            },
+        }]
+    );
+
+    // Only the parent may ratify.
+    let err: ContractError = app
+        .execute_contract(
+            child.clone(),
+            child.clone(),
+            &ExecuteMsg::Ratify { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(
+        parent.clone(),
+        child.clone(),
+        &ExecuteMsg::Ratify { id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(balance(&app), Uint128::new(220));
+
+    // Rejected messages are dropped.
+    execute(&mut app, vec![send(500)]);
+    app.execute_contract(
+        parent.clone(),
+        child.clone(),
+        &ExecuteMsg::RejectRatification { id: 2 },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            parent.clone(),
+            child.clone(),
+            &ExecuteMsg::Ratify { id: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoSuchPendingRatification { id: 2 });
+    assert_eq!(balance(&app), Uint128::new(220));
+
+    // Removing the SubDAO removes its ratification rules and drops
+    // held messages.
+    execute(&mut app, vec![send(500)]);
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![],
+            to_remove: vec![child.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    let ratification: Option<Ratification> = app
+        .wrap()
+        .query_wasm_smart(child.clone(), &QueryMsg::Ratification {})
+        .unwrap();
+    assert_eq!(ratification, None);
+    execute(&mut app, vec![send(500)]);
+    assert_eq!(balance(&app), Uint128::new(720));
+
+    // Dropped messages stay dropped if ratification is required
+    // again.
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![SubDao {
+                addr: child.to_string(),
+                charter: None,
+                ratification: Some(vec![RatificationRule::SpendAbove {
+                    denom: SpendDenom::Native("ujuno".to_string()),
+                    threshold: Uint128::new(100),
+                }]),
+            }],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            parent.clone(),
+            child.clone(),
+            &ExecuteMsg::Ratify { id: 3 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoSuchPendingRatification { id: 3 });
+    execute(&mut app, vec![send(200)]);
+    let pending: Vec<PendingRatificationResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            child.clone(),
+            &QueryMsg::PendingRatifications {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pending.into_iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![4]
+    );
+    app.execute_contract(parent, child, &ExecuteMsg::Ratify { id: 4 }, &[])
+        .unwrap();
+    assert_eq!(balance(&app), Uint128::new(920));
+}
+
+#[test]
+fn test_treasury() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(
+        BankSudo::Mint {
+            to_address: core_addr.to_string(),
+            amount: vec![coin(100, "ujuno"), coin(5, "uatom")],
+        }
+        .into(),
+    )
+    .unwrap();
+
+    let treasury = |app: &App, kind: TreasuryKind, start_after: Option<String>, limit| {
+        let response: TreasuryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::Treasury {
+                    kind,
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap();
+        response
+    };
+
+    let native = treasury(&app, TreasuryKind::Native {}, None, Some(1));
+    assert_eq!(
+        native,
+        TreasuryResponse {
+            items: vec![TreasuryItem {
+                asset: TreasuryAsset::Native {
+                    denom: "uatom".to_string()
+                },
+                amount: Uint128::new(5),
+            }],
+            start_after: Some("uatom".to_string()),
+        }
+    );
+    let native = treasury(&app, TreasuryKind::Native {}, native.start_after, None);
+    assert_eq!(
+        native,
+        TreasuryResponse {
+            items: vec![TreasuryItem {
+                asset: TreasuryAsset::Native {
+                    denom: "ujuno".to_string()
+                },
+                amount: Uint128::new(100),
+            }],
+            start_after: None,
+        }
+    );
+
+    // NFTs are listed across collections, including ones the DAO
+    // holds no tokens in.
+    let cw721_id = app.store_code(cw721_base_contract());
+    let mut collections = vec![];
+    for token_ids in [vec!["a", "b"], vec![], vec!["c"]] {
+        let collection = app
+            .instantiate_contract(
+                cw721_id,
+                Addr::unchecked(CREATOR_ADDR),
+                &cw721_base::msg::InstantiateMsg {
+                    name: "ekez".to_string(),
+                    symbol: "ekez".to_string(),
+                    minter: CREATOR_ADDR.to_string(),
+                },
+                &[],
+                "cw721",
+                None,
+            )
+            .unwrap();
+        for token_id in token_ids {
+            app.execute_contract(
+                Addr::unchecked(CREATOR_ADDR),
+                collection.clone(),
+                &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::Mint {
+                    token_id: token_id.to_string(),
+                    owner: core_addr.to_string(),
+                    token_uri: None,
+                    extension: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        collections.push(collection);
+    }
+    collections.sort();
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateCw721List {
+            to_add: collections.iter().map(|c| c.to_string()).collect(),
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let mut nfts = vec![];
+    let mut start_after = None;
+    let mut pages = 0;
+    loop {
+        let page = treasury(&app, TreasuryKind::Cw721 {}, start_after, Some(2));
+        nfts.extend(page.items);
+        pages += 1;
+        start_after = match page.start_after {
+            Some(start_after) => Some(start_after),
+            None => break,
+        };
+    }
+    assert_eq!(pages, 2);
+    let expected: Vec<TreasuryItem> = collections
+        .iter()
+        .flat_map(|collection| {
+            let tokens: cw721::TokensResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection,
+                    &cw721::Cw721QueryMsg::Tokens {
+                        owner: core_addr.to_string(),
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            tokens.tokens.into_iter().map(|token_id| TreasuryItem {
+                asset: TreasuryAsset::Cw721 {
+                    contract: collection.clone(),
+                    token_id,
+                },
+                amount: Uint128::one(),
+            })
+        })
+        .collect();
+    assert_eq!(nfts.len(), 3);
+    assert_eq!(nfts, expected);
+
+    assert_eq!(
+        treasury(&app, TreasuryKind::Delegations {}, None, None),
+        TreasuryResponse {
+            items: vec![],
+            start_after: None,
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "staking")]
+fn test_staking() {
+    let app = AppBuilder::default().build(|router, api, storage| {
+        router
+            .staking
+            .setup(
+                storage,
+                StakingInfo {
+                    bonded_denom: "ujuno".to_string(),
+                    unbonding_time: 60,
+                    apr: Decimal::percent(10),
+                },
+            )
+            .unwrap();
+        for address in ["validator1", "validator2"] {
+            router
+                .staking
+                .add_validator(
+                    api,
+                    storage,
+                    &mock_env().block,
+                    Validator {
+                        address: address.to_string(),
+                        commission: Decimal::percent(1),
+                        max_commission: Decimal::percent(100),
+                        max_change_rate: Decimal::percent(1),
+                    },
+                )
+                .unwrap();
+        }
+    });
+    let (core_addr, mut app) = instantiate_with_app(app, false, None);
+    app.sudo(
+        BankSudo::Mint {
+            to_address: core_addr.to_string(),
+            amount: coins(1000, "ujuno"),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    let delegate = |app: &mut App, sender: &Addr, validator: &str, amount: u128| {
+        app.execute_contract(
+            sender.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::Delegate {
+                validator: validator.to_string(),
+                amount: Uint128::new(amount),
+            },
+            &[],
+        )
+    };
+    let staked = |app: &App, validator: &str| -> Uint128 {
+        app.wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::Stake(StakeTrackerQuery::ValidatorStaked {
+                    validator: validator.to_string(),
+                    t: app.block_info().time,
+                }),
+            )
+            .unwrap()
+    };
+
+    let err: ContractError = delegate(&mut app, &Addr::unchecked("random"), "validator1", 600)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = delegate(&mut app, &core_addr, "validator1", 600)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::StakingNotConfigured {});
+
+    let config = StakingConfig {
+        unbonding_duration_seconds: 60,
+        auto_compound: true,
+    };
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateStakingConfig {
+            config: config.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+    let stored: Option<StakingConfig> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::StakingConfig {})
+        .unwrap();
+    assert_eq!(stored, Some(config));
+
+    delegate(&mut app, &core_addr, "validator1", 600).unwrap();
+    delegate(&mut app, &core_addr, "validator2", 200).unwrap();
+    let total: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::Stake(StakeTrackerQuery::TotalStaked {
+                t: app.block_info().time,
+            }),
+        )
+        .unwrap();
+    assert_eq!(total, Uint128::new(800));
+
+    // Unbonding tokens are tracked until they are returned.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Undelegate {
+            validator: "validator2".to_string(),
+            amount: Uint128::new(100),
+        },
+        &[],
+    )
+    .unwrap();
+    let unbondings = |app: &App| -> Vec<UnbondingResponse> {
+        app.wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::Unbondings {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    };
+    assert_eq!(
+        unbondings(&app),
+        vec![UnbondingResponse {
+            id: 1,
+            unbonding: Unbonding {
+                validator: "validator2".to_string(),
+                amount: Uint128::new(100),
+                completes_at: app.block_info().time.plus_seconds(60),
+            },
+        }]
+    );
+    assert_eq!(staked(&app, "validator2"), Uint128::new(200));
+
+    app.update_block(|block| block.time = block.time.plus_seconds(60));
+    assert_eq!(unbondings(&app), vec![]);
+    assert_eq!(staked(&app, "validator2"), Uint128::new(100));
+
+    // Rewards are delegated back to the validator they were earned
+    // from.
+    app.update_block(|block| block.time = block.time.plus_seconds(60 * 60 * 24 * 365));
+    app.execute_contract(
+        Addr::unchecked("random"),
+        core_addr.clone(),
+        &ExecuteMsg::ClaimStakingRewards {
+            validators: vec!["validator1".to_string(), "validator2".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    for validator in ["validator1", "validator2"] {
+        let delegation = app
+            .wrap()
+            .query_delegation(core_addr.clone(), validator)
+            .unwrap()
+            .unwrap();
+        assert_eq!(staked(&app, validator), delegation.amount.amount);
+    }
+    assert!(staked(&app, "validator1") > Uint128::new(600));
+    assert!(staked(&app, "validator2") > Uint128::new(100));
+
+    // Rewards are also compounded when claimed by a proposal, while
+    // the proposal module's execution is being tracked.
+    let before = staked(&app, "validator1");
+    app.update_block(|block| block.time = block.time.plus_seconds(60 * 60 * 24 * 365));
+    let proposal_module = get_active_modules(&app, core_addr.clone())[0]
+        .address
+        .clone();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module,
+        &dao_proposal_sudo::msg::ExecuteMsg::Execute {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::ClaimStakingRewards {
+                    validators: vec!["validator1".to_string()],
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+    let delegation = app
+        .wrap()
+        .query_delegation(core_addr.clone(), "validator1")
+        .unwrap()
+        .unwrap();
+    assert_eq!(staked(&app, "validator1"), delegation.amount.amount);
+    assert!(staked(&app, "validator1") > before);
+    assert_eq!(
+        EXECUTING_MODULES.query(&app.wrap(), core_addr).unwrap(),
+        Vec::<Addr>::new()
+    );
+}
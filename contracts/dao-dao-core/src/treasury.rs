@@ -0,0 +1,330 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, Env, Order, StdResult, Timestamp, Uint128};
+use cw_paginate_storage::paginate_map_keys;
+use cw_storage_plus::Bound;
+use dao_interface::{
+    spending::SpendDenom,
+    treasury::{TreasuryAsset, TreasuryItem, TreasuryKind, TreasuryResponse},
+};
+use serde::Deserialize;
+
+use crate::state::{CW20_LIST, CW721_LIST};
+
+/// The subset of the cw-payroll-factory query interface used to find
+/// the vesting contracts a DAO has instantiated.
+#[cw_serde]
+enum PayrollFactoryQuery {
+    ListVestingContractsByInstantiator {
+        instantiator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+/// The subset of the cw-vesting query interface used to value vesting
+/// contracts.
+#[cw_serde]
+enum VestingQuery {
+    Ownership {},
+    Info {},
+    TotalToVest {},
+    Vested { t: Option<Timestamp> },
+}
+
+// The following only deserialize the fields that are needed, so they
+// do not use `cw_serde`, which denies unknown fields.
+
+#[derive(Deserialize)]
+struct VestingContract {
+    contract: String,
+}
+
+#[derive(Deserialize)]
+struct Ownership {
+    owner: Option<Addr>,
+}
+
+/// cw-vesting's `CheckedDenom` serializes in the same way as
+/// `SpendDenom`.
+#[derive(Deserialize)]
+struct VestInfo {
+    denom: SpendDenom,
+}
+
+/// The number of items listed when no limit is provided.
+const DEFAULT_LIMIT: u32 = 10;
+/// The maximum number of items listed at a time. Also bounds the
+/// number of contracts queried for a page, as holdings in cw721
+/// collections and vesting contracts require a query per contract.
+const MAX_LIMIT: u32 = 30;
+
+/// Lists the DAO's holdings of `kind` in order of `TreasuryAsset::key`.
+pub(crate) fn query_treasury(
+    deps: Deps,
+    env: Env,
+    kind: TreasuryKind,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TreasuryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    match kind {
+        TreasuryKind::Native {} => native_balances(deps, env, start_after, limit),
+        TreasuryKind::Cw20 {} => cw20_balances(deps, env, start_after, limit),
+        TreasuryKind::Cw721 {} => cw721_holdings(deps, env, start_after, limit),
+        TreasuryKind::Delegations {} => delegations(deps, env, start_after, limit),
+        TreasuryKind::Vesting { payroll_factory } => {
+            vesting_contracts(deps, env, payroll_factory, start_after, limit)
+        }
+    }
+}
+
+/// Sorts `items` by key and paginates them.
+fn paginate(
+    mut items: Vec<TreasuryItem>,
+    start_after: Option<String>,
+    limit: u32,
+) -> TreasuryResponse {
+    items.sort_by_key(|item| item.asset.key());
+    let mut items: Vec<TreasuryItem> = items
+        .into_iter()
+        .filter(|item| match &start_after {
+            Some(start) => item.asset.key() > *start,
+            None => true,
+        })
+        .collect();
+    let start_after = if items.len() > limit as usize {
+        items.truncate(limit as usize);
+        items.last().map(|item| item.asset.key())
+    } else {
+        None
+    };
+    TreasuryResponse { items, start_after }
+}
+
+fn native_balances(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<TreasuryResponse> {
+    let items = deps
+        .querier
+        .query_all_balances(env.contract.address)?
+        .into_iter()
+        .map(|coin| TreasuryItem {
+            asset: TreasuryAsset::Native { denom: coin.denom },
+            amount: coin.amount,
+        })
+        .collect();
+    Ok(paginate(items, start_after, limit))
+}
+
+fn cw20_balances(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<TreasuryResponse> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    // One more key than needed is loaded to find if there are more.
+    let mut contracts = paginate_map_keys(
+        deps,
+        &CW20_LIST,
+        start_after,
+        Some(limit + 1),
+        Order::Ascending,
+    )?;
+    let more = contracts.len() > limit as usize;
+    contracts.truncate(limit as usize);
+    let items = contracts
+        .into_iter()
+        .map(|contract| {
+            let balance: cw20::BalanceResponse = deps.querier.query_wasm_smart(
+                &contract,
+                &cw20::Cw20QueryMsg::Balance {
+                    address: env.contract.address.to_string(),
+                },
+            )?;
+            Ok(TreasuryItem {
+                asset: TreasuryAsset::Cw20 { contract },
+                amount: balance.balance,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    let start_after = if more {
+        items.last().map(|item| item.asset.key())
+    } else {
+        None
+    };
+    Ok(TreasuryResponse { items, start_after })
+}
+
+fn cw721_holdings(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<TreasuryResponse> {
+    // Keys are of the form `{contract}/{token_id}`. Addresses do not
+    // contain `/`, so the first one separates the two. A key without
+    // a token ID starts after all of the contract's tokens.
+    let (start_collection, mut start_token) = match start_after {
+        Some(key) => match key.split_once('/') {
+            Some((collection, token_id)) => (
+                Some(Bound::inclusive(deps.api.addr_validate(collection)?)),
+                Some(token_id.to_string()),
+            ),
+            None => (Some(Bound::exclusive(deps.api.addr_validate(&key)?)), None),
+        },
+        None => (None, None),
+    };
+
+    let mut items = vec![];
+    let mut collections = CW721_LIST.keys(deps.storage, start_collection, None, Order::Ascending);
+    let mut collection = collections.next().transpose()?;
+    // The position reached so far, as a key to start after.
+    let mut reached = None;
+    // Collections may hold no tokens, so the number of queries made
+    // is bounded as well as the number of items listed.
+    for _ in 0..MAX_LIMIT {
+        let current = match &collection {
+            Some(current) => current,
+            None => {
+                return Ok(TreasuryResponse {
+                    items,
+                    start_after: None,
+                })
+            }
+        };
+        if items.len() >= limit as usize {
+            break;
+        }
+        let tokens: cw721::TokensResponse = deps.querier.query_wasm_smart(
+            current,
+            &cw721::Cw721QueryMsg::Tokens {
+                owner: env.contract.address.to_string(),
+                start_after: start_token.take(),
+                limit: Some(limit - items.len() as u32),
+            },
+        )?;
+        match tokens.tokens.last() {
+            Some(last) => {
+                reached = Some(format!("{current}/{last}"));
+                start_token = Some(last.clone());
+            }
+            None => {
+                reached = Some(current.to_string());
+                collection = collections.next().transpose()?;
+                continue;
+            }
+        }
+        items.extend(tokens.tokens.into_iter().map(|token_id| TreasuryItem {
+            asset: TreasuryAsset::Cw721 {
+                contract: current.clone(),
+                token_id,
+            },
+            amount: Uint128::one(),
+        }));
+    }
+    Ok(TreasuryResponse {
+        items,
+        start_after: reached,
+    })
+}
+
+#[cfg(feature = "staking")]
+fn delegations(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<TreasuryResponse> {
+    let items = deps
+        .querier
+        .query_all_delegations(env.contract.address)?
+        .into_iter()
+        .map(|delegation| TreasuryItem {
+            asset: TreasuryAsset::Delegation {
+                denom: delegation.amount.denom,
+                validator: delegation.validator,
+            },
+            amount: delegation.amount.amount,
+        })
+        .collect();
+    Ok(paginate(items, start_after, limit))
+}
+
+/// Without staking support the DAO can not have delegations.
+#[cfg(not(feature = "staking"))]
+fn delegations(
+    _deps: Deps,
+    _env: Env,
+    _start_after: Option<String>,
+    _limit: u32,
+) -> StdResult<TreasuryResponse> {
+    Ok(TreasuryResponse {
+        items: vec![],
+        start_after: None,
+    })
+}
+
+fn vesting_contracts(
+    deps: Deps,
+    env: Env,
+    payroll_factory: String,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<TreasuryResponse> {
+    let payroll_factory = deps.api.addr_validate(&payroll_factory)?;
+
+    // Contracts that are not owned by the DAO or have fully vested
+    // are skipped, so a page may list fewer than `limit` items while
+    // there are more to come.
+    let contracts: Vec<VestingContract> = deps.querier.query_wasm_smart(
+        &payroll_factory,
+        &PayrollFactoryQuery::ListVestingContractsByInstantiator {
+            instantiator: env.contract.address.to_string(),
+            start_after,
+            limit: Some(limit),
+        },
+    )?;
+    let start_after = if contracts.len() >= limit as usize {
+        contracts.last().map(|last| last.contract.clone())
+    } else {
+        None
+    };
+
+    let mut items = vec![];
+    for VestingContract { contract } in contracts {
+        let contract = deps.api.addr_validate(&contract)?;
+        let ownership: Ownership = deps
+            .querier
+            .query_wasm_smart(&contract, &VestingQuery::Ownership {})?;
+        if ownership.owner.as_ref() != Some(&env.contract.address) {
+            continue;
+        }
+        let total: Uint128 = deps
+            .querier
+            .query_wasm_smart(&contract, &VestingQuery::TotalToVest {})?;
+        let vested: Uint128 = deps
+            .querier
+            .query_wasm_smart(&contract, &VestingQuery::Vested { t: None })?;
+        let unvested = total.saturating_sub(vested);
+        if unvested.is_zero() {
+            continue;
+        }
+        let info: VestInfo = deps
+            .querier
+            .query_wasm_smart(&contract, &VestingQuery::Info {})?;
+        items.push(TreasuryItem {
+            asset: TreasuryAsset::Vesting {
+                contract,
+                denom: info.denom,
+            },
+            amount: unvested,
+        });
+    }
+    Ok(TreasuryResponse { items, start_after })
+}
@@ -0,0 +1,219 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use dao_interface::voting::InfoResponse;
+use dao_voting::delegation::{DelegationResponse, DelegatorsResponse};
+use dao_voting::voting::get_voting_power;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{Config, CONFIG, DAO, DELEGATIONS, DELEGATORS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-vote-delegation";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.max_delegators == 0 {
+        return Err(ContractError::ZeroMaxDelegators {});
+    }
+
+    DAO.save(deps.storage, &info.sender)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            max_delegators: msg.max_delegators,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", info.sender)
+        .add_attribute("max_delegators", msg.max_delegators.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Delegate { delegate } => execute_delegate(deps, env, info, delegate),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, env, info),
+        ExecuteMsg::UpdateConfig { max_delegators } => {
+            execute_update_config(deps, info, max_delegators)
+        }
+    }
+}
+
+pub fn execute_delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    if delegate == info.sender {
+        return Err(ContractError::SelfDelegation {});
+    }
+
+    let dao = DAO.load(deps.storage)?;
+    let power = get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
+    if power.is_zero() {
+        return Err(ContractError::NoVotingPower {});
+    }
+
+    if let Some(current) = DELEGATIONS.may_load(deps.storage, &info.sender)? {
+        if current == delegate {
+            return Err(ContractError::AlreadyDelegated { delegate });
+        }
+        remove_delegator(deps.storage, &current, &info.sender, env.block.height)?;
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut delegators = DELEGATORS
+        .may_load(deps.storage, &delegate)?
+        .unwrap_or_default();
+    if delegators.len() >= config.max_delegators as usize {
+        return Err(ContractError::TooManyDelegators {
+            delegate,
+            max: config.max_delegators,
+        });
+    }
+    delegators.push(info.sender.clone());
+
+    DELEGATORS.save(deps.storage, &delegate, &delegators, env.block.height)?;
+    DELEGATIONS.save(deps.storage, &info.sender, &delegate, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "delegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_undelegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let delegate = DELEGATIONS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NotDelegated {})?;
+
+    remove_delegator(deps.storage, &delegate, &info.sender, env.block.height)?;
+    DELEGATIONS.remove(deps.storage, &info.sender, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "undelegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_delegators: u32,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if max_delegators == 0 {
+        return Err(ContractError::ZeroMaxDelegators {});
+    }
+
+    // Lowering the maximum does not remove existing delegations, it
+    // only prevents new ones from being made to delegates above the
+    // new limit.
+    CONFIG.save(deps.storage, &Config { max_delegators })?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_config")
+        .add_attribute("max_delegators", max_delegators.to_string()))
+}
+
+/// Removes `delegator` from the list of addresses delegating to
+/// `delegate`.
+fn remove_delegator(
+    storage: &mut dyn Storage,
+    delegate: &Addr,
+    delegator: &Addr,
+    height: u64,
+) -> StdResult<()> {
+    let mut delegators = DELEGATORS.may_load(storage, delegate)?.unwrap_or_default();
+    delegators.retain(|a| a != delegator);
+    if delegators.is_empty() {
+        DELEGATORS.remove(storage, delegate, height)
+    } else {
+        DELEGATORS.save(storage, delegate, &delegators, height)
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Delegation { delegator, height } => {
+            query_delegation(deps, env, delegator, height)
+        }
+        QueryMsg::Delegators { delegate, height } => query_delegators(deps, env, delegate, height),
+        QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Info {} => query_info(deps),
+    }
+}
+
+pub fn query_delegation(
+    deps: Deps,
+    env: Env,
+    delegator: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let height = height.unwrap_or(env.block.height);
+    let delegate = DELEGATIONS.may_load_at_height(deps.storage, &delegator, height)?;
+    to_json_binary(&DelegationResponse { delegate })
+}
+
+pub fn query_delegators(
+    deps: Deps,
+    env: Env,
+    delegate: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let height = height.unwrap_or(env.block.height);
+    let delegators = DELEGATORS
+        .may_load_at_height(deps.storage, &delegate, height)?
+        .unwrap_or_default();
+    to_json_binary(&DelegatorsResponse { delegators })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = get_contract_version(deps.storage)?;
+    to_json_binary(&InfoResponse { info })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
@@ -0,0 +1,29 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Only addresses with voting power may delegate")]
+    NoVotingPower {},
+
+    #[error("An address may not delegate to itself")]
+    SelfDelegation {},
+
+    #[error("Voting power is already delegated to ({delegate})")]
+    AlreadyDelegated { delegate: Addr },
+
+    #[error("No delegation exists for this address")]
+    NotDelegated {},
+
+    #[error("Delegate ({delegate}) has reached the maximum of ({max}) delegators")]
+    TooManyDelegators { delegate: Addr, max: u32 },
+
+    #[error("Max delegators must be greater than zero")]
+    ZeroMaxDelegators {},
+}
@@ -0,0 +1,54 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The maximum number of addresses that may delegate to a single
+    /// delegate. Proposal modules load every delegator of an address
+    /// when it votes, so this bounds the gas cost of voting.
+    pub max_delegators: u32,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Delegates the sender's voting power to `delegate`. If the
+    /// sender has already delegated, their delegation is moved to the
+    /// new delegate. The sender must have voting power in the DAO.
+    Delegate { delegate: String },
+    /// Removes the sender's delegation.
+    Undelegate {},
+    /// Updates the registry's config. Only callable by the DAO.
+    UpdateConfig { max_delegators: u32 },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns the registry's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Returns the address (if any) that `delegator` had delegated
+    /// their voting power to at `height`. A height of None will
+    /// query for the current block height.
+    #[returns(::dao_voting::delegation::DelegationResponse)]
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Returns the addresses that had delegated their voting power
+    /// to `delegate` at `height`. A height of None will query for the
+    /// current block height.
+    #[returns(::dao_voting::delegation::DelegatorsResponse)]
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+    },
+    /// Returns the address of the DAO this registry belongs to.
+    #[returns(cosmwasm_std::Addr)]
+    Dao {},
+    /// Returns contract version info.
+    #[returns(::dao_interface::voting::InfoResponse)]
+    Info {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
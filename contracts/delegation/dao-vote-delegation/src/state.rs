@@ -0,0 +1,32 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, SnapshotMap, Strategy};
+
+#[cw_serde]
+pub struct Config {
+    /// The maximum number of addresses that may delegate to a single
+    /// delegate.
+    pub max_delegators: u32,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// The DAO whose members' voting power may be delegated.
+pub const DAO: Item<Addr> = Item::new("dao");
+
+/// Maps a delegator to the address they have delegated their voting
+/// power to.
+pub const DELEGATIONS: SnapshotMap<&Addr, Addr> = SnapshotMap::new(
+    "delegations",
+    "delegations__checkpoints",
+    "delegations__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Maps a delegate to the addresses that have delegated their voting
+/// power to them.
+pub const DELEGATORS: SnapshotMap<&Addr, Vec<Addr>> = SnapshotMap::new(
+    "delegators",
+    "delegators__checkpoints",
+    "delegators__changelog",
+    Strategy::EveryBlock,
+);
@@ -0,0 +1,274 @@
+use cosmwasm_std::Addr;
+use cw_multi_test::{App, AppResponse, Executor};
+use dao_testing::contracts::dao_vote_delegation_contract;
+use dao_testing::{DaoTestingSuite, DaoTestingSuiteBase, MEMBER1, MEMBER2, MEMBER3, MEMBER4};
+use dao_voting::delegation::{DelegationResponse, DelegatorsResponse};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::Config,
+    ContractError,
+};
+
+const NON_MEMBER: &str = "non_member";
+
+struct TestSetup {
+    base: DaoTestingSuiteBase,
+    dao: Addr,
+    registry: Addr,
+}
+
+fn setup_test(max_delegators: u32) -> TestSetup {
+    let mut base = DaoTestingSuiteBase::base();
+    let dao = base.cw4().dao();
+    // Advance a block so that member weights are visible.
+    base.advance_block();
+
+    let code_id = base.app.store_code(dao_vote_delegation_contract());
+    let registry = base
+        .app
+        .instantiate_contract(
+            code_id,
+            dao.core_addr.clone(),
+            &InstantiateMsg { max_delegators },
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap();
+
+    TestSetup {
+        base,
+        dao: dao.core_addr,
+        registry,
+    }
+}
+
+fn delegate(
+    app: &mut App,
+    registry: &Addr,
+    sender: &str,
+    delegate: &str,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        registry.clone(),
+        &ExecuteMsg::Delegate {
+            delegate: delegate.to_string(),
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn undelegate(app: &mut App, registry: &Addr, sender: &str) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        registry.clone(),
+        &ExecuteMsg::Undelegate {},
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn query_delegation(
+    app: &App,
+    registry: &Addr,
+    delegator: &str,
+    height: Option<u64>,
+) -> Option<Addr> {
+    let res: DelegationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            registry,
+            &QueryMsg::Delegation {
+                delegator: delegator.to_string(),
+                height,
+            },
+        )
+        .unwrap();
+    res.delegate
+}
+
+fn query_delegators(app: &App, registry: &Addr, delegate: &str, height: Option<u64>) -> Vec<Addr> {
+    let res: DelegatorsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            registry,
+            &QueryMsg::Delegators {
+                delegate: delegate.to_string(),
+                height,
+            },
+        )
+        .unwrap();
+    res.delegators
+}
+
+#[test]
+fn test_delegate_and_undelegate() {
+    let TestSetup {
+        mut base,
+        dao,
+        registry,
+    } = setup_test(10);
+
+    let dao_query: Addr = base
+        .app
+        .wrap()
+        .query_wasm_smart(&registry, &QueryMsg::Dao {})
+        .unwrap();
+    assert_eq!(dao_query, dao);
+
+    delegate(&mut base.app, &registry, MEMBER1, MEMBER3).unwrap();
+    delegate(&mut base.app, &registry, MEMBER2, MEMBER3).unwrap();
+    let delegated_height = base.app.block_info().height;
+    base.advance_block();
+
+    assert_eq!(
+        query_delegation(&base.app, &registry, MEMBER1, None),
+        Some(Addr::unchecked(MEMBER3))
+    );
+    assert_eq!(
+        query_delegators(&base.app, &registry, MEMBER3, None),
+        vec![Addr::unchecked(MEMBER1), Addr::unchecked(MEMBER2)]
+    );
+
+    // Delegations are not visible at the height they were made.
+    assert_eq!(
+        query_delegation(&base.app, &registry, MEMBER1, Some(delegated_height)),
+        None
+    );
+    assert!(query_delegators(&base.app, &registry, MEMBER3, Some(delegated_height)).is_empty());
+
+    // Moving a delegation removes it from the previous delegate.
+    delegate(&mut base.app, &registry, MEMBER1, MEMBER4).unwrap();
+    let moved_height = base.app.block_info().height;
+    base.advance_block();
+    assert_eq!(
+        query_delegators(&base.app, &registry, MEMBER3, None),
+        vec![Addr::unchecked(MEMBER2)]
+    );
+    assert_eq!(
+        query_delegators(&base.app, &registry, MEMBER4, None),
+        vec![Addr::unchecked(MEMBER1)]
+    );
+    // The old delegation is still visible at the height of the move.
+    assert_eq!(
+        query_delegation(&base.app, &registry, MEMBER1, Some(moved_height)),
+        Some(Addr::unchecked(MEMBER3))
+    );
+
+    let err = delegate(&mut base.app, &registry, MEMBER1, MEMBER4).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AlreadyDelegated {
+            delegate: Addr::unchecked(MEMBER4)
+        }
+    );
+
+    undelegate(&mut base.app, &registry, MEMBER1).unwrap();
+    base.advance_block();
+    assert_eq!(query_delegation(&base.app, &registry, MEMBER1, None), None);
+    assert!(query_delegators(&base.app, &registry, MEMBER4, None).is_empty());
+
+    let err = undelegate(&mut base.app, &registry, MEMBER1).unwrap_err();
+    assert_eq!(err, ContractError::NotDelegated {});
+}
+
+#[test]
+fn test_delegate_invalid() {
+    let TestSetup {
+        mut base,
+        dao: _,
+        registry,
+    } = setup_test(1);
+
+    let err = delegate(&mut base.app, &registry, MEMBER1, MEMBER1).unwrap_err();
+    assert_eq!(err, ContractError::SelfDelegation {});
+
+    let err = delegate(&mut base.app, &registry, NON_MEMBER, MEMBER1).unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+
+    delegate(&mut base.app, &registry, MEMBER1, MEMBER3).unwrap();
+    let err = delegate(&mut base.app, &registry, MEMBER2, MEMBER3).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TooManyDelegators {
+            delegate: Addr::unchecked(MEMBER3),
+            max: 1
+        }
+    );
+}
+
+#[test]
+fn test_update_config() {
+    let TestSetup {
+        mut base,
+        dao,
+        registry,
+    } = setup_test(1);
+
+    let err: ContractError = base
+        .app
+        .execute_contract(
+            Addr::unchecked(MEMBER1),
+            registry.clone(),
+            &ExecuteMsg::UpdateConfig { max_delegators: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err: ContractError = base
+        .app
+        .execute_contract(
+            dao.clone(),
+            registry.clone(),
+            &ExecuteMsg::UpdateConfig { max_delegators: 0 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroMaxDelegators {});
+
+    base.app
+        .execute_contract(
+            dao,
+            registry.clone(),
+            &ExecuteMsg::UpdateConfig { max_delegators: 2 },
+            &[],
+        )
+        .unwrap();
+
+    let config: Config = base
+        .app
+        .wrap()
+        .query_wasm_smart(&registry, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config, Config { max_delegators: 2 });
+
+    delegate(&mut base.app, &registry, MEMBER1, MEMBER3).unwrap();
+    delegate(&mut base.app, &registry, MEMBER2, MEMBER3).unwrap();
+}
+
+#[test]
+fn test_instantiate_zero_max_delegators() {
+    let mut app = App::default();
+    let code_id = app.store_code(dao_vote_delegation_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("dao"),
+            &InstantiateMsg { max_delegators: 0 },
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroMaxDelegators {});
+}
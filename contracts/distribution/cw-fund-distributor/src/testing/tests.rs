@@ -51,6 +51,7 @@ fn setup_test(initial_balances: Vec<Cw20Coin>) -> BaseTest {
                     unstaking_duration: None,
                     initial_dao_balance: None,
                 },
+                power_cap: None,
             },
             &[],
             "voting contract",
@@ -260,6 +261,7 @@ fn test_instantiate_fails_zero_voting_power() {
                     unstaking_duration: None,
                     initial_dao_balance: None,
                 },
+                power_cap: None,
             },
             &[],
             "voting contract",
@@ -65,6 +65,9 @@ fn test_issue_fantoken() -> anyhow::Result<()> {
                 )?),
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -83,6 +86,8 @@ fn test_issue_fantoken() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -167,6 +172,9 @@ fn test_initial_fantoken_balances() -> anyhow::Result<()> {
                 )?),
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -185,6 +193,8 @@ fn test_initial_fantoken_balances() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -274,6 +284,9 @@ fn test_fantoken_minter_and_authority_set_to_dao() -> anyhow::Result<()> {
                 )?),
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -292,6 +305,8 @@ fn test_fantoken_minter_and_authority_set_to_dao() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -431,6 +446,9 @@ fn test_fantoken_can_be_staked() -> anyhow::Result<()> {
                 )?),
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -449,6 +467,8 @@ fn test_fantoken_can_be_staked() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
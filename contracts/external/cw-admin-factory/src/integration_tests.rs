@@ -89,6 +89,8 @@ fn test_set_self_admin_instantiate2() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
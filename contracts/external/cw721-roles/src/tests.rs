@@ -265,6 +265,7 @@ fn test_send_permissions() {
                 },
                 unstaking_duration: None,
                 active_threshold: None,
+                power_cap: None,
             },
             &[],
             "cw721-staking",
@@ -55,6 +55,9 @@ pub fn query_proposal_v1(
         votes: v1_votes_to_v2(proposal.votes),
         allow_revoting: proposal.allow_revoting,
         veto: None,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+        expedited: None,
+        power_cast: Uint128::zero(),
     };
 
     (proposal_count, proposal)
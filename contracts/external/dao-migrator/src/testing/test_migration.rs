@@ -336,6 +336,7 @@ fn test_sub_daos() {
     let sub_dao = SubDao {
         addr: "sub_dao_1".to_string(),
         charter: None,
+        ratification: None,
     };
 
     execute_migration(
@@ -92,6 +92,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
         veto: None,
     }
 }
@@ -293,7 +294,11 @@ fn vote(app: &mut App, module: Addr, sender: &str, id: u64, option_id: u32) -> S
         module.clone(),
         &dao_proposal_multiple::msg::ExecuteMsg::Vote {
             proposal_id: id,
-            vote: MultipleChoiceVote { option_id },
+            vote: MultipleChoiceVote {
+                option_id,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -1793,6 +1798,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
             veto: None,
         }
     };
@@ -1862,6 +1868,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
             veto: None,
         }
     };
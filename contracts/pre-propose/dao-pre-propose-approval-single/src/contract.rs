@@ -100,12 +100,16 @@ pub fn execute_propose(
             description,
             msgs,
             vote,
+            relations,
+            expedited,
         } => ProposeMsg {
             title,
             description,
             msgs,
             proposer: Some(info.sender.to_string()),
             vote,
+            relations,
+            expedited,
         },
     };
 
@@ -561,6 +565,10 @@ pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response
                                 },
                                 rationale: vote.rationale,
                             }),
+                            // Proposals made with v2.4.1 could not
+                            // declare relations or be expedited.
+                            relations: None,
+                            expedited: false,
                         },
                         deposit: proposal.deposit.map(|deposit| CheckedDepositInfo {
                             denom: match deposit.denom {
@@ -619,6 +627,10 @@ pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response
                                 },
                                 rationale: vote.rationale,
                             }),
+                            // Proposals made with v2.4.1 could not
+                            // declare relations or be expedited.
+                            relations: None,
+                            expedited: false,
                         },
                         deposit: proposal.deposit.map(|deposit| CheckedDepositInfo {
                             denom: match deposit.denom {
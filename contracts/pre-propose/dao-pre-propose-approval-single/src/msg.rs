@@ -4,7 +4,10 @@ use dao_pre_propose_base::msg::{
     ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, MigrateMsg as MigrateBase,
     QueryMsg as QueryBase,
 };
-use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
+use dao_voting::{
+    proposal::SingleChoiceProposeMsg as ProposeMsg, relations::ProposalRelations,
+    voting::SingleChoiceAutoVote,
+};
 
 pub use dao_voting::approval::ApprovalExecuteExt as ExecuteExt;
 
@@ -19,6 +22,12 @@ pub enum ProposeMessage {
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
         vote: Option<SingleChoiceAutoVote>,
+        /// Proposals the proposal depends on or conflicts with.
+        relations: Option<ProposalRelations>,
+        /// Whether the proposal is expedited. The proposal module must
+        /// have expedited proposals enabled.
+        #[serde(default)]
+        expedited: bool,
     },
 }
 
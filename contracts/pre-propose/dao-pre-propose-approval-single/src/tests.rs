@@ -1,4 +1,6 @@
-use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, CosmosMsg, Uint128, WasmMsg};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, Addr, Coin, CosmosMsg, Decimal, Uint128, WasmMsg,
+};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
@@ -25,6 +27,7 @@ use dao_voting::{
     approval::ApprovalProposalStatus,
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    relations::{CheckedProposalRef, CheckedProposalRelations, ProposalRef, ProposalRelations},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
     voting::{SingleChoiceAutoVote, Vote},
@@ -82,6 +85,8 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+        expedited: None,
         veto: None,
     }
 }
@@ -200,6 +205,8 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                relations: None,
+                expedited: false,
             },
         },
         funds,
@@ -1189,6 +1196,8 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1407,6 +1416,130 @@ fn test_no_deposit_required_open_submission() {
     assert_eq!(Status::Passed, new_status)
 }
 
+#[test]
+fn test_propose_with_relations() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let first = approve_proposal(&mut app, pre_propose.clone(), "approver", pre_propose_id);
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                vote: None,
+                relations: Some(ProposalRelations {
+                    depends_on: vec![],
+                    conflicts_with: vec![ProposalRef {
+                        module: proposal_single.to_string(),
+                        proposal_id: first,
+                    }],
+                }),
+                expedited: false,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let id = approve_proposal(&mut app, pre_propose, "approver", pre_propose_id + 1);
+
+    // The relations are kept while the proposal awaits approval and
+    // passed through to the proposal module once it is approved.
+    let relations: CheckedProposalRelations = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dao_proposal_single::msg::QueryMsg::ProposalRelations { proposal_id: id },
+        )
+        .unwrap();
+    assert_eq!(
+        relations.conflicts_with,
+        vec![CheckedProposalRef {
+            module: proposal_single,
+            proposal_id: first,
+        }]
+    );
+}
+
+#[test]
+fn test_propose_expedited() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    // Enable expedited proposals on the proposal module.
+    let config: dao_proposal_single::state::Config = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dao_proposal_single::msg::QueryMsg::Config {},
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr,
+        proposal_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.to_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            veto: config.veto,
+            vote_weighting: config.vote_weighting,
+            expedited: Some(dao_proposal_single::state::ExpeditedConfig {
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Percent(Decimal::percent(67)),
+                },
+                max_voting_period: Duration::Time(3600),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                vote: None,
+                relations: None,
+                expedited: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The proposal is created as expedited once it is approved.
+    let id = approve_proposal(&mut app, pre_propose, "approver", 1);
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id: id },
+        )
+        .unwrap();
+    assert!(proposal.proposal.expedited.is_some());
+}
+
 #[test]
 fn test_no_deposit_required_members_submission() {
     let mut app = App::default();
@@ -1429,6 +1562,8 @@ fn test_no_deposit_required_members_submission() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1495,6 +1630,8 @@ fn test_anyone_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1551,6 +1688,8 @@ fn test_specific_allowlist_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1603,6 +1742,8 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1639,6 +1780,8 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1701,6 +1844,8 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+            expedited: None,
             veto: None,
         }
     };
@@ -1770,6 +1915,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+            expedited: None,
             veto: None,
         }
     };
@@ -2708,6 +2855,8 @@ fn test_migrate_from_v241() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -2775,6 +2924,8 @@ fn test_migrate_from_v241() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -2854,6 +3005,8 @@ fn test_migrate_from_v241() {
                     vote: Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -3034,6 +3187,8 @@ fn test_migrate_from_v241_with_policy_update() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -3108,6 +3263,8 @@ fn test_migrate_from_v241_with_policy_update() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -3185,6 +3342,8 @@ fn test_migrate_from_v241_with_policy_update() {
                     description: "d".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -3206,6 +3365,8 @@ fn test_migrate_from_v241_with_policy_update() {
                 description: "d".to_string(),
                 msgs: vec![],
                 vote: None,
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -132,6 +132,7 @@ fn get_proposal_module_approval_multiple_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
         veto: None,
     }
 }
@@ -165,6 +166,8 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+        expedited: None,
         veto: None,
     }
 }
@@ -454,6 +457,8 @@ fn vote_multiple(app: &mut App, module: Addr, sender: &str, id: u64, position: u
             proposal_id: id,
             vote: MultipleChoiceVote {
                 option_id: position,
+                ranking: None,
+                scores: None,
             },
             rationale: None,
         },
@@ -82,6 +82,8 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+        expedited: None,
         veto: None,
     }
 }
@@ -115,6 +117,8 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+        expedited: None,
         veto: None,
     }
 }
@@ -297,6 +301,8 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                relations: None,
+                expedited: false,
             },
         },
         funds,
@@ -1157,6 +1163,8 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -85,6 +85,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
         veto: None,
     }
 }
@@ -526,12 +527,20 @@ fn test_native_permutation(
         fn(&mut App, Addr, &str, u64) -> (),
     ) = match end_status {
         EndStatus::Passed => (
-            MultipleChoiceVote { option_id: 0 },
+            MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             Status::Passed,
             execute_proposal,
         ),
         EndStatus::Failed => (
-            MultipleChoiceVote { option_id: 2 },
+            MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             Status::Rejected,
             close_proposal,
         ),
@@ -604,12 +613,20 @@ fn test_cw20_permutation(
         fn(&mut App, Addr, &str, u64) -> (),
     ) = match end_status {
         EndStatus::Passed => (
-            MultipleChoiceVote { option_id: 0 },
+            MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             Status::Passed,
             execute_proposal,
         ),
         EndStatus::Failed => (
-            MultipleChoiceVote { option_id: 2 },
+            MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             Status::Rejected,
             close_proposal,
         ),
@@ -781,7 +798,11 @@ fn test_multiple_open_proposals() {
         proposal_single.clone(),
         "ekez",
         first_id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        },
     );
     assert_eq!(Status::Passed, new_status);
 
@@ -801,7 +822,11 @@ fn test_multiple_open_proposals() {
         proposal_single.clone(),
         "ekez",
         second_id,
-        MultipleChoiceVote { option_id: 2 },
+        MultipleChoiceVote {
+            option_id: 2,
+            ranking: None,
+            scores: None,
+        },
     );
     assert_eq!(Status::Rejected, new_status);
 
@@ -952,7 +977,11 @@ fn test_propose_open_proposal_submission() {
         proposal_single,
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        },
     );
     assert_eq!(Status::Passed, new_status)
 }
@@ -982,7 +1011,11 @@ fn test_no_deposit_required_open_submission() {
         proposal_single,
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        },
     );
     assert_eq!(Status::Passed, new_status)
 }
@@ -1033,7 +1066,11 @@ fn test_no_deposit_required_members_submission() {
         proposal_single,
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        },
     );
     assert_eq!(Status::Passed, new_status)
 }
@@ -1367,6 +1404,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
             veto: None,
         }
     };
@@ -1434,6 +1472,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
             veto: None,
         }
     };
@@ -1551,14 +1590,22 @@ fn test_update_config() {
         proposal_single.clone(),
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        },
     );
     vote(
         &mut app,
         proposal_single.clone(),
         "ekez",
         new_id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        },
     );
     execute_proposal(&mut app, proposal_single.clone(), "ekez", id);
     execute_proposal(&mut app, proposal_single.clone(), "ekez", new_id);
@@ -2193,7 +2240,11 @@ fn test_withdraw() {
         proposal_single.clone(),
         "ekez",
         cw20_id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        },
     );
     execute_proposal(&mut app, proposal_single.clone(), "ekez", cw20_id);
 
@@ -2215,7 +2266,11 @@ fn test_withdraw() {
         proposal_single.clone(),
         "ekez",
         native_id,
-        MultipleChoiceVote { option_id: 2 },
+        MultipleChoiceVote {
+            option_id: 2,
+            ranking: None,
+            scores: None,
+        },
     );
     close_proposal(&mut app, proposal_single.clone(), "ekez", native_id);
     withdraw(
@@ -2555,7 +2610,11 @@ fn test_migrate_from_v241() {
                     ],
                 },
                 vote: Some(MultipleChoiceAutoVote {
-                    vote: MultipleChoiceVote { option_id: 1 },
+                    vote: MultipleChoiceVote {
+                        option_id: 1,
+                        ranking: None,
+                        scores: None,
+                    },
                     rationale: None,
                 }),
             },
@@ -2967,7 +3026,11 @@ fn test_migrate_from_v241_with_policy_update() {
         proposal_single.clone(),
         &dao_proposal_multiple::msg::ExecuteMsg::Vote {
             proposal_id: 3,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
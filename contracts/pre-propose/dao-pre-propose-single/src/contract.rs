@@ -14,7 +14,10 @@ use dao_pre_propose_base::{
     },
     state::PreProposeContract,
 };
-use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
+use dao_voting::{
+    proposal::SingleChoiceProposeMsg as ProposeMsg, relations::ProposalRelations,
+    voting::SingleChoiceAutoVote,
+};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -30,6 +33,12 @@ pub enum ProposeMessage {
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
         vote: Option<SingleChoiceAutoVote>,
+        /// Proposals the proposal depends on or conflicts with.
+        relations: Option<ProposalRelations>,
+        /// Whether the proposal is expedited. The proposal module must
+        /// have expedited proposals enabled.
+        #[serde(default)]
+        expedited: bool,
     },
 }
 
@@ -80,6 +89,8 @@ pub fn execute(
                     description,
                     msgs,
                     vote,
+                    relations,
+                    expedited,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose(ProposeMsg {
@@ -89,6 +100,8 @@ pub fn execute(
                 description,
                 msgs,
                 vote,
+                relations,
+                expedited,
             }),
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
@@ -1,5 +1,5 @@
 use cosmwasm_std::{
-    coins, from_json, to_json_binary, Addr, Coin, CosmosMsg, Empty, Uint128, WasmMsg,
+    coins, from_json, to_json_binary, Addr, Coin, CosmosMsg, Decimal, Empty, Uint128, WasmMsg,
 };
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
@@ -26,6 +26,7 @@ use dao_voting::pre_propose::{PreProposeSubmissionPolicy, PreProposeSubmissionPo
 use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    relations::{CheckedProposalRef, CheckedProposalRelations, ProposalRef, ProposalRelations},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
     voting::{SingleChoiceAutoVote, Vote},
@@ -81,6 +82,8 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+        expedited: None,
         veto: None,
     }
 }
@@ -204,6 +207,8 @@ fn make_proposal(
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                relations: None,
+                expedited: false,
             },
         },
         funds,
@@ -863,6 +868,8 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -933,6 +940,134 @@ fn test_no_deposit_required_open_submission() {
     assert_eq!(Status::Passed, new_status)
 }
 
+#[test]
+fn test_propose_with_relations() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let first = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose,
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                vote: None,
+                relations: Some(ProposalRelations {
+                    depends_on: vec![ProposalRef {
+                        module: proposal_single.to_string(),
+                        proposal_id: first,
+                    }],
+                    conflicts_with: vec![],
+                }),
+                expedited: false,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The relations are passed through to the proposal module.
+    let relations: CheckedProposalRelations = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::ProposalRelations {
+                proposal_id: first + 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        relations.depends_on,
+        vec![CheckedProposalRef {
+            module: proposal_single,
+            proposal_id: first,
+        }]
+    );
+}
+
+#[test]
+fn test_propose_expedited() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    // Enable expedited proposals on the proposal module.
+    let config: dps::state::Config = app
+        .wrap()
+        .query_wasm_smart(&proposal_single, &dps::msg::QueryMsg::Config {})
+        .unwrap();
+    app.execute_contract(
+        core_addr,
+        proposal_single.clone(),
+        &dps::msg::ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.to_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            veto: config.veto,
+            vote_weighting: config.vote_weighting,
+            expedited: Some(dps::state::ExpeditedConfig {
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Percent(Decimal::percent(67)),
+                },
+                max_voting_period: Duration::Time(3600),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose,
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                vote: None,
+                relations: None,
+                expedited: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The proposal module creates the proposal as expedited.
+    let id: u64 = app
+        .wrap()
+        .query_wasm_smart(&proposal_single, &dps::msg::QueryMsg::ProposalCount {})
+        .unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::Proposal { proposal_id: id },
+        )
+        .unwrap();
+    assert!(proposal.proposal.expedited.is_some());
+}
+
 #[test]
 fn test_no_deposit_required_members_submission() {
     let mut app = App::default();
@@ -955,6 +1090,8 @@ fn test_no_deposit_required_members_submission() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1023,6 +1160,8 @@ fn test_anyone_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1085,6 +1224,8 @@ fn test_specific_allowlist_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1143,6 +1284,8 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1179,6 +1322,8 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -1277,6 +1422,8 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+            expedited: None,
             veto: None,
         }
     };
@@ -1344,6 +1491,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+            expedited: None,
             veto: None,
         }
     };
@@ -2313,6 +2462,8 @@ fn test_migrate_from_v241() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -2371,6 +2522,8 @@ fn test_migrate_from_v241() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -2441,6 +2594,8 @@ fn test_migrate_from_v241() {
                     vote: Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -2610,6 +2765,8 @@ fn test_migrate_from_v241_with_policy_update() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -2675,6 +2832,8 @@ fn test_migrate_from_v241_with_policy_update() {
                     vote: dv_v241::voting::Vote::Yes,
                     rationale: None,
                 }),
+                relations: None,
+                expedited: false,
             },
         },
         &[],
@@ -2743,6 +2902,8 @@ fn test_migrate_from_v241_with_policy_update() {
                     description: "d".to_string(),
                     msgs: vec![],
                     vote: None,
+                    relations: None,
+                    expedited: false,
                 },
             },
             &[],
@@ -2764,6 +2925,8 @@ fn test_migrate_from_v241_with_policy_update() {
                 description: "d".to_string(),
                 msgs: vec![],
                 vote: None,
+                relations: None,
+                expedited: false,
             },
         },
         &[],
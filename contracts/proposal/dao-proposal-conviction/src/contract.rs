@@ -0,0 +1,951 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_denom::UncheckedDenom;
+use cw_hooks::Hooks;
+use cw_storage_plus::Bound;
+use cw_utils::{parse_reply_instantiate_data, Duration};
+use dao_hooks::nft_stake::NftStakeChangedHookMsg;
+use dao_hooks::proposal::{
+    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+};
+use dao_hooks::stake::StakeChangedHookMsg;
+use dao_interface::voting::InfoResponse;
+use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+use dao_voting::proposal::{ConvictionProposeMsg as ProposeMsg, DEFAULT_LIMIT};
+use dao_voting::reply::{
+    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+};
+use dao_voting::status::Status;
+use dao_voting::voting::{get_total_power, get_voting_power};
+
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::proposal::{
+    advance_proposal_id, conviction_threshold, next_proposal_id, ConvictionProposal,
+};
+use crate::query::{ProposalListResponse, ProposalResponse, StakeInfo, StakeListResponse};
+use crate::state::{
+    Config, CONFIG, CREATION_POLICY, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, STAKES,
+};
+use crate::ContractError;
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-conviction";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let dao = info.sender;
+
+    let (initial_policy, pre_propose_messages) = msg
+        .pre_propose_info
+        .into_initial_policy_and_messages(dao.clone())?;
+
+    let config = validate_config(
+        deps.as_ref(),
+        dao.clone(),
+        msg.denom,
+        msg.decay,
+        msg.conviction_period,
+        msg.max_ratio,
+        msg.weight,
+        msg.close_proposal_on_execution_failure,
+    )?;
+
+    // Initialize proposal count to zero so that queries return zero
+    // instead of None.
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+    CONFIG.save(deps.storage, &config)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(pre_propose_messages)
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose(propose_msg) => execute_propose(deps, env, info.sender, propose_msg),
+        ExecuteMsg::Stake {
+            proposal_id,
+            amount,
+        } => execute_stake(deps, env, info, proposal_id, amount),
+        ExecuteMsg::Unstake {
+            proposal_id,
+            amount,
+        } => execute_unstake(deps, env, info, proposal_id, amount),
+        ExecuteMsg::Refresh { voter } => execute_refresh(deps, env, voter),
+        ExecuteMsg::StakeChangeHook(msg) => match msg {
+            StakeChangedHookMsg::Stake { .. } => Ok(Response::default()),
+            StakeChangedHookMsg::Unstake { addr, .. } => execute_power_decreased(deps, env, addr),
+        },
+        ExecuteMsg::NftStakeChangeHook(msg) => match msg {
+            NftStakeChangedHookMsg::Stake { .. } => Ok(Response::default()),
+            NftStakeChangedHookMsg::Unstake { addr, .. } => {
+                execute_power_decreased(deps, env, addr)
+            }
+        },
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::UpdateConfig {
+            denom,
+            decay,
+            conviction_period,
+            max_ratio,
+            weight,
+            close_proposal_on_execution_failure,
+        } => execute_update_config(
+            deps,
+            env,
+            info,
+            denom,
+            decay,
+            conviction_period,
+            max_ratio,
+            weight,
+            close_proposal_on_execution_failure,
+        ),
+        ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
+            execute_update_proposal_creation_policy(deps, info, new_info)
+        }
+        ExecuteMsg::AddProposalHook { address } => execute_add_proposal_hook(deps, info, address),
+        ExecuteMsg::RemoveProposalHook { address } => {
+            execute_remove_proposal_hook(deps, info, address)
+        }
+    }
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    ProposeMsg {
+        title,
+        description,
+        beneficiary,
+        amount,
+        proposer,
+    }: ProposeMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+
+    // Check that the sender is permitted to create proposals.
+    if !proposal_creation_policy.is_permitted(&sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Determine the appropriate proposer. If this is coming from our
+    // pre-propose module, it must be specified. Otherwise, the
+    // proposer should not be specified.
+    let proposer = match (proposer, &proposal_creation_policy) {
+        (None, ProposalCreationPolicy::Anyone {}) => sender.clone(),
+        // `is_permitted` above checks that an allowed module is
+        // actually sending the propose message.
+        (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
+            deps.api.addr_validate(&proposer)?
+        }
+        _ => return Err(ContractError::InvalidProposer {}),
+    };
+
+    let beneficiary = deps.api.addr_validate(&beneficiary)?;
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+
+    // Reject proposals that could never pass given the current
+    // treasury. The treasury may shrink after this, in which case
+    // the proposal will not be able to pass until it grows again.
+    let treasury = config.denom.query_balance(&deps.querier, &config.dao)?;
+    let total_power = get_total_power(deps.as_ref(), &config.dao, None)?;
+    if conviction_threshold(&config, amount, treasury, total_power).is_none() {
+        return Err(ContractError::RequestTooLarge { amount });
+    }
+
+    let proposal = ConvictionProposal {
+        title,
+        description,
+        proposer: proposer.clone(),
+        beneficiary,
+        amount,
+        start_height: env.block.height,
+        status: Status::Open,
+        staked: Uint128::zero(),
+        conviction: Uint128::zero(),
+        updated_height: env.block.height,
+        updated_time: env.block.time,
+    };
+    let id = advance_proposal_id(deps.storage)?;
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "propose")
+        .add_attribute("sender", sender)
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("status", proposal.status.to_string()))
+}
+
+pub fn execute_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+
+    // Stakes are limited by voting power when the proposal was
+    // created, so that tokens moved to another address after then
+    // can not be staked on it twice. They are also limited by current
+    // voting power, so that voting power given up since then can not
+    // be staked.
+    let power = get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        &config.dao,
+        Some(prop.start_height),
+    )?
+    .min(current_voting_power(
+        deps.as_ref(),
+        &env,
+        &config,
+        &info.sender,
+    )?);
+    if power.is_zero() {
+        return Err(ContractError::NoVotingPower {});
+    }
+    let staked = active_stakes(deps.storage, &info.sender)?;
+    let available = power.saturating_sub(staked);
+    if amount > available {
+        return Err(ContractError::InsufficientVotingPower { available });
+    }
+
+    // Conviction accrued so far was accrued with the previous stake.
+    prop.update_conviction(&env.block, &config);
+    prop.staked = prop.staked.checked_add(amount)?;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    STAKES.update(
+        deps.storage,
+        (&info.sender, proposal_id),
+        |stake| -> Result<_, ContractError> { Ok(stake.unwrap_or_default().checked_add(amount)?) },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "stake")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let staked = STAKES
+        .may_load(deps.storage, (&info.sender, proposal_id))?
+        .unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    if amount > staked {
+        return Err(ContractError::InsufficientStake { staked });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    remove_stake(
+        deps.storage,
+        &env,
+        &config,
+        &info.sender,
+        proposal_id,
+        amount,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "unstake")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_refresh(deps: DepsMut, env: Env, voter: String) -> Result<Response, ContractError> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let (power, removed) =
+        refresh_stakes(deps, &env, &voter)?.ok_or(ContractError::NotOverstaked {})?;
+
+    Ok(Response::default()
+        .add_attribute("action", "refresh")
+        .add_attribute("voter", voter)
+        .add_attribute("power", power)
+        .add_attribute("removed", removed))
+}
+
+/// Handles a stake changed hook from the DAO's voting module
+/// reporting that `addr` has unstaked, removing its stakes if it no
+/// longer has the voting power to back them. Does not error if they
+/// are still backed, as voting modules stop sending hooks to
+/// receivers that error.
+pub fn execute_power_decreased(
+    deps: DepsMut,
+    env: Env,
+    addr: Addr,
+) -> Result<Response, ContractError> {
+    let removed = refresh_stakes(deps, &env, &addr)?
+        .map(|(_, removed)| removed)
+        .unwrap_or_default();
+
+    Ok(Response::default()
+        .add_attribute("action", "power_decreased")
+        .add_attribute("voter", addr)
+        .add_attribute("removed", removed))
+}
+
+pub fn execute_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+
+    prop.update_conviction(&env.block, &config);
+    let threshold = query_threshold(deps.as_ref(), &config, prop.amount)?;
+    if !matches!(threshold, Some(threshold) if prop.conviction >= threshold) {
+        return Err(ContractError::NotPassed {});
+    }
+
+    let old_status = prop.status;
+    prop.status = Status::Executed;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let execute_message = WasmMsg::Execute {
+        contract_addr: config.dao.to_string(),
+        msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![config
+                .denom
+                .get_transfer_to_message(&prop.beneficiary, prop.amount)?],
+        })?,
+        funds: vec![],
+    };
+    let response = match config.close_proposal_on_execution_failure {
+        true => {
+            let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
+            Response::default()
+                .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
+        }
+        false => Response::default().add_message(execute_message),
+    };
+
+    // Add proposal status change hooks
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks =
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+
+    Ok(response
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_attribute("action", "execute")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("dao", config.dao))
+}
+
+pub fn execute_close(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if info.sender != prop.proposer && info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+
+    let old_status = prop.status;
+
+    // Stakes on closed proposals are released the next time their
+    // owners stake, so there is no need to iterate over them here.
+    prop.update_conviction(&env.block, &config);
+    prop.status = Status::Closed;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    // Add proposal status change hooks
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks =
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+
+    Ok(Response::default()
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_attribute("action", "close")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: UncheckedDenom,
+    decay: Decimal,
+    conviction_period: Duration,
+    max_ratio: Decimal,
+    weight: Decimal,
+    close_proposal_on_execution_failure: bool,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only the DAO may call this method.
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_config = validate_config(
+        deps.as_ref(),
+        config.dao.clone(),
+        denom,
+        decay,
+        conviction_period,
+        max_ratio,
+        weight,
+        close_proposal_on_execution_failure,
+    )?;
+
+    // Bring open proposals up to date so that conviction accrued
+    // under the old config is not recomputed under the new one.
+    let proposals = PROPOSALS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (id, mut prop) in proposals {
+        if prop.status != Status::Open {
+            continue;
+        }
+        prop.update_conviction(&env.block, &config);
+        // Periods are counted from the current block under the new
+        // config.
+        prop.updated_height = env.block.height;
+        prop.updated_time = env.block.time;
+        PROPOSALS.save(deps.storage, id, &prop)?;
+    }
+
+    CONFIG.save(deps.storage, &new_config)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_config")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_update_proposal_creation_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_info: PreProposeInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (initial_policy, messages) = new_info.into_initial_policy_and_messages(config.dao)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("action", "update_proposal_creation_policy")
+        .add_attribute("sender", info.sender)
+        .add_attribute("new_policy", format!("{initial_policy:?}")))
+}
+
+pub fn add_hook(
+    hooks: Hooks,
+    storage: &mut dyn Storage,
+    validated_address: Addr,
+) -> Result<(), ContractError> {
+    hooks
+        .add_hook(storage, validated_address)
+        .map_err(ContractError::HookError)?;
+    Ok(())
+}
+
+pub fn remove_hook(
+    hooks: Hooks,
+    storage: &mut dyn Storage,
+    validate_address: Addr,
+) -> Result<(), ContractError> {
+    hooks
+        .remove_hook(storage, validate_address)
+        .map_err(ContractError::HookError)?;
+    Ok(())
+}
+
+pub fn execute_add_proposal_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can add hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    add_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_proposal_hook")
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_proposal_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can remove hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    remove_hook(PROPOSAL_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_proposal_hook")
+        .add_attribute("address", address))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn validate_config(
+    deps: Deps,
+    dao: Addr,
+    denom: UncheckedDenom,
+    decay: Decimal,
+    conviction_period: Duration,
+    max_ratio: Decimal,
+    weight: Decimal,
+    close_proposal_on_execution_failure: bool,
+) -> Result<Config, ContractError> {
+    if decay.is_zero() || decay >= Decimal::one() {
+        return Err(ContractError::InvalidDecay {});
+    }
+    if max_ratio.is_zero() || max_ratio > Decimal::one() {
+        return Err(ContractError::InvalidMaxRatio {});
+    }
+    if weight.is_zero() {
+        return Err(ContractError::ZeroWeight {});
+    }
+    if matches!(conviction_period, Duration::Height(0) | Duration::Time(0)) {
+        return Err(ContractError::ZeroConvictionPeriod {});
+    }
+
+    Ok(Config {
+        dao,
+        denom: denom.into_checked(deps)?,
+        decay,
+        conviction_period,
+        max_ratio,
+        weight,
+        close_proposal_on_execution_failure,
+    })
+}
+
+/// Returns `voter`'s voting power including any changes made earlier
+/// in the current block, which voting modules make visible from the
+/// next block.
+fn current_voting_power(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    voter: &Addr,
+) -> StdResult<Uint128> {
+    get_voting_power(deps, voter.clone(), &config.dao, Some(env.block.height + 1))
+}
+
+/// Removes all of `voter`'s stakes if they exceed its current voting
+/// power. Returns its voting power and the amount removed if they
+/// did, or None if they did not.
+fn refresh_stakes(
+    deps: DepsMut,
+    env: &Env,
+    voter: &Addr,
+) -> Result<Option<(Uint128, Uint128)>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let power = current_voting_power(deps.as_ref(), env, &config, voter)?;
+    let staked = active_stakes(deps.storage, voter)?;
+    if staked <= power {
+        return Ok(None);
+    }
+
+    let stakes = STAKES
+        .prefix(voter)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (proposal_id, amount) in stakes {
+        remove_stake(deps.storage, env, &config, voter, proposal_id, amount)?;
+    }
+    Ok(Some((power, staked)))
+}
+
+/// Returns the total voting power `voter` has staked on open
+/// proposals. Stakes on proposals that are no longer open are
+/// removed.
+fn active_stakes(storage: &mut dyn Storage, voter: &Addr) -> Result<Uint128, ContractError> {
+    let stakes = STAKES
+        .prefix(voter)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut total = Uint128::zero();
+    for (proposal_id, amount) in stakes {
+        let prop = PROPOSALS.load(storage, proposal_id)?;
+        if prop.status == Status::Open {
+            total = total.checked_add(amount)?;
+        } else {
+            STAKES.remove(storage, (voter, proposal_id));
+        }
+    }
+    Ok(total)
+}
+
+/// Removes `amount` of `voter`'s stake on a proposal, updating the
+/// proposal's conviction if it is still open.
+fn remove_stake(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    voter: &Addr,
+    proposal_id: u64,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let mut prop = PROPOSALS.load(storage, proposal_id)?;
+    if prop.status == Status::Open {
+        prop.update_conviction(&env.block, config);
+        prop.staked = prop.staked.checked_sub(amount)?;
+        PROPOSALS.save(storage, proposal_id, &prop)?;
+    }
+
+    let remaining = STAKES
+        .load(storage, (voter, proposal_id))?
+        .checked_sub(amount)?;
+    if remaining.is_zero() {
+        STAKES.remove(storage, (voter, proposal_id));
+    } else {
+        STAKES.save(storage, (voter, proposal_id), &remaining)?;
+    }
+    Ok(())
+}
+
+/// Computes the conviction a proposal requesting `amount` currently
+/// needs to pass.
+fn query_threshold(deps: Deps, config: &Config, amount: Uint128) -> StdResult<Option<Uint128>> {
+    let treasury = config.denom.query_balance(&deps.querier, &config.dao)?;
+    let total_power = get_total_power(deps, &config.dao, None)?;
+    Ok(conviction_threshold(config, amount, treasury, total_power))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => query_config(deps),
+        QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::Proposal { proposal_id } => query_proposal(deps, env, proposal_id),
+        QueryMsg::ListProposals { start_after, limit } => {
+            query_list_proposals(deps, env, start_after, limit)
+        }
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
+        QueryMsg::ProposalCount {} => query_proposal_count(deps),
+        QueryMsg::GetStake { proposal_id, voter } => query_stake(deps, proposal_id, voter),
+        QueryMsg::ListStakes {
+            voter,
+            start_after,
+            limit,
+        } => query_list_stakes(deps, voter, start_after, limit),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
+        QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+    }
+}
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&config)
+}
+
+pub fn query_dao(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&config.dao)
+}
+
+/// Builds a proposal query response with conviction and status
+/// computed as of the current block.
+fn proposal_response(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    id: u64,
+    mut proposal: ConvictionProposal,
+) -> StdResult<ProposalResponse> {
+    let threshold = query_threshold(deps, config, proposal.amount)?;
+    if proposal.status == Status::Open {
+        proposal.update_conviction(&env.block, config);
+        if matches!(threshold, Some(threshold) if proposal.conviction >= threshold) {
+            proposal.status = Status::Passed;
+        }
+    }
+    Ok(ProposalResponse {
+        id,
+        conviction: proposal.conviction,
+        threshold,
+        proposal,
+    })
+}
+
+pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_json_binary(&proposal_response(deps, &env, &config, id, proposal)?)
+}
+
+pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
+    let policy = CREATION_POLICY.load(deps.storage)?;
+    to_json_binary(&policy)
+}
+
+pub fn query_list_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let props: Vec<ProposalResponse> = PROPOSALS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<Result<Vec<(u64, ConvictionProposal)>, _>>()?
+        .into_iter()
+        .map(|(id, proposal)| proposal_response(deps, &env, &config, id, proposal))
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_reverse_proposals(
+    deps: Deps,
+    env: Env,
+    start_before: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let max = start_before.map(Bound::exclusive);
+    let props: Vec<ProposalResponse> = PROPOSALS
+        .range(deps.storage, None, max, Order::Descending)
+        .take(limit as usize)
+        .collect::<Result<Vec<(u64, ConvictionProposal)>, _>>()?
+        .into_iter()
+        .map(|(id, proposal)| proposal_response(deps, &env, &config, id, proposal))
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
+    let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
+    to_json_binary(&proposal_count)
+}
+
+pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&next_proposal_id(deps.storage)?)
+}
+
+pub fn query_stake(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let stake = STAKES
+        .may_load(deps.storage, (&voter, proposal_id))?
+        .unwrap_or_default();
+    to_json_binary(&stake)
+}
+
+pub fn query_list_stakes(
+    deps: Deps,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let stakes = STAKES
+        .prefix(&voter)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            item.map(|(proposal_id, amount)| StakeInfo {
+                proposal_id,
+                amount,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&StakeListResponse { stakes })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = get_contract_version(deps.storage)?;
+    to_json_binary(&InfoResponse { info })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let repl = TaggedReplyId::new(msg.id)?;
+    match repl {
+        TaggedReplyId::FailedProposalExecution(proposal_id) => {
+            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+                Some(mut prop) => {
+                    prop.status = Status::ExecutionFailed;
+
+                    Ok(prop)
+                }
+                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("proposal_execution_failed", proposal_id.to_string())
+                .add_attribute(
+                    "error",
+                    msg.result.into_result().err().unwrap_or("None".to_string()),
+                ))
+        }
+        TaggedReplyId::FailedProposalHook(idx) => {
+            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::PreProposeModuleInstantiation => {
+            let res = parse_reply_instantiate_data(msg)?;
+
+            let module = deps.api.addr_validate(&res.contract_address)?;
+            CREATION_POLICY.save(
+                deps.storage,
+                &ProposalCreationPolicy::Module { addr: module },
+            )?;
+
+            match res.data {
+                Some(data) => Ok(Response::new()
+                    .add_attribute("update_pre_propose_module", res.contract_address)
+                    .set_data(data)),
+                None => Ok(Response::new()
+                    .add_attribute("update_pre_propose_module", res.contract_address)),
+            }
+        }
+        TaggedReplyId::FailedPreProposeModuleHook => {
+            let addr = match CREATION_POLICY.load(deps.storage)? {
+                ProposalCreationPolicy::Anyone {} => {
+                    // Something is off if we're getting this
+                    // reply and we don't have a pre-propose
+                    // module installed. This should be
+                    // unreachable.
+                    return Err(ContractError::InvalidReplyID {
+                        id: failed_pre_propose_module_hook_id(),
+                    });
+                }
+                ProposalCreationPolicy::Module { addr } => {
+                    // If we are here, our pre-propose module has
+                    // errored while receiving a proposal
+                    // hook. Rest in peace pre-propose module.
+                    CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
+                    addr
+                }
+            };
+            Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
+        }
+        // This module has no vote hooks.
+        TaggedReplyId::FailedVoteHook(_)
+        | TaggedReplyId::PartialProposalExecution { .. }
+        | TaggedReplyId::FailedExtendedProposalHook(_) => {
+            Err(ContractError::InvalidReplyID { id: msg.id })
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use cw_denom::DenomError;
+use cw_hooks::HookError;
+use cw_utils::ParseReplyError;
+use dao_voting::reply::error::TagError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error(transparent)]
+    ParseReplyError(#[from] ParseReplyError),
+
+    #[error(transparent)]
+    HookError(#[from] HookError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error(transparent)]
+    Tag(#[from] TagError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("no such proposal ({id})")]
+    NoSuchProposal { id: u64 },
+
+    #[error("decay must be greater than zero and less than one")]
+    InvalidDecay {},
+
+    #[error("max_ratio must be greater than zero and no more than one")]
+    InvalidMaxRatio {},
+
+    #[error("weight must be greater than zero")]
+    ZeroWeight {},
+
+    #[error("conviction period must be non-zero")]
+    ZeroConvictionPeriod {},
+
+    #[error("amount must be non-zero")]
+    ZeroAmount {},
+
+    #[error("proposal requests ({amount}) which is more than the maximum share of the treasury a proposal may request")]
+    RequestTooLarge { amount: Uint128 },
+
+    #[error(
+        "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
+    )]
+    InvalidProposer {},
+
+    #[error("proposal is not open")]
+    NotOpen {},
+
+    #[error("proposal has not reached the conviction required to pass")]
+    NotPassed {},
+
+    #[error("no voting power")]
+    NoVotingPower {},
+
+    #[error("can not stake more than available voting power ({available})")]
+    InsufficientVotingPower { available: Uint128 },
+
+    #[error("can not unstake more than is staked ({staked})")]
+    InsufficientStake { staked: Uint128 },
+
+    #[error("voter has not staked more than their voting power")]
+    NotOverstaked {},
+
+    #[error("received a reply failure with an invalid ID: ({id})")]
+    InvalidReplyID { id: u64 },
+}
@@ -0,0 +1,166 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
+use cw_denom::UncheckedDenom;
+use cw_utils::Duration;
+use dao_dao_macros::proposal_module_query;
+use dao_hooks::{nft_stake::NftStakeChangedHookMsg, stake::StakeChangedHookMsg};
+use dao_voting::{pre_propose::PreProposeInfo, proposal::ConvictionProposeMsg};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The denomination proposals request funding in.
+    pub denom: UncheckedDenom,
+    /// The fraction of a proposal's conviction that is retained
+    /// after each conviction period. Must be greater than zero and
+    /// less than one. Values closer to one cause conviction to build
+    /// and decay more slowly.
+    pub decay: Decimal,
+    /// The amount of time after which conviction is updated.
+    pub conviction_period: Duration,
+    /// The maximum share of the treasury that a single proposal may
+    /// request. Must be greater than zero and no more than one.
+    pub max_ratio: Decimal,
+    /// Scales the conviction required for proposals to pass. Must be
+    /// greater than zero.
+    pub weight: Decimal,
+    /// Information about what addresses may create proposals.
+    pub pre_propose_info: PreProposeInfo,
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain open after execution
+    /// failure and may be executed again once the DAO's treasury is
+    /// large enough.
+    pub close_proposal_on_execution_failure: bool,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a proposal in the module.
+    Propose(ConvictionProposeMsg),
+    /// Stakes voting power behind an open proposal. An address may
+    /// not stake more voting power across all open proposals than it
+    /// currently has, nor more than it had when the proposal was
+    /// created.
+    Stake {
+        /// The ID of the proposal to stake on.
+        proposal_id: u64,
+        /// The amount of voting power to stake.
+        amount: Uint128,
+    },
+    /// Removes voting power staked behind a proposal.
+    Unstake {
+        /// The ID of the proposal to unstake from.
+        proposal_id: u64,
+        /// The amount of voting power to unstake.
+        amount: Uint128,
+    },
+    /// Removes all of `voter`'s stakes if they have staked more
+    /// voting power than they currently have. Callable by anyone.
+    Refresh { voter: String },
+    /// Removes all of an address's stakes when it unstakes from the
+    /// DAO's voting module, if they exceed its remaining voting
+    /// power. Register this module as a stake changed hook receiver
+    /// of the voting module (or its staking contract) to keep stakes
+    /// backed without relying on `Refresh`.
+    StakeChangeHook(StakeChangedHookMsg),
+    /// As `StakeChangeHook`, for NFT staking voting modules.
+    NftStakeChangeHook(NftStakeChangedHookMsg),
+    /// Causes the funds requested by a passed proposal to be sent to
+    /// its beneficiary by the DAO. Callable by anyone.
+    Execute {
+        /// The ID of the proposal to execute.
+        proposal_id: u64,
+    },
+    /// Closes an open proposal. Only the proposer or the DAO may
+    /// call this method. If applicable this will cause the proposal
+    /// deposit associated with said proposal to be returned.
+    Close {
+        /// The ID of the proposal to close.
+        proposal_id: u64,
+    },
+    /// Updates the governance module's config. Only the DAO may call
+    /// this method. Changes apply to all open proposals.
+    UpdateConfig {
+        /// The denomination proposals request funding in.
+        denom: UncheckedDenom,
+        /// The fraction of a proposal's conviction that is retained
+        /// after each conviction period.
+        decay: Decimal,
+        /// The amount of time after which conviction is updated.
+        conviction_period: Duration,
+        /// The maximum share of the treasury that a single proposal
+        /// may request.
+        max_ratio: Decimal,
+        /// Scales the conviction required for proposals to pass.
+        weight: Decimal,
+        /// If set to true proposals will be closed if their execution
+        /// fails.
+        close_proposal_on_execution_failure: bool,
+    },
+    /// Update's the proposal creation policy used for this
+    /// module. Only the DAO may call this method.
+    UpdatePreProposeInfo { info: PreProposeInfo },
+    /// Adds an address as a consumer of proposal hooks. Consumers of
+    /// proposal hooks have hook messages executed on them whenever
+    /// the status of a proposal changes or a proposal is created. If
+    /// a consumer contract errors when handling a hook message it
+    /// will be removed from the list of consumers.
+    AddProposalHook { address: String },
+    /// Removes a consumer of proposal hooks.
+    RemoveProposalHook { address: String },
+}
+
+#[proposal_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the proposal module's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets information about a proposal.
+    #[returns(crate::query::ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Lists all the proposals that have been created in this
+    /// module.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposals {
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals that have been created in this
+    /// module in decending order of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ReverseProposals {
+        /// The proposal ID to start listing proposals before.
+        start_before: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Returns the number of proposals that have been created in this module.
+    #[returns(::std::primitive::u64)]
+    ProposalCount {},
+    /// Returns the voting power `voter` has staked on a proposal.
+    #[returns(::cosmwasm_std::Uint128)]
+    GetStake { proposal_id: u64, voter: String },
+    /// Lists the stakes of `voter` in ascending order of proposal ID.
+    #[returns(crate::query::StakeListResponse)]
+    ListStakes {
+        voter: String,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
+    /// Gets the current proposal creation policy for this module.
+    #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
+    ProposalCreationPolicy {},
+    /// Lists all of the consumers of proposal hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ProposalHooks {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
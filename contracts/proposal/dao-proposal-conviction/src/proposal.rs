@@ -0,0 +1,136 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Decimal, StdResult, Storage, Timestamp, Uint128};
+use cw_utils::Duration;
+use dao_voting::status::Status;
+
+use crate::state::{Config, PROPOSAL_COUNT};
+
+#[cw_serde]
+pub struct ConvictionProposal {
+    /// The title of the proposal
+    pub title: String,
+    /// The main body of the proposal text
+    pub description: String,
+    /// The address that created this proposal.
+    pub proposer: Addr,
+    /// The address that will receive the requested funds should this
+    /// proposal pass.
+    pub beneficiary: Addr,
+    /// The amount of the module's denomination requested.
+    pub amount: Uint128,
+    /// The block height at which this proposal was created.
+    pub start_height: u64,
+    /// The proposal status. Open proposals whose conviction exceeds
+    /// their threshold are reported as passed in query responses.
+    pub status: Status,
+    /// The total voting power currently staked behind this proposal.
+    pub staked: Uint128,
+    /// The proposal's conviction as of the last update.
+    pub conviction: Uint128,
+    /// The block height conviction was last updated at.
+    pub updated_height: u64,
+    /// The time conviction was last updated at.
+    pub updated_time: Timestamp,
+}
+
+pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
+    Ok(PROPOSAL_COUNT.may_load(store)?.unwrap_or_default() + 1)
+}
+
+pub fn advance_proposal_id(store: &mut dyn Storage) -> StdResult<u64> {
+    let id: u64 = next_proposal_id(store)?;
+    PROPOSAL_COUNT.save(store, &id)?;
+    Ok(id)
+}
+
+impl ConvictionProposal {
+    /// Accrues conviction for every conviction period that has
+    /// elapsed since the last update. After `n` periods with `x`
+    /// voting power staked, conviction `y` becomes
+    ///
+    /// ```text
+    /// y' = y * a^n + x * (1 - a^n) / (1 - a)
+    /// ```
+    ///
+    /// where `a` is the decay. Partial periods are carried over to
+    /// the next update.
+    pub fn update_conviction(&mut self, block: &BlockInfo, config: &Config) {
+        let periods = match config.conviction_period {
+            Duration::Height(h) => block.height.saturating_sub(self.updated_height) / h,
+            Duration::Time(t) => {
+                block
+                    .time
+                    .seconds()
+                    .saturating_sub(self.updated_time.seconds())
+                    / t
+            }
+        };
+        if periods == 0 {
+            return;
+        }
+
+        // `decay` is less than one, so after u32::MAX periods any
+        // previous conviction has long since decayed to zero.
+        let retained = config
+            .decay
+            .checked_pow(periods.min(u32::MAX as u64) as u32)
+            .unwrap_or(Decimal::zero());
+        let accrued = (Decimal::one() - retained) / (Decimal::one() - config.decay);
+        self.conviction = mul_saturating(self.conviction, retained)
+            .saturating_add(mul_saturating(self.staked, accrued));
+
+        match config.conviction_period {
+            Duration::Height(h) => {
+                self.updated_height += periods * h;
+                self.updated_time = block.time;
+            }
+            Duration::Time(t) => {
+                self.updated_time = self.updated_time.plus_seconds(periods * t);
+                self.updated_height = block.height;
+            }
+        }
+    }
+}
+
+/// Computes the conviction required for a proposal requesting
+/// `amount` to pass:
+///
+/// ```text
+/// weight * total_power / ((1 - decay) * (max_ratio - amount / treasury)^2)
+/// ```
+///
+/// Returns None if the proposal can not pass, either because the
+/// treasury is empty or because the proposal requests at least
+/// `max_ratio` of it.
+pub fn conviction_threshold(
+    config: &Config,
+    amount: Uint128,
+    treasury: Uint128,
+    total_power: Uint128,
+) -> Option<Uint128> {
+    if treasury.is_zero() {
+        return None;
+    }
+    let share = Decimal::checked_from_ratio(amount, treasury).ok()?;
+    if share >= config.max_ratio {
+        return None;
+    }
+    let distance = config.max_ratio - share;
+    let denominator = (Decimal::one() - config.decay)
+        .checked_mul(distance)
+        .ok()?
+        .checked_mul(distance)
+        .ok()?;
+    if denominator.is_zero() {
+        return None;
+    }
+    // Both decimals have the same number of decimal places, so
+    // dividing atomics gives the same result as dividing the
+    // decimals.
+    let threshold = total_power.full_mul(config.weight.atomics()) / denominator.atomics().into();
+    Uint128::try_from(threshold).ok()
+}
+
+fn mul_saturating(a: Uint128, b: Decimal) -> Uint128 {
+    a.checked_mul_floor(b).unwrap_or(Uint128::MAX)
+}
@@ -0,0 +1,41 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+use crate::proposal::ConvictionProposal;
+
+/// Information about a proposal returned by proposal queries.
+#[cw_serde]
+pub struct ProposalResponse {
+    /// The ID of the proposal being returned.
+    pub id: u64,
+    pub proposal: ConvictionProposal,
+    /// The proposal's conviction as of the current block.
+    pub conviction: Uint128,
+    /// The conviction the proposal must reach to pass given the
+    /// current treasury and total voting power. None if the proposal
+    /// can not currently pass because it requests too large a share
+    /// of the treasury.
+    pub threshold: Option<Uint128>,
+}
+
+/// A list of proposals returned by `ListProposals` and
+/// `ReverseProposals`.
+#[cw_serde]
+pub struct ProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+/// Voting power staked by an address on a proposal.
+#[cw_serde]
+pub struct StakeInfo {
+    /// The proposal the stake is on.
+    pub proposal_id: u64,
+    /// The amount of voting power staked.
+    pub amount: Uint128,
+}
+
+/// A list of stakes returned by `ListStakes`.
+#[cw_serde]
+pub struct StakeListResponse {
+    pub stakes: Vec<StakeInfo>,
+}
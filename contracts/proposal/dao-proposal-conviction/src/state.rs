@@ -0,0 +1,46 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw_denom::CheckedDenom;
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+use dao_voting::pre_propose::ProposalCreationPolicy;
+
+use crate::proposal::ConvictionProposal;
+
+/// The governance module's configuration.
+#[cw_serde]
+pub struct Config {
+    /// The address of the DAO that this governance module is
+    /// associated with.
+    pub dao: Addr,
+    /// The denomination proposals request funding in. The DAO's
+    /// balance of this denomination is the treasury that the share
+    /// requested by a proposal is computed against.
+    pub denom: CheckedDenom,
+    /// The fraction of a proposal's conviction that is retained
+    /// after each conviction period.
+    pub decay: Decimal,
+    /// The amount of time after which conviction is updated.
+    pub conviction_period: Duration,
+    /// The maximum share of the treasury that a single proposal may
+    /// request.
+    pub max_ratio: Decimal,
+    /// Scales the conviction required for proposals to pass.
+    pub weight: Decimal,
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain open after execution
+    /// failure.
+    pub close_proposal_on_execution_failure: bool,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub const PROPOSALS: Map<u64, ConvictionProposal> = Map::new("proposals");
+/// Voting power staked by an address on a proposal.
+pub const STAKES: Map<(&Addr, u64), Uint128> = Map::new("stakes");
+/// Consumers of proposal state change hooks.
+pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
+/// The address of the pre-propose module associated with this
+/// proposal module (if any).
+pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
@@ -0,0 +1,886 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, Uint128,
+};
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_interface::state::{ModuleInstantiateInfo, ProposalModule};
+use dao_pre_propose_base::{error::PreProposeError, state::PreProposeContract};
+use dao_testing::contracts::{
+    dao_proposal_conviction_contract, dao_proposal_hook_counter_contract,
+};
+use dao_testing::{
+    DaoTestingSuite, DaoTestingSuiteBase, GOV_DENOM, MEMBER1, MEMBER2, MEMBER3, MEMBER4,
+};
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
+    proposal::ConvictionProposeMsg,
+    status::Status,
+};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    query::{ProposalResponse, StakeInfo, StakeListResponse},
+    state::Config,
+    ContractError,
+};
+
+const DENOM: &str = "ujuno";
+const BENEFICIARY: &str = "beneficiary";
+
+struct TestSetup {
+    base: DaoTestingSuiteBase,
+    dao: Addr,
+    module: Addr,
+}
+
+/// The propose message of a minimal pre-propose module for this
+/// module. Proposers are set by the sender.
+#[cw_serde]
+enum PreProposeMessage {
+    Propose(ConvictionProposeMsg),
+}
+
+type PrePropose = PreProposeContract<Empty, Empty, Empty, Empty, PreProposeMessage>;
+
+fn pre_propose_instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: dao_pre_propose_base::msg::InstantiateMsg<Empty>,
+) -> Result<Response, PreProposeError> {
+    PrePropose::default().instantiate(deps, env, info, msg)
+}
+
+fn pre_propose_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: dao_pre_propose_base::msg::ExecuteMsg<PreProposeMessage, Empty>,
+) -> Result<Response, PreProposeError> {
+    PrePropose::default().execute(deps, env, info, msg)
+}
+
+fn pre_propose_query(
+    deps: Deps,
+    env: Env,
+    msg: dao_pre_propose_base::msg::QueryMsg<Empty>,
+) -> StdResult<Binary> {
+    PrePropose::default().query(deps, env, msg)
+}
+
+fn pre_propose_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        pre_propose_execute,
+        pre_propose_instantiate,
+        pre_propose_query,
+    ))
+}
+
+/// Creates a DAO with 10 voting power split between five members,
+/// 1000 tokens in its treasury, and a conviction module that
+/// requires 31 conviction for a proposal requesting 100 tokens.
+fn setup_test() -> TestSetup {
+    let mut base = DaoTestingSuiteBase::base();
+    let dao = base.cw4().dao();
+    // Advance a block so that member weights are visible.
+    base.advance_block();
+
+    let module = add_module(
+        &mut base,
+        &dao.core_addr,
+        PreProposeInfo::AnyoneMayPropose {},
+    );
+    TestSetup {
+        base,
+        dao: dao.core_addr,
+        module,
+    }
+}
+
+/// Funds `dao`'s treasury with 1000 tokens and adds a conviction
+/// module to it.
+fn add_module(
+    base: &mut DaoTestingSuiteBase,
+    dao: &Addr,
+    pre_propose_info: PreProposeInfo,
+) -> Addr {
+    base.app
+        .sudo(
+            BankSudo::Mint {
+                to_address: dao.to_string(),
+                amount: coins(1000, DENOM),
+            }
+            .into(),
+        )
+        .unwrap();
+
+    let code_id = base.app.store_code(dao_proposal_conviction_contract());
+    base.app
+        .execute_contract(
+            dao.clone(),
+            dao.clone(),
+            &dao_interface::msg::ExecuteMsg::UpdateProposalModules {
+                to_add: vec![ModuleInstantiateInfo {
+                    code_id,
+                    msg: to_json_binary(&InstantiateMsg {
+                        denom: UncheckedDenom::Native(DENOM.to_string()),
+                        decay: Decimal::percent(90),
+                        conviction_period: Duration::Height(1),
+                        max_ratio: Decimal::percent(50),
+                        weight: Decimal::percent(5),
+                        pre_propose_info,
+                        close_proposal_on_execution_failure: true,
+                    })
+                    .unwrap(),
+                    admin: None,
+                    funds: vec![],
+                    label: "conviction".to_string(),
+                }],
+                to_disable: vec![],
+            },
+            &[],
+        )
+        .unwrap();
+
+    let modules: Vec<ProposalModule> = base
+        .app
+        .wrap()
+        .query_wasm_smart(
+            dao,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    modules.last().unwrap().address.clone()
+}
+
+fn propose(
+    app: &mut App,
+    module: &Addr,
+    sender: &str,
+    amount: u128,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module.clone(),
+        &ExecuteMsg::Propose(ConvictionProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            beneficiary: BENEFICIARY.to_string(),
+            amount: Uint128::new(amount),
+            proposer: None,
+        }),
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn execute(
+    app: &mut App,
+    module: &Addr,
+    sender: &str,
+    msg: ExecuteMsg,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(Addr::unchecked(sender), module.clone(), &msg, &[])
+        .map_err(|e| e.downcast().unwrap())
+}
+
+fn stake(
+    app: &mut App,
+    module: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    amount: u128,
+) -> Result<AppResponse, ContractError> {
+    execute(
+        app,
+        module,
+        sender,
+        ExecuteMsg::Stake {
+            proposal_id,
+            amount: Uint128::new(amount),
+        },
+    )
+}
+
+fn query_proposal(app: &App, module: &Addr, proposal_id: u64) -> ProposalResponse {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::Proposal { proposal_id })
+        .unwrap()
+}
+
+#[test]
+fn test_conviction_passes_proposal() {
+    let TestSetup {
+        mut base,
+        dao,
+        module,
+    } = setup_test();
+
+    let config: Config = base
+        .app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.dao, dao);
+    assert_eq!(config.denom, CheckedDenom::Native(DENOM.to_string()));
+
+    propose(&mut base.app, &module, MEMBER1, 100).unwrap();
+    stake(&mut base.app, &module, MEMBER3, 1, 3).unwrap();
+    stake(&mut base.app, &module, MEMBER4, 1, 3).unwrap();
+
+    let stakes: StakeListResponse = base
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &module,
+            &QueryMsg::ListStakes {
+                voter: MEMBER3.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stakes.stakes,
+        vec![StakeInfo {
+            proposal_id: 1,
+            amount: Uint128::new(3)
+        }]
+    );
+
+    // 0.05 * 10 / ((1 - 0.9) * (0.5 - 0.1)^2) = 31.25
+    //
+    // With 6 staked, conviction after n blocks is
+    // 60 * (1 - 0.9^n). This is 28 after six blocks and 31 after
+    // seven.
+    for _ in 0..6 {
+        base.advance_block();
+    }
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.threshold, Some(Uint128::new(31)));
+    assert_eq!(prop.conviction, Uint128::new(28));
+    assert_eq!(prop.proposal.status, Status::Open);
+    let err = execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotPassed {});
+
+    base.advance_block();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.conviction, Uint128::new(31));
+    assert_eq!(prop.proposal.status, Status::Passed);
+
+    // Anyone may execute a passed proposal.
+    execute(
+        &mut base.app,
+        &module,
+        "anyone",
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap();
+
+    let balance = base.app.wrap().query_balance(BENEFICIARY, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(100));
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Executed);
+
+    let err = execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotOpen {});
+}
+
+#[test]
+fn test_stake_limited_by_voting_power() {
+    let TestSetup {
+        mut base,
+        dao: _,
+        module,
+    } = setup_test();
+
+    propose(&mut base.app, &module, MEMBER1, 100).unwrap();
+    propose(&mut base.app, &module, MEMBER1, 50).unwrap();
+
+    let err = stake(&mut base.app, &module, "non_member", 1, 1).unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+
+    // MEMBER2 has two voting power which may be split between
+    // proposals.
+    let err = stake(&mut base.app, &module, MEMBER2, 1, 3).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientVotingPower {
+            available: Uint128::new(2)
+        }
+    );
+    stake(&mut base.app, &module, MEMBER2, 1, 1).unwrap();
+    stake(&mut base.app, &module, MEMBER2, 2, 1).unwrap();
+    let err = stake(&mut base.app, &module, MEMBER2, 2, 1).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientVotingPower {
+            available: Uint128::zero()
+        }
+    );
+
+    // MEMBER2 has not staked more than their voting power.
+    let err = execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Refresh {
+            voter: MEMBER2.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotOverstaked {});
+
+    // Unstaking frees voting power.
+    let err = execute(
+        &mut base.app,
+        &module,
+        MEMBER2,
+        ExecuteMsg::Unstake {
+            proposal_id: 2,
+            amount: Uint128::new(2),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientStake {
+            staked: Uint128::one()
+        }
+    );
+    execute(
+        &mut base.app,
+        &module,
+        MEMBER2,
+        ExecuteMsg::Unstake {
+            proposal_id: 2,
+            amount: Uint128::one(),
+        },
+    )
+    .unwrap();
+    let prop = query_proposal(&base.app, &module, 2);
+    assert_eq!(prop.proposal.staked, Uint128::zero());
+    stake(&mut base.app, &module, MEMBER2, 2, 1).unwrap();
+
+    // Closing a proposal frees the voting power staked on it.
+    let err = execute(
+        &mut base.app,
+        &module,
+        MEMBER2,
+        ExecuteMsg::Close { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Close { proposal_id: 1 },
+    )
+    .unwrap();
+    let err = stake(&mut base.app, &module, MEMBER2, 1, 1).unwrap_err();
+    assert_eq!(err, ContractError::NotOpen {});
+    stake(&mut base.app, &module, MEMBER2, 2, 1).unwrap();
+
+    let stake: Uint128 = base
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &module,
+            &QueryMsg::GetStake {
+                proposal_id: 2,
+                voter: MEMBER2.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(stake, Uint128::new(2));
+}
+
+#[test]
+fn test_request_too_large() {
+    let TestSetup {
+        mut base,
+        dao,
+        module,
+    } = setup_test();
+
+    // Requests of half the treasury or more may never pass.
+    let err = propose(&mut base.app, &module, MEMBER1, 500).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RequestTooLarge {
+            amount: Uint128::new(500)
+        }
+    );
+    propose(&mut base.app, &module, MEMBER1, 499).unwrap();
+
+    // If the treasury shrinks, the proposal can not pass.
+    base.app
+        .send_tokens(dao, Addr::unchecked(MEMBER1), &coins(500, DENOM))
+        .unwrap();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.threshold, None);
+}
+
+#[test]
+fn test_update_config() {
+    let TestSetup {
+        mut base,
+        dao,
+        module,
+    } = setup_test();
+
+    let update = |decay: Decimal, max_ratio: Decimal, weight: Decimal, period: Duration| {
+        ExecuteMsg::UpdateConfig {
+            denom: UncheckedDenom::Native(DENOM.to_string()),
+            decay,
+            conviction_period: period,
+            max_ratio,
+            weight,
+            close_proposal_on_execution_failure: false,
+        }
+    };
+
+    let err = execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        update(
+            Decimal::percent(50),
+            Decimal::percent(50),
+            Decimal::percent(5),
+            Duration::Height(1),
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = execute(
+        &mut base.app,
+        &module,
+        dao.as_str(),
+        update(
+            Decimal::one(),
+            Decimal::percent(50),
+            Decimal::percent(5),
+            Duration::Height(1),
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidDecay {});
+
+    let err = execute(
+        &mut base.app,
+        &module,
+        dao.as_str(),
+        update(
+            Decimal::percent(50),
+            Decimal::percent(101),
+            Decimal::percent(5),
+            Duration::Height(1),
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidMaxRatio {});
+
+    let err = execute(
+        &mut base.app,
+        &module,
+        dao.as_str(),
+        update(
+            Decimal::percent(50),
+            Decimal::percent(50),
+            Decimal::zero(),
+            Duration::Height(1),
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ZeroWeight {});
+
+    let err = execute(
+        &mut base.app,
+        &module,
+        dao.as_str(),
+        update(
+            Decimal::percent(50),
+            Decimal::percent(50),
+            Decimal::percent(5),
+            Duration::Time(0),
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ZeroConvictionPeriod {});
+
+    execute(
+        &mut base.app,
+        &module,
+        dao.as_str(),
+        update(
+            Decimal::percent(50),
+            Decimal::one(),
+            Decimal::percent(5),
+            Duration::Time(6),
+        ),
+    )
+    .unwrap();
+
+    let config: Config = base
+        .app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.decay, Decimal::percent(50));
+    assert_eq!(config.conviction_period, Duration::Time(6));
+    assert!(!config.close_proposal_on_execution_failure);
+
+    // With max_ratio set to one, proposals may request up to the
+    // entire treasury.
+    propose(&mut base.app, &module, MEMBER1, 999).unwrap();
+}
+
+#[test]
+fn test_stake_limited_by_power_at_proposal_creation() {
+    let mut base = DaoTestingSuiteBase::base();
+    // MEMBER1 stakes 100 of 1000 governance tokens.
+    let dao = base.token().dao();
+    let module = add_module(
+        &mut base,
+        &dao.core_addr,
+        PreProposeInfo::AnyoneMayPropose {},
+    );
+    base.app
+        .execute_contract(
+            dao.core_addr.clone(),
+            dao.voting_module_addr.clone(),
+            &dao_voting_token_staked::msg::ExecuteMsg::AddHook {
+                addr: module.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    propose(&mut base.app, &module, MEMBER1, 100).unwrap();
+    stake(&mut base.app, &module, MEMBER1, 1, 100).unwrap();
+
+    // MEMBER1 unstakes and moves their tokens to another address,
+    // which stakes them. The stake changed hook removes MEMBER1's
+    // stake, which is no longer backed by voting power.
+    base.app
+        .execute_contract(
+            Addr::unchecked(MEMBER1),
+            dao.voting_module_addr.clone(),
+            &dao_voting_token_staked::msg::ExecuteMsg::Unstake {
+                amount: Uint128::new(100),
+            },
+            &[],
+        )
+        .unwrap();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.staked, Uint128::zero());
+    base.app
+        .send_tokens(
+            Addr::unchecked(MEMBER1),
+            Addr::unchecked("other"),
+            &coins(100, GOV_DENOM),
+        )
+        .unwrap();
+    base.app
+        .execute_contract(
+            Addr::unchecked("other"),
+            dao.voting_module_addr.clone(),
+            &dao_voting_token_staked::msg::ExecuteMsg::Stake {},
+            &coins(100, GOV_DENOM),
+        )
+        .unwrap();
+    base.advance_block();
+
+    // Neither address may stake the tokens on the proposal again.
+    let err = stake(&mut base.app, &module, MEMBER1, 1, 100).unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+    let err = stake(&mut base.app, &module, "other", 1, 100).unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+
+    // The new address may stake them on proposals created since.
+    propose(&mut base.app, &module, MEMBER2, 100).unwrap();
+    stake(&mut base.app, &module, "other", 2, 100).unwrap();
+}
+
+#[test]
+fn test_stake_change_hook_keeps_backed_stakes() {
+    let mut base = DaoTestingSuiteBase::base();
+    // MEMBER2 stakes 200 of 1000 governance tokens.
+    let dao = base.token().dao();
+    let module = add_module(
+        &mut base,
+        &dao.core_addr,
+        PreProposeInfo::AnyoneMayPropose {},
+    );
+    base.app
+        .execute_contract(
+            dao.core_addr.clone(),
+            dao.voting_module_addr.clone(),
+            &dao_voting_token_staked::msg::ExecuteMsg::AddHook {
+                addr: module.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    propose(&mut base.app, &module, MEMBER2, 100).unwrap();
+    stake(&mut base.app, &module, MEMBER2, 1, 50).unwrap();
+
+    let unstake = |app: &mut App, amount: u128| {
+        app.execute_contract(
+            Addr::unchecked(MEMBER2),
+            dao.voting_module_addr.clone(),
+            &dao_voting_token_staked::msg::ExecuteMsg::Unstake {
+                amount: Uint128::new(amount),
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // 150 voting power is enough to back a stake of 50.
+    unstake(&mut base.app, 50);
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.staked, Uint128::new(50));
+
+    // 30 is not.
+    unstake(&mut base.app, 120);
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.staked, Uint128::zero());
+}
+
+#[test]
+fn test_pre_propose_module() {
+    let mut base = DaoTestingSuiteBase::base();
+    let dao = base.cw4().dao();
+    base.advance_block();
+
+    let pre_propose_id = base.app.store_code(pre_propose_contract());
+    let module = add_module(
+        &mut base,
+        &dao.core_addr,
+        PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&dao_pre_propose_base::msg::InstantiateMsg::<Empty> {
+                    deposit_info: Some(UncheckedDepositInfo {
+                        denom: DepositToken::Token {
+                            denom: UncheckedDenom::Native(DENOM.to_string()),
+                        },
+                        amount: Uint128::new(10),
+                        refund_policy: DepositRefundPolicy::Always,
+                    }),
+                    submission_policy: PreProposeSubmissionPolicy::Specific {
+                        dao_members: true,
+                        allowlist: vec![],
+                        denylist: vec![],
+                    },
+                    extension: Empty {},
+                })
+                .unwrap(),
+                admin: None,
+                funds: vec![],
+                label: "pre-propose".to_string(),
+            },
+        },
+    );
+    let pre_propose = match base
+        .app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::ProposalCreationPolicy {})
+        .unwrap()
+    {
+        ProposalCreationPolicy::Module { addr } => addr,
+        policy => panic!("unexpected creation policy: {policy:?}"),
+    };
+
+    // Proposals may only be created through the pre-propose module.
+    let err = propose(&mut base.app, &module, MEMBER1, 100).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    base.app
+        .sudo(
+            BankSudo::Mint {
+                to_address: MEMBER1.to_string(),
+                amount: coins(20, DENOM),
+            }
+            .into(),
+        )
+        .unwrap();
+    let pre_propose_msg =
+        |amount: u128| dao_pre_propose_base::msg::ExecuteMsg::<PreProposeMessage, Empty>::Propose {
+            msg: PreProposeMessage::Propose(ConvictionProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                beneficiary: BENEFICIARY.to_string(),
+                amount: Uint128::new(amount),
+                proposer: Some(MEMBER1.to_string()),
+            }),
+        };
+    for amount in [100, 50] {
+        base.app
+            .execute_contract(
+                Addr::unchecked(MEMBER1),
+                pre_propose.clone(),
+                &pre_propose_msg(amount),
+                &coins(10, DENOM),
+            )
+            .unwrap();
+    }
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.proposer, Addr::unchecked(MEMBER1));
+    let balance = base.app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::zero());
+
+    // Deposits are refunded when proposals are executed or closed.
+    stake(&mut base.app, &module, MEMBER3, 1, 3).unwrap();
+    stake(&mut base.app, &module, MEMBER4, 1, 3).unwrap();
+    for _ in 0..7 {
+        base.advance_block();
+    }
+    execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap();
+    let balance = base.app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(10));
+
+    execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Close { proposal_id: 2 },
+    )
+    .unwrap();
+    let balance = base.app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(20));
+}
+
+#[test]
+fn test_proposal_hooks() {
+    let TestSetup {
+        mut base,
+        dao,
+        module,
+    } = setup_test();
+
+    let counter_id = base.app.store_code(dao_proposal_hook_counter_contract());
+    let counter = base
+        .app
+        .instantiate_contract(
+            counter_id,
+            dao.clone(),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "counter",
+            None,
+        )
+        .unwrap();
+    let err = execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::AddProposalHook {
+            address: counter.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        &mut base.app,
+        &module,
+        dao.as_str(),
+        ExecuteMsg::AddProposalHook {
+            address: counter.to_string(),
+        },
+    )
+    .unwrap();
+
+    let count = |app: &App, msg: dao_proposal_hook_counter::msg::QueryMsg| {
+        let res: dao_proposal_hook_counter::msg::CountResponse =
+            app.wrap().query_wasm_smart(&counter, &msg).unwrap();
+        res.count
+    };
+
+    propose(&mut base.app, &module, MEMBER1, 100).unwrap();
+    propose(&mut base.app, &module, MEMBER1, 50).unwrap();
+    assert_eq!(
+        count(
+            &base.app,
+            dao_proposal_hook_counter::msg::QueryMsg::ProposalCounter {}
+        ),
+        2
+    );
+
+    stake(&mut base.app, &module, MEMBER3, 1, 3).unwrap();
+    stake(&mut base.app, &module, MEMBER4, 1, 3).unwrap();
+    for _ in 0..7 {
+        base.advance_block();
+    }
+    execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Execute { proposal_id: 1 },
+    )
+    .unwrap();
+    execute(
+        &mut base.app,
+        &module,
+        MEMBER1,
+        ExecuteMsg::Close { proposal_id: 2 },
+    )
+    .unwrap();
+    assert_eq!(
+        count(
+            &base.app,
+            dao_proposal_hook_counter::msg::QueryMsg::StatusChangedCounter {}
+        ),
+        2
+    );
+
+    execute(
+        &mut base.app,
+        &module,
+        dao.as_str(),
+        ExecuteMsg::RemoveProposalHook {
+            address: counter.to_string(),
+        },
+    )
+    .unwrap();
+    propose(&mut base.app, &module, MEMBER1, 50).unwrap();
+    assert_eq!(
+        count(
+            &base.app,
+            dao_proposal_hook_counter::msg::QueryMsg::ProposalCounter {}
+        ),
+        2
+    );
+}
@@ -2,7 +2,7 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
@@ -10,29 +10,42 @@ use cw_hooks::Hooks;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
 use dao_hooks::proposal::{
-    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+    new_proposal_hooks, proposal_amended_hooks, proposal_completed_hooks,
+    proposal_status_changed_hooks,
 };
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
 use dao_voting::{
+    delegation::{get_delegators, DelegatedVote},
+    error::VotingError,
+    execution::{ExecutionResult, PartialExecution, PartialExecutionConfig},
     multiple_choice::{MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{MultipleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
+    proposal::{
+        AmendmentConfig, MultipleChoiceAmendMsg as AmendMsg,
+        MultipleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_AMENDMENT_VOTE_REMOVALS,
+        MAX_PROPOSAL_SIZE,
+    },
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
     },
     status::Status,
     veto::{VetoConfig, VetoError},
-    voting::{get_total_power, get_voting_power, validate_voting_period},
+    voting::{get_total_power, get_voting_power, validate_voting_period, VoteWeighting},
 };
 
 use crate::{msg::MigrateMsg, state::CREATION_POLICY};
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        AmendmentListResponse, AmendmentResponse, ProposalListResponse, ProposalResponse, VoteInfo,
+        VoteListResponse, VoteResponse,
+    },
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        Amendment, Ballot, Config, AMENDMENTS, AMENDMENT_CONFIG, BALLOTS, CONFIG, DELEGATED_VOTES,
+        DELEGATION_MODULE, EXTENDED_PROPOSAL_HOOKS, PARTIAL_EXECUTIONS, PARTIAL_EXECUTION_CONFIG,
+        PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
     },
     ContractError,
 };
@@ -74,6 +87,7 @@ pub fn instantiate(
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        vote_weighting: msg.vote_weighting,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -97,6 +111,7 @@ pub fn execute(
 ) -> Result<Response<Empty>, ContractError> {
     match msg {
         ExecuteMsg::Propose(propose_msg) => execute_propose(deps, env, info, propose_msg),
+        ExecuteMsg::Amend(amend_msg) => execute_amend(deps, env, info, amend_msg),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
@@ -114,6 +129,7 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
         } => execute_update_config(
             deps,
             info,
@@ -125,16 +141,36 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::UpdateDelegationModule { delegation_module } => {
+            execute_update_delegation_module(deps, info, delegation_module)
+        }
+        ExecuteMsg::UpdatePartialExecution { partial_execution } => {
+            execute_update_partial_execution(deps, info, partial_execution)
+        }
+        ExecuteMsg::UpdateAmendmentConfig { amendment_config } => {
+            execute_update_amendment_config(deps, info, amendment_config)
+        }
+        ExecuteMsg::RetryFailed {
+            proposal_id,
+            msg_indexes,
+        } => execute_retry_failed(deps, env, info, proposal_id, msg_indexes),
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
         ExecuteMsg::RemoveProposalHook { address } => {
             execute_remove_proposal_hook(deps, env, info, address)
         }
+        ExecuteMsg::AddExtendedProposalHook { address } => {
+            execute_add_extended_proposal_hook(deps, env, info, address)
+        }
+        ExecuteMsg::RemoveExtendedProposalHook { address } => {
+            execute_remove_extended_proposal_hook(deps, env, info, address)
+        }
         ExecuteMsg::AddVoteHook { address } => execute_add_vote_hook(deps, env, info, address),
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
@@ -217,6 +253,9 @@ pub fn execute_propose(
             allow_revoting: config.allow_revoting,
             choices: checked_multiple_choice_options,
             veto: config.veto,
+            vote_weighting: config.vote_weighting,
+            power_cast: Uint128::zero(),
+            ranked_votes: vec![],
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -255,6 +294,7 @@ pub fn execute_propose(
 
     // Auto cast vote if given.
     let (vote_hooks, vote_attributes) = if let Some(vote) = vote {
+        let position = vote.vote.to_string();
         let response = execute_vote(
             deps,
             env,
@@ -268,7 +308,7 @@ pub fn execute_propose(
             vec![
                 Attribute {
                     key: "position".to_string(),
-                    value: vote.vote.to_string(),
+                    value: position,
                 },
                 Attribute {
                     key: "rationale".to_string(),
@@ -290,6 +330,143 @@ pub fn execute_propose(
         .add_attribute("status", proposal.status.to_string()))
 }
 
+pub fn execute_amend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    AmendMsg {
+        proposal_id,
+        title,
+        description,
+        choices,
+    }: AmendMsg,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // The pre-propose module may amend proposals on behalf of their
+    // proposers.
+    let is_module = matches!(
+        CREATION_POLICY.load(deps.storage)?,
+        ProposalCreationPolicy::Module { addr } if addr == info.sender
+    );
+    if prop.proposer != info.sender && !is_module {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    prop.update_status(&env.block)?;
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+
+    // Votes are always removed when a proposal's choices change, as
+    // otherwise voters would support choices they have not seen.
+    let choices = choices
+        .map(|choices| choices.into_checked())
+        .transpose()?
+        .map(|choices| choices.options);
+    let choices_changed = choices.as_ref().is_some_and(|choices| {
+        choices.len() != prop.choices.len()
+            || choices
+                .iter()
+                .zip(prop.choices.iter())
+                .any(|(new, old)| new.title != old.title || new.msgs != old.msgs)
+    });
+    let preserve_votes = !choices_changed
+        && AMENDMENT_CONFIG
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .preserve_votes;
+    let amendment = Amendment {
+        sender: info.sender.clone(),
+        height: env.block.height,
+        title: prop.title.clone(),
+        description: prop.description.clone(),
+        choices: prop.choices.clone(),
+        votes_reset: !preserve_votes,
+    };
+
+    if let Some(title) = title {
+        prop.title = title;
+    }
+    if let Some(description) = description {
+        prop.description = description;
+    }
+    if let Some(choices) = choices {
+        prop.choices = choices;
+    }
+    if !preserve_votes {
+        remove_votes(deps.storage, proposal_id)?;
+        prop.votes = MultipleChoiceVotes::zero(prop.choices.len());
+        prop.power_cast = Uint128::zero();
+        prop.ranked_votes = vec![];
+    }
+
+    // Limit the size of proposals. See `execute_propose`.
+    let proposal_size = cosmwasm_std::to_json_vec(&prop)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+
+    let amendment_id = AMENDMENTS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |id| id + 1);
+    AMENDMENTS.save(deps.storage, (proposal_id, amendment_id), &amendment)?;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let hooks = proposal_amended_hooks(
+        EXTENDED_PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        amendment_id,
+        amendment.votes_reset,
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "amend")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("amendment_id", amendment_id.to_string())
+        .add_attribute("votes_reset", amendment.votes_reset.to_string()))
+}
+
+/// Removes all votes cast on a proposal, including those cast by
+/// delegates on behalf of their delegators. Errors if there are more
+/// than `MAX_AMENDMENT_VOTE_REMOVALS` of them, so that the cost of
+/// amending a proposal is bounded.
+fn remove_votes(storage: &mut dyn Storage, proposal_id: u64) -> Result<(), ContractError> {
+    let voters = BALLOTS
+        .prefix(proposal_id)
+        .keys(storage, None, None, Order::Ascending)
+        .take(MAX_AMENDMENT_VOTE_REMOVALS + 1)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let delegators = DELEGATED_VOTES
+        .prefix(proposal_id)
+        .keys(storage, None, None, Order::Ascending)
+        .take(MAX_AMENDMENT_VOTE_REMOVALS + 1)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    if voters.len() + delegators.len() > MAX_AMENDMENT_VOTE_REMOVALS {
+        return Err(ContractError::TooManyVotesToRemove {
+            max: MAX_AMENDMENT_VOTE_REMOVALS,
+        });
+    }
+    for voter in voters {
+        BALLOTS.remove(storage, (proposal_id, &voter));
+    }
+    for delegator in delegators {
+        DELEGATED_VOTES.remove(storage, (proposal_id, &delegator));
+    }
+    Ok(())
+}
+
 pub fn execute_veto(
     deps: DepsMut,
     env: Env,
@@ -364,7 +541,7 @@ pub fn execute_veto(
 }
 
 pub fn execute_vote(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     proposal_id: u64,
@@ -377,7 +554,7 @@ pub fn execute_vote(
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     // Check that this is a valid vote.
-    if vote.option_id as usize >= prop.choices.len() {
+    if !vote.is_valid(&prop.voting_strategy, prop.choices.len()) {
         return Err(ContractError::InvalidVote {});
     }
 
@@ -392,17 +569,57 @@ pub fn execute_vote(
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    // If a delegate has already voted with the sender's voting
+    // power, remove it from their ballot as the sender is now voting
+    // directly.
+    if let Some(DelegatedVote { delegate, power }) =
+        DELEGATED_VOTES.may_load(deps.storage, (proposal_id, &sender))?
+    {
+        let weight = prop.vote_weighting.weight(power);
+        let mut delegate_ballot = BALLOTS.load(deps.storage, (proposal_id, &delegate))?;
+        prop.remove_vote(&delegate_ballot.vote, weight)?;
+        delegate_ballot.power = delegate_ballot.power.checked_sub(weight)?;
+        BALLOTS.save(deps.storage, (proposal_id, &delegate), &delegate_ballot)?;
+        DELEGATED_VOTES.remove(deps.storage, (proposal_id, &sender));
+        if prop.tracks_power_cast() {
+            prop.power_cast = prop.power_cast.checked_sub(power)?;
+        }
+    }
+
+    let has_voted = BALLOTS.has(deps.storage, (proposal_id, &sender));
+
+    let mut vote_power = get_voting_power(
         deps.as_ref(),
         sender.clone(),
         &config.dao,
         Some(prop.start_height),
     )?;
-    if vote_power.is_zero() {
-        return Err(ContractError::NotRegistered {});
+    let mut vote_weight = prop.vote_weighting.weight(vote_power);
+    // Voting power delegated to the sender is counted when they first
+    // vote. Revotes carry over the power of their previous ballot.
+    if !has_voted {
+        if let Some(delegation_module) = DELEGATION_MODULE.may_load(deps.storage)? {
+            let (delegated_power, delegated_weight) = count_delegated_power(
+                deps.branch(),
+                &config.dao,
+                &delegation_module,
+                proposal_id,
+                &sender,
+                prop.start_height,
+                prop.vote_weighting,
+            )?;
+            vote_power = vote_power.checked_add(delegated_power)?;
+            vote_weight = vote_weight.checked_add(delegated_weight)?;
+        }
+        if vote_power.is_zero() {
+            return Err(ContractError::NotRegistered {});
+        }
+        if prop.tracks_power_cast() {
+            prop.power_cast = prop.power_cast.checked_add(vote_power)?;
+        }
     }
 
-    BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
+    let ballot = BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
                 if current_ballot.vote == vote {
@@ -412,11 +629,10 @@ pub fn execute_vote(
                     Err(ContractError::AlreadyCast {})
                 } else {
                     // Remove the old vote if this is a re-vote.
-                    prop.votes
-                        .remove_vote(current_ballot.vote, current_ballot.power)?;
+                    prop.remove_vote(&current_ballot.vote, current_ballot.power)?;
                     Ok(Ballot {
-                        power: vote_power,
-                        vote,
+                        power: current_ballot.power,
+                        vote: vote.clone(),
                         rationale: rationale.clone(),
                     })
                 }
@@ -425,15 +641,15 @@ pub fn execute_vote(
             }
         }
         None => Ok(Ballot {
-            vote,
-            power: vote_power,
+            vote: vote.clone(),
+            power: vote_weight,
             rationale: rationale.clone(),
         }),
     })?;
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power)?;
+    prop.add_vote(&vote, ballot.power)?;
     prop.update_status(&env.block)?;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
     let new_status = prop.status;
@@ -465,6 +681,49 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Counts the voting power of addresses that had delegated to
+/// `delegate` at `height` and have not voted on the proposal
+/// themselves. The power counted for each delegator is recorded so
+/// that it can be removed from the delegate's ballot if the delegator
+/// later votes. Returns the total delegated power and its weight
+/// under `vote_weighting`. Each delegator's power is weighted
+/// separately, as though they had voted themselves.
+fn count_delegated_power(
+    deps: DepsMut,
+    dao: &Addr,
+    delegation_module: &Addr,
+    proposal_id: u64,
+    delegate: &Addr,
+    height: u64,
+    vote_weighting: VoteWeighting,
+) -> StdResult<(Uint128, Uint128)> {
+    let delegators = get_delegators(deps.as_ref(), delegation_module, delegate, height)?;
+    let mut delegated_power = Uint128::zero();
+    let mut delegated_weight = Uint128::zero();
+    for delegator in delegators {
+        if BALLOTS.has(deps.storage, (proposal_id, &delegator))
+            || DELEGATED_VOTES.has(deps.storage, (proposal_id, &delegator))
+        {
+            continue;
+        }
+        let power = get_voting_power(deps.as_ref(), delegator.clone(), dao, Some(height))?;
+        if power.is_zero() {
+            continue;
+        }
+        DELEGATED_VOTES.save(
+            deps.storage,
+            (proposal_id, &delegator),
+            &DelegatedVote {
+                delegate: delegate.clone(),
+                power,
+            },
+        )?;
+        delegated_power = delegated_power.checked_add(power)?;
+        delegated_weight = delegated_weight.checked_add(vote_weighting.weight(power))?;
+    }
+    Ok((delegated_power, delegated_weight))
+}
+
 pub fn execute_execute(
     deps: DepsMut,
     env: Env,
@@ -537,7 +796,16 @@ pub fn execute_execute(
     match vote_result {
         VoteResult::Tie => Err(ContractError::Tie {}), // We don't anticipate this case as the proposal would not be in passed state, checked above.
         VoteResult::SingleWinner(winning_choice) => {
-            let response = if !winning_choice.msgs.is_empty() {
+            let response = if winning_choice.msgs.is_empty() {
+                Response::default()
+            } else if let Some(partial_config) = PARTIAL_EXECUTION_CONFIG.may_load(deps.storage)? {
+                let mut execution =
+                    PartialExecution::new(winning_choice.msgs, &partial_config, &env.block)?;
+                let msg_indexes = execution.all_indexes();
+                let submsgs = execution.execute(&config.dao, proposal_id, &msg_indexes)?;
+                PARTIAL_EXECUTIONS.save(deps.storage, proposal_id, &execution)?;
+                Response::default().add_submessages(submsgs)
+            } else {
                 let execute_message = WasmMsg::Execute {
                     contract_addr: config.dao.to_string(),
                     msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
@@ -555,8 +823,6 @@ pub fn execute_execute(
                     }
                     false => Response::default().add_message(execute_message),
                 }
-            } else {
-                Response::default()
             };
 
             let proposal_status_changed_hooks = proposal_status_changed_hooks(
@@ -635,6 +901,7 @@ pub fn execute_update_config(
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    vote_weighting: VoteWeighting,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -666,6 +933,7 @@ pub fn execute_update_config(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
         },
     )?;
 
@@ -694,6 +962,34 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    delegation_module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let delegation_module = match delegation_module {
+        Some(addr) => {
+            let addr = deps.api.addr_validate(&addr)?;
+            DELEGATION_MODULE.save(deps.storage, &addr)?;
+            addr.into_string()
+        }
+        None => {
+            DELEGATION_MODULE.remove(deps.storage);
+            "_none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute("delegation_module", delegation_module))
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -766,6 +1062,48 @@ pub fn execute_remove_proposal_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_add_extended_proposal_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can add hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    add_hook(EXTENDED_PROPOSAL_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_extended_proposal_hook")
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_extended_proposal_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can remove hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    remove_hook(EXTENDED_PROPOSAL_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_extended_proposal_hook")
+        .add_attribute("address", address))
+}
+
 pub fn execute_add_vote_hook(
     deps: DepsMut,
     _env: Env,
@@ -808,6 +1146,78 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_update_partial_execution(
+    deps: DepsMut,
+    info: MessageInfo,
+    partial_execution: Option<PartialExecutionConfig>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let retry_window = match partial_execution {
+        Some(partial_execution) => {
+            PARTIAL_EXECUTION_CONFIG.save(deps.storage, &partial_execution)?;
+            partial_execution.retry_window.to_string()
+        }
+        None => {
+            PARTIAL_EXECUTION_CONFIG.remove(deps.storage);
+            "_none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_partial_execution")
+        .add_attribute("sender", info.sender)
+        .add_attribute("retry_window", retry_window))
+}
+
+pub fn execute_update_amendment_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    amendment_config: AmendmentConfig,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    AMENDMENT_CONFIG.save(deps.storage, &amendment_config)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_amendment_config")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "preserve_votes",
+            amendment_config.preserve_votes.to_string(),
+        ))
+}
+
+pub fn execute_retry_failed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    msg_indexes: Vec<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut execution = PARTIAL_EXECUTIONS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(VotingError::NoPartialExecution { id: proposal_id })?;
+
+    let msg_indexes = execution.retryable(msg_indexes, &env.block)?;
+    let submsgs = execution.execute(&config.dao, proposal_id, &msg_indexes)?;
+    PARTIAL_EXECUTIONS.save(deps.storage, proposal_id, &execution)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "retry_failed")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("retried", msg_indexes.len().to_string())
+        .add_submessages(submsgs))
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -864,7 +1274,29 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::ExtendedProposalHooks {} => {
+            to_json_binary(&EXTENDED_PROPOSAL_HOOKS.query_hooks(deps)?)
+        }
         QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::DelegationModule {} => to_json_binary(&DELEGATION_MODULE.may_load(deps.storage)?),
+        QueryMsg::GetDelegatedVote {
+            proposal_id,
+            delegator,
+        } => query_delegated_vote(deps, proposal_id, delegator),
+        QueryMsg::PartialExecutionConfig {} => {
+            to_json_binary(&PARTIAL_EXECUTION_CONFIG.may_load(deps.storage)?)
+        }
+        QueryMsg::PartialExecution { proposal_id } => {
+            to_json_binary(&PARTIAL_EXECUTIONS.may_load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::AmendmentConfig {} => {
+            to_json_binary(&AMENDMENT_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::ListAmendments {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_list_amendments(deps, proposal_id, start_after, limit),
     }
 }
 
@@ -947,6 +1379,11 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
     to_json_binary(&VoteResponse { vote })
 }
 
+pub fn query_delegated_vote(deps: Deps, proposal_id: u64, delegator: String) -> StdResult<Binary> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    to_json_binary(&DELEGATED_VOTES.may_load(deps.storage, (proposal_id, &delegator))?)
+}
+
 pub fn query_list_votes(
     deps: Deps,
     proposal_id: u64,
@@ -977,6 +1414,28 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_amendments(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let amendments = AMENDMENTS
+        .prefix(proposal_id)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, amendment) = item?;
+            Ok(AmendmentResponse { id, amendment })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&AmendmentListResponse { amendments })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -1002,6 +1461,21 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                     msg.result.into_result().err().unwrap_or("None".to_string()),
                 ))
         }
+        TaggedReplyId::PartialProposalExecution {
+            proposal_id,
+            msg_index,
+        } => {
+            let result = ExecutionResult::from(msg.result);
+            let success = result.is_success();
+            let mut execution = PARTIAL_EXECUTIONS.load(deps.storage, proposal_id)?;
+            execution.record(msg_index, result)?;
+            PARTIAL_EXECUTIONS.save(deps.storage, proposal_id, &execution)?;
+
+            Ok(Response::new()
+                .add_attribute("partial_execution", proposal_id.to_string())
+                .add_attribute("msg_index", msg_index.to_string())
+                .add_attribute("success", success.to_string()))
+        }
         TaggedReplyId::FailedProposalHook(idx) => {
             let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
             Ok(Response::new().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
@@ -1010,6 +1484,11 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
             Ok(Response::new().add_attribute("removed vote hook", format!("{addr}:{idx}")))
         }
+        TaggedReplyId::FailedExtendedProposalHook(idx) => {
+            let addr = EXTENDED_PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new()
+                .add_attribute("removed_extended_proposal_hook", format!("{addr}:{idx}")))
+        }
         TaggedReplyId::PreProposeModuleInstantiation => {
             let res = parse_reply_instantiate_data(msg)?;
             let module = deps.api.addr_validate(&res.contract_address)?;
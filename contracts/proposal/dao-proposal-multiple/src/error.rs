@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError};
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
 use dao_voting::{reply::error::TagError, threshold::ThresholdError, veto::VetoError};
@@ -9,6 +9,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
     #[error(transparent)]
     ParseReplyError(#[from] ParseReplyError),
 
@@ -63,6 +66,12 @@ pub enum ContractError {
     #[error("Only rejected proposals may be closed.")]
     WrongCloseStatus {},
 
+    #[error("Only open proposals may be amended.")]
+    NotOpen {},
+
+    #[error("Amending the proposal would remove more than ({max}) votes.")]
+    TooManyVotesToRemove { max: usize },
+
     #[error("The DAO is currently inactive, you cannot create proposals.")]
     InactiveDao {},
 
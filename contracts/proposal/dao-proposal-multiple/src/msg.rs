@@ -2,10 +2,12 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
+    execution::PartialExecutionConfig,
     multiple_choice::{MultipleChoiceVote, VotingStrategy},
     pre_propose::PreProposeInfo,
-    proposal::MultipleChoiceProposeMsg,
+    proposal::{AmendmentConfig, MultipleChoiceAmendMsg, MultipleChoiceProposeMsg},
     veto::VetoConfig,
+    voting::VoteWeighting,
 };
 
 #[cw_serde]
@@ -45,12 +47,22 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// How voting power is converted into the weight of a vote.
+    /// Defaults to linear weighting if not set.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a proposal in the governance module.
     Propose(MultipleChoiceProposeMsg),
+    /// Amends an open proposal. Only the proposal's proposer or the
+    /// pre-propose module may amend a proposal. Unless the DAO has
+    /// configured amendments to preserve votes, amending a proposal
+    /// removes all votes cast on it. If votes are preserved, the
+    /// number of choices may not change.
+    Amend(MultipleChoiceAmendMsg),
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
     Vote {
@@ -119,6 +131,10 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// How voting power is converted into the weight of a
+        /// vote. This will only apply to proposals created after the
+        /// config update.
+        vote_weighting: VoteWeighting,
     },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
@@ -131,6 +147,37 @@ pub enum ExecuteMsg {
     UpdatePreProposeInfo {
         info: PreProposeInfo,
     },
+    /// Updates the vote delegation registry used by this module. If
+    /// set, the voting power of addresses that have delegated to a
+    /// voter is counted along with the voter's own power unless the
+    /// delegator votes themselves. Only the DAO may call this method.
+    UpdateDelegationModule {
+        delegation_module: Option<String>,
+    },
+    /// Updates how proposals are executed. If set, each message of a
+    /// proposal's winning choice is executed on its own, so that
+    /// messages which fail do not revert the others, and failed
+    /// messages may be retried with `RetryFailed`. This takes
+    /// precedence over `close_proposal_on_execution_failure`. If
+    /// unset, proposals are executed atomically. Only the DAO may
+    /// call this method.
+    UpdatePartialExecution {
+        partial_execution: Option<PartialExecutionConfig>,
+    },
+    /// Executes the failed messages of a proposal that was executed
+    /// one message at a time again. May be called by anyone until the
+    /// proposal's retry window expires.
+    RetryFailed {
+        /// The ID of the proposal to retry messages of.
+        proposal_id: u64,
+        /// The indexes of the failed messages to retry.
+        msg_indexes: Vec<u32>,
+    },
+    /// Updates how proposals are amended. Only the DAO may call this
+    /// method.
+    UpdateAmendmentConfig {
+        amendment_config: AmendmentConfig,
+    },
     AddProposalHook {
         address: String,
     },
@@ -143,6 +190,17 @@ pub enum ExecuteMsg {
     RemoveVoteHook {
         address: String,
     },
+    /// Adds an address as a consumer of extended proposal hooks,
+    /// which are sent `ProposalAmended` hook messages. Only the DAO
+    /// may call this method.
+    AddExtendedProposalHook {
+        address: String,
+    },
+    /// Removes a consumer of extended proposal hooks. Only the DAO
+    /// may call this method.
+    RemoveExtendedProposalHook {
+        address: String,
+    },
 }
 
 #[proposal_module_query]
@@ -190,6 +248,40 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Lists all of the consumers of extended proposal hooks for this
+    /// module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ExtendedProposalHooks {},
+    /// Gets the vote delegation registry used by this module (if
+    /// any).
+    #[returns(Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
+    /// Returns the voting power (if any) that a delegate cast on
+    /// behalf of `delegator` on a proposal.
+    #[returns(Option<::dao_voting::delegation::DelegatedVote>)]
+    GetDelegatedVote { proposal_id: u64, delegator: String },
+    /// Gets how proposals are executed. Returns `None` if proposals
+    /// are executed atomically.
+    #[returns(Option<::dao_voting::execution::PartialExecutionConfig>)]
+    PartialExecutionConfig {},
+    /// Gets the results of executing a proposal one message at a
+    /// time. Returns `None` if the proposal was not executed this
+    /// way.
+    #[returns(Option<::dao_voting::execution::PartialExecution>)]
+    PartialExecution { proposal_id: u64 },
+    /// Gets how proposals are amended.
+    #[returns(::dao_voting::proposal::AmendmentConfig)]
+    AmendmentConfig {},
+    /// Lists the amendments made to a proposal, oldest first.
+    #[returns(crate::query::AmendmentListResponse)]
+    ListAmendments {
+        /// The ID of the proposal to list the amendments of.
+        proposal_id: u64,
+        /// The amendment ID to start listing amendments after.
+        start_after: Option<u64>,
+        /// The maximum number of amendments to return.
+        limit: Option<u64>,
+    },
 }
 
 #[cw_serde]
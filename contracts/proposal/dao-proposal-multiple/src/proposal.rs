@@ -5,11 +5,12 @@ use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Uint128};
 use cw_utils::Expiration;
 use dao_voting::{
     multiple_choice::{
-        CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVotes, VotingStrategy,
+        CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVote,
+        MultipleChoiceVotes, VotingStrategy,
     },
     status::Status,
     veto::VetoConfig,
-    voting::does_vote_count_pass,
+    voting::{does_vote_count_pass, VoteWeighting},
 };
 
 use crate::query::ProposalResponse;
@@ -51,6 +52,32 @@ pub struct MultipleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How voting power is converted into the weight of a vote on
+    /// this proposal. Votes are tallied by weight. If the key is
+    /// missing (i.e. the proposal was created before vote weighting
+    /// was added), we deserialize into linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
+    /// The voting power of all addresses that have voted on this
+    /// proposal. Only tracked under quadratic weighting and scored
+    /// voting strategies, where it differs from the total weight of
+    /// votes cast.
+    #[serde(default)]
+    pub power_cast: Uint128,
+    /// The weight of votes cast on this proposal, grouped by their
+    /// ranked preferences. Only tracked under ranked-choice voting,
+    /// where the winner depends on more than first preferences.
+    #[serde(default)]
+    pub ranked_votes: Vec<RankedVotes>,
+}
+
+/// The total weight of ranked-choice votes with the same preferences.
+#[cw_serde]
+pub struct RankedVotes {
+    /// The options ranked, most preferred first.
+    pub preferences: Vec<u32>,
+    /// The total weight of votes with these preferences.
+    pub weight: Uint128,
 }
 
 pub enum VoteResult {
@@ -112,6 +139,71 @@ impl MultipleChoiceProposal {
         Ok(())
     }
 
+    /// Adds a vote to the proposal's tally.
+    pub fn add_vote(&mut self, vote: &MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
+        self.votes.add_vote(vote, weight)?;
+        if self.voting_strategy.is_ranked() {
+            let preferences = vote.preferences();
+            match self
+                .ranked_votes
+                .iter_mut()
+                .find(|ranked| ranked.preferences == preferences)
+            {
+                Some(ranked) => {
+                    ranked.weight = ranked
+                        .weight
+                        .checked_add(weight)
+                        .map_err(StdError::overflow)?
+                }
+                None => self.ranked_votes.push(RankedVotes {
+                    preferences,
+                    weight,
+                }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes a vote from the proposal's tally.
+    pub fn remove_vote(&mut self, vote: &MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
+        self.votes.remove_vote(vote, weight)?;
+        if self.voting_strategy.is_ranked() {
+            let preferences = vote.preferences();
+            let idx = self
+                .ranked_votes
+                .iter()
+                .position(|ranked| ranked.preferences == preferences)
+                .ok_or_else(|| StdError::not_found("ranked votes"))?;
+            let ranked = &mut self.ranked_votes[idx];
+            ranked.weight = ranked
+                .weight
+                .checked_sub(weight)
+                .map_err(StdError::overflow)?;
+            if ranked.weight.is_zero() {
+                self.ranked_votes.swap_remove(idx);
+            }
+        }
+        Ok(())
+    }
+
+    /// The voting power of the addresses that have voted on this
+    /// proposal. Quorum is measured against this so that it has the
+    /// same meaning regardless of how votes are weighted.
+    pub fn participation(&self) -> Uint128 {
+        if self.tracks_power_cast() {
+            self.power_cast
+        } else {
+            self.votes.total()
+        }
+    }
+
+    /// Returns true if the total weight of votes cast on this
+    /// proposal may differ from the voting power behind them, in
+    /// which case the voting power cast is tracked separately.
+    pub fn tracks_power_cast(&self) -> bool {
+        self.vote_weighting.is_quadratic() || self.voting_strategy.is_scored()
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail). Passing in the case of multiple choice proposals
@@ -137,7 +229,7 @@ impl MultipleChoiceProposal {
 
         // Proposal can only pass if quorum has been met.
         if does_vote_count_pass(
-            self.votes.total(),
+            self.participation(),
             self.total_power,
             self.voting_strategy.get_quorum(),
         ) {
@@ -176,13 +268,13 @@ impl MultipleChoiceProposal {
             // there is no voting power left.
             VoteResult::Tie => {
                 let rejected =
-                    self.expiration.is_expired(block) || self.total_power == self.votes.total();
+                    self.expiration.is_expired(block) || self.total_power == self.participation();
                 Ok(rejected)
             }
             VoteResult::SingleWinner(winning_choice) => {
                 match (
                     does_vote_count_pass(
-                        self.votes.total(),
+                        self.participation(),
                         self.total_power,
                         self.voting_strategy.get_quorum(),
                     ),
@@ -215,7 +307,12 @@ impl MultipleChoiceProposal {
     /// Find the option with the highest vote weight, and note if there is a tie.
     pub fn calculate_vote_result(&self) -> StdResult<VoteResult> {
         match self.voting_strategy {
-            VotingStrategy::SingleChoice { quorum: _ } => {
+            VotingStrategy::RankedChoice { quorum: _ } => Ok(self.calculate_runoff_result()),
+            // Scored votes count towards every option they score, so
+            // the highest total score wins.
+            VotingStrategy::SingleChoice { .. }
+            | VotingStrategy::Approval { .. }
+            | VotingStrategy::Score { .. } => {
                 // We expect to have at least 3 vote weights
                 if let Some(max_weight) = self.votes.vote_weights.iter().max_by(|&a, &b| a.cmp(b)) {
                     let top_choices: Vec<(usize, &Uint128)> = self
@@ -247,6 +344,56 @@ impl MultipleChoiceProposal {
         }
     }
 
+    /// Find the instant-runoff winner. Each round, ballots count
+    /// towards their most preferred option that has not been
+    /// eliminated. An option with a majority of the counted ballots
+    /// wins, otherwise the options with the fewest votes are
+    /// eliminated together. If every remaining option is tied, the
+    /// result is a tie.
+    fn calculate_runoff_result(&self) -> VoteResult {
+        let mut eliminated = vec![false; self.choices.len()];
+        loop {
+            let mut tally = vec![Uint128::zero(); self.choices.len()];
+            for ranked in &self.ranked_votes {
+                if let Some(option_id) = ranked
+                    .preferences
+                    .iter()
+                    .find(|option_id| !eliminated[**option_id as usize])
+                {
+                    tally[*option_id as usize] += ranked.weight;
+                }
+            }
+            let counted: Uint128 = tally.iter().sum();
+            let remaining: Vec<usize> = (0..self.choices.len())
+                .filter(|idx| !eliminated[*idx])
+                .collect();
+
+            if let Some(winner) = remaining
+                .iter()
+                .find(|idx| tally[**idx] > counted - tally[**idx])
+            {
+                return VoteResult::SingleWinner(self.choices[*winner].clone());
+            }
+
+            let fewest = remaining
+                .iter()
+                .map(|idx| tally[*idx])
+                .min()
+                .unwrap_or_default();
+            let last: Vec<usize> = remaining
+                .iter()
+                .copied()
+                .filter(|idx| tally[*idx] == fewest)
+                .collect();
+            if last.len() == remaining.len() {
+                return VoteResult::Tie;
+            }
+            for idx in last {
+                eliminated[idx] = true;
+            }
+        }
+    }
+
     /// Ensure that with the remaining vote power, the choice with the second highest votes
     /// cannot overtake the first choice.
     fn is_choice_unbeatable(
@@ -254,6 +401,19 @@ impl MultipleChoiceProposal {
         winning_choice: &CheckedMultipleChoiceOption,
     ) -> StdResult<bool> {
         let winning_choice_power = self.votes.vote_weights[winning_choice.index as usize];
+        if self.voting_strategy.is_ranked() {
+            // Transfers from eliminated options can change the outcome
+            // unless the winning choice holds a majority of first
+            // preferences even if all remaining voting power is cast
+            // against it.
+            let remaining_vote_power = self.total_power - self.participation();
+            let others = self.votes.total() + remaining_vote_power - winning_choice_power;
+            return Ok(match winning_choice.option_type {
+                MultipleChoiceOptionType::Standard => winning_choice_power > others,
+                // A tie also fails the proposal.
+                MultipleChoiceOptionType::None => winning_choice_power >= others,
+            });
+        }
         if let Some(second_choice_power) = self
             .votes
             .vote_weights
@@ -262,7 +422,12 @@ impl MultipleChoiceProposal {
             .max_by(|&a, &b| a.cmp(b))
         {
             // Check if the remaining vote power can be used to overtake the current winning choice.
-            let remaining_vote_power = self.total_power - self.votes.total();
+            // The weight of a vote is never more than the voting power behind it, so the
+            // remaining voting power, multiplied by the highest score a vote may give, is an
+            // upper bound on the weight votes yet to be cast may add to an option.
+            let remaining_vote_power = (self.total_power - self.participation())
+                .checked_mul(Uint128::from(self.voting_strategy.max_score()))
+                .map_err(StdError::overflow)?;
             match winning_choice.option_type {
                 MultipleChoiceOptionType::Standard => {
                     if winning_choice_power > *second_choice_power + remaining_vote_power {
@@ -336,6 +501,9 @@ mod tests {
             allow_revoting,
             min_voting_period: None,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            power_cast: Uint128::zero(),
+            ranked_votes: vec![],
         }
     }
 
@@ -875,4 +1043,175 @@ mod tests {
         // No quorum reached & proposal has expired => rejection
         assert!(prop.is_rejected(&env.block).unwrap());
     }
+
+    fn ranked_vote(preferences: &[u32]) -> MultipleChoiceVote {
+        MultipleChoiceVote {
+            option_id: preferences[0],
+            ranking: Some(preferences[1..].to_vec()),
+            scores: None,
+        }
+    }
+
+    #[test]
+    fn test_ranked_choice_runoff() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::RankedChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(10),
+            true,
+            false,
+        );
+        prop.add_vote(&ranked_vote(&[0]), Uint128::new(4)).unwrap();
+        prop.add_vote(&ranked_vote(&[1]), Uint128::new(3)).unwrap();
+        prop.add_vote(&ranked_vote(&[2, 1]), Uint128::new(2))
+            .unwrap();
+
+        // The first option has the most first preferences, but the
+        // second wins once "None of the above" is eliminated.
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::SingleWinner(choice) => assert_eq!(choice.index, 1),
+            VoteResult::Tie => panic!("expected a single winner"),
+        }
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // Votes with the same preferences share a tally, which is
+        // removed once empty.
+        prop.add_vote(&ranked_vote(&[0]), Uint128::new(1)).unwrap();
+        assert_eq!(prop.ranked_votes.len(), 3);
+        prop.remove_vote(&ranked_vote(&[2, 1]), Uint128::new(2))
+            .unwrap();
+        assert_eq!(prop.ranked_votes.len(), 2);
+        assert_eq!(
+            prop.votes.vote_weights,
+            vec![Uint128::new(5), Uint128::new(3), Uint128::zero()]
+        );
+
+        // Options tied for the fewest votes are eliminated together,
+        // and a tie between all remaining options fails.
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(10),
+            true,
+            false,
+        );
+        prop.add_vote(&ranked_vote(&[0, 1]), Uint128::new(3))
+            .unwrap();
+        prop.add_vote(&ranked_vote(&[1, 0]), Uint128::new(3))
+            .unwrap();
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::Tie
+        ));
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_ranked_choice_early_pass() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::RankedChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+
+        // A majority of first preferences can not be overturned.
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(10),
+            false,
+            false,
+        );
+        prop.add_vote(&ranked_vote(&[0]), Uint128::new(6)).unwrap();
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // A majority of the votes cast so far may still be overturned
+        // by votes yet to be cast.
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(10),
+            false,
+            false,
+        );
+        prop.add_vote(&ranked_vote(&[0]), Uint128::new(5)).unwrap();
+        prop.add_vote(&ranked_vote(&[1, 0]), Uint128::new(4))
+            .unwrap();
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+    }
+
+    fn scored_vote(scores: &[u32]) -> MultipleChoiceVote {
+        let highest = scores.iter().max().unwrap();
+        MultipleChoiceVote {
+            option_id: scores.iter().position(|score| score == highest).unwrap() as u32,
+            ranking: None,
+            scores: Some(scores.to_vec()),
+        }
+    }
+
+    #[test]
+    fn test_approval_voting() {
+        let env = mock_env();
+        let mut prop = create_proposal(
+            &env.block,
+            VotingStrategy::Approval {
+                quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            },
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(10),
+            false,
+            false,
+        );
+        prop.add_vote(&scored_vote(&[1, 1, 0]), Uint128::new(4))
+            .unwrap();
+        prop.add_vote(&scored_vote(&[0, 1, 0]), Uint128::new(3))
+            .unwrap();
+        prop.power_cast = Uint128::new(7);
+
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::SingleWinner(choice) => assert_eq!(choice.index, 1),
+            VoteResult::Tie => panic!("expected a single winner"),
+        }
+        // The remaining 3 voting power could approve the first option
+        // and tie.
+        assert!(!prop.is_passed(&env.block).unwrap());
+
+        prop.add_vote(&scored_vote(&[0, 0, 1]), Uint128::new(2))
+            .unwrap();
+        prop.power_cast = Uint128::new(9);
+        assert!(prop.is_passed(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_score_voting_quorum() {
+        let env = mock_env();
+        let mut prop = create_proposal(
+            &env.block,
+            VotingStrategy::Score {
+                quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+                max_score: 5,
+            },
+            MultipleChoiceVotes::zero(3),
+            Uint128::new(10),
+            true,
+            false,
+        );
+        prop.add_vote(&scored_vote(&[5, 0, 0]), Uint128::new(4))
+            .unwrap();
+        prop.power_cast = Uint128::new(4);
+
+        // Quorum is measured in voting power rather than score, so 4
+        // of 10 voting power does not reach it.
+        assert_eq!(prop.votes.total(), Uint128::new(20));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
 }
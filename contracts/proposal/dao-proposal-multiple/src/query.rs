@@ -1,4 +1,7 @@
-use crate::{proposal::MultipleChoiceProposal, state::Config};
+use crate::{
+    proposal::MultipleChoiceProposal,
+    state::{Amendment, Config},
+};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 
@@ -48,3 +51,15 @@ pub struct VoterResponse {
 pub struct ConfigResponse {
     pub config: Config,
 }
+
+/// Information about an amendment returned by `ListAmendments`.
+#[cw_serde]
+pub struct AmendmentResponse {
+    pub id: u64,
+    pub amendment: Amendment,
+}
+
+#[cw_serde]
+pub struct AmendmentListResponse {
+    pub amendments: Vec<AmendmentResponse>,
+}
@@ -5,11 +5,34 @@ use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    delegation::DelegatedVote,
+    execution::{PartialExecution, PartialExecutionConfig},
+    multiple_choice::{CheckedMultipleChoiceOption, MultipleChoiceVote, VotingStrategy},
     pre_propose::ProposalCreationPolicy,
+    proposal::AmendmentConfig,
     veto::VetoConfig,
+    voting::VoteWeighting,
 };
 
+/// A change made to a proposal while it was open for voting. Records
+/// the contents of the proposal before the change.
+#[cw_serde]
+pub struct Amendment {
+    /// The address that amended the proposal.
+    pub sender: Addr,
+    /// The block height at which the proposal was amended.
+    pub height: u64,
+    /// The title of the proposal before it was amended.
+    pub title: String,
+    /// The description of the proposal before it was amended.
+    pub description: String,
+    /// The choices of the proposal before it was amended.
+    pub choices: Vec<CheckedMultipleChoiceOption>,
+    /// Whether the votes cast on the proposal before it was amended
+    /// were removed.
+    pub votes_reset: bool,
+}
+
 /// The proposal module's configuration.
 #[cw_serde]
 pub struct Config {
@@ -47,12 +70,19 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How voting power is converted into the weight of a vote. If
+    /// the key is missing (i.e. the config was saved before vote
+    /// weighting was added), we deserialize into linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
 }
 
 // Each ballot stores a chosen vote and corresponding voting power and rationale.
 #[cw_serde]
 pub struct Ballot {
-    /// The amount of voting power behind the vote.
+    /// The amount of voting power behind the vote. Under quadratic
+    /// weighting this is the weight of the vote rather than the
+    /// voter's voting power.
     pub power: Uint128,
     /// The position.
     pub vote: MultipleChoiceVote,
@@ -69,6 +99,27 @@ pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
 pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// Consumers of extended proposal hooks, which are sent when
+/// proposals are amended.
+pub const EXTENDED_PROPOSAL_HOOKS: Hooks = Hooks::new("extended_proposal_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The vote delegation registry consulted when votes are cast (if
+/// any).
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// Voting power that a delegate cast on behalf of a delegator, keyed
+/// by proposal ID and delegator.
+pub const DELEGATED_VOTES: Map<(u64, &Addr), DelegatedVote> = Map::new("delegated_votes");
+/// How proposals are executed. Not set if proposals are executed
+/// atomically.
+pub const PARTIAL_EXECUTION_CONFIG: Item<PartialExecutionConfig> =
+    Item::new("partial_execution_config");
+/// Proposals that were executed one message at a time and the results
+/// of executing their messages.
+pub const PARTIAL_EXECUTIONS: Map<u64, PartialExecution> = Map::new("partial_executions");
+/// How proposals are amended. Defaults to removing votes if not set.
+pub const AMENDMENT_CONFIG: Item<AmendmentConfig> = Item::new("amendment_config");
+/// Amendments made to proposals, keyed by proposal ID and amendment
+/// ID. Amendment IDs start at one for each proposal.
+pub const AMENDMENTS: Map<(u64, u64), Amendment> = Map::new("amendments");
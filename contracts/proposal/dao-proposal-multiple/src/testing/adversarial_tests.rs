@@ -20,6 +20,7 @@ use dao_voting::{
     },
     status::Status,
     threshold::PercentageThreshold,
+    voting::VoteWeighting,
 };
 
 struct CommonTest {
@@ -112,7 +113,11 @@ fn test_execute_proposal_rejected_closed() {
     assert_eq!(prop.proposal.status, Status::Open);
 
     // Vote on both options to reject the proposal
-    let vote = MultipleChoiceVote { option_id: 0 };
+    let vote = MultipleChoiceVote {
+        option_id: 0,
+        ranking: None,
+        scores: None,
+    };
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         proposal_module.clone(),
@@ -125,7 +130,11 @@ fn test_execute_proposal_rejected_closed() {
     )
     .unwrap();
 
-    let vote = MultipleChoiceVote { option_id: 1 };
+    let vote = MultipleChoiceVote {
+        option_id: 1,
+        ranking: None,
+        scores: None,
+    };
     app.execute_contract(
         Addr::unchecked(ALTERNATIVE_ADDR),
         proposal_module.clone(),
@@ -198,13 +207,17 @@ fn test_execute_proposal_more_than_once() {
     app.update_block(next_block);
 
     // get the proposal to pass
-    let vote = MultipleChoiceVote { option_id: 0 };
+    let vote = MultipleChoiceVote {
+        option_id: 0,
+        ranking: None,
+        scores: None,
+    };
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id,
-            vote,
+            vote: vote.clone(),
             rationale: None,
         },
         &[],
@@ -282,6 +295,7 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         veto: None,
     };
 
@@ -340,7 +354,11 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
 
     app.update_block(next_block);
 
-    let vote = MultipleChoiceVote { option_id: 0 };
+    let vote = MultipleChoiceVote {
+        option_id: 0,
+        ranking: None,
+        scores: None,
+    };
 
     // someone votes enough to pass the proposal
     app.execute_contract(
@@ -365,7 +383,11 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
 
     // someone wakes up and casts their vote to express their
     // opinion (not affecting the result of proposal)
-    let vote = MultipleChoiceVote { option_id: 1 };
+    let vote = MultipleChoiceVote {
+        option_id: 1,
+        ranking: None,
+        scores: None,
+    };
     app.execute_contract(
         Addr::unchecked(ALTERNATIVE_ADDR),
         proposal_module.clone(),
@@ -11,6 +11,7 @@ use dao_voting::{
     },
     status::Status,
     threshold::PercentageThreshold,
+    voting::VoteWeighting,
 };
 use rand::{prelude::SliceRandom, Rng};
 use std::panic;
@@ -129,6 +130,7 @@ where
         allow_revoting: false,
         voting_strategy,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info,
         veto: None,
     };
@@ -234,7 +236,7 @@ where
             govmod.clone(),
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: position,
+                vote: position.clone(),
                 rationale: None,
             },
             &[],
@@ -334,7 +336,11 @@ where
     do_test_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -350,7 +356,11 @@ where
     do_test_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -371,7 +381,11 @@ where
     do_test_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 10 },
+            position: MultipleChoiceVote {
+                option_id: 10,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::No,
         }],
@@ -391,7 +405,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(u128::MAX),
             should_execute: ShouldExecute::Yes,
         }],
@@ -407,13 +425,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(1),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "bob".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(u128::MAX - 1),
                 should_execute: ShouldExecute::Yes,
             },
@@ -435,13 +461,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(1),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "bob".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(1),
                 should_execute: ShouldExecute::Yes,
             },
@@ -462,7 +496,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 }, // the last index is none of the above
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            }, // the last index is none of the above
             weight: Uint128::new(u64::MAX.into()),
             should_execute: ShouldExecute::Yes,
         }],
@@ -478,7 +516,11 @@ where
         do_votes(
             vec![TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 2 },
+                position: MultipleChoiceVote {
+                    option_id: 2,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(u64::MAX.into()),
                 should_execute: ShouldExecute::Yes,
             }],
@@ -503,7 +545,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(1),
             should_execute: ShouldExecute::Yes,
         }],
@@ -518,7 +564,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -535,7 +585,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 1 },
+            position: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(9999999),
             should_execute: ShouldExecute::Yes,
         }],
@@ -551,7 +605,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(1),
             should_execute: ShouldExecute::Yes,
         }],
@@ -572,13 +630,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(2),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(2),
                 should_execute: ShouldExecute::No,
             },
@@ -608,13 +674,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "blue".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
@@ -632,13 +706,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "blue".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
@@ -659,7 +741,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(60),
             should_execute: ShouldExecute::Yes,
         }],
@@ -675,7 +761,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(60),
             should_execute: ShouldExecute::Yes,
         }],
@@ -718,7 +808,11 @@ where
             .enumerate()
             .map(|(idx, weight)| TestMultipleChoiceVote {
                 voter: format!("zero_{idx}"),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(weight as u128),
                 should_execute: ShouldExecute::Meh,
             });
@@ -727,7 +821,11 @@ where
             .enumerate()
             .map(|(idx, weight)| TestMultipleChoiceVote {
                 voter: format!("one_{idx}"),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(weight as u128),
                 should_execute: ShouldExecute::Meh,
             });
@@ -737,7 +835,11 @@ where
             .enumerate()
             .map(|(idx, weight)| TestMultipleChoiceVote {
                 voter: format!("none_{idx}"),
-                position: MultipleChoiceVote { option_id: 2 },
+                position: MultipleChoiceVote {
+                    option_id: 2,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(weight as u128),
                 should_execute: ShouldExecute::Meh,
             });
@@ -18,6 +18,7 @@ use dao_voting::{
         ActiveThreshold::{self, AbsoluteCount},
         PercentageThreshold,
     },
+    voting::VoteWeighting,
 };
 use dao_voting_cw4::msg::GroupContract;
 
@@ -80,6 +81,7 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
             false,
         ),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         veto: None,
     }
 }
@@ -97,6 +99,7 @@ fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         veto: None,
     }
 }
@@ -164,6 +167,7 @@ pub fn _instantiate_with_staked_cw721_governance(
                     address: nft_address.to_string(),
                 },
                 active_threshold: None,
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -283,6 +287,9 @@ pub fn instantiate_with_native_staked_balances_governance(
                 },
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -486,6 +493,7 @@ pub fn instantiate_with_staked_balances_governance(
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
                 },
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -625,6 +633,7 @@ pub fn instantiate_with_multiple_staked_balances_governance(
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
                 },
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -740,6 +749,7 @@ pub fn instantiate_with_staking_active_threshold(
                     initial_dao_balance: None,
                 },
                 active_threshold,
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
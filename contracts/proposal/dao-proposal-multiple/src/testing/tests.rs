@@ -1,5 +1,6 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128, WasmMsg,
+    coins, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128,
+    WasmMsg,
 };
 use cw20::Cw20Coin;
 use cw_denom::{CheckedDenom, UncheckedDenom};
@@ -16,22 +17,25 @@ use dao_voting::{
         CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo,
         VotingModuleTokenType,
     },
+    error::VotingError,
+    execution::{ExecutionResult, PartialExecution, PartialExecutionConfig},
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
         MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
         MAX_NUM_CHOICES,
     },
     pre_propose::PreProposeInfo,
-    proposal::MultipleChoiceProposeMsg as ProposeMsg,
+    proposal::{AmendmentConfig, MultipleChoiceAmendMsg, MultipleChoiceProposeMsg as ProposeMsg},
     status::Status,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
+    voting::VoteWeighting,
 };
 use std::ops::Add;
 use std::panic;
 
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-    proposal::MultipleChoiceProposal,
+    proposal::{MultipleChoiceProposal, VoteResult},
     query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
     state::Config,
     testing::{
@@ -56,7 +60,7 @@ use dao_proposal_multiple::ContractError;
 use dao_testing::{
     contracts::{
         cw20_base_contract, dao_pre_propose_multiple_contract, dao_proposal_multiple_contract,
-        dao_voting_cw20_balance_contract,
+        dao_vote_delegation_contract, dao_voting_cw20_balance_contract,
     },
     ShouldExecute,
 };
@@ -125,6 +129,7 @@ fn test_propose() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -142,6 +147,7 @@ fn test_propose() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         veto: None,
     };
     assert_eq!(config, expected);
@@ -184,6 +190,9 @@ fn test_propose() {
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        power_cast: Uint128::zero(),
+        ranked_votes: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -203,6 +212,7 @@ fn test_propose_wrong_num_choices() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -219,6 +229,7 @@ fn test_propose_wrong_num_choices() {
     let expected = Config {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -285,6 +296,7 @@ fn test_proposal_count_initialized_to_zero() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -326,6 +338,7 @@ fn test_propose_auto_vote_winner() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -343,6 +356,7 @@ fn test_propose_auto_vote_winner() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         veto: None,
     };
     assert_eq!(config, expected);
@@ -369,7 +383,11 @@ fn test_propose_auto_vote_winner() {
         CREATOR_ADDR,
         mc_options.clone(),
         Some(MultipleChoiceAutoVote {
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: Some("rationale".to_string()),
         }),
     );
@@ -394,6 +412,9 @@ fn test_propose_auto_vote_winner() {
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        power_cast: Uint128::zero(),
+        ranked_votes: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -417,6 +438,7 @@ fn test_propose_auto_vote_reject() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -434,6 +456,7 @@ fn test_propose_auto_vote_reject() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         veto: None,
     };
     assert_eq!(config, expected);
@@ -460,7 +483,11 @@ fn test_propose_auto_vote_reject() {
         CREATOR_ADDR,
         mc_options.clone(),
         Some(MultipleChoiceAutoVote {
-            vote: MultipleChoiceVote { option_id: 2 },
+            vote: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             rationale: Some("rationale".to_string()),
         }),
     );
@@ -485,6 +512,9 @@ fn test_propose_auto_vote_reject() {
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        power_cast: Uint128::zero(),
+        ranked_votes: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -509,6 +539,7 @@ fn test_propose_non_member_auto_vote_fail() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -526,6 +557,7 @@ fn test_propose_non_member_auto_vote_fail() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         veto: None,
     };
     assert_eq!(config, expected);
@@ -552,7 +584,11 @@ fn test_propose_non_member_auto_vote_fail() {
         "anyone",
         mc_options.clone(),
         Some(MultipleChoiceAutoVote {
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: Some("rationale".to_string()),
         }),
     );
@@ -571,6 +607,7 @@ fn test_no_early_pass_with_min_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -635,7 +672,11 @@ fn test_no_early_pass_with_min_duration() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -665,6 +706,7 @@ fn test_propose_with_messages() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -701,6 +743,7 @@ fn test_propose_with_messages() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period: cw_utils::Duration::Height(20),
         only_members_execute: false,
         allow_revoting: false,
@@ -748,7 +791,11 @@ fn test_propose_with_messages() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -789,6 +836,7 @@ fn test_min_duration_units_missmatch() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -822,6 +870,7 @@ fn test_min_duration_larger_than_proposal_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -854,6 +903,7 @@ fn test_min_duration_same_as_proposal_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -918,7 +968,11 @@ fn test_min_duration_same_as_proposal_duration() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -935,7 +989,11 @@ fn test_min_duration_same_as_proposal_duration() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 2 },
+            vote: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -964,6 +1022,7 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1040,6 +1099,7 @@ fn test_different_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1102,6 +1162,7 @@ fn test_bad_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1135,6 +1196,7 @@ fn test_take_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1243,6 +1305,7 @@ fn test_take_native_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1341,6 +1404,7 @@ fn test_native_proposal_deposit() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -1441,7 +1505,11 @@ fn test_native_proposal_deposit() {
             govmod.clone(),
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 1 },
+                vote: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 rationale: None,
             },
             &[],
@@ -1478,7 +1546,11 @@ fn test_deposit_return_on_execute() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1541,7 +1613,11 @@ fn test_deposit_return_zero() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1589,13 +1665,21 @@ fn test_query_list_votes() {
         vec![
             TestMultipleChoiceVote {
                 voter: "blue".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "note".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 weight: Uint128::new(20),
                 should_execute: ShouldExecute::Yes,
             },
@@ -1633,13 +1717,21 @@ fn test_query_list_votes() {
     let expected = vec![
         VoteInfo {
             voter: Addr::unchecked("blue"),
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             power: Uint128::new(10),
             rationale: None,
         },
         VoteInfo {
             voter: Addr::unchecked("note"),
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
             power: Uint128::new(20),
             rationale: None,
         },
@@ -1654,7 +1746,11 @@ fn test_invalid_quorum() {
     let (_app, _core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1674,7 +1770,11 @@ fn test_cant_vote_executed_or_closed() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1711,7 +1811,11 @@ fn test_cant_vote_executed_or_closed() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -1722,7 +1826,11 @@ fn test_cant_vote_executed_or_closed() {
     let (mut app, _core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1750,7 +1858,11 @@ fn test_cant_vote_executed_or_closed() {
         govmod,
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -1768,6 +1880,7 @@ fn test_cant_propose_zero_power() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1885,7 +1998,11 @@ fn test_cant_vote_not_registered() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1920,7 +2037,11 @@ fn test_cant_vote_not_registered() {
             govmod,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 rationale: None,
             },
             &[],
@@ -1947,6 +2068,7 @@ fn test_cant_execute_not_member() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: true,
         allow_revoting: false,
@@ -2001,7 +2123,11 @@ fn test_cant_execute_not_member() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -2039,6 +2165,7 @@ fn test_cant_execute_not_member_when_proposal_created() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: true,
         allow_revoting: false,
@@ -2093,7 +2220,11 @@ fn test_cant_execute_not_member_when_proposal_created() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -2162,6 +2293,7 @@ fn test_open_proposal_submission() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
         veto: None,
     };
@@ -2234,6 +2366,9 @@ fn test_open_proposal_submission() {
             vote_weights: vec![Uint128::zero(); 3],
         },
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        power_cast: Uint128::zero(),
+        ranked_votes: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -2245,7 +2380,11 @@ fn test_close_open_proposal() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2315,7 +2454,11 @@ fn test_no_refund_failed_proposal() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2375,7 +2518,11 @@ fn test_zero_deposit() {
     do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2397,7 +2544,11 @@ fn test_deposit_return_on_close() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2460,6 +2611,7 @@ fn test_execute_expired_proposal() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2526,7 +2678,11 @@ fn test_execute_expired_proposal() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -2581,7 +2737,11 @@ fn test_update_config() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2625,6 +2785,7 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            vote_weighting: VoteWeighting::Linear {},
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2645,6 +2806,7 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            vote_weighting: VoteWeighting::Linear {},
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2663,6 +2825,7 @@ fn test_update_config() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period: cw_utils::Duration::Height(10),
         only_members_execute: false,
         allow_revoting: false,
@@ -2682,6 +2845,7 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            vote_weighting: VoteWeighting::Linear {},
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2698,7 +2862,11 @@ fn test_no_return_if_no_refunds() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2759,6 +2927,7 @@ fn test_query_list_proposals() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2847,6 +3016,9 @@ fn test_query_list_proposals() {
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            power_cast: Uint128::zero(),
+            ranked_votes: vec![],
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2876,6 +3048,9 @@ fn test_query_list_proposals() {
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            vote_weighting: VoteWeighting::Linear {},
+            power_cast: Uint128::zero(),
+            ranked_votes: vec![],
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2896,6 +3071,7 @@ fn test_hooks() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -3023,6 +3199,7 @@ fn test_active_threshold_absolute() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -3154,6 +3331,7 @@ fn test_active_threshold_percent() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -3286,6 +3464,7 @@ fn test_active_threshold_none() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -3403,6 +3582,7 @@ fn test_revoting() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3455,7 +3635,11 @@ fn test_revoting() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3468,7 +3652,11 @@ fn test_revoting() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3500,7 +3688,11 @@ fn test_revoting() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3537,6 +3729,7 @@ fn test_allow_revoting_config_changes() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3597,6 +3790,7 @@ fn test_allow_revoting_config_changes() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             veto: None,
         },
         &[],
@@ -3612,7 +3806,11 @@ fn test_allow_revoting_config_changes() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3623,7 +3821,11 @@ fn test_allow_revoting_config_changes() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3650,7 +3852,11 @@ fn test_allow_revoting_config_changes() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 2,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3663,7 +3869,11 @@ fn test_allow_revoting_config_changes() {
             proposal_module,
             &ExecuteMsg::Vote {
                 proposal_id: 2,
-                vote: MultipleChoiceVote { option_id: 1 },
+                vote: MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
                 rationale: None,
             },
             &[],
@@ -3692,6 +3902,7 @@ fn test_revoting_same_vote_twice() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3744,7 +3955,11 @@ fn test_revoting_same_vote_twice() {
         proprosal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3758,7 +3973,11 @@ fn test_revoting_same_vote_twice() {
             proprosal_module,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 rationale: None,
             },
             &[],
@@ -3788,6 +4007,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3840,7 +4060,11 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3853,7 +4077,11 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -3885,7 +4113,11 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             proposal_module,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 99 },
+                vote: MultipleChoiceVote {
+                    option_id: 99,
+                    ranking: None,
+                    scores: None,
+                },
                 rationale: None,
             },
             &[],
@@ -3910,7 +4142,11 @@ fn test_return_deposit_to_dao_on_proposal_failure() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -3983,6 +4219,7 @@ fn test_close_failed_proposal() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -4072,7 +4309,11 @@ fn test_close_failed_proposal() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -4120,6 +4361,7 @@ fn test_close_failed_proposal() {
                                     allow_revoting: false,
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
+                                    vote_weighting: VoteWeighting::Linear {},
                                     veto: None,
                                 })
                                 .unwrap(),
@@ -4148,7 +4390,11 @@ fn test_close_failed_proposal() {
             govmod.clone(),
             &ExecuteMsg::Vote {
                 proposal_id: 2,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 rationale: None,
             },
             &[],
@@ -4186,7 +4432,11 @@ fn test_close_failed_proposal() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 3,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -4225,6 +4475,7 @@ fn test_no_double_refund_on_execute_fail_and_close() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -4357,7 +4608,11 @@ fn test_no_double_refund_on_execute_fail_and_close() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -4418,6 +4673,7 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -4480,7 +4736,11 @@ pub fn test_not_allow_voting_on_expired_proposal() {
             govmod,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
                 rationale: None,
             },
             &[],
@@ -4511,6 +4771,7 @@ fn test_next_proposal_id() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4584,6 +4845,7 @@ fn test_vote_with_rationale() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4641,7 +4903,11 @@ fn test_vote_with_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: Some("I think this is a good idea".to_string()),
         },
         &[],
@@ -4682,6 +4948,7 @@ fn test_revote_with_rationale() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4739,7 +5006,11 @@ fn test_revote_with_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: Some("I think this is a good idea".to_string()),
         },
         &[],
@@ -4771,7 +5042,11 @@ fn test_revote_with_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
             rationale: Some("Nah".to_string()),
         },
         &[],
@@ -4800,7 +5075,11 @@ fn test_revote_with_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 2 },
+            vote: MultipleChoiceVote {
+                option_id: 2,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -4838,6 +5117,7 @@ fn test_update_rationale() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4897,7 +5177,11 @@ fn test_update_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: Some("I think this is a good idea".to_string()),
         },
         &[],
@@ -4977,6 +5261,7 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5036,7 +5321,11 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -5086,6 +5375,7 @@ fn test_veto_non_existing_prop_id() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5132,6 +5422,7 @@ fn test_veto_with_no_veto_configuration() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -5224,6 +5515,7 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5282,7 +5574,11 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -5332,6 +5628,7 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5390,7 +5687,11 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -5447,6 +5748,7 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5505,7 +5807,11 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -5558,6 +5864,7 @@ fn test_veto_from_catchall_state() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5616,7 +5923,11 @@ fn test_veto_from_catchall_state() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -5679,6 +5990,7 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5737,7 +6049,11 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -5803,6 +6119,7 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5861,7 +6178,11 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -5916,6 +6237,7 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5974,7 +6296,11 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
             rationale: None,
         },
         &[],
@@ -6022,3 +6348,836 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_delegated_voting() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        // Prevent the proposal from completing early so that
+        // delegators have time to override their delegate.
+        allow_revoting: true,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "delegate".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "alice".to_string(),
+                amount: Uint128::new(2),
+            },
+            Cw20Coin {
+                address: "bob".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "carol".to_string(),
+                amount: Uint128::new(4),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let delegation_id = app.store_code(dao_vote_delegation_contract());
+    let delegation_module = app
+        .instantiate_contract(
+            delegation_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg { max_delegators: 10 },
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            delegation_module: Some(delegation_module.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    for delegator in ["alice", "bob"] {
+        app.execute_contract(
+            Addr::unchecked(delegator),
+            delegation_module.clone(),
+            &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+                delegate: "delegate".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.update_block(next_block);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        "carol",
+        MultipleChoiceOptions { options },
+        None,
+    );
+
+    app.execute_contract(
+        Addr::unchecked("delegate"),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let vote: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::GetVote {
+                proposal_id,
+                voter: "delegate".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(6));
+
+    // Alice overrides her delegate. Her power is removed from the
+    // delegate's ballot.
+    app.execute_contract(
+        Addr::unchecked("alice"),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                ranking: None,
+                scores: None,
+            },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let vote: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::GetVote {
+                proposal_id,
+                voter: "delegate".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(4));
+    let delegated: Option<dao_voting::delegation::DelegatedVote> = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::GetDelegatedVote {
+                proposal_id,
+                delegator: "alice".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(delegated, None);
+
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(4), Uint128::new(2), Uint128::zero()]
+    );
+}
+
+#[test]
+fn test_quadratic_voting() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Quadratic {},
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+    };
+    let core_addr = instantiate_with_cw20_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "alice".to_string(),
+                amount: Uint128::new(16),
+            },
+            Cw20Coin {
+                address: "bob".to_string(),
+                amount: Uint128::new(16),
+            },
+            Cw20Coin {
+                address: "carol".to_string(),
+                amount: Uint128::new(16),
+            },
+            Cw20Coin {
+                address: "dave".to_string(),
+                amount: Uint128::new(16),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        "alice",
+        MultipleChoiceOptions { options },
+        None,
+    );
+
+    let mut vote = |voter: &str, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote {
+                    option_id,
+                    ranking: None,
+                    scores: None,
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // The whale's vote is weighted by the square root of their
+    // voting power. With linear weighting they would win 100 to 64.
+    vote("whale", 1);
+    for voter in ["alice", "bob", "carol", "dave"] {
+        vote(voter, 0);
+    }
+
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(16), Uint128::new(10), Uint128::zero()]
+    );
+    assert_eq!(proposal.proposal.power_cast, Uint128::new(164));
+    assert_eq!(proposal.proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_ranked_choice_runoff() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(dao_proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::RankedChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "a-1".to_string(),
+                amount: Uint128::new(40),
+            },
+            Cw20Coin {
+                address: "a-2".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "a-3".to_string(),
+                amount: Uint128::new(20),
+            },
+            Cw20Coin {
+                address: "a-4".to_string(),
+                amount: Uint128::new(10),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+
+    // a-1 proposes and ranks only the first option.
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "A simple text proposal".to_string(),
+            choices: MultipleChoiceOptions { options },
+            proposer: None,
+            vote: Some(MultipleChoiceAutoVote {
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
+                rationale: None,
+            }),
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let mut vote = |voter: &str, option_id: u32, ranking: Option<Vec<u32>>| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id,
+                    ranking,
+                    scores: None,
+                },
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Options may not be ranked twice or be out of range.
+    for ranking in [vec![0], vec![1, 1], vec![3]] {
+        let err: ContractError = vote("a-4", 0, Some(ranking))
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::InvalidVote {});
+    }
+
+    vote("a-2", 1, None).unwrap();
+    // a-3 prefers none of the options, then the second option.
+    vote("a-3", 2, Some(vec![1])).unwrap();
+
+    // The first option leads on first preferences but without a
+    // majority the outcome is not known until voting ends.
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(40), Uint128::new(30), Uint128::new(20)]
+    );
+    assert_eq!(proposal.proposal.ranked_votes.len(), 3);
+
+    // "None of the above" is eliminated first and its votes move to
+    // the second option, which then holds a majority.
+    app.update_block(|b| b.height += 6);
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    match proposal.proposal.calculate_vote_result().unwrap() {
+        VoteResult::SingleWinner(choice) => assert_eq!(choice.index, 1),
+        VoteResult::Tie => panic!("expected a single winner"),
+    }
+}
+
+#[test]
+fn test_score_voting() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(dao_proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::Score {
+                quorum: PercentageThreshold::Majority {},
+                max_score: 3,
+            },
+            close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "a-1".to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "a-2".to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "A simple text proposal".to_string(),
+            choices: MultipleChoiceOptions { options },
+            proposer: None,
+            vote: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let mut vote = |voter: &str, option_id: u32, scores: Option<Vec<u32>>| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id,
+                    ranking: None,
+                    scores,
+                },
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Every option must be scored, no higher than the max score.
+    for scores in [None, Some(vec![3, 1]), Some(vec![4, 1, 0])] {
+        let err: ContractError = vote("a-2", 0, scores).unwrap_err().downcast().unwrap();
+        assert_eq!(err, ContractError::InvalidVote {});
+    }
+
+    // The full ballot is included in the vote's position.
+    let res = vote("a-1", 0, Some(vec![3, 1, 0])).unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|event| &event.attributes)
+        .any(|attr| attr.key == "position" && attr.value == "0:3,1:1,2:0"));
+
+    // Option 1 scores 60 * 3 + 40 * 1 = 220, option 2 scores
+    // 60 * 1 + 40 * 3 = 180. All voting power has been cast, so the
+    // proposal passes early.
+    vote("a-2", 1, Some(vec![1, 3, 0])).unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(220), Uint128::new(180), Uint128::zero()]
+    );
+    assert_eq!(proposal.proposal.power_cast, Uint128::new(100));
+    assert_eq!(proposal.proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_partial_execution() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    app.execute_contract(
+        core_addr.clone(),
+        govmod.clone(),
+        &ExecuteMsg::UpdatePartialExecution {
+            partial_execution: Some(PartialExecutionConfig {
+                retry_window: Duration::Height(10),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: vec![Coin {
+                denom: "ujuno".to_string(),
+                amount: Uint128::new(amount),
+            }],
+        }
+        .into()
+    };
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![
+                MultipleChoiceOption {
+                    title: "send".to_string(),
+                    description: "send twice".to_string(),
+                    msgs: vec![send(10), send(10)],
+                },
+                MultipleChoiceOption {
+                    title: "don't send".to_string(),
+                    description: "don't send".to_string(),
+                    msgs: vec![],
+                },
+            ],
+        },
+        None,
+    );
+
+    // The DAO only has enough tokens for one of the messages.
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(10),
+        }],
+    }))
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                ranking: None,
+                scores: None,
+            },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap();
+
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+    let execution: PartialExecution = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::PartialExecution { proposal_id })
+        .unwrap();
+    assert!(matches!(
+        execution.results[0],
+        Some(ExecutionResult::Success { .. })
+    ));
+    assert!(matches!(
+        execution.results[1],
+        Some(ExecutionResult::Failure { .. })
+    ));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            govmod.clone(),
+            &ExecuteMsg::RetryFailed {
+                proposal_id,
+                msg_indexes: vec![0],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::NotRetryable { index: 0 })
+    );
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(10),
+        }],
+    }))
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        govmod,
+        &ExecuteMsg::RetryFailed {
+            proposal_id,
+            msg_indexes: vec![1],
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(20)
+    );
+}
+
+#[test]
+fn test_amend() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(50),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(50),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |title: &str| MultipleChoiceOption {
+        title: title.to_string(),
+        description: title.to_string(),
+        msgs: vec![],
+    };
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        CREATOR_ADDR,
+        MultipleChoiceOptions {
+            options: vec![option("a"), option("b")],
+        },
+        None,
+    );
+    let vote = MultipleChoiceVote {
+        option_id: 0,
+        ranking: None,
+        scores: None,
+    };
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote: vote.clone(),
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Amending the choices removes the votes cast on the proposal.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Amend(MultipleChoiceAmendMsg {
+            proposal_id,
+            title: None,
+            description: None,
+            choices: Some(MultipleChoiceOptions {
+                options: vec![option("a"), option("b"), option("c")],
+            }),
+        }),
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    // The "none of the above" option is added to each proposal.
+    assert_eq!(proposal.proposal.choices.len(), 4);
+    assert_eq!(proposal.proposal.votes, MultipleChoiceVotes::zero(4));
+    let amendments: crate::query::AmendmentListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListAmendments {
+                proposal_id,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(amendments.amendments.len(), 1);
+    assert_eq!(amendments.amendments[0].amendment.choices.len(), 3);
+
+    // If votes are preserved they are kept while the choices voted
+    // on stay the same.
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateAmendmentConfig {
+            amendment_config: AmendmentConfig {
+                preserve_votes: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let amend_choices = |app: &mut App, options: Vec<MultipleChoiceOption>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Amend(MultipleChoiceAmendMsg {
+                proposal_id,
+                title: None,
+                description: None,
+                choices: Some(MultipleChoiceOptions { options }),
+            }),
+            &[],
+        )
+        .unwrap();
+    };
+    let vote_and_amend = |app: &mut App, options: Vec<MultipleChoiceOption>| {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: vote.clone(),
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+        amend_choices(app, options);
+        query_proposal(app, &govmod, proposal_id).proposal.votes
+    };
+    let mut voted = MultipleChoiceVotes::zero(4);
+    voted.vote_weights[0] = Uint128::new(50);
+
+    let votes = vote_and_amend(
+        &mut app,
+        vec![
+            MultipleChoiceOption {
+                description: "new description".to_string(),
+                ..option("a")
+            },
+            option("b"),
+            option("c"),
+        ],
+    );
+    assert_eq!(votes, voted);
+
+    // Changing the messages of a choice removes them.
+    amend_choices(
+        &mut app,
+        vec![
+            MultipleChoiceOption {
+                msgs: vec![BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(1, "ujuno"),
+                }
+                .into()],
+                ..option("a")
+            },
+            option("b"),
+            option("c"),
+        ],
+    );
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.votes, MultipleChoiceVotes::zero(4));
+
+    // As does changing the title of a choice, or the number of
+    // choices.
+    let votes = vote_and_amend(&mut app, vec![option("a"), option("renamed"), option("c")]);
+    assert_eq!(votes, MultipleChoiceVotes::zero(4));
+    let votes = vote_and_amend(&mut app, vec![option("a"), option("b")]);
+    assert_eq!(votes, MultipleChoiceVotes::zero(3));
+}
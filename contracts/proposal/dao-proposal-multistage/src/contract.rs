@@ -0,0 +1,780 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_hooks::Hooks;
+use cw_storage_plus::Bound;
+use cw_utils::{parse_reply_instantiate_data, Duration};
+use dao_hooks::proposal::{
+    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+};
+use dao_hooks::vote::new_vote_hooks;
+use dao_interface::voting::{InfoResponse, IsActiveResponse};
+use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+use dao_voting::proposal::{MultistageProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE};
+use dao_voting::reply::{
+    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+};
+use dao_voting::status::Status;
+use dao_voting::voting::{get_voting_power, Vote};
+
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, StageInfo};
+use crate::proposal::{advance_proposal_id, next_proposal_id, MultistageProposal, StageRound};
+use crate::query::{
+    ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse,
+};
+use crate::state::{
+    Ballot, Config, Stage, BALLOTS, CONFIG, CREATION_POLICY, PROPOSALS, PROPOSAL_COUNT,
+    PROPOSAL_HOOKS, VOTE_HOOKS,
+};
+use crate::ContractError;
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-multistage";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let stages = validate_stages(deps.as_ref(), msg.stages)?;
+
+    let dao = info.sender;
+
+    let (initial_policy, pre_propose_messages) = msg
+        .pre_propose_info
+        .into_initial_policy_and_messages(dao.clone())?;
+
+    let config = Config {
+        dao: dao.clone(),
+        stages,
+        only_members_execute: msg.only_members_execute,
+        close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+    };
+
+    // Initialize proposal count to zero so that queries return zero
+    // instead of None.
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+    CONFIG.save(deps.storage, &config)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(pre_propose_messages)
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose(propose_msg) => execute_propose(deps, env, info.sender, propose_msg),
+        ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale,
+        } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        ExecuteMsg::Advance { proposal_id } => execute_advance(deps, env, info, proposal_id),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::UpdateConfig {
+            stages,
+            only_members_execute,
+            close_proposal_on_execution_failure,
+        } => execute_update_config(
+            deps,
+            info,
+            stages,
+            only_members_execute,
+            close_proposal_on_execution_failure,
+        ),
+        ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
+            execute_update_proposal_creation_policy(deps, info, new_info)
+        }
+        ExecuteMsg::AddProposalHook { address } => {
+            execute_add_hook(deps, info, PROPOSAL_HOOKS, "add_proposal_hook", address)
+        }
+        ExecuteMsg::RemoveProposalHook { address } => {
+            execute_remove_hook(deps, info, PROPOSAL_HOOKS, "remove_proposal_hook", address)
+        }
+        ExecuteMsg::AddVoteHook { address } => {
+            execute_add_hook(deps, info, VOTE_HOOKS, "add_vote_hook", address)
+        }
+        ExecuteMsg::RemoveVoteHook { address } => {
+            execute_remove_hook(deps, info, VOTE_HOOKS, "remove_vote_hook", address)
+        }
+    }
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    ProposeMsg {
+        title,
+        description,
+        msgs,
+        proposer,
+    }: ProposeMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+
+    // Check that the sender is permitted to create proposals.
+    if !proposal_creation_policy.is_permitted(&sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Determine the appropriate proposer. If this is coming from our
+    // pre-propose module, it must be specified. Otherwise, the
+    // proposer should not be specified.
+    let proposer = match (proposer, &proposal_creation_policy) {
+        (None, ProposalCreationPolicy::Anyone {}) => sender.clone(),
+        // `is_permitted` above checks that an allowed module is
+        // actually sending the propose message.
+        (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
+            deps.api.addr_validate(&proposer)?
+        }
+        _ => return Err(ContractError::InvalidProposer {}),
+    };
+
+    let voting_module: Addr = deps.querier.query_wasm_smart(
+        config.dao.clone(),
+        &dao_interface::msg::QueryMsg::VotingModule {},
+    )?;
+
+    // Voting modules are not required to implement this
+    // query. Lacking an implementation they are active by default.
+    let active_resp: IsActiveResponse = deps
+        .querier
+        .query_wasm_smart(voting_module, &dao_interface::voting::Query::IsActive {})
+        .unwrap_or(IsActiveResponse { active: true });
+
+    if !active_resp.active {
+        return Err(ContractError::InactiveDao {});
+    }
+
+    let first_round = StageRound::open(deps.as_ref(), &config.dao, &config.stages[0], &env.block)?;
+    let proposal = MultistageProposal {
+        title,
+        description,
+        proposer: proposer.clone(),
+        start_height: env.block.height,
+        stages: config.stages,
+        rounds: vec![first_round],
+        msgs,
+        status: Status::Open,
+    };
+    let id = advance_proposal_id(deps.storage)?;
+
+    // Limit the size of proposals so that they may always be
+    // queried. See `dao-proposal-single` for details.
+    let proposal_size = cosmwasm_std::to_json_vec(&proposal)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "propose")
+        .add_attribute("sender", sender)
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("status", proposal.status_string()))
+}
+
+/// Returns proposal status changed hooks for each of a proposal's
+/// status transitions.
+fn transition_hooks(
+    storage: &dyn Storage,
+    proposal_id: u64,
+    transitions: Vec<(String, String)>,
+) -> StdResult<Vec<SubMsg>> {
+    let mut hooks = vec![];
+    for (old, new) in transitions {
+        hooks.extend(proposal_status_changed_hooks(
+            PROPOSAL_HOOKS,
+            storage,
+            proposal_id,
+            old,
+            new,
+        )?);
+    }
+    Ok(hooks)
+}
+
+pub fn execute_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: Vote,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let mut transitions = prop.update_status(deps.as_ref(), &config.dao, &env.block)?;
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen { id: proposal_id });
+    }
+
+    let stage = prop.current_stage();
+    if BALLOTS.has(deps.storage, (proposal_id, stage, &info.sender)) {
+        return Err(ContractError::AlreadyVoted {});
+    }
+
+    let power = get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        prop.stage().voting_source(&config.dao),
+        Some(prop.current_round().start_height),
+    )?;
+    if power.is_zero() {
+        return Err(ContractError::NotRegistered {});
+    }
+
+    BALLOTS.save(
+        deps.storage,
+        (proposal_id, stage, &info.sender),
+        &Ballot {
+            power,
+            vote,
+            rationale: rationale.clone(),
+        },
+    )?;
+
+    prop.current_round_mut().votes.add_vote(vote, power);
+    transitions.extend(prop.update_status(deps.as_ref(), &config.dao, &env.block)?);
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let status_hooks = transition_hooks(deps.storage, proposal_id, transitions)?;
+    let vote_hooks = new_vote_hooks(
+        VOTE_HOOKS,
+        deps.storage,
+        proposal_id,
+        info.sender.to_string(),
+        vote.to_string(),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(status_hooks)
+        .add_submessages(vote_hooks)
+        .add_attribute("action", "vote")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("stage", stage.to_string())
+        .add_attribute("position", vote.to_string())
+        .add_attribute(
+            "rationale",
+            rationale.unwrap_or_else(|| "_none".to_string()),
+        )
+        .add_attribute("status", prop.status_string()))
+}
+
+pub fn execute_advance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let transitions = prop.update_status(deps.as_ref(), &config.dao, &env.block)?;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let status_hooks = transition_hooks(deps.storage, proposal_id, transitions)?;
+
+    Ok(Response::default()
+        .add_submessages(status_hooks)
+        .add_attribute("action", "advance")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("status", prop.status_string()))
+}
+
+pub fn execute_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let config = CONFIG.load(deps.storage)?;
+    if config.only_members_execute {
+        let power = get_voting_power(
+            deps.as_ref(),
+            info.sender.clone(),
+            &config.dao,
+            Some(prop.start_height),
+        )?;
+        if power.is_zero() {
+            return Err(ContractError::Unauthorized {});
+        }
+    }
+
+    let mut transitions = prop.update_status(deps.as_ref(), &config.dao, &env.block)?;
+    if prop.status != Status::Passed {
+        return Err(ContractError::NotPassed {});
+    }
+
+    let old_status = prop.status_string();
+    prop.status = Status::Executed;
+    transitions.push((old_status, prop.status_string()));
+
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let response = {
+        if !prop.msgs.is_empty() {
+            let execute_message = WasmMsg::Execute {
+                contract_addr: config.dao.to_string(),
+                msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+                    msgs: prop.msgs,
+                })?,
+                funds: vec![],
+            };
+            match config.close_proposal_on_execution_failure {
+                true => {
+                    let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
+                    Response::default()
+                        .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
+                }
+                false => Response::default().add_message(execute_message),
+            }
+        } else {
+            Response::default()
+        }
+    };
+
+    let status_hooks = transition_hooks(deps.storage, proposal_id, transitions)?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks =
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+
+    Ok(response
+        .add_submessages(status_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_attribute("action", "execute")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("dao", config.dao))
+}
+
+pub fn execute_close(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let mut transitions = prop.update_status(deps.as_ref(), &config.dao, &env.block)?;
+    if prop.status != Status::Rejected {
+        return Err(ContractError::WrongCloseStatus {});
+    }
+
+    let old_status = prop.status_string();
+    prop.status = Status::Closed;
+    transitions.push((old_status, prop.status_string()));
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let status_hooks = transition_hooks(deps.storage, proposal_id, transitions)?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks =
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+
+    Ok(Response::default()
+        .add_submessages(status_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_attribute("action", "close")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    stages: Vec<StageInfo>,
+    only_members_execute: bool,
+    close_proposal_on_execution_failure: bool,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only the DAO may call this method.
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    let stages = validate_stages(deps.as_ref(), stages)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: config.dao,
+            stages,
+            only_members_execute,
+            close_proposal_on_execution_failure,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_config")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_update_proposal_creation_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_info: PreProposeInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (initial_policy, messages) = new_info.into_initial_policy_and_messages(config.dao)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("action", "update_proposal_creation_policy")
+        .add_attribute("sender", info.sender)
+        .add_attribute("new_policy", format!("{initial_policy:?}")))
+}
+
+pub fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    action: &str,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can add hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+    hooks.add_hook(deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", action)
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    action: &str,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can remove hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+    hooks.remove_hook(deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", action)
+        .add_attribute("address", address))
+}
+
+fn validate_stages(deps: Deps, stages: Vec<StageInfo>) -> Result<Vec<Stage>, ContractError> {
+    if stages.is_empty() {
+        return Err(ContractError::NoStages {});
+    }
+    stages
+        .into_iter()
+        .map(|stage| {
+            if matches!(stage.voting_period, Duration::Height(0) | Duration::Time(0)) {
+                return Err(ContractError::ZeroVotingPeriod {});
+            }
+            stage.threshold.validate()?;
+            Ok(Stage {
+                threshold: stage.threshold,
+                voting_period: stage.voting_period,
+                voting_module: stage
+                    .voting_module
+                    .map(|addr| deps.api.addr_validate(&addr))
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => query_config(deps),
+        QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::Proposal { proposal_id } => query_proposal(deps, env, proposal_id),
+        QueryMsg::ListProposals { start_after, limit } => {
+            query_list_proposals(deps, env, start_after, limit)
+        }
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
+        QueryMsg::ProposalCount {} => query_proposal_count(deps),
+        QueryMsg::GetVote {
+            proposal_id,
+            stage,
+            voter,
+        } => query_vote(deps, proposal_id, stage, voter),
+        QueryMsg::ListVotes {
+            proposal_id,
+            stage,
+            start_after,
+            limit,
+        } => query_list_votes(deps, proposal_id, stage, start_after, limit),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
+        QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+    }
+}
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&config)
+}
+
+pub fn query_dao(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&config.dao)
+}
+
+pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_json_binary(&proposal.into_response(deps, &config.dao, &env.block, id)?)
+}
+
+pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
+    let policy = CREATION_POLICY.load(deps.storage)?;
+    to_json_binary(&policy)
+}
+
+pub fn query_list_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let props: Vec<ProposalResponse> = PROPOSALS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, proposal) = item?;
+            proposal.into_response(deps, &config.dao, &env.block, id)
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_reverse_proposals(
+    deps: Deps,
+    env: Env,
+    start_before: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let max = start_before.map(Bound::exclusive);
+    let props: Vec<ProposalResponse> = PROPOSALS
+        .range(deps.storage, None, max, Order::Descending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, proposal) = item?;
+            proposal.into_response(deps, &config.dao, &env.block, id)
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
+    let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
+    to_json_binary(&proposal_count)
+}
+
+pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&next_proposal_id(deps.storage)?)
+}
+
+pub fn query_vote(deps: Deps, proposal_id: u64, stage: u32, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let vote = BALLOTS
+        .may_load(deps.storage, (proposal_id, stage, &voter))?
+        .map(|ballot| VoteInfo {
+            voter,
+            vote: ballot.vote,
+            power: ballot.power,
+            rationale: ballot.rationale,
+        });
+    to_json_binary(&VoteResponse { vote })
+}
+
+pub fn query_list_votes(
+    deps: Deps,
+    proposal_id: u64,
+    stage: u32,
+    start_after: Option<String>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::<&Addr>::exclusive);
+
+    let votes = BALLOTS
+        .prefix((proposal_id, stage))
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (voter, ballot) = item?;
+            Ok(VoteInfo {
+                voter,
+                vote: ballot.vote,
+                power: ballot.power,
+                rationale: ballot.rationale,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&VoteListResponse { votes })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = get_contract_version(deps.storage)?;
+    to_json_binary(&InfoResponse { info })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let repl = TaggedReplyId::new(msg.id)?;
+    match repl {
+        TaggedReplyId::FailedProposalExecution(proposal_id) => {
+            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+                Some(mut prop) => {
+                    prop.status = Status::ExecutionFailed;
+
+                    Ok(prop)
+                }
+                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("proposal_execution_failed", proposal_id.to_string())
+                .add_attribute(
+                    "error",
+                    msg.result.into_result().err().unwrap_or("None".to_string()),
+                ))
+        }
+        TaggedReplyId::FailedProposalHook(idx) => {
+            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::FailedVoteHook(idx) => {
+            let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new().add_attribute("removed_vote_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::PreProposeModuleInstantiation => {
+            let res = parse_reply_instantiate_data(msg)?;
+
+            let module = deps.api.addr_validate(&res.contract_address)?;
+            CREATION_POLICY.save(
+                deps.storage,
+                &ProposalCreationPolicy::Module { addr: module },
+            )?;
+
+            match res.data {
+                Some(data) => Ok(Response::new()
+                    .add_attribute("update_pre_propose_module", res.contract_address)
+                    .set_data(data)),
+                None => Ok(Response::new()
+                    .add_attribute("update_pre_propose_module", res.contract_address)),
+            }
+        }
+        TaggedReplyId::FailedPreProposeModuleHook => {
+            let addr = match CREATION_POLICY.load(deps.storage)? {
+                ProposalCreationPolicy::Anyone {} => {
+                    // Something is off if we're getting this
+                    // reply and we don't have a pre-propose
+                    // module installed. This should be
+                    // unreachable.
+                    return Err(ContractError::InvalidReplyID {
+                        id: failed_pre_propose_module_hook_id(),
+                    });
+                }
+                ProposalCreationPolicy::Module { addr } => {
+                    // If we are here, our pre-propose module has
+                    // errored while receiving a proposal
+                    // hook. Rest in peace pre-propose module.
+                    CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
+                    addr
+                }
+            };
+            Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
+        }
+        TaggedReplyId::PartialProposalExecution { .. }
+        | TaggedReplyId::FailedExtendedProposalHook(_) => {
+            Err(ContractError::InvalidReplyID { id: msg.id })
+        }
+    }
+}
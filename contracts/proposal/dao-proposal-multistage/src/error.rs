@@ -0,0 +1,64 @@
+use cosmwasm_std::StdError;
+use cw_hooks::HookError;
+use cw_utils::ParseReplyError;
+use dao_voting::{reply::error::TagError, threshold::ThresholdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    ParseReplyError(#[from] ParseReplyError),
+
+    #[error(transparent)]
+    HookError(#[from] HookError),
+
+    #[error(transparent)]
+    ThresholdError(#[from] ThresholdError),
+
+    #[error(transparent)]
+    Tag(#[from] TagError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("the DAO is currently inactive, you cannot create proposals")]
+    InactiveDao {},
+
+    #[error("no such proposal ({id})")]
+    NoSuchProposal { id: u64 },
+
+    #[error("at least one stage must be configured")]
+    NoStages {},
+
+    #[error("stage voting periods must be non-zero")]
+    ZeroVotingPeriod {},
+
+    #[error(
+        "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
+    )]
+    InvalidProposer {},
+
+    #[error("proposal is ({size}) bytes, must be <= ({max}) bytes")]
+    ProposalTooLarge { size: u64, max: u64 },
+
+    #[error("proposal is not open ({id})")]
+    NotOpen { id: u64 },
+
+    #[error("proposal has not passed")]
+    NotPassed {},
+
+    #[error("only rejected proposals may be closed")]
+    WrongCloseStatus {},
+
+    #[error("not registered to vote (no voting power) at the start of this stage")]
+    NotRegistered {},
+
+    #[error("already voted in this stage of the proposal")]
+    AlreadyVoted {},
+
+    #[error("received a reply failure with an invalid ID: ({id})")]
+    InvalidReplyID { id: u64 },
+}
@@ -0,0 +1,175 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::Duration;
+use dao_dao_macros::proposal_module_query;
+use dao_voting::{
+    pre_propose::PreProposeInfo, proposal::MultistageProposeMsg, threshold::Threshold, voting::Vote,
+};
+
+/// Information about a stage that proposals must pass through.
+#[cw_serde]
+pub struct StageInfo {
+    /// The threshold a proposal must reach to pass this stage.
+    pub threshold: Threshold,
+    /// The amount of time this stage is open for voting.
+    pub voting_period: Duration,
+    /// The contract voting power is queried from during this stage.
+    /// This must implement the voting module query interface. If
+    /// None, the DAO's voting power is used.
+    pub voting_module: Option<String>,
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The stages that proposals must pass, in order. Each stage
+    /// opens once the previous one passes.
+    pub stages: Vec<StageInfo>,
+    /// If set to true only members may execute passed
+    /// proposals. Otherwise, any address may execute a passed
+    /// proposal.
+    pub only_members_execute: bool,
+    /// Information about what addresses may create proposals.
+    pub pre_propose_info: PreProposeInfo,
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain passed after execution
+    /// failure and may be executed again.
+    pub close_proposal_on_execution_failure: bool,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a proposal in the module. The proposal opens in its
+    /// first stage.
+    Propose(MultistageProposeMsg),
+    /// Votes on the current stage of a proposal. Voting power is
+    /// determined by the stage's voting module at the time the stage
+    /// opened.
+    Vote {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The senders position on the proposal.
+        vote: Vote,
+        /// An optional rationale for why this vote was cast.
+        rationale: Option<String>,
+    },
+    /// Opens the next stage of a proposal whose current stage has
+    /// passed, or rejects a proposal whose current stage has
+    /// failed. Stages also advance when a proposal is voted on,
+    /// executed, or closed.
+    Advance {
+        /// The ID of the proposal to advance.
+        proposal_id: u64,
+    },
+    /// Causes the messages associated with a proposal that has
+    /// passed all of its stages to be executed by the DAO.
+    Execute {
+        /// The ID of the proposal to execute.
+        proposal_id: u64,
+    },
+    /// Closes a proposal that has been rejected.
+    Close {
+        /// The ID of the proposal to close.
+        proposal_id: u64,
+    },
+    /// Updates the governance module's config. Changes to stages
+    /// apply to proposals created after the update.
+    UpdateConfig {
+        /// The stages that proposals must pass, in order.
+        stages: Vec<StageInfo>,
+        /// If set to true only members may execute passed
+        /// proposals. Applies to all outstanding and future
+        /// proposals.
+        only_members_execute: bool,
+        /// If set to true proposals will be closed if their execution
+        /// fails.
+        close_proposal_on_execution_failure: bool,
+    },
+    /// Update's the proposal creation policy used for this
+    /// module. Only the DAO may call this method.
+    UpdatePreProposeInfo { info: PreProposeInfo },
+    /// Adds an address as a consumer of proposal hooks. Consumers of
+    /// proposal hooks have hook messages executed on them whenever
+    /// the status of a proposal changes or a proposal is created. If
+    /// a consumer contract errors when handling a hook message it
+    /// will be removed from the list of consumers.
+    AddProposalHook { address: String },
+    /// Removes a consumer of proposal hooks.
+    RemoveProposalHook { address: String },
+    /// Adds an address as a consumer of vote hooks. Consumers of vote
+    /// hooks have hook messages executed on them whenever a vote is
+    /// cast. If a consumer contract errors when handling a hook
+    /// message it will be removed from the list of consumers.
+    AddVoteHook { address: String },
+    /// Removed a consumer of vote hooks.
+    RemoveVoteHook { address: String },
+}
+
+#[proposal_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the proposal module's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets information about a proposal.
+    #[returns(crate::query::ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Lists all the proposals that have been created in this
+    /// module.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposals {
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals that have been created in this
+    /// module in decending order of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ReverseProposals {
+        /// The proposal ID to start listing proposals before.
+        start_before: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Returns a voter's vote in a stage of a proposal (if any).
+    /// Stages are indexed from zero.
+    #[returns(crate::query::VoteResponse)]
+    GetVote {
+        proposal_id: u64,
+        stage: u32,
+        voter: String,
+    },
+    /// Lists the votes cast in a stage of a proposal.
+    #[returns(crate::query::VoteListResponse)]
+    ListVotes {
+        /// The proposal to list the votes of.
+        proposal_id: u64,
+        /// The stage to list the votes of, indexed from zero.
+        stage: u32,
+        /// The voter to start listing votes after. Ordering is done
+        /// alphabetically.
+        start_after: Option<String>,
+        /// The maximum number of votes to return in response to this
+        /// query. If no limit is specified a max of 30 are returned.
+        limit: Option<u64>,
+    },
+    /// Returns the number of proposals that have been created in this module.
+    #[returns(::std::primitive::u64)]
+    ProposalCount {},
+    /// Gets the current proposal creation policy for this module.
+    #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
+    ProposalCreationPolicy {},
+    /// Lists all of the consumers of proposal hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ProposalHooks {},
+    /// Lists all of the consumers of vote hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    VoteHooks {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,205 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Deps, Empty, StdResult, Storage, Uint128};
+use cw_utils::Expiration;
+use dao_voting::status::Status;
+use dao_voting::threshold::{PercentageThreshold, Threshold};
+use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, get_total_power, Votes};
+
+use crate::query::ProposalResponse;
+use crate::state::{Stage, PROPOSAL_COUNT};
+
+/// The voting in one stage of a proposal.
+#[cw_serde]
+pub struct StageRound {
+    /// The block height at which this stage opened. Voting power
+    /// queries for this stage should query for voting power at this
+    /// block height.
+    pub start_height: u64,
+    /// When voting in this stage closes.
+    pub expiration: Expiration,
+    /// The total amount of voting power when this stage opened.
+    pub total_power: Uint128,
+    /// The votes cast in this stage.
+    pub votes: Votes,
+}
+
+#[cw_serde]
+pub struct MultistageProposal {
+    /// The title of the proposal
+    pub title: String,
+    /// The main body of the proposal text
+    pub description: String,
+    /// The address that created this proposal.
+    pub proposer: Addr,
+    /// The block height at which this proposal was created.
+    pub start_height: u64,
+    /// The stages this proposal must pass, as configured when it was
+    /// created.
+    pub stages: Vec<Stage>,
+    /// The voting in each stage that has opened, in order. The last
+    /// entry is the current stage.
+    pub rounds: Vec<StageRound>,
+    /// The messages that will be executed should this proposal pass
+    /// its final stage.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The proposal status. Proposals remain open until they are
+    /// rejected in a stage or pass the final stage.
+    pub status: Status,
+}
+
+pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
+    Ok(PROPOSAL_COUNT.may_load(store)?.unwrap_or_default() + 1)
+}
+
+pub fn advance_proposal_id(store: &mut dyn Storage) -> StdResult<u64> {
+    let id: u64 = next_proposal_id(store)?;
+    PROPOSAL_COUNT.save(store, &id)?;
+    Ok(id)
+}
+
+impl Stage {
+    /// The contract to query voting power from during this stage.
+    pub fn voting_source<'a>(&'a self, dao: &'a Addr) -> &'a Addr {
+        self.voting_module.as_ref().unwrap_or(dao)
+    }
+}
+
+impl StageRound {
+    /// Opens `stage` at the current block.
+    pub fn open(deps: Deps, dao: &Addr, stage: &Stage, block: &BlockInfo) -> StdResult<Self> {
+        Ok(Self {
+            start_height: block.height,
+            expiration: stage.voting_period.after(block),
+            total_power: get_total_power(deps, stage.voting_source(dao), Some(block.height))?,
+            votes: Votes::zero(),
+        })
+    }
+
+    /// Whether this round has passed `threshold`. Works the same way
+    /// as `dao-proposal-single` without revoting.
+    pub fn is_passed(&self, threshold: &Threshold, block: &BlockInfo) -> bool {
+        match *threshold {
+            Threshold::AbsolutePercentage { percentage } => {
+                let options = self.total_power - self.votes.abstain;
+                does_vote_count_pass(self.votes.yes, options, percentage)
+            }
+            Threshold::ThresholdQuorum { threshold, quorum } => {
+                if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
+                    return false;
+                }
+                let options = if self.expiration.is_expired(block) {
+                    self.votes.total() - self.votes.abstain
+                } else {
+                    self.total_power - self.votes.abstain
+                };
+                does_vote_count_pass(self.votes.yes, options, threshold)
+            }
+            Threshold::AbsoluteCount { threshold } => self.votes.yes >= threshold,
+        }
+    }
+
+    /// Whether this round can no longer pass `threshold`.
+    pub fn is_rejected(&self, threshold: &Threshold, block: &BlockInfo) -> bool {
+        if self.expiration.is_expired(block) {
+            return !self.is_passed(threshold, block);
+        }
+        match *threshold {
+            Threshold::AbsolutePercentage { percentage: needed }
+            | Threshold::ThresholdQuorum {
+                threshold: needed, ..
+            } => {
+                let options = self.total_power - self.votes.abstain;
+                // A 100% threshold inverts to a 0% requirement for no
+                // votes, so is handled separately.
+                if needed == PercentageThreshold::Percent(Decimal::percent(100)) {
+                    return options.is_zero() || !self.votes.no.is_zero();
+                }
+                does_vote_count_fail(self.votes.no, options, needed)
+            }
+            Threshold::AbsoluteCount { threshold } => {
+                let outstanding = self.total_power - self.votes.total();
+                self.votes.yes + outstanding < threshold
+            }
+        }
+    }
+}
+
+impl MultistageProposal {
+    /// Consumes the proposal and returns a version which may be used
+    /// in a query response. Stages advance without any state change
+    /// once voting in them ends, so the status is recomputed here.
+    pub fn into_response(
+        mut self,
+        deps: Deps,
+        dao: &Addr,
+        block: &BlockInfo,
+        id: u64,
+    ) -> StdResult<ProposalResponse> {
+        self.update_status(deps, dao, block)?;
+        Ok(ProposalResponse { id, proposal: self })
+    }
+
+    /// The index of the stage currently open, or most recently open
+    /// if the proposal is no longer open.
+    pub fn current_stage(&self) -> u32 {
+        self.rounds.len() as u32 - 1
+    }
+
+    /// The current round of voting.
+    pub fn current_round(&self) -> &StageRound {
+        self.rounds.last().expect("proposals always have a round")
+    }
+
+    /// The current round of voting.
+    pub fn current_round_mut(&mut self) -> &mut StageRound {
+        self.rounds
+            .last_mut()
+            .expect("proposals always have a round")
+    }
+
+    /// The stage the proposal is in.
+    pub fn stage(&self) -> &Stage {
+        &self.stages[self.current_stage() as usize]
+    }
+
+    /// A description of the proposal's status for proposal hooks.
+    /// Open proposals include the stage they are in, so that a hook
+    /// is sent with a distinct status each time a stage opens.
+    pub fn status_string(&self) -> String {
+        match self.status {
+            Status::Open => format!("open_stage_{}", self.current_stage() + 1),
+            status => status.to_string(),
+        }
+    }
+
+    /// Updates the status of the proposal, opening the next stage if
+    /// the current one has passed. Returns each (old, new) status
+    /// transition as formatted by `status_string`.
+    pub fn update_status(
+        &mut self,
+        deps: Deps,
+        dao: &Addr,
+        block: &BlockInfo,
+    ) -> StdResult<Vec<(String, String)>> {
+        let mut transitions = vec![];
+        while self.status == Status::Open {
+            let old = self.status_string();
+            let threshold = &self.stage().threshold;
+            if self.current_round().is_passed(threshold, block) {
+                let next = self.rounds.len();
+                if next == self.stages.len() {
+                    self.status = Status::Passed;
+                } else {
+                    let round = StageRound::open(deps, dao, &self.stages[next], block)?;
+                    self.rounds.push(round);
+                }
+            } else if self.current_round().is_rejected(threshold, block) {
+                self.status = Status::Rejected;
+            } else {
+                break;
+            }
+            transitions.push((old, self.status_string()));
+        }
+        Ok(transitions)
+    }
+}
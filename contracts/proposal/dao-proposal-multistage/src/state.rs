@@ -0,0 +1,63 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+use dao_voting::{pre_propose::ProposalCreationPolicy, threshold::Threshold, voting::Vote};
+
+use crate::proposal::MultistageProposal;
+
+/// A voting round that proposals must pass through.
+#[cw_serde]
+pub struct Stage {
+    /// The threshold a proposal must reach to pass this stage.
+    pub threshold: Threshold,
+    /// The amount of time this stage is open for voting.
+    pub voting_period: Duration,
+    /// The contract voting power is queried from during this stage.
+    /// If None, the DAO's voting power is used.
+    pub voting_module: Option<Addr>,
+}
+
+/// A vote cast in a stage of a proposal.
+#[cw_serde]
+pub struct Ballot {
+    /// The amount of voting power behind the vote.
+    pub power: Uint128,
+    /// The position taken by the voter.
+    pub vote: Vote,
+    /// An optional rationale for why this vote was cast.
+    pub rationale: Option<String>,
+}
+
+/// The governance module's configuration.
+#[cw_serde]
+pub struct Config {
+    /// The address of the DAO that this governance module is
+    /// associated with.
+    pub dao: Addr,
+    /// The stages that proposals created in this module must pass,
+    /// in order.
+    pub stages: Vec<Stage>,
+    /// If set to true only members may execute passed
+    /// proposals. Otherwise, any address may execute a passed
+    /// proposal.
+    pub only_members_execute: bool,
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain passed after execution
+    /// failure.
+    pub close_proposal_on_execution_failure: bool,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub const PROPOSALS: Map<u64, MultistageProposal> = Map::new("proposals");
+/// Votes keyed by (proposal ID, stage index, voter).
+pub const BALLOTS: Map<(u64, u32, &Addr), Ballot> = Map::new("ballots");
+/// Consumers of proposal state change hooks.
+pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
+/// Consumers of vote hooks.
+pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// The address of the pre-propose module associated with this
+/// proposal module (if any).
+pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
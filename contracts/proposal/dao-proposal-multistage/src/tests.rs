@@ -0,0 +1,385 @@
+use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, Decimal, Uint128};
+use cw_multi_test::{App, AppResponse, BankSudo, Executor};
+use cw_utils::Duration;
+use dao_interface::state::{ModuleInstantiateInfo, ProposalModule};
+use dao_proposal_hook_counter::msg::CountResponse;
+use dao_testing::contracts::{
+    dao_proposal_hook_counter_contract, dao_proposal_multistage_contract,
+};
+use dao_testing::{DaoTestingSuite, DaoTestingSuiteBase, MEMBER1, MEMBER2, MEMBER3, MEMBER4};
+use dao_voting::{
+    pre_propose::PreProposeInfo,
+    proposal::MultistageProposeMsg,
+    status::Status,
+    threshold::{PercentageThreshold, Threshold},
+    voting::{Vote, Votes},
+};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, StageInfo},
+    query::{ProposalResponse, VoteInfo, VoteListResponse},
+    ContractError,
+};
+
+const DENOM: &str = "ujuno";
+const RECIPIENT: &str = "recipient";
+
+struct TestSetup {
+    base: DaoTestingSuiteBase,
+    module: Addr,
+    hook_counter: Addr,
+}
+
+/// A temperature check open for 5 blocks followed by a binding vote
+/// open for 10 blocks.
+fn stages() -> Vec<StageInfo> {
+    vec![
+        StageInfo {
+            threshold: Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Majority {},
+            },
+            voting_period: Duration::Height(5),
+            voting_module: None,
+        },
+        StageInfo {
+            threshold: Threshold::ThresholdQuorum {
+                threshold: PercentageThreshold::Majority {},
+                quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+            },
+            voting_period: Duration::Height(10),
+            voting_module: None,
+        },
+    ]
+}
+
+/// Creates a DAO with 10 voting power split between five members, a
+/// two stage multistage module, and a proposal hook counter.
+fn setup_test() -> TestSetup {
+    let mut base = DaoTestingSuiteBase::base();
+    let dao = base.cw4().dao();
+    // Advance a block so that member weights are visible.
+    base.advance_block();
+
+    base.app
+        .sudo(
+            BankSudo::Mint {
+                to_address: dao.core_addr.to_string(),
+                amount: coins(100, DENOM),
+            }
+            .into(),
+        )
+        .unwrap();
+
+    let code_id = base.app.store_code(dao_proposal_multistage_contract());
+    base.app
+        .execute_contract(
+            dao.core_addr.clone(),
+            dao.core_addr.clone(),
+            &dao_interface::msg::ExecuteMsg::UpdateProposalModules {
+                to_add: vec![ModuleInstantiateInfo {
+                    code_id,
+                    msg: to_json_binary(&InstantiateMsg {
+                        stages: stages(),
+                        only_members_execute: false,
+                        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                        close_proposal_on_execution_failure: true,
+                    })
+                    .unwrap(),
+                    admin: None,
+                    funds: vec![],
+                    label: "multistage".to_string(),
+                }],
+                to_disable: vec![],
+            },
+            &[],
+        )
+        .unwrap();
+
+    let modules: Vec<ProposalModule> = base
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &dao.core_addr,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let module = modules.last().unwrap().address.clone();
+
+    let counter_id = base.app.store_code(dao_proposal_hook_counter_contract());
+    let hook_counter = base
+        .app
+        .instantiate_contract(
+            counter_id,
+            Addr::unchecked(MEMBER1),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "hook counter",
+            None,
+        )
+        .unwrap();
+    base.app
+        .execute_contract(
+            dao.core_addr.clone(),
+            module.clone(),
+            &ExecuteMsg::AddProposalHook {
+                address: hook_counter.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    TestSetup {
+        base,
+        module,
+        hook_counter,
+    }
+}
+
+fn propose(app: &mut App, module: &Addr, sender: &str) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module.clone(),
+        &ExecuteMsg::Propose(MultistageProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![BankMsg::Send {
+                to_address: RECIPIENT.to_string(),
+                amount: coins(10, DENOM),
+            }
+            .into()],
+            proposer: None,
+        }),
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn vote(
+    app: &mut App,
+    module: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    vote: Vote,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale: None,
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn query_proposal(app: &App, module: &Addr, proposal_id: u64) -> ProposalResponse {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::Proposal { proposal_id })
+        .unwrap()
+}
+
+fn status_changes(app: &App, hook_counter: &Addr) -> u64 {
+    let count: CountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            hook_counter,
+            &dao_proposal_hook_counter::msg::QueryMsg::StatusChangedCounter {},
+        )
+        .unwrap();
+    count.count
+}
+
+#[test]
+fn test_stages_pass_in_sequence() {
+    let TestSetup {
+        mut base,
+        module,
+        hook_counter,
+    } = setup_test();
+
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Open);
+    assert_eq!(prop.proposal.current_stage(), 0);
+    assert_eq!(prop.proposal.status_string(), "open_stage_1");
+
+    // 3 + 3 of 10 voting power passes the temperature check early,
+    // which opens the binding vote.
+    vote(&mut base.app, &module, MEMBER3, 1, Vote::Yes).unwrap();
+    vote(&mut base.app, &module, MEMBER4, 1, Vote::Yes).unwrap();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Open);
+    assert_eq!(prop.proposal.current_stage(), 1);
+    assert_eq!(
+        prop.proposal.rounds[0].votes,
+        Votes::with_yes(Uint128::new(6))
+    );
+    assert_eq!(prop.proposal.current_round().votes, Votes::zero());
+    assert_eq!(status_changes(&base.app, &hook_counter), 1);
+
+    let err = vote(&mut base.app, &module, MEMBER1, 1, Vote::Yes)
+        .and_then(|_| vote(&mut base.app, &module, MEMBER1, 1, Vote::Yes))
+        .unwrap_err();
+    assert_eq!(err, ContractError::AlreadyVoted {});
+
+    let err = base
+        .app
+        .execute_contract(
+            Addr::unchecked(MEMBER1),
+            module.clone(),
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()
+        .unwrap();
+    assert_eq!(err, ContractError::NotPassed {});
+
+    vote(&mut base.app, &module, MEMBER2, 1, Vote::No).unwrap();
+    vote(&mut base.app, &module, MEMBER3, 1, Vote::Yes).unwrap();
+    vote(&mut base.app, &module, MEMBER4, 1, Vote::Yes).unwrap();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Passed);
+    assert_eq!(status_changes(&base.app, &hook_counter), 2);
+
+    let votes: VoteListResponse = base
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &module,
+            &QueryMsg::ListVotes {
+                proposal_id: 1,
+                stage: 0,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        votes.votes,
+        vec![
+            VoteInfo {
+                voter: Addr::unchecked(MEMBER3),
+                vote: Vote::Yes,
+                power: Uint128::new(3),
+                rationale: None,
+            },
+            VoteInfo {
+                voter: Addr::unchecked(MEMBER4),
+                vote: Vote::Yes,
+                power: Uint128::new(3),
+                rationale: None,
+            },
+        ]
+    );
+
+    base.app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            module.clone(),
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &[],
+        )
+        .unwrap();
+    let balance = base.app.wrap().query_balance(RECIPIENT, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(10));
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Executed);
+    assert_eq!(status_changes(&base.app, &hook_counter), 3);
+}
+
+#[test]
+fn test_stage_rejection() {
+    let TestSetup {
+        mut base,
+        module,
+        hook_counter,
+    } = setup_test();
+
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    vote(&mut base.app, &module, MEMBER3, 1, Vote::Yes).unwrap();
+    vote(&mut base.app, &module, MEMBER4, 1, Vote::Yes).unwrap();
+
+    // The binding vote does not reach quorum before it expires.
+    vote(&mut base.app, &module, MEMBER1, 1, Vote::Yes).unwrap();
+    for _ in 0..10 {
+        base.advance_block();
+    }
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Rejected);
+    assert_eq!(prop.proposal.current_stage(), 1);
+
+    let err = vote(&mut base.app, &module, MEMBER2, 1, Vote::Yes).unwrap_err();
+    assert_eq!(err, ContractError::NotOpen { id: 1 });
+
+    base.app
+        .execute_contract(
+            Addr::unchecked(MEMBER2),
+            module.clone(),
+            &ExecuteMsg::Close { proposal_id: 1 },
+            &[],
+        )
+        .unwrap();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Closed);
+    // Opening stage 2, rejection, and closing.
+    assert_eq!(status_changes(&base.app, &hook_counter), 3);
+
+    // A proposal that fails its first stage never opens the second.
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    for _ in 0..5 {
+        base.advance_block();
+    }
+    base.app
+        .execute_contract(
+            Addr::unchecked(MEMBER2),
+            module.clone(),
+            &ExecuteMsg::Advance { proposal_id: 2 },
+            &[],
+        )
+        .unwrap();
+    let prop = query_proposal(&base.app, &module, 2);
+    assert_eq!(prop.proposal.status, Status::Rejected);
+    assert_eq!(prop.proposal.rounds.len(), 1);
+}
+
+#[test]
+fn test_instantiate_validation() {
+    let mut app = App::default();
+    let code_id = app.store_code(dao_proposal_multistage_contract());
+
+    let instantiate = |app: &mut App, stages: Vec<StageInfo>| -> ContractError {
+        app.instantiate_contract(
+            code_id,
+            Addr::unchecked("dao"),
+            &InstantiateMsg {
+                stages,
+                only_members_execute: false,
+                pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                close_proposal_on_execution_failure: true,
+            },
+            &[],
+            "multistage",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+
+    assert_eq!(instantiate(&mut app, vec![]), ContractError::NoStages {});
+
+    let mut zero_period = stages();
+    zero_period[1].voting_period = Duration::Time(0);
+    assert_eq!(
+        instantiate(&mut app, zero_period),
+        ContractError::ZeroVotingPeriod {}
+    );
+}
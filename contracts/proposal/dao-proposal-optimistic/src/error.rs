@@ -0,0 +1,58 @@
+use cosmwasm_std::StdError;
+use cw_hooks::HookError;
+use cw_utils::ParseReplyError;
+use dao_voting::{reply::error::TagError, threshold::ThresholdError, veto::VetoError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    ParseReplyError(#[from] ParseReplyError),
+
+    #[error(transparent)]
+    HookError(#[from] HookError),
+
+    #[error(transparent)]
+    ThresholdError(#[from] ThresholdError),
+
+    #[error(transparent)]
+    VetoError(#[from] VetoError),
+
+    #[error(transparent)]
+    Tag(#[from] TagError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("the DAO is currently inactive, you cannot create proposals")]
+    InactiveDao {},
+
+    #[error("no such proposal ({id})")]
+    NoSuchProposal { id: u64 },
+
+    #[error("challenge period must be non-zero")]
+    ZeroChallengePeriod {},
+
+    #[error(
+        "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
+    )]
+    InvalidProposer {},
+
+    #[error("proposal is ({size}) bytes, must be <= ({max}) bytes")]
+    ProposalTooLarge { size: u64, max: u64 },
+
+    #[error("proposal has not passed")]
+    NotPassed {},
+
+    #[error("not registered to vote (no voting power) at time of proposal creation")]
+    NotRegistered {},
+
+    #[error("already objected to this proposal")]
+    AlreadyObjected {},
+
+    #[error("received a reply failure with an invalid ID: ({id})")]
+    InvalidReplyID { id: u64 },
+}
@@ -0,0 +1,150 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::Duration;
+use dao_dao_macros::proposal_module_query;
+use dao_voting::{
+    pre_propose::PreProposeInfo, proposal::OptimisticProposeMsg, threshold::PercentageThreshold,
+};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The amount of time after creation during which a proposal may
+    /// be objected to. Proposals that have not been vetoed by the end
+    /// of this period pass.
+    pub challenge_period: Duration,
+    /// The percentage of the total voting power at the time of a
+    /// proposal's creation that must object to it for it to be
+    /// vetoed.
+    pub objection_threshold: PercentageThreshold,
+    /// If set to true only members may execute passed
+    /// proposals. Otherwise, any address may execute a passed
+    /// proposal.
+    pub only_members_execute: bool,
+    /// Information about what addresses may create proposals.
+    pub pre_propose_info: PreProposeInfo,
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain passed after execution
+    /// failure and may be executed again.
+    pub close_proposal_on_execution_failure: bool,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a proposal in the module.
+    Propose(OptimisticProposeMsg),
+    /// Objects to a proposal during its challenge period. The
+    /// sender's voting power at the time of the proposal's creation
+    /// is counted against it. Objections may not be withdrawn.
+    Object {
+        /// The ID of the proposal to object to.
+        proposal_id: u64,
+        /// An optional rationale for why this objection was made.
+        rationale: Option<String>,
+    },
+    /// Causes the messages associated with a passed proposal to be
+    /// executed by the DAO.
+    Execute {
+        /// The ID of the proposal to execute.
+        proposal_id: u64,
+    },
+    /// Updates the governance module's config. Changes apply to
+    /// proposals created after the update.
+    UpdateConfig {
+        /// The amount of time after creation during which a proposal
+        /// may be objected to.
+        challenge_period: Duration,
+        /// The percentage of the total voting power at the time of a
+        /// proposal's creation that must object to it for it to be
+        /// vetoed.
+        objection_threshold: PercentageThreshold,
+        /// If set to true only members may execute passed
+        /// proposals. Applies to all outstanding and future
+        /// proposals.
+        only_members_execute: bool,
+        /// If set to true proposals will be closed if their execution
+        /// fails.
+        close_proposal_on_execution_failure: bool,
+    },
+    /// Update's the proposal creation policy used for this
+    /// module. Only the DAO may call this method.
+    UpdatePreProposeInfo { info: PreProposeInfo },
+    /// Adds an address as a consumer of proposal hooks. Consumers of
+    /// proposal hooks have hook messages executed on them whenever
+    /// the status of a proposal changes or a proposal is created. If
+    /// a consumer contract errors when handling a hook message it
+    /// will be removed from the list of consumers.
+    AddProposalHook { address: String },
+    /// Removes a consumer of proposal hooks.
+    RemoveProposalHook { address: String },
+    /// Adds an address as a consumer of vote hooks. Consumers of vote
+    /// hooks have hook messages executed on them whenever a proposal
+    /// is objected to. If a consumer contract errors when handling a
+    /// hook message it will be removed from the list of consumers.
+    AddVoteHook { address: String },
+    /// Removed a consumer of vote hooks.
+    RemoveVoteHook { address: String },
+}
+
+#[proposal_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the proposal module's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets information about a proposal.
+    #[returns(crate::query::ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Lists all the proposals that have been created in this
+    /// module.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposals {
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals that have been created in this
+    /// module in decending order of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ReverseProposals {
+        /// The proposal ID to start listing proposals before.
+        start_before: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Returns a voter's objection to a proposal (if any).
+    #[returns(crate::query::ObjectionResponse)]
+    GetObjection { proposal_id: u64, voter: String },
+    /// Lists all of the objections to a proposal.
+    #[returns(crate::query::ObjectionListResponse)]
+    ListObjections {
+        /// The proposal to list the objections to.
+        proposal_id: u64,
+        /// The voter to start listing objections after. Ordering is
+        /// done alphabetically.
+        start_after: Option<String>,
+        /// The maximum number of objections to return in response to
+        /// this query. If no limit is specified a max of 30 are
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Returns the number of proposals that have been created in this module.
+    #[returns(::std::primitive::u64)]
+    ProposalCount {},
+    /// Gets the current proposal creation policy for this module.
+    #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
+    ProposalCreationPolicy {},
+    /// Lists all of the consumers of proposal hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ProposalHooks {},
+    /// Lists all of the consumers of vote hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    VoteHooks {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
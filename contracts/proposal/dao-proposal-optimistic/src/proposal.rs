@@ -0,0 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Empty, StdResult, Storage, Uint128};
+use dao_voting::status::Status;
+use dao_voting::threshold::PercentageThreshold;
+use dao_voting::voting::does_vote_count_pass;
+
+use crate::query::ProposalResponse;
+use crate::state::PROPOSAL_COUNT;
+
+#[cw_serde]
+pub struct OptimisticProposal {
+    /// The title of the proposal
+    pub title: String,
+    /// The main body of the proposal text
+    pub description: String,
+    /// The address that created this proposal.
+    pub proposer: Addr,
+    /// The block height at which this proposal was created. Voting
+    /// power queries should query for voting power at this block
+    /// height.
+    pub start_height: u64,
+    /// The percentage of `total_power` that must object to this
+    /// proposal for it to be vetoed.
+    pub objection_threshold: PercentageThreshold,
+    /// The total amount of voting power at the time of this
+    /// proposal's creation.
+    pub total_power: Uint128,
+    /// The messages that will be executed should this proposal pass.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The proposal status. Proposals are created timelocked until
+    /// the end of their challenge period.
+    pub status: Status,
+    /// The total voting power that has objected to this proposal.
+    pub objections: Uint128,
+}
+
+pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
+    Ok(PROPOSAL_COUNT.may_load(store)?.unwrap_or_default() + 1)
+}
+
+pub fn advance_proposal_id(store: &mut dyn Storage) -> StdResult<u64> {
+    let id: u64 = next_proposal_id(store)?;
+    PROPOSAL_COUNT.save(store, &id)?;
+    Ok(id)
+}
+
+impl OptimisticProposal {
+    /// Consumes the proposal and returns a version which may be used
+    /// in a query response. Proposals pass once their challenge
+    /// period expires without any state change, so the status is
+    /// recomputed here.
+    pub fn into_response(mut self, block: &BlockInfo, id: u64) -> ProposalResponse {
+        self.update_status(block);
+        ProposalResponse { id, proposal: self }
+    }
+
+    /// Whether enough voting power has objected to this proposal for
+    /// it to be vetoed.
+    pub fn is_vetoed(&self) -> bool {
+        does_vote_count_pass(self.objections, self.total_power, self.objection_threshold)
+    }
+
+    /// Updates the status of the proposal. Timelocked proposals are
+    /// vetoed once the objection threshold is reached, and pass once
+    /// their challenge period expires.
+    pub fn update_status(&mut self, block: &BlockInfo) {
+        if let Status::VetoTimelock { expiration } = self.status {
+            if self.is_vetoed() {
+                self.status = Status::Vetoed;
+            } else if expiration.is_expired(block) {
+                self.status = Status::Passed;
+            }
+        }
+    }
+}
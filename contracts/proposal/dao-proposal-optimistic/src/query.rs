@@ -0,0 +1,43 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+
+use crate::proposal::OptimisticProposal;
+
+/// Information about a proposal returned by proposal queries.
+#[cw_serde]
+pub struct ProposalResponse {
+    /// The ID of the proposal being returned.
+    pub id: u64,
+    pub proposal: OptimisticProposal,
+}
+
+/// A list of proposals returned by `ListProposals` and
+/// `ReverseProposals`.
+#[cw_serde]
+pub struct ProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+/// Information about an objection that was made.
+#[cw_serde]
+pub struct ObjectionInfo {
+    /// The address that objected.
+    pub voter: Addr,
+    /// The voting power behind the objection.
+    pub power: Uint128,
+    /// Address-specified rationale for the objection.
+    pub rationale: Option<String>,
+}
+
+/// Information about an objection.
+#[cw_serde]
+pub struct ObjectionResponse {
+    /// None if no such objection, Some otherwise.
+    pub objection: Option<ObjectionInfo>,
+}
+
+/// Information about the objections to a proposal.
+#[cw_serde]
+pub struct ObjectionListResponse {
+    pub objections: Vec<ObjectionInfo>,
+}
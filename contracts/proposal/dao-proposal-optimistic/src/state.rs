@@ -0,0 +1,54 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+use dao_voting::{pre_propose::ProposalCreationPolicy, threshold::PercentageThreshold};
+
+use crate::proposal::OptimisticProposal;
+
+/// An objection to a proposal.
+#[cw_serde]
+pub struct Objection {
+    /// The voting power behind the objection.
+    pub power: Uint128,
+    /// An optional rationale for why this objection was made.
+    pub rationale: Option<String>,
+}
+
+/// The governance module's configuration.
+#[cw_serde]
+pub struct Config {
+    /// The address of the DAO that this governance module is
+    /// associated with.
+    pub dao: Addr,
+    /// The amount of time after creation during which a proposal may
+    /// be objected to. Proposals that have not been vetoed by the end
+    /// of this period pass.
+    pub challenge_period: Duration,
+    /// The percentage of the total voting power at the time of a
+    /// proposal's creation that must object to it for it to be
+    /// vetoed.
+    pub objection_threshold: PercentageThreshold,
+    /// If set to true only members may execute passed
+    /// proposals. Otherwise, any address may execute a passed
+    /// proposal.
+    pub only_members_execute: bool,
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain passed after execution
+    /// failure.
+    pub close_proposal_on_execution_failure: bool,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub const PROPOSALS: Map<u64, OptimisticProposal> = Map::new("proposals");
+pub const OBJECTIONS: Map<(u64, &Addr), Objection> = Map::new("objections");
+/// Consumers of proposal state change hooks.
+pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
+/// Consumers of vote hooks. A vote hook is sent whenever a proposal
+/// is objected to.
+pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// The address of the pre-propose module associated with this
+/// proposal module (if any).
+pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
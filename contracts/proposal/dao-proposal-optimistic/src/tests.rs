@@ -0,0 +1,659 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, BankMsg, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdResult, Uint128,
+};
+use cw_denom::UncheckedDenom;
+use cw_hooks::HooksResponse;
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_interface::state::{ModuleInstantiateInfo, ProposalModule};
+use dao_pre_propose_base::{error::PreProposeError, state::PreProposeContract};
+use dao_proposal_hook_counter::msg::{
+    CountResponse, InstantiateMsg as CounterInstantiateMsg, QueryMsg as CounterQueryMsg,
+};
+use dao_testing::contracts::{
+    dao_proposal_hook_counter_contract, dao_proposal_optimistic_contract,
+};
+use dao_testing::{DaoTestingSuite, DaoTestingSuiteBase, MEMBER1, MEMBER2, MEMBER3};
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
+    proposal::OptimisticProposeMsg,
+    status::Status,
+    threshold::{PercentageThreshold, ThresholdError},
+    veto::VetoError,
+};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    query::{ObjectionInfo, ObjectionListResponse, ProposalResponse},
+    ContractError,
+};
+
+const DENOM: &str = "ujuno";
+const RECIPIENT: &str = "recipient";
+
+struct TestSetup {
+    base: DaoTestingSuiteBase,
+    dao: Addr,
+    module: Addr,
+}
+
+/// The propose message of a minimal pre-propose module for this
+/// module. Proposers are set by the sender.
+#[cw_serde]
+enum PreProposeMessage {
+    Propose(OptimisticProposeMsg),
+}
+
+type PrePropose = PreProposeContract<Empty, Empty, Empty, Empty, PreProposeMessage>;
+
+fn pre_propose_instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: dao_pre_propose_base::msg::InstantiateMsg<Empty>,
+) -> Result<Response, PreProposeError> {
+    PrePropose::default().instantiate(deps, env, info, msg)
+}
+
+fn pre_propose_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: dao_pre_propose_base::msg::ExecuteMsg<PreProposeMessage, Empty>,
+) -> Result<Response, PreProposeError> {
+    PrePropose::default().execute(deps, env, info, msg)
+}
+
+fn pre_propose_query(
+    deps: Deps,
+    env: Env,
+    msg: dao_pre_propose_base::msg::QueryMsg<Empty>,
+) -> StdResult<Binary> {
+    PrePropose::default().query(deps, env, msg)
+}
+
+fn pre_propose_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        pre_propose_execute,
+        pre_propose_instantiate,
+        pre_propose_query,
+    ))
+}
+
+fn instantiate_msg(
+    challenge_period: Duration,
+    objection_threshold: PercentageThreshold,
+) -> InstantiateMsg {
+    InstantiateMsg {
+        challenge_period,
+        objection_threshold,
+        only_members_execute: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        close_proposal_on_execution_failure: true,
+    }
+}
+
+/// Creates a DAO with 10 voting power split between five members
+/// and an optimistic module with a 10 block challenge period where
+/// 30% of voting power must object to veto a proposal.
+fn setup_test() -> TestSetup {
+    let mut base = DaoTestingSuiteBase::base();
+    let dao = base.cw4().dao();
+    // Advance a block so that member weights are visible.
+    base.advance_block();
+
+    let module = add_module(
+        &mut base,
+        &dao.core_addr,
+        PreProposeInfo::AnyoneMayPropose {},
+    );
+    TestSetup {
+        base,
+        dao: dao.core_addr,
+        module,
+    }
+}
+
+/// Funds `dao`'s treasury with 100 tokens and adds an optimistic
+/// module to it.
+fn add_module(
+    base: &mut DaoTestingSuiteBase,
+    dao: &Addr,
+    pre_propose_info: PreProposeInfo,
+) -> Addr {
+    base.app
+        .sudo(
+            BankSudo::Mint {
+                to_address: dao.to_string(),
+                amount: coins(100, DENOM),
+            }
+            .into(),
+        )
+        .unwrap();
+
+    let code_id = base.app.store_code(dao_proposal_optimistic_contract());
+    base.app
+        .execute_contract(
+            dao.clone(),
+            dao.clone(),
+            &dao_interface::msg::ExecuteMsg::UpdateProposalModules {
+                to_add: vec![ModuleInstantiateInfo {
+                    code_id,
+                    msg: to_json_binary(&InstantiateMsg {
+                        pre_propose_info,
+                        ..instantiate_msg(
+                            Duration::Height(10),
+                            PercentageThreshold::Percent(Decimal::percent(30)),
+                        )
+                    })
+                    .unwrap(),
+                    admin: None,
+                    funds: vec![],
+                    label: "optimistic".to_string(),
+                }],
+                to_disable: vec![],
+            },
+            &[],
+        )
+        .unwrap();
+
+    let modules: Vec<ProposalModule> = base
+        .app
+        .wrap()
+        .query_wasm_smart(
+            dao,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    modules.last().unwrap().address.clone()
+}
+
+fn propose(app: &mut App, module: &Addr, sender: &str) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module.clone(),
+        &ExecuteMsg::Propose(OptimisticProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![BankMsg::Send {
+                to_address: RECIPIENT.to_string(),
+                amount: coins(10, DENOM),
+            }
+            .into()],
+            proposer: None,
+        }),
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn object(
+    app: &mut App,
+    module: &Addr,
+    sender: &str,
+    proposal_id: u64,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module.clone(),
+        &ExecuteMsg::Object {
+            proposal_id,
+            rationale: None,
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn execute(
+    app: &mut App,
+    module: &Addr,
+    sender: &str,
+    proposal_id: u64,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn query_proposal(app: &App, module: &Addr, proposal_id: u64) -> ProposalResponse {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::Proposal { proposal_id })
+        .unwrap()
+}
+
+#[test]
+fn test_proposal_passes_without_objection() {
+    let TestSetup {
+        mut base, module, ..
+    } = setup_test();
+
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    let prop = query_proposal(&base.app, &module, 1);
+    assert!(matches!(prop.proposal.status, Status::VetoTimelock { .. }));
+    assert_eq!(prop.proposal.total_power, Uint128::new(10));
+
+    // Objections below the threshold do not veto the proposal.
+    object(&mut base.app, &module, MEMBER2, 1).unwrap();
+
+    let err = execute(&mut base.app, &module, MEMBER1, 1).unwrap_err();
+    assert_eq!(err, ContractError::NotPassed {});
+
+    for _ in 0..10 {
+        base.advance_block();
+    }
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Passed);
+
+    let err = object(&mut base.app, &module, MEMBER3, 1).unwrap_err();
+    assert_eq!(err, ContractError::VetoError(VetoError::TimelockExpired {}));
+
+    execute(&mut base.app, &module, "anyone", 1).unwrap();
+    let balance = base.app.wrap().query_balance(RECIPIENT, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(10));
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_objections_veto_proposal() {
+    let TestSetup {
+        mut base, module, ..
+    } = setup_test();
+
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+
+    let err = object(&mut base.app, &module, "non_member", 1).unwrap_err();
+    assert_eq!(err, ContractError::NotRegistered {});
+
+    object(&mut base.app, &module, MEMBER1, 1).unwrap();
+    let err = object(&mut base.app, &module, MEMBER1, 1).unwrap_err();
+    assert_eq!(err, ContractError::AlreadyObjected {});
+
+    // 1 + 3 of 10 voting power is over the 30% threshold.
+    let res = object(&mut base.app, &module, MEMBER3, 1).unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|e| e.attributes.iter())
+        .any(|a| a.key == "status" && a.value == "vetoed"));
+
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.status, Status::Vetoed);
+    assert_eq!(prop.proposal.objections, Uint128::new(4));
+
+    let err = object(&mut base.app, &module, MEMBER2, 1).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::VetoError(VetoError::InvalidProposalStatus {
+            status: "vetoed".to_string()
+        })
+    );
+
+    for _ in 0..10 {
+        base.advance_block();
+    }
+    let err = execute(&mut base.app, &module, MEMBER1, 1).unwrap_err();
+    assert_eq!(err, ContractError::NotPassed {});
+
+    let objections: ObjectionListResponse = base
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &module,
+            &QueryMsg::ListObjections {
+                proposal_id: 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        objections.objections,
+        vec![
+            ObjectionInfo {
+                voter: Addr::unchecked(MEMBER1),
+                power: Uint128::new(1),
+                rationale: None,
+            },
+            ObjectionInfo {
+                voter: Addr::unchecked(MEMBER3),
+                power: Uint128::new(3),
+                rationale: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_instantiate_validation() {
+    let mut app = App::default();
+    let code_id = app.store_code(dao_proposal_optimistic_contract());
+
+    let err: ContractError = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("dao"),
+            &instantiate_msg(Duration::Time(0), PercentageThreshold::Majority {}),
+            &[],
+            "optimistic",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroChallengePeriod {});
+
+    let err: ContractError = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("dao"),
+            &instantiate_msg(
+                Duration::Height(10),
+                PercentageThreshold::Percent(Decimal::zero()),
+            ),
+            &[],
+            "optimistic",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ThresholdError(ThresholdError::ZeroThreshold {})
+    );
+}
+
+/// Instantiates a hook counter and registers it as a consumer of
+/// `module`'s proposal and vote hooks.
+fn add_hook_counter(app: &mut App, dao: &Addr, module: &Addr, should_error: bool) -> Addr {
+    let counter_id = app.store_code(dao_proposal_hook_counter_contract());
+    let counter = app
+        .instantiate_contract(
+            counter_id,
+            dao.clone(),
+            &CounterInstantiateMsg { should_error },
+            &[],
+            "counter",
+            None,
+        )
+        .unwrap();
+    for msg in [
+        ExecuteMsg::AddProposalHook {
+            address: counter.to_string(),
+        },
+        ExecuteMsg::AddVoteHook {
+            address: counter.to_string(),
+        },
+    ] {
+        app.execute_contract(dao.clone(), module.clone(), &msg, &[])
+            .unwrap();
+    }
+    counter
+}
+
+fn query_count(app: &App, counter: &Addr, msg: CounterQueryMsg) -> u64 {
+    let res: CountResponse = app.wrap().query_wasm_smart(counter, &msg).unwrap();
+    res.count
+}
+
+#[test]
+fn test_proposal_and_vote_hooks() {
+    let TestSetup {
+        mut base,
+        dao,
+        module,
+    } = setup_test();
+
+    let counter = add_hook_counter(&mut base.app, &dao, &module, false);
+
+    // Only the DAO may add hooks.
+    let err = base
+        .app
+        .execute_contract(
+            Addr::unchecked(MEMBER1),
+            module.clone(),
+            &ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    assert_eq!(
+        query_count(&base.app, &counter, CounterQueryMsg::ProposalCounter {}),
+        2
+    );
+
+    // Objections fire vote hooks, and vetoes fire status hooks.
+    object(&mut base.app, &module, MEMBER1, 1).unwrap();
+    assert_eq!(
+        query_count(&base.app, &counter, CounterQueryMsg::VoteCounter {}),
+        1
+    );
+    assert_eq!(
+        query_count(
+            &base.app,
+            &counter,
+            CounterQueryMsg::StatusChangedCounter {}
+        ),
+        0
+    );
+    object(&mut base.app, &module, MEMBER3, 1).unwrap();
+    assert_eq!(
+        query_count(&base.app, &counter, CounterQueryMsg::VoteCounter {}),
+        2
+    );
+    assert_eq!(
+        query_count(
+            &base.app,
+            &counter,
+            CounterQueryMsg::StatusChangedCounter {}
+        ),
+        1
+    );
+
+    // Execution fires status hooks.
+    for _ in 0..10 {
+        base.advance_block();
+    }
+    execute(&mut base.app, &module, MEMBER1, 2).unwrap();
+    assert_eq!(
+        query_count(
+            &base.app,
+            &counter,
+            CounterQueryMsg::StatusChangedCounter {}
+        ),
+        2
+    );
+
+    // Removed hooks no longer fire.
+    for msg in [
+        ExecuteMsg::RemoveProposalHook {
+            address: counter.to_string(),
+        },
+        ExecuteMsg::RemoveVoteHook {
+            address: counter.to_string(),
+        },
+    ] {
+        base.app
+            .execute_contract(dao.clone(), module.clone(), &msg, &[])
+            .unwrap();
+    }
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    object(&mut base.app, &module, MEMBER1, 3).unwrap();
+    assert_eq!(
+        query_count(&base.app, &counter, CounterQueryMsg::ProposalCounter {}),
+        2
+    );
+    assert_eq!(
+        query_count(&base.app, &counter, CounterQueryMsg::VoteCounter {}),
+        2
+    );
+}
+
+#[test]
+fn test_erroring_hooks_are_removed() {
+    let TestSetup {
+        mut base,
+        dao,
+        module,
+    } = setup_test();
+
+    let counter = add_hook_counter(&mut base.app, &dao, &module, true);
+
+    // The failing proposal hook is removed, and the proposal is
+    // still created.
+    propose(&mut base.app, &module, MEMBER1).unwrap();
+    let hooks: HooksResponse = base
+        .app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::ProposalHooks {})
+        .unwrap();
+    assert!(hooks.hooks.is_empty());
+
+    // As is the failing vote hook.
+    object(&mut base.app, &module, MEMBER1, 1).unwrap();
+    let hooks: HooksResponse = base
+        .app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::VoteHooks {})
+        .unwrap();
+    assert!(hooks.hooks.is_empty());
+    assert_eq!(
+        query_proposal(&base.app, &module, 1).proposal.objections,
+        Uint128::one()
+    );
+
+    assert_eq!(
+        query_count(&base.app, &counter, CounterQueryMsg::ProposalCounter {}),
+        0
+    );
+}
+
+#[test]
+fn test_pre_propose_module() {
+    let mut base = DaoTestingSuiteBase::base();
+    let dao = base.cw4().dao();
+    base.advance_block();
+
+    let pre_propose_id = base.app.store_code(pre_propose_contract());
+    let module = add_module(
+        &mut base,
+        &dao.core_addr,
+        PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&dao_pre_propose_base::msg::InstantiateMsg::<Empty> {
+                    deposit_info: Some(UncheckedDepositInfo {
+                        denom: DepositToken::Token {
+                            denom: UncheckedDenom::Native(DENOM.to_string()),
+                        },
+                        amount: Uint128::new(10),
+                        refund_policy: DepositRefundPolicy::OnlyPassed,
+                    }),
+                    submission_policy: PreProposeSubmissionPolicy::Specific {
+                        dao_members: true,
+                        allowlist: vec![],
+                        denylist: vec![],
+                    },
+                    extension: Empty {},
+                })
+                .unwrap(),
+                admin: None,
+                funds: vec![],
+                label: "pre-propose".to_string(),
+            },
+        },
+    );
+    let pre_propose = match base
+        .app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::ProposalCreationPolicy {})
+        .unwrap()
+    {
+        ProposalCreationPolicy::Module { addr } => addr,
+        policy => panic!("unexpected creation policy: {policy:?}"),
+    };
+
+    // Proposals may only be created through the pre-propose module.
+    let err = propose(&mut base.app, &module, MEMBER1).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    base.app
+        .sudo(
+            BankSudo::Mint {
+                to_address: MEMBER1.to_string(),
+                amount: coins(20, DENOM),
+            }
+            .into(),
+        )
+        .unwrap();
+    for _ in 0..2 {
+        base.app
+            .execute_contract(
+                Addr::unchecked(MEMBER1),
+                pre_propose.clone(),
+                &dao_pre_propose_base::msg::ExecuteMsg::<PreProposeMessage, Empty>::Propose {
+                    msg: PreProposeMessage::Propose(OptimisticProposeMsg {
+                        title: "title".to_string(),
+                        description: "description".to_string(),
+                        msgs: vec![BankMsg::Send {
+                            to_address: RECIPIENT.to_string(),
+                            amount: coins(10, DENOM),
+                        }
+                        .into()],
+                        proposer: Some(MEMBER1.to_string()),
+                    }),
+                },
+                &coins(10, DENOM),
+            )
+            .unwrap();
+    }
+    let prop = query_proposal(&base.app, &module, 1);
+    assert_eq!(prop.proposal.proposer, Addr::unchecked(MEMBER1));
+    let balance = base.app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::zero());
+
+    // Vetoed proposals do not pass, so their deposit goes to the DAO.
+    object(&mut base.app, &module, MEMBER1, 2).unwrap();
+    object(&mut base.app, &module, MEMBER3, 2).unwrap();
+    assert_eq!(
+        query_proposal(&base.app, &module, 2).proposal.status,
+        Status::Vetoed
+    );
+    let balance = base.app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::zero());
+    let balance = base
+        .app
+        .wrap()
+        .query_balance(&dao.core_addr, DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(110));
+
+    // Executed proposals have their deposit refunded.
+    for _ in 0..10 {
+        base.advance_block();
+    }
+    execute(&mut base.app, &module, MEMBER1, 1).unwrap();
+    let balance = base.app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(10));
+    let balance = base.app.wrap().query_balance(RECIPIENT, DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(10));
+}
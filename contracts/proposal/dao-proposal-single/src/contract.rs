@@ -1,21 +1,29 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
+    Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_hooks::Hooks;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
 use dao_hooks::proposal::{
-    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+    new_proposal_hooks, proposal_amended_hooks, proposal_completed_hooks, proposal_executed_hooks,
+    proposal_status_changed_hooks,
 };
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
+use dao_voting::delegation::{get_delegators, DelegatedVote};
+use dao_voting::error::VotingError;
+use dao_voting::execution::{ExecutionResult, PartialExecution, PartialExecutionConfig};
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use dao_voting::proposal::{
-    SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
+    AmendmentConfig, SingleChoiceAmendMsg as AmendMsg, SingleChoiceProposeMsg as ProposeMsg,
+    DEFAULT_LIMIT, MAX_AMENDMENT_VOTE_REMOVALS, MAX_PROPOSAL_SIZE,
+};
+use dao_voting::relations::{
+    query_proposal_status, CheckedProposalRef, CheckedProposalRelations, MAX_PROPOSAL_RELATIONS,
 };
 use dao_voting::reply::{
     failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
@@ -23,11 +31,17 @@ use dao_voting::reply::{
 use dao_voting::status::Status;
 use dao_voting::threshold::Threshold;
 use dao_voting::veto::{VetoConfig, VetoError};
-use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
+use dao_voting::voting::{
+    get_total_power, get_voting_power, validate_voting_period, Vote, VoteWeighting, Votes,
+};
 
 use crate::msg::MigrateMsg;
-use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use crate::proposal::{next_proposal_id, ExpeditedProposal, SingleChoiceProposal};
+use crate::state::{
+    Amendment, Config, ExpeditedConfig, AMENDMENTS, AMENDMENT_CONFIG, CONFLICTS, CREATION_POLICY,
+    DELEGATED_VOTES, DELEGATION_MODULE, DEPENDENTS, EXECUTION_RESULTS, EXTENDED_PROPOSAL_HOOKS,
+    PARTIAL_EXECUTIONS, PARTIAL_EXECUTION_CONFIG, RELATIONS,
+};
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
 };
@@ -36,7 +50,10 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        AmendmentListResponse, AmendmentResponse, ProposalResponse, ReverseRelationsResponse,
+        VoteInfo, VoteListResponse, VoteResponse,
+    },
     state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
 };
 use cw_proposal_single_v1 as v1;
@@ -53,6 +70,7 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.threshold.validate()?;
+    msg.vote_weighting.validate(&msg.threshold)?;
 
     let dao = info.sender;
 
@@ -68,6 +86,11 @@ pub fn instantiate(
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    if let Some(expedited) = &msg.expedited {
+        expedited.validate(min_voting_period, max_voting_period)?;
+        msg.vote_weighting.validate(&expedited.threshold)?;
+    }
+
     let config = Config {
         threshold: msg.threshold,
         max_voting_period,
@@ -77,6 +100,8 @@ pub fn instantiate(
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        vote_weighting: msg.vote_weighting,
+        expedited: msg.expedited,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -100,6 +125,7 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::Propose(propose_msg) => execute_propose(deps, env, info.sender, propose_msg),
+        ExecuteMsg::Amend(amend_msg) => execute_amend(deps, env, info, amend_msg),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
@@ -120,6 +146,8 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
+            expedited,
         } => execute_update_config(
             deps,
             info,
@@ -131,16 +159,37 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
+            expedited,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::UpdateDelegationModule { delegation_module } => {
+            execute_update_delegation_module(deps, info, delegation_module)
+        }
+        ExecuteMsg::UpdatePartialExecution { partial_execution } => {
+            execute_update_partial_execution(deps, info, partial_execution)
+        }
+        ExecuteMsg::UpdateAmendmentConfig { amendment_config } => {
+            execute_update_amendment_config(deps, info, amendment_config)
+        }
+        ExecuteMsg::RetryFailed {
+            proposal_id,
+            msg_indexes,
+        } => execute_retry_failed(deps, env, info, proposal_id, msg_indexes),
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
         ExecuteMsg::RemoveProposalHook { address } => {
             execute_remove_proposal_hook(deps, env, info, address)
         }
+        ExecuteMsg::AddExtendedProposalHook { address } => {
+            execute_add_extended_proposal_hook(deps, env, info, address)
+        }
+        ExecuteMsg::RemoveExtendedProposalHook { address } => {
+            execute_remove_extended_proposal_hook(deps, env, info, address)
+        }
         ExecuteMsg::AddVoteHook { address } => execute_add_vote_hook(deps, env, info, address),
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
@@ -159,6 +208,8 @@ pub fn execute_propose(
         msgs,
         proposer,
         vote,
+        relations,
+        expedited,
     }: ProposeMsg,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -200,6 +251,27 @@ pub fn execute_propose(
 
     let expiration = config.max_voting_period.after(&env.block);
 
+    // Expedited proposals are voted on under the expedited threshold
+    // and voting period, falling back to the module's threshold and
+    // the expiration they would otherwise have had if they do not
+    // pass in time.
+    let (threshold, expiration, expedited) = if expedited {
+        let expedited_config = config
+            .expedited
+            .ok_or(ContractError::ExpeditedNotEnabled {})?;
+        (
+            expedited_config.threshold,
+            expedited_config.max_voting_period.after(&env.block),
+            Some(ExpeditedProposal {
+                fallback_threshold: config.threshold,
+                fallback_expiration: expiration,
+                fell_back: false,
+            }),
+        )
+    } else {
+        (config.threshold, expiration, None)
+    };
+
     let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
 
     let proposal = {
@@ -211,13 +283,16 @@ pub fn execute_propose(
             start_height: env.block.height,
             min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
             expiration,
-            threshold: config.threshold,
+            threshold,
             total_power,
             msgs,
             status: Status::Open,
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
             veto: config.veto,
+            vote_weighting: config.vote_weighting,
+            power_cast: Uint128::zero(),
+            expedited,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -250,6 +325,41 @@ pub fn execute_propose(
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
 
+    if let Some(relations) = relations {
+        let relations =
+            relations.into_checked(deps.as_ref(), &config.dao, &env.contract.address, id)?;
+        for dependency in &relations.depends_on {
+            DEPENDENTS.save(
+                deps.storage,
+                (&dependency.module, dependency.proposal_id, id),
+                &Empty {},
+            )?;
+        }
+        for conflict in &relations.conflicts_with {
+            // Proposals that declared a conflict with an executed
+            // proposal are rejected, so their number is bounded.
+            let declared = CONFLICTS
+                .prefix((&conflict.module, conflict.proposal_id))
+                .keys(deps.storage, None, None, Order::Ascending)
+                .take(MAX_PROPOSAL_RELATIONS)
+                .count();
+            if declared >= MAX_PROPOSAL_RELATIONS {
+                return Err(ContractError::TooManyConflicts {
+                    proposal_id: conflict.proposal_id,
+                    max: MAX_PROPOSAL_RELATIONS,
+                });
+            }
+            CONFLICTS.save(
+                deps.storage,
+                (&conflict.module, conflict.proposal_id, id),
+                &Empty {},
+            )?;
+        }
+        if !relations.is_empty() {
+            RELATIONS.save(deps.storage, id, &relations)?;
+        }
+    }
+
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
     // Auto cast vote if given.
@@ -282,6 +392,132 @@ pub fn execute_propose(
         .add_attribute("status", proposal.status.to_string()))
 }
 
+pub fn execute_amend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    AmendMsg {
+        proposal_id,
+        title,
+        description,
+        msgs,
+    }: AmendMsg,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // The pre-propose module may amend proposals on behalf of their
+    // proposers.
+    let is_module = matches!(
+        CREATION_POLICY.load(deps.storage)?,
+        ProposalCreationPolicy::Module { addr } if addr == info.sender
+    );
+    if prop.proposer != info.sender && !is_module {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    prop.update_status(&env.block)?;
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+
+    // Votes are always removed when a proposal's messages change, as
+    // otherwise voters would support messages they have not seen.
+    let msgs_changed = msgs.as_ref().is_some_and(|msgs| *msgs != prop.msgs);
+    let preserve_votes = !msgs_changed
+        && AMENDMENT_CONFIG
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .preserve_votes;
+    let amendment = Amendment {
+        sender: info.sender.clone(),
+        height: env.block.height,
+        title: prop.title.clone(),
+        description: prop.description.clone(),
+        msgs: prop.msgs.clone(),
+        votes_reset: !preserve_votes,
+    };
+
+    if let Some(title) = title {
+        prop.title = title;
+    }
+    if let Some(description) = description {
+        prop.description = description;
+    }
+    if let Some(msgs) = msgs {
+        prop.msgs = msgs;
+    }
+    if !preserve_votes {
+        remove_votes(deps.storage, proposal_id)?;
+        prop.votes = Votes::zero();
+        prop.power_cast = Uint128::zero();
+    }
+
+    // Limit the size of proposals. See `execute_propose`.
+    let proposal_size = cosmwasm_std::to_json_vec(&prop)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+
+    let amendment_id = AMENDMENTS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |id| id + 1);
+    AMENDMENTS.save(deps.storage, (proposal_id, amendment_id), &amendment)?;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let hooks = proposal_amended_hooks(
+        EXTENDED_PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        amendment_id,
+        amendment.votes_reset,
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "amend")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("amendment_id", amendment_id.to_string())
+        .add_attribute("votes_reset", amendment.votes_reset.to_string()))
+}
+
+/// Removes all votes cast on a proposal, including those cast by
+/// delegates on behalf of their delegators. Errors if there are more
+/// than `MAX_AMENDMENT_VOTE_REMOVALS` of them, so that the cost of
+/// amending a proposal is bounded.
+fn remove_votes(storage: &mut dyn Storage, proposal_id: u64) -> Result<(), ContractError> {
+    let voters = BALLOTS
+        .prefix(proposal_id)
+        .keys(storage, None, None, Order::Ascending)
+        .take(MAX_AMENDMENT_VOTE_REMOVALS + 1)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let delegators = DELEGATED_VOTES
+        .prefix(proposal_id)
+        .keys(storage, None, None, Order::Ascending)
+        .take(MAX_AMENDMENT_VOTE_REMOVALS + 1)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    if voters.len() + delegators.len() > MAX_AMENDMENT_VOTE_REMOVALS {
+        return Err(ContractError::TooManyVotesToRemove {
+            max: MAX_AMENDMENT_VOTE_REMOVALS,
+        });
+    }
+    for voter in voters {
+        BALLOTS.remove(storage, (proposal_id, &voter));
+    }
+    for delegator in delegators {
+        DELEGATED_VOTES.remove(storage, (proposal_id, &delegator));
+    }
+    Ok(())
+}
+
 pub fn execute_veto(
     deps: DepsMut,
     env: Env,
@@ -420,12 +656,74 @@ pub fn execute_execute(
         }
     }
 
+    let relations = RELATIONS
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or_default();
+    for dependency in &relations.depends_on {
+        if proposal_status(deps.as_ref(), &env, dependency)? != Status::Executed {
+            return Err(ContractError::DependencyNotExecuted {
+                module: dependency.module.clone(),
+                proposal_id: dependency.proposal_id,
+            });
+        }
+    }
+    // If a proposal this one conflicts with, in either direction, has
+    // been executed, this proposal is rejected instead of executed.
+    let conflicts = conflicting_proposals(deps.storage, &env, proposal_id, &relations)?;
+    for &conflict in &conflicts {
+        if PROPOSALS.load(deps.storage, conflict)?.status == Status::Executed {
+            prop.status = Status::Rejected;
+            PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+            let hooks = proposal_status_changed_hooks(
+                PROPOSAL_HOOKS,
+                deps.storage,
+                proposal_id,
+                old_status.to_string(),
+                prop.status.to_string(),
+            )?;
+            return Ok(Response::default()
+                .add_submessages(hooks)
+                .add_attribute("action", "execute")
+                .add_attribute("sender", info.sender)
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute(
+                    "rejected_for_conflict",
+                    format!("{}:{}", env.contract.address, conflict),
+                ));
+        }
+    }
+
     prop.status = Status::Executed;
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
+    let conflict_hooks = reject_conflicts(deps.storage, &env, &conflicts)?;
+
+    // Add proposal status change hooks. These are sent before the
+    // proposal's messages are executed, as the execution result is
+    // sent to the same hooks from `reply`, and a hook that errors is
+    // removed by its index when its reply is handled.
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+    let response = Response::default()
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(conflict_hooks);
+
     let response = {
-        if !prop.msgs.is_empty() {
+        if prop.msgs.is_empty() {
+            response
+        } else if let Some(partial_config) = PARTIAL_EXECUTION_CONFIG.may_load(deps.storage)? {
+            let mut execution = PartialExecution::new(prop.msgs, &partial_config, &env.block)?;
+            let msg_indexes = execution.all_indexes();
+            let submsgs = execution.execute(&config.dao, proposal_id, &msg_indexes)?;
+            PARTIAL_EXECUTIONS.save(deps.storage, proposal_id, &execution)?;
+            response.add_submessages(submsgs)
+        } else {
             let execute_message = WasmMsg::Execute {
                 contract_addr: config.dao.to_string(),
                 msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
@@ -433,35 +731,28 @@ pub fn execute_execute(
                 })?,
                 funds: vec![],
             };
+            // The result of execution is recorded in `reply`. If
+            // proposals are not closed on execution failure, a
+            // failure reverts the transaction so there is nothing to
+            // record.
+            let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
             match config.close_proposal_on_execution_failure {
-                true => {
-                    let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                    Response::default()
-                        .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
-                }
-                false => Response::default().add_message(execute_message),
+                true => response
+                    .add_submessage(SubMsg::reply_always(execute_message, masked_proposal_id)),
+                false => response.add_submessage(SubMsg::reply_on_success(
+                    execute_message,
+                    masked_proposal_id,
+                )),
             }
-        } else {
-            Response::default()
         }
     };
 
-    // Add proposal status change hooks
-    let proposal_status_changed_hooks = proposal_status_changed_hooks(
-        PROPOSAL_HOOKS,
-        deps.storage,
-        proposal_id,
-        old_status.to_string(),
-        prop.status.to_string(),
-    )?;
-
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let proposal_completed_hooks =
         proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
 
     Ok(response
-        .add_submessages(proposal_status_changed_hooks)
         .add_submessages(proposal_completed_hooks)
         .add_attribute("action", "execute")
         .add_attribute("sender", info.sender)
@@ -469,8 +760,76 @@ pub fn execute_execute(
         .add_attribute("dao", config.dao))
 }
 
+/// Gets the status of a proposal in this or another proposal module.
+fn proposal_status(deps: Deps, env: &Env, proposal: &CheckedProposalRef) -> StdResult<Status> {
+    if proposal.module == env.contract.address {
+        Ok(PROPOSALS.load(deps.storage, proposal.proposal_id)?.status)
+    } else {
+        query_proposal_status(deps, proposal)
+    }
+}
+
+/// The IDs of the proposals a proposal conflicts with: those it
+/// declared conflicts with and those that declared a conflict with
+/// it. Conflicts are only declared within this module, and each is
+/// bounded by `MAX_PROPOSAL_RELATIONS`.
+fn conflicting_proposals(
+    storage: &dyn Storage,
+    env: &Env,
+    proposal_id: u64,
+    relations: &CheckedProposalRelations,
+) -> StdResult<Vec<u64>> {
+    let mut conflicts: Vec<u64> = relations
+        .conflicts_with
+        .iter()
+        .map(|conflict| conflict.proposal_id)
+        .collect();
+    for id in CONFLICTS.prefix((&env.contract.address, proposal_id)).keys(
+        storage,
+        None,
+        None,
+        Order::Ascending,
+    ) {
+        let id = id?;
+        if !conflicts.contains(&id) {
+            conflicts.push(id);
+        }
+    }
+    Ok(conflicts)
+}
+
+/// Rejects the proposals in `conflicts` that have not yet completed.
+/// Returns the status changed hooks of the rejected proposals.
+fn reject_conflicts(
+    storage: &mut dyn Storage,
+    env: &Env,
+    conflicts: &[u64],
+) -> Result<Vec<SubMsg>, ContractError> {
+    let mut hooks = vec![];
+    for &id in conflicts {
+        let mut prop = PROPOSALS.load(storage, id)?;
+        prop.update_status(&env.block)?;
+        let old_status = prop.status;
+        if matches!(
+            old_status,
+            Status::Open | Status::Passed | Status::VetoTimelock { .. }
+        ) {
+            prop.status = Status::Rejected;
+            PROPOSALS.save(storage, id, &prop)?;
+            hooks.extend(proposal_status_changed_hooks(
+                PROPOSAL_HOOKS,
+                storage,
+                id,
+                old_status.to_string(),
+                prop.status.to_string(),
+            )?);
+        }
+    }
+    Ok(hooks)
+}
+
 pub fn execute_vote(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     proposal_id: u64,
@@ -489,21 +848,65 @@ pub fn execute_vote(
     // cause a different one. This then serves to allow
     // for better tallies of opinions in the event that a
     // proposal passes or is rejected early.
+    //
+    // An expedited proposal that did not pass in time may be voted
+    // on until its fallback expiration.
+    prop.update_expedited(&env.block);
     if prop.expiration.is_expired(&env.block) {
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    // If a delegate has already voted with the sender's voting
+    // power, remove it from their ballot as the sender is now voting
+    // directly.
+    if let Some(DelegatedVote { delegate, power }) =
+        DELEGATED_VOTES.may_load(deps.storage, (proposal_id, &sender))?
+    {
+        let weight = prop.vote_weighting.weight(power);
+        let mut delegate_ballot = BALLOTS.load(deps.storage, (proposal_id, &delegate))?;
+        prop.votes.remove_vote(delegate_ballot.vote, weight);
+        delegate_ballot.power = delegate_ballot.power.checked_sub(weight)?;
+        BALLOTS.save(deps.storage, (proposal_id, &delegate), &delegate_ballot)?;
+        DELEGATED_VOTES.remove(deps.storage, (proposal_id, &sender));
+        if prop.vote_weighting.is_quadratic() {
+            prop.power_cast = prop.power_cast.checked_sub(power)?;
+        }
+    }
+
+    let has_voted = BALLOTS.has(deps.storage, (proposal_id, &sender));
+
+    let mut vote_power = get_voting_power(
         deps.as_ref(),
         sender.clone(),
         &config.dao,
         Some(prop.start_height),
     )?;
-    if vote_power.is_zero() {
-        return Err(ContractError::NotRegistered {});
+    let mut vote_weight = prop.vote_weighting.weight(vote_power);
+    // Voting power delegated to the sender is counted when they first
+    // vote. Revotes carry over the power of their previous ballot.
+    if !has_voted {
+        if let Some(delegation_module) = DELEGATION_MODULE.may_load(deps.storage)? {
+            let (delegated_power, delegated_weight) = count_delegated_power(
+                deps.branch(),
+                &config.dao,
+                &delegation_module,
+                proposal_id,
+                &sender,
+                prop.start_height,
+                prop.vote_weighting,
+            )?;
+            vote_power = vote_power.checked_add(delegated_power)?;
+            vote_weight = vote_weight.checked_add(delegated_weight)?;
+        }
+        if vote_power.is_zero() {
+            return Err(ContractError::NotRegistered {});
+        }
+        if prop.vote_weighting.is_quadratic() {
+            prop.power_cast = prop.power_cast.checked_add(vote_power)?;
+        }
     }
 
-    BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
+    let ballot = BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
                 if current_ballot.vote == vote {
@@ -516,7 +919,7 @@ pub fn execute_vote(
                     prop.votes
                         .remove_vote(current_ballot.vote, current_ballot.power);
                     Ok(Ballot {
-                        power: vote_power,
+                        power: current_ballot.power,
                         vote,
                         // Roll over the previous rationale. If
                         // you're changing your vote, you've also
@@ -529,7 +932,7 @@ pub fn execute_vote(
             }
         }
         None => Ok(Ballot {
-            power: vote_power,
+            power: vote_weight,
             vote,
             rationale: rationale.clone(),
         }),
@@ -537,7 +940,7 @@ pub fn execute_vote(
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power);
+    prop.votes.add_vote(vote, ballot.power);
     prop.update_status(&env.block)?;
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
@@ -573,6 +976,49 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Counts the voting power of addresses that had delegated to
+/// `delegate` at `height` and have not voted on the proposal
+/// themselves. The power counted for each delegator is recorded so
+/// that it can be removed from the delegate's ballot if the delegator
+/// later votes. Returns the total delegated power and its weight
+/// under `vote_weighting`. Each delegator's power is weighted
+/// separately, as though they had voted themselves.
+fn count_delegated_power(
+    deps: DepsMut,
+    dao: &Addr,
+    delegation_module: &Addr,
+    proposal_id: u64,
+    delegate: &Addr,
+    height: u64,
+    vote_weighting: VoteWeighting,
+) -> StdResult<(Uint128, Uint128)> {
+    let delegators = get_delegators(deps.as_ref(), delegation_module, delegate, height)?;
+    let mut delegated_power = Uint128::zero();
+    let mut delegated_weight = Uint128::zero();
+    for delegator in delegators {
+        if BALLOTS.has(deps.storage, (proposal_id, &delegator))
+            || DELEGATED_VOTES.has(deps.storage, (proposal_id, &delegator))
+        {
+            continue;
+        }
+        let power = get_voting_power(deps.as_ref(), delegator.clone(), dao, Some(height))?;
+        if power.is_zero() {
+            continue;
+        }
+        DELEGATED_VOTES.save(
+            deps.storage,
+            (proposal_id, &delegator),
+            &DelegatedVote {
+                delegate: delegate.clone(),
+                power,
+            },
+        )?;
+        delegated_power = delegated_power.checked_add(power)?;
+        delegated_weight = delegated_weight.checked_add(vote_weighting.weight(power))?;
+    }
+    Ok((delegated_power, delegated_weight))
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -657,6 +1103,8 @@ pub fn execute_update_config(
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    vote_weighting: VoteWeighting,
+    expedited: Option<ExpeditedConfig>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -665,6 +1113,7 @@ pub fn execute_update_config(
         return Err(ContractError::Unauthorized {});
     }
     threshold.validate()?;
+    vote_weighting.validate(&threshold)?;
     let dao = deps.api.addr_validate(&dao)?;
 
     let (min_voting_period, max_voting_period) =
@@ -675,6 +1124,11 @@ pub fn execute_update_config(
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    if let Some(expedited) = &expedited {
+        expedited.validate(min_voting_period, max_voting_period)?;
+        vote_weighting.validate(&expedited.threshold)?;
+    }
+
     CONFIG.save(
         deps.storage,
         &Config {
@@ -686,6 +1140,8 @@ pub fn execute_update_config(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            vote_weighting,
+            expedited,
         },
     )?;
 
@@ -714,6 +1170,106 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    delegation_module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let delegation_module = match delegation_module {
+        Some(addr) => {
+            let addr = deps.api.addr_validate(&addr)?;
+            DELEGATION_MODULE.save(deps.storage, &addr)?;
+            addr.into_string()
+        }
+        None => {
+            DELEGATION_MODULE.remove(deps.storage);
+            "_none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute("delegation_module", delegation_module))
+}
+
+pub fn execute_update_partial_execution(
+    deps: DepsMut,
+    info: MessageInfo,
+    partial_execution: Option<PartialExecutionConfig>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let retry_window = match partial_execution {
+        Some(partial_execution) => {
+            PARTIAL_EXECUTION_CONFIG.save(deps.storage, &partial_execution)?;
+            partial_execution.retry_window.to_string()
+        }
+        None => {
+            PARTIAL_EXECUTION_CONFIG.remove(deps.storage);
+            "_none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_partial_execution")
+        .add_attribute("sender", info.sender)
+        .add_attribute("retry_window", retry_window))
+}
+
+pub fn execute_update_amendment_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    amendment_config: AmendmentConfig,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    AMENDMENT_CONFIG.save(deps.storage, &amendment_config)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_amendment_config")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "preserve_votes",
+            amendment_config.preserve_votes.to_string(),
+        ))
+}
+
+pub fn execute_retry_failed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    msg_indexes: Vec<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut execution = PARTIAL_EXECUTIONS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(VotingError::NoPartialExecution { id: proposal_id })?;
+
+    let msg_indexes = execution.retryable(msg_indexes, &env.block)?;
+    let submsgs = execution.execute(&config.dao, proposal_id, &msg_indexes)?;
+    PARTIAL_EXECUTIONS.save(deps.storage, proposal_id, &execution)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "retry_failed")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("retried", msg_indexes.len().to_string())
+        .add_submessages(submsgs))
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -778,6 +1334,48 @@ pub fn execute_remove_proposal_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_add_extended_proposal_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can add hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    add_hook(EXTENDED_PROPOSAL_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_extended_proposal_hook")
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_extended_proposal_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can remove hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    remove_hook(EXTENDED_PROPOSAL_HOOKS, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_extended_proposal_hook")
+        .add_attribute("address", address))
+}
+
 pub fn execute_add_vote_hook(
     deps: DepsMut,
     _env: Env,
@@ -845,6 +1443,40 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::ExtendedProposalHooks {} => {
+            to_json_binary(&EXTENDED_PROPOSAL_HOOKS.query_hooks(deps)?)
+        }
+        QueryMsg::DelegationModule {} => to_json_binary(&DELEGATION_MODULE.may_load(deps.storage)?),
+        QueryMsg::GetDelegatedVote {
+            proposal_id,
+            delegator,
+        } => query_delegated_vote(deps, proposal_id, delegator),
+        QueryMsg::ProposalExecutionResult { proposal_id } => {
+            to_json_binary(&EXECUTION_RESULTS.may_load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::PartialExecutionConfig {} => {
+            to_json_binary(&PARTIAL_EXECUTION_CONFIG.may_load(deps.storage)?)
+        }
+        QueryMsg::PartialExecution { proposal_id } => {
+            to_json_binary(&PARTIAL_EXECUTIONS.may_load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::AmendmentConfig {} => {
+            to_json_binary(&AMENDMENT_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::ProposalRelations { proposal_id } => to_json_binary(
+            &RELATIONS
+                .may_load(deps.storage, proposal_id)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::ReverseRelations {
+            module,
+            proposal_id,
+        } => query_reverse_relations(deps, module, proposal_id),
+        QueryMsg::ListAmendments {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_list_amendments(deps, proposal_id, start_after, limit),
     }
 }
 
@@ -927,6 +1559,11 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
     to_json_binary(&VoteResponse { vote })
 }
 
+pub fn query_delegated_vote(deps: Deps, proposal_id: u64, delegator: String) -> StdResult<Binary> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    to_json_binary(&DELEGATED_VOTES.may_load(deps.storage, (proposal_id, &delegator))?)
+}
+
 pub fn query_list_votes(
     deps: Deps,
     proposal_id: u64,
@@ -957,6 +1594,45 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_reverse_relations(deps: Deps, module: String, proposal_id: u64) -> StdResult<Binary> {
+    let module = deps.api.addr_validate(&module)?;
+    let dependents = DEPENDENTS
+        .prefix((&module, proposal_id))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    let conflicting = CONFLICTS
+        .prefix((&module, proposal_id))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    to_json_binary(&ReverseRelationsResponse {
+        dependents,
+        conflicting,
+    })
+}
+
+pub fn query_list_amendments(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let amendments = AMENDMENTS
+        .prefix(proposal_id)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, amendment) = item?;
+            Ok(AmendmentResponse { id, amendment })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&AmendmentListResponse { amendments })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -1002,6 +1678,8 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
                     veto,
+                    vote_weighting: VoteWeighting::Linear {},
+                    expedited: None,
                 },
             )?;
 
@@ -1047,6 +1725,9 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
                         veto: None,
+                        vote_weighting: VoteWeighting::Linear {},
+                        power_cast: Uint128::zero(),
+                        expedited: None,
                     };
 
                     PROPOSALS
@@ -1059,9 +1740,22 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                 .add_attribute("from", "v1")
                 .add_submessages(pre_propose_messages))
         }
-        MigrateMsg::FromCompatible {} => Ok(Response::default()
-            .add_attribute("action", "migrate")
-            .add_attribute("from", "compatible")),
+        MigrateMsg::FromCompatible { expedited } => {
+            // Configs and proposals saved before expedited proposals
+            // were added deserialize as not expedited, so expedited
+            // proposals only need configuring if they are to be
+            // enabled.
+            if let Some(expedited) = expedited {
+                let mut config = CONFIG.load(deps.storage)?;
+                expedited.validate(config.min_voting_period, config.max_voting_period)?;
+                config.vote_weighting.validate(&expedited.threshold)?;
+                config.expedited = Some(expedited);
+                CONFIG.save(deps.storage, &config)?;
+            }
+            Ok(Response::default()
+                .add_attribute("action", "migrate")
+                .add_attribute("from", "compatible"))
+        }
     }
 }
 
@@ -1070,21 +1764,76 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
-                Some(mut prop) => {
-                    prop.status = Status::ExecutionFailed;
+            let result = ExecutionResult::from(msg.result);
+            EXECUTION_RESULTS.save(deps.storage, proposal_id, &result)?;
+            let hooks = proposal_executed_hooks(
+                EXTENDED_PROPOSAL_HOOKS,
+                deps.storage,
+                proposal_id,
+                result.clone(),
+            )?;
 
-                    Ok(prop)
+            match result {
+                ExecutionResult::Success { .. } => Ok(Response::new()
+                    .add_attribute("proposal_executed", proposal_id.to_string())
+                    .add_submessages(hooks)),
+                ExecutionResult::Failure { error } => {
+                    PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+                        Some(mut prop) => {
+                            prop.status = Status::ExecutionFailed;
+
+                            Ok(prop)
+                        }
+                        None => Err(ContractError::NoSuchProposal { id: proposal_id }),
+                    })?;
+
+                    Ok(Response::new()
+                        .add_attribute("proposal_execution_failed", proposal_id.to_string())
+                        .add_attribute("error", error)
+                        .add_submessages(hooks))
                 }
-                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
-            })?;
-
-            Ok(Response::new()
-                .add_attribute("proposal_execution_failed", proposal_id.to_string())
-                .add_attribute(
-                    "error",
-                    msg.result.into_result().err().unwrap_or("None".to_string()),
-                ))
+            }
+        }
+        TaggedReplyId::PartialProposalExecution {
+            proposal_id,
+            msg_index,
+        } => {
+            let result = ExecutionResult::from(msg.result);
+            let success = result.is_success();
+            let mut execution = PARTIAL_EXECUTIONS.load(deps.storage, proposal_id)?;
+            execution.record(msg_index, result)?;
+            PARTIAL_EXECUTIONS.save(deps.storage, proposal_id, &execution)?;
+
+            let response = Response::new()
+                .add_attribute("partial_execution", proposal_id.to_string())
+                .add_attribute("msg_index", msg_index.to_string())
+                .add_attribute("success", success.to_string());
+
+            // Once the last message being executed has replied, the
+            // outcome of the execution is recorded. A proposal none of
+            // whose messages succeeded is marked as failed until a
+            // retry succeeds.
+            match execution.outcome() {
+                Some(result) => {
+                    EXECUTION_RESULTS.save(deps.storage, proposal_id, &result)?;
+                    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+                    prop.status = match result.is_success() {
+                        true => Status::Executed,
+                        false => Status::ExecutionFailed,
+                    };
+                    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+                    let hooks = proposal_executed_hooks(
+                        EXTENDED_PROPOSAL_HOOKS,
+                        deps.storage,
+                        proposal_id,
+                        result,
+                    )?;
+                    Ok(response
+                        .add_attribute("status", prop.status.to_string())
+                        .add_submessages(hooks))
+                }
+                None => Ok(response),
+            }
         }
         TaggedReplyId::FailedProposalHook(idx) => {
             let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
@@ -1094,6 +1843,11 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
             Ok(Response::new().add_attribute("removed_vote_hook", format!("{addr}:{idx}")))
         }
+        TaggedReplyId::FailedExtendedProposalHook(idx) => {
+            let addr = EXTENDED_PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new()
+                .add_attribute("removed_extended_proposal_hook", format!("{addr}:{idx}")))
+        }
         TaggedReplyId::PreProposeModuleInstantiation => {
             let res = parse_reply_instantiate_data(msg)?;
 
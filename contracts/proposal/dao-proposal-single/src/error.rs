@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Addr, OverflowError, StdError};
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
 use dao_voting::{reply::error::TagError, veto::VetoError};
@@ -9,6 +9,9 @@ pub enum ContractError {
     #[error(transparent)]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
     #[error(transparent)]
     ParseReplyError(#[from] ParseReplyError),
 
@@ -60,6 +63,18 @@ pub enum ContractError {
     #[error("only rejected proposals may be closed")]
     WrongCloseStatus {},
 
+    #[error("only open proposals may be amended")]
+    NotOpen {},
+
+    #[error("amending the proposal would remove more than ({max}) votes")]
+    TooManyVotesToRemove { max: usize },
+
+    #[error("proposal ({proposal_id}) in module ({module}) must be executed first")]
+    DependencyNotExecuted { module: Addr, proposal_id: u64 },
+
+    #[error("at most ({max}) proposals may declare a conflict with proposal ({proposal_id})")]
+    TooManyConflicts { proposal_id: u64, max: usize },
+
     #[error("the DAO is currently inactive, you cannot create proposals")]
     InactiveDao {},
 
@@ -69,6 +84,12 @@ pub enum ContractError {
     #[error("min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
 
+    #[error("expedited max voting period must be less than max voting period")]
+    InvalidExpeditedVotingPeriod {},
+
+    #[error("expedited proposals are not enabled")]
+    ExpeditedNotEnabled {},
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
@@ -2,10 +2,16 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
-    veto::VetoConfig, voting::Vote,
+    execution::PartialExecutionConfig,
+    pre_propose::PreProposeInfo,
+    proposal::{AmendmentConfig, SingleChoiceAmendMsg, SingleChoiceProposeMsg},
+    threshold::Threshold,
+    veto::VetoConfig,
+    voting::{Vote, VoteWeighting},
 };
 
+use crate::state::ExpeditedConfig;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The threshold a proposal must reach to complete.
@@ -44,12 +50,28 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// How voting power is converted into the weight of a vote.
+    /// Defaults to linear weighting if not set.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
+    /// Optional configuration for expedited proposals. If set,
+    /// proposers may flag proposals as expedited, in which case they
+    /// are voted on under this threshold and voting period, falling
+    /// back to `threshold` and `max_voting_period` if they do not
+    /// pass in time.
+    #[serde(default)]
+    pub expedited: Option<ExpeditedConfig>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a proposal in the module.
     Propose(SingleChoiceProposeMsg),
+    /// Amends an open proposal. Only the proposal's proposer or the
+    /// pre-propose module may amend a proposal. Unless the DAO has
+    /// configured amendments to preserve votes, amending a proposal
+    /// removes all votes cast on it.
+    Amend(SingleChoiceAmendMsg),
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
     Vote {
@@ -124,10 +146,44 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// How voting power is converted into the weight of a
+        /// vote. This will only apply to proposals created after the
+        /// config update.
+        vote_weighting: VoteWeighting,
+        /// Optional configuration for expedited proposals. If set to
+        /// `None`, proposals may not be expedited. This will only
+        /// apply to proposals created after the config update.
+        expedited: Option<ExpeditedConfig>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
     UpdatePreProposeInfo { info: PreProposeInfo },
+    /// Updates the vote delegation registry used by this module. If
+    /// set, the voting power of addresses that have delegated to a
+    /// voter is counted along with the voter's own power unless the
+    /// delegator votes themselves. Only the DAO may call this method.
+    UpdateDelegationModule { delegation_module: Option<String> },
+    /// Updates how proposals are executed. If set, each message of a
+    /// proposal is executed on its own, so that messages which fail
+    /// do not revert the others, and failed messages may be retried
+    /// with `RetryFailed`. This takes precedence over
+    /// `close_proposal_on_execution_failure`. If unset, proposals are
+    /// executed atomically. Only the DAO may call this method.
+    UpdatePartialExecution {
+        partial_execution: Option<PartialExecutionConfig>,
+    },
+    /// Executes the failed messages of a proposal that was executed
+    /// one message at a time again. May be called by anyone until the
+    /// proposal's retry window expires.
+    RetryFailed {
+        /// The ID of the proposal to retry messages of.
+        proposal_id: u64,
+        /// The indexes of the failed messages to retry.
+        msg_indexes: Vec<u32>,
+    },
+    /// Updates how proposals are amended. Only the DAO may call this
+    /// method.
+    UpdateAmendmentConfig { amendment_config: AmendmentConfig },
     /// Adds an address as a consumer of proposal hooks. Consumers of
     /// proposal hooks have hook messages executed on them whenever
     /// the status of a proposal changes or a proposal is created. If
@@ -143,6 +199,15 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Adds an address as a consumer of extended proposal hooks.
+    /// Consumers of extended proposal hooks are sent
+    /// `ProposalExecuted` and `ProposalAmended` hook messages, which
+    /// are not sent to consumers of proposal hooks. If a consumer
+    /// contract errors when handling a hook message it will be
+    /// removed from the list of consumers.
+    AddExtendedProposalHook { address: String },
+    /// Removes a consumer of extended proposal hooks.
+    RemoveExtendedProposalHook { address: String },
 }
 
 #[proposal_module_query]
@@ -209,6 +274,56 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Lists all of the consumers of extended proposal hooks for this
+    /// module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ExtendedProposalHooks {},
+    /// Gets the vote delegation registry used by this module (if
+    /// any).
+    #[returns(Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
+    /// Returns the voting power (if any) that a delegate cast on
+    /// behalf of `delegator` on a proposal.
+    #[returns(Option<::dao_voting::delegation::DelegatedVote>)]
+    GetDelegatedVote { proposal_id: u64, delegator: String },
+    /// Gets the outcome of executing a proposal. Returns `None` if the
+    /// proposal has not been executed, has no messages, or was
+    /// executed before results were recorded.
+    #[returns(Option<::dao_voting::execution::ExecutionResult>)]
+    ProposalExecutionResult { proposal_id: u64 },
+    /// Gets how proposals are executed. Returns `None` if proposals
+    /// are executed atomically.
+    #[returns(Option<::dao_voting::execution::PartialExecutionConfig>)]
+    PartialExecutionConfig {},
+    /// Gets the results of executing a proposal one message at a
+    /// time. Returns `None` if the proposal was not executed this
+    /// way.
+    #[returns(Option<::dao_voting::execution::PartialExecution>)]
+    PartialExecution { proposal_id: u64 },
+    /// Gets how proposals are amended.
+    #[returns(::dao_voting::proposal::AmendmentConfig)]
+    AmendmentConfig {},
+    /// Gets the proposals a proposal depends on and conflicts with.
+    #[returns(::dao_voting::relations::CheckedProposalRelations)]
+    ProposalRelations { proposal_id: u64 },
+    /// Lists the proposals in this module that depend on or conflict
+    /// with a proposal, which may be in another proposal module.
+    #[returns(crate::query::ReverseRelationsResponse)]
+    ReverseRelations {
+        /// The proposal module of the proposal.
+        module: String,
+        proposal_id: u64,
+    },
+    /// Lists the amendments made to a proposal, oldest first.
+    #[returns(crate::query::AmendmentListResponse)]
+    ListAmendments {
+        /// The ID of the proposal to list the amendments of.
+        proposal_id: u64,
+        /// The amendment ID to start listing amendments after.
+        start_after: Option<u64>,
+        /// The maximum number of amendments to return.
+        limit: Option<u64>,
+    },
 }
 
 #[cw_serde]
@@ -239,5 +354,10 @@ pub enum MigrateMsg {
         /// optional configuration for veto feature
         veto: Option<VetoConfig>,
     },
-    FromCompatible {},
+    FromCompatible {
+        /// If set, enables expedited proposals with this
+        /// configuration. Otherwise the module's config is unchanged.
+        #[serde(default)]
+        expedited: Option<ExpeditedConfig>,
+    },
 }
@@ -8,7 +8,7 @@ use cw_utils::Expiration;
 use dao_voting::status::Status;
 use dao_voting::threshold::{PercentageThreshold, Threshold};
 use dao_voting::veto::VetoConfig;
-use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, Votes};
+use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, VoteWeighting, Votes};
 
 #[cw_serde]
 pub struct SingleChoiceProposal {
@@ -46,6 +46,37 @@ pub struct SingleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How voting power is converted into the weight of a vote on
+    /// this proposal. Votes are tallied by weight. If the key is
+    /// missing (i.e. the proposal was created before vote weighting
+    /// was added), we deserialize into linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
+    /// The voting power of all addresses that have voted on this
+    /// proposal. Only tracked under quadratic weighting, where it
+    /// differs from the total weight of votes cast.
+    #[serde(default)]
+    pub power_cast: Uint128,
+    /// Set if this proposal was created as an expedited
+    /// proposal. While expedited, `threshold` and `expiration` are
+    /// the expedited threshold and expiration.
+    #[serde(default)]
+    pub expedited: Option<ExpeditedProposal>,
+}
+
+/// Information about an expedited proposal.
+#[cw_serde]
+pub struct ExpeditedProposal {
+    /// The threshold the proposal falls back to if it has not passed
+    /// when its expedited voting period ends.
+    pub fallback_threshold: Threshold,
+    /// The expiration the proposal falls back to if it has not passed
+    /// when its expedited voting period ends. This is the expiration
+    /// the proposal would have had were it not expedited.
+    pub fallback_expiration: Expiration,
+    /// Whether the proposal has fallen back to its fallback threshold
+    /// and expiration.
+    pub fell_back: bool,
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -73,6 +104,11 @@ impl SingleChoiceProposal {
 
     /// Gets the current status of the proposal.
     pub fn current_status(&self, block: &BlockInfo) -> StdResult<Status> {
+        // An expedited proposal that did not pass in time is
+        // considered under its fallback threshold and expiration.
+        if let Some(fallen_back) = self.fallen_back(block) {
+            return fallen_back.current_status(block);
+        }
         match self.status {
             Status::Open if self.is_passed(block) => match &self.veto {
                 // if prop is passed and veto is configured, calculate timelock
@@ -90,7 +126,12 @@ impl SingleChoiceProposal {
                 // Otherwise the proposal is simply passed
                 None => Ok(Status::Passed),
             },
-            Status::Open if self.expiration.is_expired(block) || self.is_rejected(block) => {
+            // Expedited proposals are not rejected until they have
+            // fallen back.
+            Status::Open
+                if !self.is_expedited()
+                    && (self.expiration.is_expired(block) || self.is_rejected(block)) =>
+            {
                 Ok(Status::Rejected)
             }
             Status::VetoTimelock { expiration } => {
@@ -107,11 +148,70 @@ impl SingleChoiceProposal {
 
     /// Sets a proposals status to its current status.
     pub fn update_status(&mut self, block: &BlockInfo) -> StdResult<()> {
+        self.update_expedited(block);
         let new_status = self.current_status(block)?;
         self.status = new_status;
         Ok(())
     }
 
+    /// Returns true if this proposal is expedited and has not fallen
+    /// back to its fallback threshold and expiration.
+    pub fn is_expedited(&self) -> bool {
+        matches!(
+            self.expedited,
+            Some(ExpeditedProposal {
+                fell_back: false,
+                ..
+            })
+        )
+    }
+
+    /// Falls back to the fallback threshold and expiration if this
+    /// proposal is expedited and did not pass before its expedited
+    /// voting period ended.
+    pub fn update_expedited(&mut self, block: &BlockInfo) {
+        if let Some(fallen_back) = self.fallen_back(block) {
+            *self = fallen_back;
+        }
+    }
+
+    /// Returns this proposal with its fallback threshold and
+    /// expiration if it is open, expedited, and its expedited voting
+    /// period has ended without it passing.
+    fn fallen_back(&self, block: &BlockInfo) -> Option<Self> {
+        if self.status != Status::Open
+            || !self.is_expedited()
+            || !self.expiration.is_expired(block)
+            || self.is_passed(block)
+        {
+            return None;
+        }
+        let mut prop = self.clone();
+        let expedited = prop.expedited.as_mut()?;
+        expedited.fell_back = true;
+        prop.threshold = expedited.fallback_threshold.clone();
+        prop.expiration = expedited.fallback_expiration;
+        Some(prop)
+    }
+
+    /// The voting power of the addresses that have voted on this
+    /// proposal. Quorum is measured against this so that it has the
+    /// same meaning regardless of how votes are weighted.
+    pub fn participation(&self) -> Uint128 {
+        match self.vote_weighting {
+            VoteWeighting::Linear {} => self.votes.total(),
+            VoteWeighting::Quadratic {} => self.power_cast,
+        }
+    }
+
+    /// An upper bound on the total weight of votes that may be cast
+    /// on this proposal. Addresses that have not yet voted are
+    /// assumed to contribute their full voting power, which is never
+    /// less than its weight.
+    pub fn max_weighted_power(&self) -> Uint128 {
+        self.votes.total() + self.total_power.saturating_sub(self.participation())
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail).
@@ -134,11 +234,11 @@ impl SingleChoiceProposal {
 
         match self.threshold {
             Threshold::AbsolutePercentage { percentage } => {
-                let options = self.total_power - self.votes.abstain;
+                let options = self.max_weighted_power() - self.votes.abstain;
                 does_vote_count_pass(self.votes.yes, options, percentage)
             }
             Threshold::ThresholdQuorum { threshold, quorum } => {
-                if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
+                if !does_vote_count_pass(self.participation(), self.total_power, quorum) {
                     return false;
                 }
 
@@ -150,7 +250,7 @@ impl SingleChoiceProposal {
                     let options = self.votes.total() - self.votes.abstain;
                     does_vote_count_pass(self.votes.yes, options, threshold)
                 } else {
-                    let options = self.total_power - self.votes.abstain;
+                    let options = self.max_weighted_power() - self.votes.abstain;
                     does_vote_count_pass(self.votes.yes, options, threshold)
                 }
             }
@@ -171,7 +271,7 @@ impl SingleChoiceProposal {
             Threshold::AbsolutePercentage {
                 percentage: percentage_needed,
             } => {
-                let options = self.total_power - self.votes.abstain;
+                let options = self.max_weighted_power() - self.votes.abstain;
 
                 // If there is a 100% passing threshold..
                 if percentage_needed == PercentageThreshold::Percent(Decimal::percent(100)) {
@@ -197,7 +297,7 @@ impl SingleChoiceProposal {
             }
             Threshold::ThresholdQuorum { threshold, quorum } => {
                 match (
-                    does_vote_count_pass(self.votes.total(), self.total_power, quorum),
+                    does_vote_count_pass(self.participation(), self.total_power, quorum),
                     self.expiration.is_expired(block),
                 ) {
                     // Has met quorum and is expired.
@@ -233,7 +333,7 @@ impl SingleChoiceProposal {
                     (true, false) | (false, false) => {
                         // => consider all possible votes and see if
                         //    no votes meet threshold.
-                        let options = self.total_power - self.votes.abstain;
+                        let options = self.max_weighted_power() - self.votes.abstain;
 
                         // If there is a 100% passing threshold..
                         if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
@@ -265,7 +365,7 @@ impl SingleChoiceProposal {
             Threshold::AbsoluteCount { threshold } => {
                 // If all the outstanding votes voting yes would not
                 // cause this proposal to pass then it is rejected.
-                let outstanding_votes = self.total_power - self.votes.total();
+                let outstanding_votes = self.max_weighted_power() - self.votes.total();
                 self.votes.yes + outstanding_votes < threshold
             }
         }
@@ -312,6 +412,9 @@ mod test {
             veto: None,
             total_power,
             votes,
+            vote_weighting: VoteWeighting::Linear {},
+            power_cast: Uint128::zero(),
+            expedited: None,
         };
         (prop, block)
     }
@@ -1126,6 +1229,105 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_quadratic_quorum() {
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+        };
+        // A single voter with 25 voting power votes yes. Their vote
+        // has a weight of 5.
+        let votes = Votes {
+            yes: Uint128::new(5),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+
+        let (mut prop, block) = setup_prop(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            false,
+        );
+        prop.vote_weighting = VoteWeighting::Quadratic {};
+        prop.power_cast = Uint128::new(25);
+
+        // Quorum is measured in voting power so it is met, but the
+        // 75 voting power that has not been cast could still reject
+        // the proposal.
+        assert_eq!(prop.participation(), Uint128::new(25));
+        assert_eq!(prop.max_weighted_power(), Uint128::new(80));
+        assert!(!prop.is_passed(&block));
+        assert!(!prop.is_rejected(&block));
+
+        let (mut prop, block) = setup_prop(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false,
+        );
+        prop.vote_weighting = VoteWeighting::Quadratic {};
+        prop.power_cast = Uint128::new(25);
+        assert!(prop.is_passed(&block));
+
+        // Under linear weighting the same tally does not meet quorum.
+        let (prop, block) = setup_prop(threshold, votes, Uint128::new(100), true, true, false);
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+    }
+
+    #[test]
+    fn test_expedited_fallback() {
+        let threshold = Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Percent(Decimal::percent(67)),
+        };
+        let votes = Votes {
+            yes: Uint128::new(50),
+            no: Uint128::new(40),
+            abstain: Uint128::zero(),
+        };
+        let expedited = |block: &BlockInfo| ExpeditedProposal {
+            fallback_threshold: Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Majority {},
+            },
+            fallback_expiration: Expiration::AtHeight(block.height + 200),
+            fell_back: false,
+        };
+
+        // Failing the expedited threshold does not reject a proposal
+        // before its expedited voting period ends.
+        let (mut prop, block) = setup_prop(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            false,
+        );
+        assert!(prop.is_rejected(&block));
+        prop.expedited = Some(expedited(&block));
+        assert_eq!(prop.current_status(&block).unwrap(), Status::Open);
+
+        // Once it ends, the proposal falls back.
+        let (mut prop, block) = setup_prop(threshold, votes, Uint128::new(100), true, true, false);
+        prop.expedited = Some(expedited(&block));
+        assert!(prop.is_expedited());
+        prop.update_status(&block).unwrap();
+        assert_eq!(prop.status, Status::Open);
+        assert!(!prop.is_expedited());
+        assert_eq!(
+            prop.threshold,
+            Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Majority {},
+            }
+        );
+        assert_eq!(prop.expiration, Expiration::AtHeight(block.height + 200));
+    }
+
     #[test]
     fn test_proposal_ids_advance() {
         // do they advance, lets find out!
@@ -1,4 +1,5 @@
 use crate::proposal::SingleChoiceProposal;
+use crate::state::Amendment;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 use dao_voting::voting::Vote;
@@ -43,3 +44,28 @@ pub struct VoteListResponse {
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// Information about an amendment returned by `ListAmendments`.
+#[cw_serde]
+pub struct AmendmentResponse {
+    /// The ID of the amendment.
+    pub id: u64,
+    pub amendment: Amendment,
+}
+
+/// A list of amendments returned by `ListAmendments`.
+#[cw_serde]
+pub struct AmendmentListResponse {
+    pub amendments: Vec<AmendmentResponse>,
+}
+
+/// Proposals in this module that depend on or conflict with a
+/// proposal, returned by `ReverseRelations`.
+#[cw_serde]
+pub struct ReverseRelationsResponse {
+    /// The IDs of the proposals that depend on the proposal.
+    pub dependents: Vec<u64>,
+    /// The IDs of the proposals that declared a conflict with the
+    /// proposal.
+    pub conflicting: Vec<u64>,
+}
@@ -1,18 +1,29 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, Empty, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 use dao_voting::{
-    pre_propose::ProposalCreationPolicy, threshold::Threshold, veto::VetoConfig, voting::Vote,
+    delegation::DelegatedVote,
+    execution::ExecutionResult,
+    execution::{PartialExecution, PartialExecutionConfig},
+    pre_propose::ProposalCreationPolicy,
+    proposal::AmendmentConfig,
+    relations::CheckedProposalRelations,
+    threshold::Threshold,
+    veto::VetoConfig,
+    voting::{validate_voting_period, Vote, VoteWeighting},
 };
 
 use crate::proposal::SingleChoiceProposal;
+use crate::ContractError;
 
 /// A vote cast for a proposal.
 #[cw_serde]
 pub struct Ballot {
-    /// The amount of voting power behind the vote.
+    /// The amount of voting power behind the vote. Under quadratic
+    /// weighting this is the weight of the vote rather than the
+    /// voter's voting power.
     pub power: Uint128,
     /// The position.
     pub vote: Vote,
@@ -24,6 +35,25 @@ pub struct Ballot {
     pub rationale: Option<String>,
 }
 
+/// A change made to a proposal while it was open for voting. Records
+/// the contents of the proposal before the change.
+#[cw_serde]
+pub struct Amendment {
+    /// The address that amended the proposal.
+    pub sender: Addr,
+    /// The block height at which the proposal was amended.
+    pub height: u64,
+    /// The title of the proposal before it was amended.
+    pub title: String,
+    /// The description of the proposal before it was amended.
+    pub description: String,
+    /// The messages of the proposal before it was amended.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// Whether the votes cast on the proposal before it was amended
+    /// were removed.
+    pub votes_reset: bool,
+}
+
 /// The governance module's configuration.
 #[cw_serde]
 pub struct Config {
@@ -61,6 +91,54 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// How voting power is converted into the weight of a vote. If
+    /// the key is missing (i.e. the config was saved before vote
+    /// weighting was added), we deserialize into linear weighting.
+    #[serde(default)]
+    pub vote_weighting: VoteWeighting,
+    /// Optional configuration for expedited proposals. If set to
+    /// `None`, proposals may not be expedited. If the key is missing
+    /// (i.e. the config was saved before expedited proposals were
+    /// added), we deserialize into `None`.
+    #[serde(default)]
+    pub expedited: Option<ExpeditedConfig>,
+}
+
+/// The threshold and voting period used by expedited proposals. An
+/// expedited proposal that has not passed by the end of its voting
+/// period falls back to the module's threshold and voting period.
+#[cw_serde]
+pub struct ExpeditedConfig {
+    /// The threshold an expedited proposal must reach to pass before
+    /// its voting period ends.
+    pub threshold: Threshold,
+    /// The maximum amount of time an expedited proposal may be voted
+    /// on before falling back. Must be shorter than the module's
+    /// `max_voting_period`.
+    pub max_voting_period: Duration,
+}
+
+impl ExpeditedConfig {
+    /// Validates the expedited threshold and checks that the expedited
+    /// voting period is shorter than `max_voting_period` and no
+    /// shorter than `min_voting_period`.
+    pub fn validate(
+        &self,
+        min_voting_period: Option<Duration>,
+        max_voting_period: Duration,
+    ) -> Result<(), ContractError> {
+        self.threshold.validate()?;
+        let shorter = match (self.max_voting_period, max_voting_period) {
+            (Duration::Time(expedited), Duration::Time(max)) => expedited < max,
+            (Duration::Height(expedited), Duration::Height(max)) => expedited < max,
+            _ => return Err(ContractError::DurationUnitsConflict {}),
+        };
+        if !shorter {
+            return Err(ContractError::InvalidExpeditedVotingPeriod {});
+        }
+        validate_voting_period(min_voting_period, self.max_voting_period)?;
+        Ok(())
+    }
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -74,6 +152,41 @@ pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
 pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// Consumers of extended proposal hooks, which are sent when
+/// proposals are executed or amended.
+pub const EXTENDED_PROPOSAL_HOOKS: Hooks = Hooks::new("extended_proposal_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The vote delegation registry consulted when votes are cast (if
+/// any).
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// Voting power that a delegate cast on behalf of a delegator, keyed
+/// by proposal ID and delegator.
+pub const DELEGATED_VOTES: Map<(u64, &Addr), DelegatedVote> = Map::new("delegated_votes");
+/// The outcome of executing each executed proposal. Only recorded
+/// for proposals executed after results were first recorded.
+pub const EXECUTION_RESULTS: Map<u64, ExecutionResult> = Map::new("execution_results");
+/// How proposals are executed. Not set if proposals are executed
+/// atomically.
+pub const PARTIAL_EXECUTION_CONFIG: Item<PartialExecutionConfig> =
+    Item::new("partial_execution_config");
+/// Proposals that were executed one message at a time and the results
+/// of executing their messages.
+pub const PARTIAL_EXECUTIONS: Map<u64, PartialExecution> = Map::new("partial_executions");
+/// How proposals are amended. Defaults to removing votes if not set.
+pub const AMENDMENT_CONFIG: Item<AmendmentConfig> = Item::new("amendment_config");
+/// Amendments made to proposals, keyed by proposal ID and amendment
+/// ID. Amendment IDs start at one for each proposal.
+pub const AMENDMENTS: Map<(u64, u64), Amendment> = Map::new("amendments");
+/// The proposals each proposal depends on and conflicts with. Not set
+/// for proposals without relations.
+pub const RELATIONS: Map<u64, CheckedProposalRelations> = Map::new("relations");
+/// Proposals in this module that depend on a proposal, keyed by the
+/// module and ID of the proposal depended on and the ID of the
+/// dependent proposal.
+pub const DEPENDENTS: Map<(&Addr, u64, u64), Empty> = Map::new("dependents");
+/// Proposals in this module that declared a conflict with a proposal,
+/// keyed by the module and ID of the conflicting proposal and the ID
+/// of the proposal that declared it.
+pub const CONFLICTS: Map<(&Addr, u64, u64), Empty> = Map::new("conflicts");
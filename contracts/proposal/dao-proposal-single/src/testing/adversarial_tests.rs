@@ -19,7 +19,7 @@ use dao_voting::{
     deposit::{DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType},
     status::Status,
     threshold::{PercentageThreshold, Threshold::AbsolutePercentage},
-    voting::Vote,
+    voting::{Vote, VoteWeighting},
 };
 
 use super::CREATOR_ADDR;
@@ -181,6 +181,8 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -280,6 +282,8 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
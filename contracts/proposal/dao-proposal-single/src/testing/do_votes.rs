@@ -13,6 +13,7 @@ use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
     status::Status,
     threshold::Threshold,
+    voting::VoteWeighting,
 };
 
 use crate::{
@@ -141,6 +142,8 @@ where
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
         pre_propose_info,
     };
 
@@ -208,6 +211,8 @@ where
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
                 vote: None,
+                relations: None,
+                expedited: false,
             },
         },
         &funds,
@@ -81,6 +81,8 @@ pub(crate) fn make_proposal(
                     msgs: msgs.clone(),
                     proposer: None,
                     vote,
+                    relations: None,
+                    expedited: false,
                 }),
                 &[],
             )
@@ -95,6 +97,8 @@ pub(crate) fn make_proposal(
                         description: "description".to_string(),
                         msgs: msgs.clone(),
                         vote,
+                        relations: None,
+                        expedited: false,
                     },
                 },
                 &funds,
@@ -332,6 +336,23 @@ pub(crate) fn add_proposal_hook(
     .unwrap();
 }
 
+pub(crate) fn add_extended_proposal_hook(
+    app: &mut App,
+    proposal_module: &Addr,
+    sender: &str,
+    hook_addr: &str,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_module.clone(),
+        &ExecuteMsg::AddExtendedProposalHook {
+            address: hook_addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
 pub(crate) fn add_proposal_hook_should_fail(
     app: &mut App,
     proposal_module: &Addr,
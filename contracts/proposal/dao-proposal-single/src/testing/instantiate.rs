@@ -16,6 +16,7 @@ use dao_voting::{
     deposit::{DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType},
     pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy},
     threshold::{ActiveThreshold, PercentageThreshold, Threshold::ThresholdQuorum},
+    voting::VoteWeighting,
 };
 use dao_voting_cw4::msg::GroupContract;
 
@@ -79,6 +80,8 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
             false,
         ),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
     }
 }
 
@@ -98,6 +101,8 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
     }
 }
 
@@ -165,6 +170,7 @@ pub(crate) fn instantiate_with_staked_cw721_governance(
                     address: nft_address.to_string(),
                 },
                 active_threshold: None,
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -284,6 +290,9 @@ pub(crate) fn instantiate_with_native_staked_balances_governance(
                 },
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -405,6 +414,7 @@ pub(crate) fn instantiate_with_staked_balances_governance(
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
                 },
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -520,6 +530,7 @@ pub(crate) fn instantiate_with_staking_active_threshold(
                     initial_dao_balance: None,
                 },
                 active_threshold,
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
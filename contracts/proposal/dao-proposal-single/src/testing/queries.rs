@@ -4,11 +4,17 @@ use dao_interface::state::{ProposalModule, ProposalModuleStatus};
 
 use cw_hooks::HooksResponse;
 use dao_pre_propose_single as dpps;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{
+    execution::{ExecutionResult, PartialExecution},
+    pre_propose::ProposalCreationPolicy,
+};
 
 use crate::{
     msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse, VoteListResponse, VoteResponse},
+    query::{
+        AmendmentListResponse, ProposalListResponse, ProposalResponse, VoteListResponse,
+        VoteResponse,
+    },
     state::Config,
 };
 
@@ -201,6 +207,32 @@ pub(crate) fn query_proposal(app: &App, proposal_single: &Addr, id: u64) -> Prop
         .unwrap()
 }
 
+pub(crate) fn query_execution_result(
+    app: &App,
+    proposal_single: &Addr,
+    id: u64,
+) -> Option<ExecutionResult> {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ProposalExecutionResult { proposal_id: id },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_partial_execution(
+    app: &App,
+    proposal_single: &Addr,
+    id: u64,
+) -> Option<PartialExecution> {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::PartialExecution { proposal_id: id },
+        )
+        .unwrap()
+}
+
 pub(crate) fn query_next_proposal_id(app: &App, proposal_single: &Addr) -> u64 {
     app.wrap()
         .query_wasm_smart(proposal_single, &QueryMsg::NextProposalId {})
@@ -212,3 +244,20 @@ pub(crate) fn query_proposal_count(app: &App, proposal_single: &Addr) -> u64 {
         .query_wasm_smart(proposal_single, &QueryMsg::ProposalCount {})
         .unwrap()
 }
+
+pub(crate) fn query_list_amendments(
+    app: &App,
+    proposal_single: &Addr,
+    id: u64,
+) -> AmendmentListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ListAmendments {
+                proposal_id: id,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
@@ -17,13 +17,26 @@ use dao_interface::{
     voting::InfoResponse,
 };
 use dao_testing::{
-    contracts::{dao_pre_propose_single_contract, dao_proposal_single_contract},
+    contracts::{
+        dao_pre_propose_single_contract, dao_proposal_hook_counter_contract,
+        dao_proposal_single_contract, dao_vote_delegation_contract,
+    },
     ShouldExecute, TestSingleChoiceVote,
 };
 use dao_voting::{
+    delegation::DelegatedVote,
     deposit::{CheckedDepositInfo, UncheckedDepositInfo, VotingModuleTokenType},
+    error::VotingError,
+    execution::{ExecutionResult, PartialExecutionConfig},
     pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
-    proposal::{SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
+    proposal::{
+        AmendmentConfig, SingleChoiceAmendMsg, SingleChoiceProposeMsg as ProposeMsg,
+        MAX_PROPOSAL_SIZE,
+    },
+    relations::{
+        CheckedProposalRef, CheckedProposalRelations, ProposalRef, ProposalRelations,
+        MAX_PROPOSAL_RELATIONS,
+    },
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
         mask_proposal_hook_index, mask_vote_hook_index,
@@ -31,19 +44,19 @@ use dao_voting::{
     status::Status,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
     veto::{VetoConfig, VetoError},
-    voting::{SingleChoiceAutoVote, Vote, Votes},
+    voting::{SingleChoiceAutoVote, Vote, VoteWeighting, Votes},
 };
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-    proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
-    state::Config,
+    proposal::{ExpeditedProposal, SingleChoiceProposal},
+    query::{ProposalResponse, ReverseRelationsResponse, VoteInfo},
+    state::{Config, ExpeditedConfig},
     testing::{
         execute::{
-            add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
-            add_vote_hook_should_fail, close_proposal, close_proposal_should_fail,
+            add_extended_proposal_hook, add_proposal_hook, add_proposal_hook_should_fail,
+            add_vote_hook, add_vote_hook_should_fail, close_proposal, close_proposal_should_fail,
             execute_proposal, execute_proposal_should_fail, instantiate_cw20_base_default,
             make_proposal, mint_cw20s, mint_natives, remove_proposal_hook,
             remove_proposal_hook_should_fail, remove_vote_hook, remove_vote_hook_should_fail,
@@ -70,7 +83,10 @@ use dao_proposal_single::ContractError;
 use super::{
     do_votes::do_votes_staked_balances,
     execute::vote_on_proposal_with_rationale,
-    queries::{query_next_proposal_id, query_vote},
+    queries::{
+        query_execution_result, query_list_amendments, query_next_proposal_id,
+        query_partial_execution, query_vote,
+    },
     CREATOR_ADDR,
 };
 
@@ -133,6 +149,9 @@ fn test_simple_propose_staked_balances() {
         status: Status::Open,
         veto: None,
         votes: Votes::zero(),
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
+        power_cast: Uint128::zero(),
     };
 
     assert_eq!(created.proposal, expected);
@@ -183,6 +202,9 @@ fn test_simple_proposal_cw4_voting() {
         status: Status::Open,
         veto: None,
         votes: Votes::zero(),
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
+        power_cast: Uint128::zero(),
     };
 
     assert_eq!(created.proposal, expected);
@@ -239,6 +261,9 @@ fn test_simple_proposal_auto_vote_yes() {
             no: Uint128::zero(),
             abstain: Uint128::zero(),
         },
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
+        power_cast: Uint128::zero(),
     };
 
     assert_eq!(created.proposal, expected);
@@ -288,6 +313,9 @@ fn test_simple_proposal_auto_vote_no() {
             no: Uint128::new(1),
             abstain: Uint128::zero(),
         },
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
+        power_cast: Uint128::zero(),
     };
 
     assert_eq!(created.proposal, expected);
@@ -386,6 +414,9 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         status: Status::Open,
         votes: Votes::zero(),
         veto: None,
+        vote_weighting: VoteWeighting::Linear {},
+        expedited: None,
+        power_cast: Uint128::zero(),
     };
 
     assert_eq!(created.proposal, expected);
@@ -2075,6 +2106,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                vote_weighting: VoteWeighting::Linear {},
+                expedited: None,
             })
             .unwrap(),
             funds: vec![],
@@ -2110,6 +2143,8 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            expedited: None,
         }
     );
 
@@ -2129,6 +2164,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                vote_weighting: VoteWeighting::Linear {},
+                expedited: None,
             },
             &[],
         )
@@ -2158,6 +2195,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                vote_weighting: VoteWeighting::Linear {},
+                expedited: None,
             },
             &[],
         )
@@ -2246,7 +2285,10 @@ fn test_anyone_may_propose_and_proposal_listing() {
                     no: Uint128::zero(),
                     abstain: Uint128::zero()
                 },
-                veto: None
+                veto: None,
+                vote_weighting: VoteWeighting::Linear {},
+                expedited: None,
+                power_cast: Uint128::zero(),
             }
         }
     )
@@ -2430,6 +2472,8 @@ fn test_active_threshold_absolute() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -2469,6 +2513,8 @@ fn test_active_threshold_absolute() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -2513,6 +2559,8 @@ fn test_active_threshold_percent() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -2553,6 +2601,8 @@ fn test_active_threshold_percent() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -2751,6 +2801,8 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            expedited: None,
         },
         &[],
     )
@@ -3055,6 +3107,8 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            vote_weighting: VoteWeighting::Linear {},
+            expedited: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3102,7 +3156,7 @@ fn test_migrate_from_compatible() {
         CosmosMsg::Wasm(WasmMsg::Migrate {
             contract_addr: proposal_module.to_string(),
             new_code_id,
-            msg: to_json_binary(&MigrateMsg::FromCompatible {}).unwrap(),
+            msg: to_json_binary(&MigrateMsg::FromCompatible { expedited: None }).unwrap(),
         }),
     )
     .unwrap();
@@ -3115,7 +3169,12 @@ fn test_migrate_from_compatible() {
 pub fn test_migrate_updates_version() {
     let mut deps = mock_dependencies();
     cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg::FromCompatible { expedited: None },
+    )
+    .unwrap();
     let version = cw2::get_contract_version(&deps.storage).unwrap();
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
@@ -3464,6 +3523,8 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            vote_weighting: VoteWeighting::Linear {},
+            expedited: None,
         },
         &[],
     )
@@ -3529,6 +3590,9 @@ fn test_reply_proposal_mock() {
                 status: Status::Open,
                 veto: None,
                 votes: Votes::zero(),
+                vote_weighting: VoteWeighting::Linear {},
+                expedited: None,
+                power_cast: Uint128::zero(),
             },
         )
         .unwrap();
@@ -3569,6 +3633,8 @@ fn test_proposal_too_large() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -3621,6 +3687,8 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -3647,6 +3715,8 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -3678,6 +3748,8 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
                 vote: None,
+                relations: None,
+                expedited: false,
             }),
             &[],
         )
@@ -4251,3 +4323,1218 @@ fn test_proposal_count_goes_up() {
     let next = query_next_proposal_id(&app, &proposal_module);
     assert_eq!(next, 3);
 }
+
+#[test]
+fn test_delegated_voting() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(7),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "delegate".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "alice".to_string(),
+                amount: Uint128::new(2),
+            },
+            Cw20Coin {
+                address: "bob".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "carol".to_string(),
+                amount: Uint128::new(4),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let delegation_id = app.store_code(dao_vote_delegation_contract());
+    let delegation_module = app
+        .instantiate_contract(
+            delegation_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg { max_delegators: 10 },
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap();
+
+    // Only the DAO may set the delegation module.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateDelegationModule {
+                delegation_module: Some(delegation_module.to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            delegation_module: Some(delegation_module.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    let module: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::DelegationModule {})
+        .unwrap();
+    assert_eq!(module, Some(delegation_module.clone()));
+
+    for delegator in ["alice", "bob"] {
+        app.execute_contract(
+            Addr::unchecked(delegator),
+            delegation_module.clone(),
+            &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+                delegate: "delegate".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.update_block(next_block);
+
+    // The delegate votes with their own power and the power of both
+    // delegators.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "delegate",
+        proposal_id,
+        Vote::Yes,
+    );
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(6));
+    let delegated: Option<DelegatedVote> = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_module,
+            &QueryMsg::GetDelegatedVote {
+                proposal_id,
+                delegator: "alice".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        delegated,
+        Some(DelegatedVote {
+            delegate: Addr::unchecked("delegate"),
+            power: Uint128::new(2),
+        })
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // Alice overrides her delegate. Her power is removed from the
+    // delegate's ballot.
+    vote_on_proposal(&mut app, &proposal_module, "alice", proposal_id, Vote::No);
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(4));
+    let vote = query_vote(&app, &proposal_module, "alice", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(2));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(4));
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(2));
+
+    vote_on_proposal(&mut app, &proposal_module, "carol", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    // A delegator who votes before their delegate is not counted in
+    // the delegate's ballot.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "bob",
+        proposal_id,
+        Vote::Abstain,
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "delegate",
+        proposal_id,
+        Vote::Yes,
+    );
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(3));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(3));
+    assert_eq!(proposal.proposal.votes.abstain, Uint128::new(3));
+
+    // Delegations made after a proposal is created do not apply to
+    // it.
+    app.execute_contract(
+        Addr::unchecked("carol"),
+        delegation_module,
+        &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+            delegate: "delegate".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    vote_on_proposal(&mut app, &proposal_module, "alice", proposal_id, Vote::No);
+    let vote = query_vote(&app, &proposal_module, "delegate", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(1));
+}
+
+#[test]
+fn test_quadratic_voting() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(15)),
+    };
+    instantiate.vote_weighting = VoteWeighting::Quadratic {};
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "alice".to_string(),
+                amount: Uint128::new(16),
+            },
+            Cw20Coin {
+                address: "bob".to_string(),
+                amount: Uint128::new(16),
+            },
+            Cw20Coin {
+                address: "carol".to_string(),
+                amount: Uint128::new(16),
+            },
+            Cw20Coin {
+                address: "dave".to_string(),
+                amount: Uint128::new(16),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    // The whale's vote is weighted by the square root of their
+    // voting power. Quorum is met as it is measured in voting power,
+    // but the remaining voters could still outvote them.
+    vote_on_proposal(&mut app, &proposal_module, "whale", proposal_id, Vote::No);
+    let vote = query_vote(&app, &proposal_module, "whale", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(10));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(proposal.proposal.power_cast, Uint128::new(100));
+
+    for voter in ["alice", "bob", "carol"] {
+        vote_on_proposal(&mut app, &proposal_module, voter, proposal_id, Vote::Yes);
+    }
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // With linear weighting the whale would win 100 to 64.
+    vote_on_proposal(&mut app, &proposal_module, "dave", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(16));
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(10));
+    assert_eq!(proposal.proposal.power_cast, Uint128::new(164));
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    // Quadratic weighting may not be used with an absolute
+    // percentage threshold.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateConfig {
+                veto: None,
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                max_voting_period: Duration::Height(6),
+                min_voting_period: None,
+                only_members_execute: true,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                close_proposal_on_execution_failure: false,
+                vote_weighting: VoteWeighting::Quadratic {},
+                expedited: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::QuadraticAbsolutePercentage {})
+    );
+}
+
+#[test]
+fn test_execution_result() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id,
+    } = setup_test(vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()]);
+
+    let counter_id = app.store_code(dao_proposal_hook_counter_contract());
+    let counter = app
+        .instantiate_contract(
+            counter_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "counter",
+            None,
+        )
+        .unwrap();
+    add_extended_proposal_hook(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        counter.as_str(),
+    );
+    // Receivers of regular proposal hooks are not sent execution
+    // results.
+    let legacy_counter = app
+        .instantiate_contract(
+            counter_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "legacy counter",
+            None,
+        )
+        .unwrap();
+    add_proposal_hook(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        legacy_counter.as_str(),
+    );
+    let executed_hooks = |app: &App, counter: &Addr| -> u64 {
+        let count: dao_proposal_hook_counter::msg::CountResponse = app
+            .wrap()
+            .query_wasm_smart(
+                counter,
+                &dao_proposal_hook_counter::msg::QueryMsg::ExecutedCounter {},
+            )
+            .unwrap();
+        count.count
+    };
+
+    // The DAO has no tokens to send, so execution fails.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    assert_eq!(
+        query_execution_result(&app, &proposal_module, proposal_id),
+        None
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    match query_execution_result(&app, &proposal_module, proposal_id) {
+        Some(ExecutionResult::Failure { error }) => assert!(!error.is_empty()),
+        result => panic!("expected execution failure, got {result:?}"),
+    }
+    assert_eq!(executed_hooks(&app, &counter), 1);
+    assert_eq!(executed_hooks(&app, &legacy_counter), 0);
+
+    // Once the DAO has tokens the same proposal succeeds.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(10, "ujuno"),
+        }
+        .into()],
+        None,
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    match query_execution_result(&app, &proposal_module, proposal_id) {
+        Some(ExecutionResult::Success { events, .. }) => {
+            assert!(events.iter().any(|event| event.ty == "transfer"))
+        }
+        result => panic!("expected execution success, got {result:?}"),
+    }
+    assert_eq!(executed_hooks(&app, &counter), 2);
+    assert_eq!(executed_hooks(&app, &legacy_counter), 0);
+
+    let hooks: HooksResponse = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::ExtendedProposalHooks {})
+        .unwrap();
+    assert_eq!(hooks.hooks, vec![counter.to_string()]);
+}
+
+#[test]
+fn test_partial_execution() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(10, "ujuno"),
+        }
+        .into(),
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(10, "ujuno"),
+        }
+        .into(),
+    ]);
+
+    // Only the DAO may enable partial execution.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdatePartialExecution {
+                partial_execution: Some(PartialExecutionConfig {
+                    retry_window: Duration::Height(10),
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdatePartialExecution {
+            partial_execution: Some(PartialExecutionConfig {
+                retry_window: Duration::Height(10),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+    let config: Option<PartialExecutionConfig> = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::PartialExecutionConfig {})
+        .unwrap();
+    assert_eq!(
+        config,
+        Some(PartialExecutionConfig {
+            retry_window: Duration::Height(10),
+        })
+    );
+
+    // The DAO only has enough tokens for the first message, so the
+    // second fails without reverting the first.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+    match query_execution_result(&app, &proposal_module, proposal_id) {
+        Some(ExecutionResult::Success { events, .. }) => {
+            assert!(events.iter().any(|event| event.ty == "transfer"))
+        }
+        result => panic!("expected execution success, got {result:?}"),
+    }
+    let execution = query_partial_execution(&app, &proposal_module, proposal_id).unwrap();
+    assert!(matches!(
+        execution.results[0],
+        Some(ExecutionResult::Success { .. })
+    ));
+    assert!(matches!(
+        execution.results[1],
+        Some(ExecutionResult::Failure { .. })
+    ));
+
+    // Messages that succeeded may not be retried.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            proposal_module.clone(),
+            &ExecuteMsg::RetryFailed {
+                proposal_id,
+                msg_indexes: vec![0, 1],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::NotRetryable { index: 0 })
+    );
+
+    // Once the DAO has tokens anyone may retry the failed message.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        proposal_module.clone(),
+        &ExecuteMsg::RetryFailed {
+            proposal_id,
+            msg_indexes: vec![1],
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(20)
+    );
+    let execution = query_partial_execution(&app, &proposal_module, proposal_id).unwrap();
+    assert!(execution
+        .results
+        .iter()
+        .all(|result| matches!(result, Some(ExecutionResult::Success { .. }))));
+
+    // Nothing may be retried once the retry window expires.
+    app.update_block(|block| block.height += 10);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            proposal_module,
+            &ExecuteMsg::RetryFailed {
+                proposal_id,
+                msg_indexes: vec![1],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::RetryWindowExpired {})
+    );
+}
+
+#[test]
+fn test_partial_execution_all_failed() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()]);
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdatePartialExecution {
+            partial_execution: Some(PartialExecutionConfig {
+                retry_window: Duration::Height(10),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let counter_id = app.store_code(dao_proposal_hook_counter_contract());
+    let counter = app
+        .instantiate_contract(
+            counter_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "counter",
+            None,
+        )
+        .unwrap();
+    add_extended_proposal_hook(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        counter.as_str(),
+    );
+    let executed_hooks = |app: &App| -> u64 {
+        let count: dao_proposal_hook_counter::msg::CountResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &counter,
+                &dao_proposal_hook_counter::msg::QueryMsg::ExecutedCounter {},
+            )
+            .unwrap();
+        count.count
+    };
+
+    // The DAO has no tokens, so no message succeeds and the proposal
+    // is marked as failed.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    match query_execution_result(&app, &proposal_module, proposal_id) {
+        Some(ExecutionResult::Failure { error }) => assert!(!error.is_empty()),
+        result => panic!("expected execution failure, got {result:?}"),
+    }
+    assert_eq!(executed_hooks(&app), 1);
+
+    // The failed message may still be retried, after which the
+    // proposal is executed.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        proposal_module.clone(),
+        &ExecuteMsg::RetryFailed {
+            proposal_id,
+            msg_indexes: vec![0],
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert!(matches!(
+        query_execution_result(&app, &proposal_module, proposal_id),
+        Some(ExecutionResult::Success { .. })
+    ));
+    assert_eq!(executed_hooks(&app), 2);
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+}
+
+#[test]
+fn test_amend() {
+    let mut app = App::default();
+    let instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(50),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(50),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    let counter_id = app.store_code(dao_proposal_hook_counter_contract());
+    let counter = app
+        .instantiate_contract(
+            counter_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "counter",
+            None,
+        )
+        .unwrap();
+    add_extended_proposal_hook(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        counter.as_str(),
+    );
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    let amend = |title: Option<&str>, msgs: Option<Vec<CosmosMsg>>| {
+        ExecuteMsg::Amend(SingleChoiceAmendMsg {
+            proposal_id,
+            title: title.map(str::to_string),
+            description: None,
+            msgs,
+        })
+    };
+
+    // Only the proposer may amend a proposal.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_module.clone(),
+            &amend(Some("typo"), None),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // By default amending a proposal removes its votes.
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &amend(Some("amended"), Some(msgs.clone())),
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.title, "amended");
+    assert_eq!(proposal.proposal.msgs, msgs);
+    assert_eq!(proposal.proposal.votes, Votes::zero());
+    assert_eq!(
+        query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id).vote,
+        None
+    );
+    let amendments = query_list_amendments(&app, &proposal_module, proposal_id).amendments;
+    assert_eq!(amendments.len(), 1);
+    assert_eq!(amendments[0].id, 1);
+    assert_eq!(amendments[0].amendment.title, "title");
+    assert_eq!(amendments[0].amendment.msgs, vec![]);
+    assert!(amendments[0].amendment.votes_reset);
+
+    // The DAO may configure amendments to preserve votes.
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateAmendmentConfig {
+            amendment_config: AmendmentConfig {
+                preserve_votes: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &amend(Some("amended again"), None),
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(50));
+    assert_eq!(proposal.proposal.msgs, msgs);
+    let amendments = query_list_amendments(&app, &proposal_module, proposal_id).amendments;
+    assert_eq!(amendments.len(), 2);
+    assert_eq!(amendments[1].amendment.title, "amended");
+    assert!(!amendments[1].amendment.votes_reset);
+
+    let count: dao_proposal_hook_counter::msg::CountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &counter,
+            &dao_proposal_hook_counter::msg::QueryMsg::AmendedCounter {},
+        )
+        .unwrap();
+    assert_eq!(count.count, 2);
+
+    // Votes are removed when the messages are amended, even though
+    // the DAO preserves votes.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &amend(None, Some(vec![])),
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes, Votes::zero());
+    assert_eq!(
+        query_vote(&app, &proposal_module, CREATOR_ADDR, proposal_id).vote,
+        None
+    );
+    let amendments = query_list_amendments(&app, &proposal_module, proposal_id).amendments;
+    assert_eq!(amendments.len(), 3);
+    assert!(amendments[2].amendment.votes_reset);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // Proposals may not be amended once voting has finished.
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module,
+            &amend(Some("too late"), None),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotOpen {});
+}
+
+#[test]
+fn test_proposal_relations() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        gov_token: _,
+        proposal_id: first,
+    } = setup_test(vec![]);
+    let pre_propose = match query_creation_policy(&app, &proposal_module) {
+        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
+        ProposalCreationPolicy::Module { addr } => addr,
+    };
+    let reference = |proposal_id: u64| ProposalRef {
+        module: proposal_module.to_string(),
+        proposal_id,
+    };
+    let propose = |app: &mut App, relations: ProposalRelations| {
+        app.execute_contract(
+            pre_propose.clone(),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: Some(CREATOR_ADDR.to_string()),
+                vote: None,
+                relations: Some(relations),
+                expedited: false,
+            }),
+            &[],
+        )
+    };
+
+    // Relations must refer to existing proposals.
+    let err: ContractError = propose(
+        &mut app,
+        ProposalRelations {
+            depends_on: vec![reference(99)],
+            conflicts_with: vec![],
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::InvalidProposalRef {
+            module: proposal_module.to_string(),
+            proposal_id: 99,
+        })
+    );
+
+    propose(
+        &mut app,
+        ProposalRelations {
+            depends_on: vec![reference(first)],
+            conflicts_with: vec![],
+        },
+    )
+    .unwrap();
+    let dependent = first + 1;
+    propose(
+        &mut app,
+        ProposalRelations {
+            depends_on: vec![],
+            conflicts_with: vec![reference(dependent)],
+        },
+    )
+    .unwrap();
+    let conflicting = dependent + 1;
+
+    let relations: CheckedProposalRelations = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_module,
+            &QueryMsg::ProposalRelations {
+                proposal_id: conflicting,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        relations.conflicts_with,
+        vec![CheckedProposalRef {
+            module: proposal_module.clone(),
+            proposal_id: dependent,
+        }]
+    );
+    let reverse: ReverseRelationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_module,
+            &QueryMsg::ReverseRelations {
+                module: proposal_module.to_string(),
+                proposal_id: dependent,
+            },
+        )
+        .unwrap();
+    assert_eq!(reverse.dependents, Vec::<u64>::new());
+    assert_eq!(reverse.conflicting, vec![conflicting]);
+
+    // A proposal may not be executed before its dependencies.
+    for id in [dependent, conflicting] {
+        vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, id, Vote::Yes);
+    }
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, dependent);
+    assert_eq!(
+        err,
+        ContractError::DependencyNotExecuted {
+            module: proposal_module.clone(),
+            proposal_id: first,
+        }
+    );
+
+    // Executing a proposal rejects the proposals that declared a
+    // conflict with it.
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, first, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, first);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, dependent);
+    let proposal = query_proposal(&app, &proposal_module, dependent);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    let proposal = query_proposal(&app, &proposal_module, conflicting);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, conflicting);
+    assert_eq!(err, ContractError::NotPassed {});
+
+    // Executing a proposal rejects the proposals in this module it
+    // declared conflicts with.
+    let declared = conflicting + 1;
+    propose(
+        &mut app,
+        ProposalRelations {
+            depends_on: vec![],
+            conflicts_with: vec![],
+        },
+    )
+    .unwrap();
+    propose(
+        &mut app,
+        ProposalRelations {
+            depends_on: vec![],
+            conflicts_with: vec![reference(declared)],
+        },
+    )
+    .unwrap();
+    let declaring = declared + 1;
+    for id in [declared, declaring] {
+        vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, id, Vote::Yes);
+    }
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, declaring);
+    let proposal = query_proposal(&app, &proposal_module, declaring);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    let proposal = query_proposal(&app, &proposal_module, declared);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+
+    // Conflicts with proposals in other modules can not be enforced
+    // in both directions, so are not allowed.
+    let err: ContractError = propose(
+        &mut app,
+        ProposalRelations {
+            depends_on: vec![],
+            conflicts_with: vec![ProposalRef {
+                module: pre_propose.to_string(),
+                proposal_id: 1,
+            }],
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::CrossModuleConflict {
+            module: pre_propose.to_string(),
+            proposal_id: 1,
+        })
+    );
+
+    // The number of proposals that may declare a conflict with a
+    // proposal is bounded, as they are rejected when it is executed.
+    let target = declaring + 1;
+    propose(&mut app, ProposalRelations::default()).unwrap();
+    for _ in 0..MAX_PROPOSAL_RELATIONS {
+        propose(
+            &mut app,
+            ProposalRelations {
+                depends_on: vec![],
+                conflicts_with: vec![reference(target)],
+            },
+        )
+        .unwrap();
+    }
+    let err: ContractError = propose(
+        &mut app,
+        ProposalRelations {
+            depends_on: vec![],
+            conflicts_with: vec![reference(target)],
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::TooManyConflicts {
+            proposal_id: target,
+            max: MAX_PROPOSAL_RELATIONS,
+        }
+    );
+}
+
+#[test]
+fn test_expedited_proposals() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsolutePercentage {
+        percentage: PercentageThreshold::Majority {},
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let pre_propose = match query_creation_policy(&app, &proposal_module) {
+        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
+        ProposalCreationPolicy::Module { addr } => addr,
+    };
+    let propose_expedited = |app: &mut App| {
+        app.execute_contract(
+            pre_propose.clone(),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: Some(CREATOR_ADDR.to_string()),
+                vote: None,
+                relations: None,
+                expedited: true,
+            }),
+            &[],
+        )
+    };
+
+    // Proposals may not be expedited unless the module is configured
+    // to allow it.
+    let err: ContractError = propose_expedited(&mut app).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::ExpeditedNotEnabled {});
+
+    // The expedited voting period must be shorter than the normal
+    // one.
+    let config = query_proposal_config(&app, &proposal_module);
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateConfig {
+                veto: None,
+                threshold: config.threshold.clone(),
+                max_voting_period: config.max_voting_period,
+                min_voting_period: config.min_voting_period,
+                only_members_execute: config.only_members_execute,
+                allow_revoting: config.allow_revoting,
+                dao: config.dao.to_string(),
+                close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+                vote_weighting: config.vote_weighting,
+                expedited: Some(ExpeditedConfig {
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: PercentageThreshold::Percent(Decimal::percent(67)),
+                    },
+                    max_voting_period: config.max_voting_period,
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidExpeditedVotingPeriod {});
+
+    // Expedited proposals may be enabled while migrating.
+    let expedited = ExpeditedConfig {
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Percent(Decimal::percent(67)),
+        },
+        max_voting_period: Duration::Time(86400), // One day.
+    };
+    let new_code_id = app.store_code(dao_proposal_single_contract());
+    app.execute(
+        core_addr,
+        CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: proposal_module.to_string(),
+            new_code_id,
+            msg: to_json_binary(&MigrateMsg::FromCompatible {
+                expedited: Some(expedited.clone()),
+            })
+            .unwrap(),
+        }),
+    )
+    .unwrap();
+    let config = query_proposal_config(&app, &proposal_module);
+    assert_eq!(config.expedited, Some(expedited.clone()));
+
+    propose_expedited(&mut app).unwrap();
+    let fallen_back = query_next_proposal_id(&app, &proposal_module) - 1;
+    propose_expedited(&mut app).unwrap();
+    let unvoted = fallen_back + 1;
+    propose_expedited(&mut app).unwrap();
+    let passed = unvoted + 1;
+
+    let proposal = query_proposal(&app, &proposal_module, fallen_back).proposal;
+    assert_eq!(proposal.threshold, expedited.threshold);
+    assert_eq!(
+        proposal.expiration,
+        expedited.max_voting_period.after(&app.block_info())
+    );
+    let fallback_expiration = config.max_voting_period.after(&app.block_info());
+    assert_eq!(
+        proposal.expedited,
+        Some(ExpeditedProposal {
+            fallback_threshold: config.threshold.clone(),
+            fallback_expiration,
+            fell_back: false,
+        })
+    );
+
+    // Expedited proposals pass early if they meet the expedited
+    // threshold.
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passed, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "ekez", passed, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, passed).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+
+    // 60% is enough to pass the normal threshold but not the
+    // expedited one, so the proposal remains open until its
+    // expedited voting period ends.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        fallen_back,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, fallen_back).proposal;
+    assert_eq!(proposal.status, Status::Open);
+
+    app.update_block(|block| block.time = block.time.plus_seconds(86400));
+
+    // Once the expedited voting period ends, proposals fall back to
+    // the normal threshold and voting period.
+    let proposal = query_proposal(&app, &proposal_module, fallen_back).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+    assert_eq!(proposal.threshold, config.threshold);
+    assert_eq!(proposal.expiration, fallback_expiration);
+    assert!(!proposal.is_expedited());
+
+    let proposal = query_proposal(&app, &proposal_module, unvoted).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    assert_eq!(proposal.expiration, fallback_expiration);
+
+    // Proposals that have fallen back may be voted on until their
+    // fallback expiration.
+    vote_on_proposal(&mut app, &proposal_module, "ekez", unvoted, Vote::No);
+    let proposal = query_proposal(&app, &proposal_module, unvoted).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    assert_eq!(proposal.votes.no, Uint128::new(40));
+
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, unvoted).proposal;
+    assert_eq!(proposal.status, Status::Rejected);
+}
@@ -46,6 +46,8 @@ fn instantiate_staking(app: &mut App, cw20: Addr, unstaking_duration: Option<Dur
         owner: Some(OWNER.to_string()),
         token_address: cw20.to_string(),
         unstaking_duration,
+        bonus_schedule: vec![],
+        power_cap: None,
     };
     app.instantiate_contract(
         staking_code_id,
@@ -38,6 +38,8 @@ fn instantiate_staking(app: &mut App, cw20_addr: Addr) -> Addr {
         owner: Some(OWNER.to_string()),
         token_address: cw20_addr.to_string(),
         unstaking_duration: None,
+        bonus_schedule: vec![],
+        power_cap: None,
     };
     app.instantiate_contract(
         staking_id,
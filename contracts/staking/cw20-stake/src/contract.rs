@@ -21,15 +21,18 @@ use cw_controllers::ClaimsResponse;
 use cw_utils::Duration;
 use dao_hooks::stake::{stake_hook_msgs, unstake_hook_msgs};
 use dao_voting::duration::validate_duration;
+use dao_voting::stake_bonus::validate_bonus_schedule;
 
 use crate::math;
 use crate::msg::{
     ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg,
-    ReceiveMsg, StakedBalanceAtHeightResponse, StakedValueResponse, StakerBalanceResponse,
-    TotalStakedAtHeightResponse, TotalValueResponse,
+    ReceiveMsg, StakedBalanceAtHeightResponse, StakedPowerAtHeightResponse, StakedValueResponse,
+    StakerBalanceResponse, TotalStakedAtHeightResponse, TotalStakedPowerAtHeightResponse,
+    TotalValueResponse,
 };
 use crate::state::{
-    Config, BALANCE, CLAIMS, CONFIG, HOOKS, MAX_CLAIMS, STAKED_BALANCES, STAKED_TOTAL,
+    Config, BALANCE, CAPPED_TOTAL, CLAIMS, CONFIG, HOOKS, MAX_CLAIMS, STAKED_BALANCES,
+    STAKED_TOTAL, STAKE_BONUS,
 };
 use crate::ContractError;
 
@@ -56,9 +59,18 @@ pub fn instantiate(
         .map_err(|_| ContractError::InvalidCw20 {})?;
 
     validate_duration(msg.unstaking_duration)?;
+    validate_bonus_schedule(&msg.bonus_schedule)?;
+    if let Some(power_cap) = msg.power_cap.as_ref() {
+        if !msg.bonus_schedule.is_empty() {
+            return Err(ContractError::UnsupportedPowerCap {});
+        }
+        power_cap.validate()?;
+    }
     let config = Config {
         token_address,
         unstaking_duration: msg.unstaking_duration,
+        bonus_schedule: msg.bonus_schedule,
+        power_cap: msg.power_cap,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -146,13 +158,22 @@ pub fn execute_stake(
     let balance = BALANCE.load(deps.storage)?;
     let staked_total = STAKED_TOTAL.load(deps.storage)?;
     let amount_to_stake = math::amount_to_stake(staked_total, balance, amount);
-    STAKED_BALANCES.update(
+    let config = CONFIG.load(deps.storage)?;
+    let staked = STAKED_BALANCES.update(
         deps.storage,
         &sender,
         env.block.height,
         |bal| -> StdResult<Uint128> { Ok(bal.unwrap_or_default().checked_add(amount_to_stake)?) },
     )?;
-    STAKED_TOTAL.update(
+    STAKE_BONUS.on_stake_changed(
+        deps.storage,
+        &config.bonus_schedule,
+        &sender,
+        env.block.height,
+        staked - amount_to_stake,
+        staked,
+    )?;
+    let total = STAKED_TOTAL.update(
         deps.storage,
         env.block.height,
         |total| -> StdResult<Uint128> {
@@ -160,6 +181,17 @@ pub fn execute_stake(
             Ok(total.unwrap().checked_add(amount_to_stake)?)
         },
     )?;
+    if let Some(power_cap) = config.power_cap.as_ref() {
+        CAPPED_TOTAL.on_balance_changed(
+            deps.storage,
+            power_cap,
+            &sender,
+            env.block.height,
+            staked - amount_to_stake,
+            staked,
+            total,
+        )?;
+    }
     BALANCE.save(
         deps.storage,
         &balance.checked_add(amount).map_err(StdError::overflow)?,
@@ -192,13 +224,21 @@ pub fn execute_unstake(
         return Err(ContractError::ImpossibleUnstake {});
     }
     let amount_to_claim = math::amount_to_claim(staked_total, balance, amount);
-    STAKED_BALANCES.update(
+    let staked = STAKED_BALANCES.update(
         deps.storage,
         &info.sender,
         env.block.height,
         |bal| -> StdResult<Uint128> { Ok(bal.unwrap_or_default().checked_sub(amount)?) },
     )?;
-    STAKED_TOTAL.update(
+    STAKE_BONUS.on_stake_changed(
+        deps.storage,
+        &config.bonus_schedule,
+        &info.sender,
+        env.block.height,
+        staked + amount,
+        staked,
+    )?;
+    let total = STAKED_TOTAL.update(
         deps.storage,
         env.block.height,
         |total| -> StdResult<Uint128> {
@@ -206,6 +246,17 @@ pub fn execute_unstake(
             Ok(total.unwrap().checked_sub(amount)?)
         },
     )?;
+    if let Some(power_cap) = config.power_cap.as_ref() {
+        CAPPED_TOTAL.on_balance_changed(
+            deps.storage,
+            power_cap,
+            &info.sender,
+            env.block.height,
+            staked + amount,
+            staked,
+            total,
+        )?;
+    }
     BALANCE.save(
         deps.storage,
         &balance
@@ -343,6 +394,12 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::TotalStakedAtHeight { height } => {
             to_json_binary(&query_total_staked_at_height(deps, env, height)?)
         }
+        QueryMsg::StakedPowerAtHeight { address, height } => {
+            to_json_binary(&query_staked_power_at_height(deps, env, address, height)?)
+        }
+        QueryMsg::TotalStakedPowerAtHeight { height } => {
+            to_json_binary(&query_total_staked_power_at_height(deps, env, height)?)
+        }
         QueryMsg::StakedValue { address } => {
             to_json_binary(&query_staked_value(deps, env, address)?)
         }
@@ -382,6 +439,53 @@ pub fn query_total_staked_at_height(
     Ok(TotalStakedAtHeightResponse { total, height })
 }
 
+pub fn query_staked_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<StakedPowerAtHeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(env.block.height);
+    let config = CONFIG.load(deps.storage)?;
+    let balance = STAKED_BALANCES
+        .may_load_at_height(deps.storage, &address, height)?
+        .unwrap_or_default();
+    let power = match config.power_cap {
+        // Power caps are not used with bonuses.
+        Some(power_cap) => power_cap.apply(
+            balance,
+            STAKED_TOTAL
+                .may_load_at_height(deps.storage, height)?
+                .unwrap_or_default(),
+        ),
+        None => STAKE_BONUS.power_at_height(
+            deps.storage,
+            &config.bonus_schedule,
+            &address,
+            balance,
+            height,
+        )?,
+    };
+    Ok(StakedPowerAtHeightResponse { power, height })
+}
+
+pub fn query_total_staked_power_at_height(
+    deps: Deps,
+    env: Env,
+    height: Option<u64>,
+) -> StdResult<TotalStakedPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+    let power = match CONFIG.load(deps.storage)?.power_cap {
+        Some(_) => CAPPED_TOTAL.total_at_height(deps.storage, height)?,
+        None => STAKED_TOTAL
+            .may_load_at_height(deps.storage, height)?
+            .unwrap_or_default()
+            .checked_add(STAKE_BONUS.total_bonus_at_height(deps.storage, height)?)?,
+    };
+    Ok(TotalStakedPowerAtHeightResponse { power, height })
+}
+
 pub fn query_staked_value(
     deps: Deps,
     _env: Env,
@@ -480,6 +584,8 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     cw_utils_v1::Duration::Time(t) => Duration::Time(t),
                     cw_utils_v1::Duration::Height(h) => Duration::Height(h),
                 }),
+                bonus_schedule: vec![],
+                power_cap: None,
             };
             CONFIG.save(deps.storage, &config)?;
 
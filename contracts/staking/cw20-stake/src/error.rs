@@ -15,6 +15,12 @@ pub enum ContractError {
     #[error(transparent)]
     HookError(#[from] cw_hooks::HookError),
 
+    #[error(transparent)]
+    PowerCapError(#[from] dao_voting::power_cap::PowerCapError),
+
+    #[error(transparent)]
+    StakeBonusError(#[from] dao_voting::stake_bonus::StakeBonusError),
+
     #[error(transparent)]
     UnstakingDurationError(#[from] dao_voting::duration::UnstakingDurationError),
 
@@ -41,4 +47,7 @@ pub enum ContractError {
 
     #[error("Too many outstanding claims. Claim some tokens before unstaking more.")]
     TooManyClaims {},
+
+    #[error("A voting power cap can not be used with a stake bonus schedule")]
+    UnsupportedPowerCap {},
 }
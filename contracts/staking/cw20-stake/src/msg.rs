@@ -3,6 +3,8 @@ use cosmwasm_std::Uint128;
 use cw20::Cw20ReceiveMsg;
 
 use cw_utils::Duration;
+use dao_voting::power_cap::PowerCap;
+use dao_voting::stake_bonus::BonusTier;
 
 use cw_ownable::cw_ownable_execute;
 
@@ -17,6 +19,17 @@ pub struct InstantiateMsg {
     pub owner: Option<String>,
     pub token_address: String,
     pub unstaking_duration: Option<Duration>,
+    // Voting power multipliers for tokens that have been staked for a
+    // number of blocks, ordered by `min_stake_blocks`. Unstaking
+    // resets the age of an address's stake. May not be changed after
+    // instantiation.
+    #[serde(default)]
+    pub bonus_schedule: Vec<BonusTier>,
+    // The maximum voting power of any one address, as an absolute
+    // amount or a percentage of the total staked. May not be used
+    // with a bonus schedule, or changed after instantiation.
+    #[serde(default)]
+    pub power_cap: Option<PowerCap>,
 }
 
 #[cw_ownable_execute]
@@ -46,6 +59,18 @@ pub enum QueryMsg {
     },
     #[returns(TotalStakedAtHeightResponse)]
     TotalStakedAtHeight { height: Option<u64> },
+    /// The staked balance of `address` at `height`, including any
+    /// bonus for how long it has been staked and limited by any
+    /// power cap.
+    #[returns(StakedPowerAtHeightResponse)]
+    StakedPowerAtHeight {
+        address: String,
+        height: Option<u64>,
+    },
+    /// The total staked balance at `height`, including bonuses and
+    /// limited by any power cap.
+    #[returns(TotalStakedPowerAtHeightResponse)]
+    TotalStakedPowerAtHeight { height: Option<u64> },
     #[returns(StakedValueResponse)]
     StakedValue { address: String },
     #[returns(TotalValueResponse)]
@@ -85,6 +110,18 @@ pub struct TotalStakedAtHeightResponse {
     pub height: u64,
 }
 
+#[cw_serde]
+pub struct StakedPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct TotalStakedPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
 #[cw_serde]
 pub struct StakedValueResponse {
     pub value: Uint128,
@@ -4,11 +4,21 @@ use cw_controllers::Claims;
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
+use dao_voting::power_cap::{CappedTotal, PowerCap};
+use dao_voting::stake_bonus::{BonusTier, StakeBonus};
 
 #[cw_serde]
 pub struct Config {
     pub token_address: Addr,
     pub unstaking_duration: Option<Duration>,
+    // Voting power multipliers for long-term stakers. Fixed at
+    // instantiation.
+    #[serde(default)]
+    pub bonus_schedule: Vec<BonusTier>,
+    // The maximum voting power of any one address. Fixed at
+    // instantiation.
+    #[serde(default)]
+    pub power_cap: Option<PowerCap>,
 }
 
 // `"config"` key stores v1 configuration.
@@ -28,6 +38,24 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
+// Tracks how long addresses have been staked for, and the total
+// bonus voting power this gives over time.
+pub const STAKE_BONUS: StakeBonus = StakeBonus::new(
+    "stake_bonus_since",
+    "stake_bonus_since__checkpoints",
+    "stake_bonus_since__changelog",
+    "stake_bonus_total",
+);
+
+// Tracks the total capped voting power over time, if a power cap is
+// configured.
+pub const CAPPED_TOTAL: CappedTotal = CappedTotal::new(
+    "capped_stakers",
+    "capped_total",
+    "capped_total__checkpoints",
+    "capped_total__changelog",
+);
+
 /// The maximum number of claims that may be outstanding.
 pub const MAX_CLAIMS: u64 = 100;
 
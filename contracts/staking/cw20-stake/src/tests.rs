@@ -1,6 +1,6 @@
 use anyhow::Result as AnyResult;
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{to_json_binary, Addr, MessageInfo, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Decimal, MessageInfo, Uint128, WasmMsg};
 use cw20::Cw20Coin;
 use cw_controllers::{Claim, ClaimsResponse};
 use cw_multi_test::{next_block, App, AppResponse, Executor};
@@ -9,12 +9,15 @@ use cw_utils::Duration;
 use cw_utils::Expiration::AtHeight;
 use dao_testing::contracts::{cw20_base_contract, cw20_stake_contract, v1::cw20_stake_v1_contract};
 use dao_voting::duration::UnstakingDurationError;
+use dao_voting::power_cap::PowerCap;
+use dao_voting::stake_bonus::{BonusTier, StakeBonusError};
 use std::borrow::BorrowMut;
 
 use crate::msg::{
     ExecuteMsg, ListStakersResponse, MigrateMsg, QueryMsg, ReceiveMsg,
-    StakedBalanceAtHeightResponse, StakedValueResponse, StakerBalanceResponse,
-    TotalStakedAtHeightResponse, TotalValueResponse,
+    StakedBalanceAtHeightResponse, StakedPowerAtHeightResponse, StakedValueResponse,
+    StakerBalanceResponse, TotalStakedAtHeightResponse, TotalStakedPowerAtHeightResponse,
+    TotalValueResponse,
 };
 use crate::state::{Config, MAX_CLAIMS};
 use cw20_stake::ContractError;
@@ -64,6 +67,8 @@ fn instantiate_staking(app: &mut App, cw20: Addr, unstaking_duration: Option<Dur
         owner: Some(OWNER.to_string()),
         token_address: cw20.to_string(),
         unstaking_duration,
+        bonus_schedule: vec![],
+        power_cap: None,
     };
     app.instantiate_contract(
         staking_code_id,
@@ -1164,6 +1169,265 @@ fn test_migrate_from_v1() {
         Config {
             token_address: cw20_addr,
             unstaking_duration: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         }
     );
 }
+
+#[test]
+fn test_stake_bonus() {
+    let mut app = mock_app();
+    let cw20_addr = instantiate_cw20(
+        &mut app,
+        vec![Cw20Coin {
+            address: ADDR1.to_string(),
+            amount: Uint128::new(100),
+        }],
+    );
+    let staking_code_id = app.store_code(cw20_stake_contract());
+    let bonus_schedule = vec![BonusTier {
+        min_stake_blocks: 10,
+        multiplier: Decimal::percent(150),
+    }];
+    let staking_addr = app
+        .instantiate_contract(
+            staking_code_id,
+            Addr::unchecked(ADDR1),
+            &crate::msg::InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                token_address: cw20_addr.to_string(),
+                unstaking_duration: None,
+                bonus_schedule: bonus_schedule.clone(),
+                power_cap: None,
+            },
+            &[],
+            "staking",
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        query_config(&app, &staking_addr).bonus_schedule,
+        bonus_schedule
+    );
+
+    let query_power = |app: &App, height: Option<u64>| {
+        let power: StakedPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staking_addr,
+                &QueryMsg::StakedPowerAtHeight {
+                    address: ADDR1.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        let total: TotalStakedPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staking_addr,
+                &QueryMsg::TotalStakedPowerAtHeight { height },
+            )
+            .unwrap();
+        (power.power.u128(), total.power.u128())
+    };
+
+    let start = app.block_info().height;
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info(ADDR1, &[]),
+        Uint128::new(100),
+    )
+    .unwrap();
+
+    app.update_block(|b| b.height += 9);
+    assert_eq!(query_power(&app, None), (100, 100));
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, None), (150, 150));
+    // The staked balance does not include the bonus.
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::new(100)
+    );
+
+    // Unstaking resets the age of the remaining stake.
+    unstake_tokens(
+        &mut app,
+        &staking_addr,
+        mock_info(ADDR1, &[]),
+        Uint128::new(40),
+    )
+    .unwrap();
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, None), (60, 60));
+    app.update_block(|b| b.height += 9);
+    assert_eq!(query_power(&app, None), (90, 90));
+    assert_eq!(query_power(&app, Some(start + 10)), (150, 150));
+}
+
+#[test]
+fn test_instantiate_invalid_bonus_schedule() {
+    let mut app = mock_app();
+    let cw20_addr = instantiate_cw20(&mut app, vec![]);
+    let staking_code_id = app.store_code(cw20_stake_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            staking_code_id,
+            Addr::unchecked(ADDR1),
+            &crate::msg::InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                token_address: cw20_addr.to_string(),
+                unstaking_duration: None,
+                bonus_schedule: vec![BonusTier {
+                    min_stake_blocks: 10,
+                    multiplier: Decimal::percent(50),
+                }],
+                power_cap: None,
+            },
+            &[],
+            "staking",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::StakeBonusError(StakeBonusError::InvalidMultiplier {})
+    );
+}
+
+#[test]
+fn test_power_cap() {
+    let mut app = mock_app();
+    let cw20_addr = instantiate_cw20(
+        &mut app,
+        vec![
+            Cw20Coin {
+                address: ADDR1.to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: ADDR2.to_string(),
+                amount: Uint128::new(100),
+            },
+        ],
+    );
+    let staking_code_id = app.store_code(cw20_stake_contract());
+    let power_cap = PowerCap::Absolute {
+        amount: Uint128::new(50),
+    };
+    let staking_addr = app
+        .instantiate_contract(
+            staking_code_id,
+            Addr::unchecked(ADDR1),
+            &crate::msg::InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                token_address: cw20_addr.to_string(),
+                unstaking_duration: None,
+                bonus_schedule: vec![],
+                power_cap: Some(power_cap.clone()),
+            },
+            &[],
+            "staking",
+            None,
+        )
+        .unwrap();
+    assert_eq!(query_config(&app, &staking_addr).power_cap, Some(power_cap));
+
+    let query_power = |app: &App, address: &str| {
+        let power: StakedPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staking_addr,
+                &QueryMsg::StakedPowerAtHeight {
+                    address: address.to_string(),
+                    height: None,
+                },
+            )
+            .unwrap();
+        power.power.u128()
+    };
+    let query_total_power = |app: &App| {
+        let total: TotalStakedPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staking_addr,
+                &QueryMsg::TotalStakedPowerAtHeight { height: None },
+            )
+            .unwrap();
+        total.power.u128()
+    };
+
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info(ADDR1, &[]),
+        Uint128::new(100),
+    )
+    .unwrap();
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info(ADDR2, &[]),
+        Uint128::new(30),
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    assert_eq!(query_power(&app, ADDR1), 50);
+    assert_eq!(query_power(&app, ADDR2), 30);
+    assert_eq!(query_total_power(&app), 80);
+    // Staked balances are not capped.
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::new(100)
+    );
+    assert_eq!(query_total_staked(&app, &staking_addr), Uint128::new(130));
+
+    unstake_tokens(
+        &mut app,
+        &staking_addr,
+        mock_info(ADDR1, &[]),
+        Uint128::new(60),
+    )
+    .unwrap();
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, ADDR1), 40);
+    assert_eq!(query_total_power(&app), 70);
+}
+
+#[test]
+fn test_instantiate_power_cap_with_bonus() {
+    let mut app = mock_app();
+    let cw20_addr = instantiate_cw20(&mut app, vec![]);
+    let staking_code_id = app.store_code(cw20_stake_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            staking_code_id,
+            Addr::unchecked(ADDR1),
+            &crate::msg::InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                token_address: cw20_addr.to_string(),
+                unstaking_duration: None,
+                bonus_schedule: vec![BonusTier {
+                    min_stake_blocks: 10,
+                    multiplier: Decimal::percent(150),
+                }],
+                power_cap: Some(PowerCap::Absolute {
+                    amount: Uint128::new(50),
+                }),
+            },
+            &[],
+            "staking",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UnsupportedPowerCap {});
+}
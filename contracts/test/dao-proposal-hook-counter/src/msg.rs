@@ -25,6 +25,10 @@ pub enum QueryMsg {
     ProposalCounter {},
     #[returns(u64)]
     StatusChangedCounter {},
+    #[returns(u64)]
+    ExecutedCounter {},
+    #[returns(u64)]
+    AmendedCounter {},
 }
 
 #[cw_serde]
@@ -11,3 +11,5 @@ pub const PROPOSAL_COUNTER: Item<u64> = Item::new("proposal_counter");
 pub const STAKE_COUNTER: Item<Uint128> = Item::new("stake_counter");
 pub const STATUS_CHANGED_COUNTER: Item<u64> = Item::new("stauts_changed_counter");
 pub const VOTE_COUNTER: Item<u64> = Item::new("vote_counter");
+pub const EXECUTED_COUNTER: Item<u64> = Item::new("executed_counter");
+pub const AMENDED_COUNTER: Item<u64> = Item::new("amended_counter");
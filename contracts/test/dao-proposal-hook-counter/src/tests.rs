@@ -110,6 +110,8 @@ fn test_counters() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
+        vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+        expedited: None,
         veto: None,
     };
 
@@ -216,6 +218,8 @@ fn test_counters() {
             msgs: vec![],
             proposer: None,
             vote: None,
+            relations: None,
+            expedited: false,
         }),
         &[],
     )
@@ -317,6 +321,8 @@ fn test_counters() {
             msgs: vec![],
             proposer: None,
             vote: None,
+            relations: None,
+            expedited: false,
         }),
         &[],
     )
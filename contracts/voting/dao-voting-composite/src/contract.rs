@@ -0,0 +1,277 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+
+use cosmwasm_std::{
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    StdResult, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use dao_interface::voting::{
+    Query as VotingQuery, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SourceInfo};
+use crate::state::{Config, Source, CONFIG, DAO, NORMALIZED_POWER};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-composite";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let sources = update_sources(deps.as_ref(), &env, vec![], msg.sources, vec![])?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            sources,
+            normalize: msg.normalize,
+        },
+        env.block.height,
+    )?;
+    DAO.save(deps.storage, &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("normalize", msg.normalize.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::UpdateSources { to_add, to_remove } => {
+            execute_update_sources(deps, env, info, to_add, to_remove)
+        }
+        ExecuteMsg::UpdateConfig { normalize } => execute_update_config(deps, env, info, normalize),
+    }
+}
+
+pub fn execute_update_sources(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    to_add: Vec<SourceInfo>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.sources = update_sources(deps.as_ref(), &env, config.sources, to_add, to_remove)?;
+    CONFIG.save(deps.storage, &config, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_sources")
+        .add_attribute("sources", config.sources.len().to_string()))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    normalize: bool,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.normalize = normalize;
+    CONFIG.save(deps.storage, &config, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("normalize", normalize.to_string()))
+}
+
+/// Removes `to_remove` from `sources`, then adds `to_add`, updating
+/// the weight of sources that are already present. Errors if a
+/// source is not a voting module or no sources remain.
+fn update_sources(
+    deps: Deps,
+    env: &Env,
+    mut sources: Vec<Source>,
+    to_add: Vec<SourceInfo>,
+    to_remove: Vec<String>,
+) -> Result<Vec<Source>, ContractError> {
+    for address in to_remove {
+        let addr = deps.api.addr_validate(&address)?;
+        let index = sources
+            .iter()
+            .position(|source| source.address == addr)
+            .ok_or(ContractError::SourceNotFound { address })?;
+        sources.remove(index);
+    }
+
+    let mut added = Vec::with_capacity(to_add.len());
+    for SourceInfo { address, weight } in to_add {
+        let addr = deps.api.addr_validate(&address)?;
+        if weight.is_zero() {
+            return Err(ContractError::ZeroWeight {});
+        }
+        if added.contains(&addr) {
+            return Err(ContractError::DuplicateSource { address });
+        }
+        added.push(addr.clone());
+
+        if let Some(source) = sources.iter_mut().find(|source| source.address == addr) {
+            source.weight = weight;
+            continue;
+        }
+
+        // Make sure the source is a voting module so that queries
+        // against this one do not fail later.
+        if addr == env.contract.address
+            || deps
+                .querier
+                .query_wasm_smart::<TotalPowerAtHeightResponse>(
+                    &addr,
+                    &VotingQuery::TotalPowerAtHeight { height: None },
+                )
+                .is_err()
+        {
+            return Err(ContractError::NotVotingModule { address });
+        }
+        sources.push(Source {
+            address: addr,
+            weight,
+        });
+    }
+
+    if sources.is_empty() {
+        return Err(ContractError::NoSources {});
+    }
+    Ok(sources)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            to_json_binary(&query_voting_power_at_height(deps, env, address, height)?)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => {
+            to_json_binary(&query_total_power_at_height(deps, env, height)?)
+        }
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::GetConfig { height } => to_json_binary(&match height {
+            Some(height) => CONFIG.may_load_at_height(deps.storage, height)?,
+            None => CONFIG.may_load(deps.storage)?,
+        }),
+    }
+}
+
+fn apply_weight(power: Uint128, weight: Decimal) -> StdResult<Uint128> {
+    power
+        .checked_mul_floor(weight)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+fn query_source_total_power(deps: Deps, source: &Source, height: u64) -> StdResult<Uint128> {
+    let res: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &source.address,
+        &VotingQuery::TotalPowerAtHeight {
+            height: Some(height),
+        },
+    )?;
+    Ok(res.power)
+}
+
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+    deps.api.addr_validate(&address)?;
+
+    let mut power = Uint128::zero();
+    if let Some(config) = CONFIG.may_load_at_height(deps.storage, height)? {
+        for source in config.sources.iter() {
+            let res: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+                &source.address,
+                &VotingQuery::VotingPowerAtHeight {
+                    address: address.clone(),
+                    height: Some(height),
+                },
+            )?;
+            let source_power = if !config.normalize || res.power.is_zero() {
+                res.power
+            } else {
+                let total = query_source_total_power(deps, source, height)?;
+                if total.is_zero() {
+                    Uint128::zero()
+                } else {
+                    res.power.multiply_ratio(NORMALIZED_POWER, total)
+                }
+            };
+            power = power.checked_add(apply_weight(source_power, source.weight)?)?;
+        }
+    }
+
+    Ok(VotingPowerAtHeightResponse { power, height })
+}
+
+pub fn query_total_power_at_height(
+    deps: Deps,
+    env: Env,
+    height: Option<u64>,
+) -> StdResult<TotalPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+
+    let mut power = Uint128::zero();
+    if let Some(config) = CONFIG.may_load_at_height(deps.storage, height)? {
+        for source in config.sources.iter() {
+            let total = query_source_total_power(deps, source, height)?;
+            // Sources with no voting power contribute none when
+            // normalized, rather than `NORMALIZED_POWER` that nobody
+            // can vote with.
+            let source_power = if !config.normalize || total.is_zero() {
+                total
+            } else {
+                Uint128::new(NORMALIZED_POWER)
+            };
+            power = power.checked_add(apply_weight(source_power, source.weight)?)?;
+        }
+    }
+
+    Ok(TotalPowerAtHeightResponse { power, height })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_json_binary(&dao_interface::voting::InfoResponse { info })
+}
+
+pub fn query_dao(deps: Deps) -> StdResult<Binary> {
+    let dao = DAO.load(deps.storage)?;
+    to_json_binary(&dao)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
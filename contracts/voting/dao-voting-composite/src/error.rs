@@ -0,0 +1,26 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Source ({address}) appears more than once")]
+    DuplicateSource { address: String },
+
+    #[error("At least one source must be provided")]
+    NoSources {},
+
+    #[error("Source ({address}) is not a voting module")]
+    NotVotingModule { address: String },
+
+    #[error("No source with address ({address})")]
+    SourceNotFound { address: String },
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Source weights must be non-zero")]
+    ZeroWeight {},
+}
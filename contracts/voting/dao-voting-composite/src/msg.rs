@@ -0,0 +1,49 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Decimal;
+use dao_dao_macros::voting_module_query;
+
+#[cw_serde]
+pub struct SourceInfo {
+    /// The address of a voting module whose voting power is
+    /// included.
+    pub address: String,
+    /// The weight given to voting power from this module.
+    pub weight: Decimal,
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The voting modules to combine the voting power of.
+    pub sources: Vec<SourceInfo>,
+    /// If true, each source's voting power is scaled relative to its
+    /// total power before weights are applied, so that sources with
+    /// different token supplies can be combined. See `Config`.
+    pub normalize: bool,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Adds and removes voting modules. Adding a module that is
+    /// already a source updates its weight. Only the DAO may call
+    /// this method.
+    UpdateSources {
+        to_add: Vec<SourceInfo>,
+        to_remove: Vec<String>,
+    },
+    /// Updates whether voting power is normalized. Only the DAO may
+    /// call this method.
+    UpdateConfig { normalize: bool },
+}
+
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the sources and normalization in effect at `height`, or
+    /// the current ones if no height is provided.
+    #[returns(Option<crate::state::Config>)]
+    GetConfig { height: Option<u64> },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,42 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal};
+use cw_storage_plus::{Item, SnapshotItem, Strategy};
+
+/// The voting power of each source is scaled so that the source's
+/// total power is this, multiplied by its weight, when voting power
+/// is normalized.
+pub const NORMALIZED_POWER: u128 = 1_000_000_000_000_000_000;
+
+#[cw_serde]
+pub struct Source {
+    pub address: Addr,
+    pub weight: Decimal,
+}
+
+#[cw_serde]
+pub struct Config {
+    /// The voting modules whose voting power is combined.
+    pub sources: Vec<Source>,
+    /// If false, an address's voting power is the sum of its voting
+    /// power in each source multiplied by that source's weight.
+    ///
+    /// If true, an address's voting power in each source is first
+    /// divided by that source's total power and multiplied by
+    /// `NORMALIZED_POWER`. For example, with equal weights, holding
+    /// 10% of one source's tokens gives the same voting power as
+    /// holding 10% of another's, regardless of their supplies.
+    pub normalize: bool,
+}
+
+/// The address of the DAO this voting contract is connected to
+pub const DAO: Item<Addr> = Item::new("dao");
+
+/// The sources and normalization over time. Proposals are voted on
+/// with the voting power at their start height, so changes must not
+/// affect past heights.
+pub const CONFIG: SnapshotItem<Config> = SnapshotItem::new(
+    "config",
+    "config__checkpoints",
+    "config__changelog",
+    Strategy::EveryBlock,
+);
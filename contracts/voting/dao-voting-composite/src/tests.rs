@@ -0,0 +1,364 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{coins, Addr, Coin, Decimal, Uint128};
+use cw_multi_test::{next_block, App, BankSudo, Executor, SudoMsg};
+use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+use dao_testing::contracts::{dao_voting_composite_contract, dao_voting_token_staked_contract};
+
+use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SourceInfo};
+use crate::state::{Config, Source, NORMALIZED_POWER};
+use crate::ContractError;
+
+const DAO_ADDR: &str = "dao";
+const ADDR1: &str = "addr1";
+const ADDR2: &str = "addr2";
+const JUNO: &str = "ujuno";
+const ATOM: &str = "uatom";
+
+struct Suite {
+    app: App,
+    composite: Addr,
+    juno: Addr,
+    atom: Addr,
+}
+
+/// Sets up a composite voting module with two token staking sources.
+/// ADDR1 stakes 100 of 400 JUNO and 10 of 40 ATOM, and ADDR2 stakes
+/// the rest. JUNO is weighted 1 and ATOM 2.
+fn setup(normalize: bool) -> Suite {
+    let mut app = App::default();
+    for addr in [ADDR1, ADDR2] {
+        app.sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: addr.to_string(),
+            amount: vec![Coin::new(10000, JUNO), Coin::new(10000, ATOM)],
+        }))
+        .unwrap();
+    }
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    let composite_id = app.store_code(dao_voting_composite_contract());
+
+    let mut staking = |denom: &str| {
+        app.instantiate_contract(
+            staking_id,
+            Addr::unchecked(DAO_ADDR),
+            &dao_voting_token_staked::msg::InstantiateMsg {
+                token_info: dao_voting_token_staked::msg::TokenInfo::Existing {
+                    denom: denom.to_string(),
+                },
+                unstaking_duration: None,
+                active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
+            },
+            &[],
+            "staking",
+            None,
+        )
+        .unwrap()
+    };
+    let juno = staking(JUNO);
+    let atom = staking(ATOM);
+
+    for (sender, contract, amount, denom) in [
+        (ADDR1, &juno, 100, JUNO),
+        (ADDR2, &juno, 300, JUNO),
+        (ADDR1, &atom, 10, ATOM),
+        (ADDR2, &atom, 30, ATOM),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            contract.clone(),
+            &dao_voting_token_staked::msg::ExecuteMsg::Stake {},
+            &coins(amount, denom),
+        )
+        .unwrap();
+    }
+
+    let composite = app
+        .instantiate_contract(
+            composite_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                sources: vec![
+                    SourceInfo {
+                        address: juno.to_string(),
+                        weight: Decimal::one(),
+                    },
+                    SourceInfo {
+                        address: atom.to_string(),
+                        weight: Decimal::percent(200),
+                    },
+                ],
+                normalize,
+            },
+            &[],
+            "composite",
+            None,
+        )
+        .unwrap();
+    app.update_block(next_block);
+
+    Suite {
+        app,
+        composite,
+        juno,
+        atom,
+    }
+}
+
+impl Suite {
+    fn voting_power(&self, address: &str, height: Option<u64>) -> Uint128 {
+        let res: VotingPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.composite,
+                &QueryMsg::VotingPowerAtHeight {
+                    address: address.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        res.power
+    }
+
+    fn total_power(&self, height: Option<u64>) -> Uint128 {
+        let res: TotalPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.composite, &QueryMsg::TotalPowerAtHeight { height })
+            .unwrap();
+        res.power
+    }
+
+    fn config(&self) -> Option<Config> {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.composite, &QueryMsg::GetConfig { height: None })
+            .unwrap()
+    }
+
+    fn execute(&mut self, sender: &str, msg: ExecuteMsg) -> Result<(), ContractError> {
+        self.app
+            .execute_contract(Addr::unchecked(sender), self.composite.clone(), &msg, &[])
+            .map(|_| ())
+            .map_err(|e| e.downcast().unwrap())
+    }
+
+    fn update_sources(
+        &mut self,
+        to_add: Vec<SourceInfo>,
+        to_remove: Vec<String>,
+    ) -> Result<(), ContractError> {
+        self.execute(DAO_ADDR, ExecuteMsg::UpdateSources { to_add, to_remove })
+    }
+}
+
+#[test]
+fn test_weighted_power() {
+    let suite = setup(false);
+
+    assert_eq!(suite.voting_power(ADDR1, None), Uint128::new(120));
+    assert_eq!(suite.voting_power(ADDR2, None), Uint128::new(360));
+    assert_eq!(suite.total_power(None), Uint128::new(480));
+}
+
+#[test]
+fn test_normalized_power() {
+    let suite = setup(true);
+
+    // 1/4 of JUNO at weight 1 and 1/4 of ATOM at weight 2.
+    assert_eq!(
+        suite.voting_power(ADDR1, None),
+        Uint128::new(NORMALIZED_POWER / 4 + NORMALIZED_POWER / 2)
+    );
+    assert_eq!(
+        suite.voting_power(ADDR2, None),
+        Uint128::new(NORMALIZED_POWER * 3 / 4 + NORMALIZED_POWER * 3 / 2)
+    );
+    assert_eq!(suite.total_power(None), Uint128::new(NORMALIZED_POWER * 3));
+}
+
+#[test]
+fn test_update_config() {
+    let mut suite = setup(false);
+    let height = suite.app.block_info().height;
+
+    let err = suite
+        .execute(ADDR1, ExecuteMsg::UpdateConfig { normalize: true })
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite
+        .execute(DAO_ADDR, ExecuteMsg::UpdateConfig { normalize: true })
+        .unwrap();
+    // Changes take effect from the next block.
+    assert_eq!(suite.total_power(None), Uint128::new(480));
+    suite.app.update_block(next_block);
+    assert_eq!(suite.total_power(None), Uint128::new(NORMALIZED_POWER * 3));
+
+    // Past voting power is unchanged.
+    assert_eq!(suite.voting_power(ADDR1, Some(height)), Uint128::new(120));
+    assert_eq!(suite.total_power(Some(height)), Uint128::new(480));
+}
+
+#[test]
+fn test_update_sources() {
+    let mut suite = setup(false);
+    let height = suite.app.block_info().height;
+    let juno = suite.juno.to_string();
+    let atom = suite.atom.to_string();
+
+    let err = suite
+        .execute(
+            ADDR1,
+            ExecuteMsg::UpdateSources {
+                to_add: vec![],
+                to_remove: vec![atom.clone()],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite.update_sources(vec![], vec![atom.clone()]).unwrap();
+    suite.app.update_block(next_block);
+    assert_eq!(suite.voting_power(ADDR1, None), Uint128::new(100));
+    assert_eq!(suite.total_power(None), Uint128::new(400));
+    assert_eq!(suite.voting_power(ADDR1, Some(height)), Uint128::new(120));
+
+    // Adding an existing source updates its weight.
+    suite
+        .update_sources(
+            vec![
+                SourceInfo {
+                    address: juno.clone(),
+                    weight: Decimal::percent(50),
+                },
+                SourceInfo {
+                    address: atom.clone(),
+                    weight: Decimal::percent(1000),
+                },
+            ],
+            vec![],
+        )
+        .unwrap();
+    suite.app.update_block(next_block);
+    assert_eq!(
+        suite.config(),
+        Some(Config {
+            sources: vec![
+                Source {
+                    address: suite.juno.clone(),
+                    weight: Decimal::percent(50),
+                },
+                Source {
+                    address: suite.atom.clone(),
+                    weight: Decimal::percent(1000),
+                },
+            ],
+            normalize: false,
+        })
+    );
+    assert_eq!(suite.voting_power(ADDR1, None), Uint128::new(150));
+    assert_eq!(suite.total_power(None), Uint128::new(600));
+}
+
+#[test]
+fn test_invalid_sources() {
+    let mut suite = setup(false);
+    let juno = suite.juno.to_string();
+    let atom = suite.atom.to_string();
+
+    let err = suite
+        .update_sources(
+            vec![SourceInfo {
+                address: juno.clone(),
+                weight: Decimal::zero(),
+            }],
+            vec![],
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::ZeroWeight {});
+
+    let err = suite
+        .update_sources(
+            vec![
+                SourceInfo {
+                    address: juno.clone(),
+                    weight: Decimal::one(),
+                },
+                SourceInfo {
+                    address: juno.clone(),
+                    weight: Decimal::one(),
+                },
+            ],
+            vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateSource {
+            address: juno.clone()
+        }
+    );
+
+    for address in [ADDR1.to_string(), suite.composite.to_string()] {
+        let err = suite
+            .update_sources(
+                vec![SourceInfo {
+                    address: address.clone(),
+                    weight: Decimal::one(),
+                }],
+                vec![],
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::NotVotingModule { address });
+    }
+
+    let err = suite
+        .update_sources(vec![], vec![ADDR1.to_string()])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SourceNotFound {
+            address: ADDR1.to_string()
+        }
+    );
+
+    let err = suite.update_sources(vec![], vec![juno, atom]).unwrap_err();
+    assert_eq!(err, ContractError::NoSources {});
+}
+
+#[test]
+fn test_instantiate_no_sources() {
+    let mut app = App::default();
+    let composite_id = app.store_code(dao_voting_composite_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            composite_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                sources: vec![],
+                normalize: false,
+            },
+            &[],
+            "composite",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoSources {});
+}
+
+#[test]
+pub fn test_migrate_update_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "my-contract", "1.0.0").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    let version = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(version.version, CONTRACT_VERSION);
+    assert_eq!(version.contract, CONTRACT_NAME);
+}
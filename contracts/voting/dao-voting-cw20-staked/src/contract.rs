@@ -14,7 +14,7 @@ use std::convert::TryInto;
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, StakingInfo, TokenInfo};
 use crate::state::{
-    ACTIVE_THRESHOLD, DAO, STAKING_CONTRACT, STAKING_CONTRACT_CODE_ID,
+    ACTIVE_THRESHOLD, DAO, STAKING_CONTRACT, STAKING_CONTRACT_CODE_ID, STAKING_CONTRACT_POWER_CAP,
     STAKING_CONTRACT_UNSTAKING_DURATION, TOKEN,
 };
 
@@ -62,6 +62,9 @@ pub fn instantiate(
                 StakingInfo::Existing {
                     staking_contract_address,
                 } => {
+                    if msg.power_cap.is_some() {
+                        return Err(ContractError::PowerCapWithExistingStaking {});
+                    }
                     let staking_contract_address =
                         deps.api.addr_validate(&staking_contract_address)?;
                     let resp: cw20_stake::state::Config = deps.querier.query_wasm_smart(
@@ -93,6 +96,8 @@ pub fn instantiate(
                             owner: Some(info.sender.to_string()),
                             unstaking_duration,
                             token_address: address.to_string(),
+                            bonus_schedule: vec![],
+                            power_cap: msg.power_cap,
                         })?,
                     };
                     let msg = SubMsg::reply_on_success(msg, INSTANTIATE_STAKING_REPLY_ID);
@@ -137,6 +142,7 @@ pub fn instantiate(
 
             STAKING_CONTRACT_CODE_ID.save(deps.storage, &staking_code_id)?;
             STAKING_CONTRACT_UNSTAKING_DURATION.save(deps.storage, &unstaking_duration)?;
+            STAKING_CONTRACT_POWER_CAP.save(deps.storage, &msg.power_cap)?;
 
             let msg = WasmMsg::Instantiate {
                 admin: Some(info.sender.to_string()),
@@ -261,17 +267,30 @@ pub fn query_voting_power_at_height(
 ) -> StdResult<Binary> {
     let staking_contract = STAKING_CONTRACT.load(deps.storage)?;
     let address = deps.api.addr_validate(&address)?;
-    let res: cw20_stake::msg::StakedBalanceAtHeightResponse = deps.querier.query_wasm_smart(
-        staking_contract,
-        &cw20_stake::msg::QueryMsg::StakedBalanceAtHeight {
-            address: address.to_string(),
-            height,
-        },
-    )?;
-    to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse {
-        power: res.balance,
-        height: res.height,
-    })
+    // Staking contracts from before stake bonuses were added do not
+    // support power queries, and their power is their staked balance.
+    let (power, height) = deps
+        .querier
+        .query_wasm_smart::<cw20_stake::msg::StakedPowerAtHeightResponse>(
+            &staking_contract,
+            &cw20_stake::msg::QueryMsg::StakedPowerAtHeight {
+                address: address.to_string(),
+                height,
+            },
+        )
+        .map(|res| (res.power, res.height))
+        .or_else(|_| {
+            deps.querier
+                .query_wasm_smart::<cw20_stake::msg::StakedBalanceAtHeightResponse>(
+                    &staking_contract,
+                    &cw20_stake::msg::QueryMsg::StakedBalanceAtHeight {
+                        address: address.to_string(),
+                        height,
+                    },
+                )
+                .map(|res| (res.balance, res.height))
+        })?;
+    to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse { power, height })
 }
 
 pub fn query_total_power_at_height(
@@ -280,14 +299,22 @@ pub fn query_total_power_at_height(
     height: Option<u64>,
 ) -> StdResult<Binary> {
     let staking_contract = STAKING_CONTRACT.load(deps.storage)?;
-    let res: cw20_stake::msg::TotalStakedAtHeightResponse = deps.querier.query_wasm_smart(
-        staking_contract,
-        &cw20_stake::msg::QueryMsg::TotalStakedAtHeight { height },
-    )?;
-    to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse {
-        power: res.total,
-        height: res.height,
-    })
+    let (power, height) = deps
+        .querier
+        .query_wasm_smart::<cw20_stake::msg::TotalStakedPowerAtHeightResponse>(
+            &staking_contract,
+            &cw20_stake::msg::QueryMsg::TotalStakedPowerAtHeight { height },
+        )
+        .map(|res| (res.power, res.height))
+        .or_else(|_| {
+            deps.querier
+                .query_wasm_smart::<cw20_stake::msg::TotalStakedAtHeightResponse>(
+                    &staking_contract,
+                    &cw20_stake::msg::QueryMsg::TotalStakedAtHeight { height },
+                )
+                .map(|res| (res.total, res.height))
+        })?;
+    to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse { power, height })
 }
 
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
@@ -407,6 +434,7 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
                     let staking_contract_code_id = STAKING_CONTRACT_CODE_ID.load(deps.storage)?;
                     let unstaking_duration =
                         STAKING_CONTRACT_UNSTAKING_DURATION.load(deps.storage)?;
+                    let power_cap = STAKING_CONTRACT_POWER_CAP.load(deps.storage)?;
                     let dao = DAO.load(deps.storage)?;
                     let msg = WasmMsg::Instantiate {
                         code_id: staking_contract_code_id,
@@ -417,6 +445,8 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
                             owner: Some(dao.to_string()),
                             unstaking_duration,
                             token_address: token.to_string(),
+                            bonus_schedule: vec![],
+                            power_cap,
                         })?,
                     };
                     let msg = SubMsg::reply_on_success(msg, INSTANTIATE_STAKING_REPLY_ID);
@@ -38,4 +38,7 @@ pub enum ContractError {
 
     #[error("Absolute count threshold cannot be greater than the total token supply")]
     InvalidAbsoluteCount {},
+
+    #[error("A power cap can only be set when instantiating a new staking contract")]
+    PowerCapWithExistingStaking {},
 }
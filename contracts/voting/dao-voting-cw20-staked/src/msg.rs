@@ -5,6 +5,7 @@ use cw20_base::msg::InstantiateMarketingInfo;
 use cw_utils::Duration;
 
 use dao_dao_macros::{active_query, cw20_token_query, voting_module_query};
+use dao_voting::power_cap::PowerCap;
 use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
 
 /// Information about the staking contract to be used with this voting
@@ -58,6 +59,12 @@ pub struct InstantiateMsg {
     /// The number or percentage of tokens that must be staked
     /// for the DAO to be active
     pub active_threshold: Option<ActiveThreshold>,
+    /// The maximum voting power of any one address, as an absolute
+    /// amount or a percentage of the total staked. Passed to the
+    /// staking contract, so may only be set when a new one is
+    /// instantiated.
+    #[serde(default)]
+    pub power_cap: Option<PowerCap>,
 }
 
 #[cw_serde]
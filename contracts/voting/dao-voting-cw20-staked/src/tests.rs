@@ -5,10 +5,13 @@ use cosmwasm_std::{
 use cw2::ContractVersion;
 use cw20::{BalanceResponse, Cw20Coin, MinterResponse, TokenInfoResponse};
 use cw_multi_test::{next_block, App, Executor};
-use dao_interface::voting::{InfoResponse, IsActiveResponse, VotingPowerAtHeightResponse};
+use dao_interface::voting::{
+    InfoResponse, IsActiveResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
 use dao_testing::contracts::{
     cw20_base_contract, cw20_stake_contract, dao_voting_cw20_staked_contract,
 };
+use dao_voting::power_cap::PowerCap;
 use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
 
 use crate::{
@@ -68,6 +71,7 @@ fn test_instantiate_zero_supply() {
                 initial_dao_balance: Some(Uint128::zero()),
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 }
@@ -96,6 +100,7 @@ fn test_instantiate_no_balances() {
                 initial_dao_balance: Some(Uint128::zero()),
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 }
@@ -129,6 +134,7 @@ fn test_instantiate_zero_active_threshold_count() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(0),
             }),
+            power_cap: None,
         },
     );
 }
@@ -160,6 +166,7 @@ fn test_contract_info() {
                 initial_dao_balance: Some(Uint128::zero()),
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -211,6 +218,7 @@ fn test_new_cw20() {
                 initial_dao_balance: Some(Uint128::from(10u64)),
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -385,6 +393,7 @@ fn test_existing_cw20_new_staking() {
                 },
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -536,6 +545,7 @@ fn test_existing_cw20_existing_staking() {
                 },
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -574,6 +584,7 @@ fn test_existing_cw20_existing_staking() {
                 },
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -689,6 +700,7 @@ fn test_existing_cw20_existing_staking() {
                 },
             },
             active_threshold: None,
+            power_cap: None,
         },
         &[],
         "voting module",
@@ -697,6 +709,130 @@ fn test_existing_cw20_existing_staking() {
     .unwrap_err();
 }
 
+#[test]
+fn test_power_cap() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_base_contract());
+    let voting_id = app.store_code(dao_voting_cw20_staked_contract());
+    let staking_contract_id = app.store_code(cw20_stake_contract());
+
+    let voting_addr = instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::New {
+                code_id: cw20_id,
+                label: "DAO DAO voting".to_string(),
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 6,
+                initial_balances: vec![
+                    Cw20Coin {
+                        address: CREATOR_ADDR.to_string(),
+                        amount: Uint128::new(60),
+                    },
+                    Cw20Coin {
+                        address: DAO_ADDR.to_string(),
+                        amount: Uint128::new(40),
+                    },
+                ],
+                marketing: None,
+                unstaking_duration: None,
+                staking_code_id: staking_contract_id,
+                initial_dao_balance: None,
+            },
+            active_threshold: None,
+            power_cap: Some(PowerCap::Percentage {
+                percent: Decimal::percent(50),
+            }),
+        },
+    );
+
+    let token_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::TokenContract {})
+        .unwrap();
+    let staking_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::StakingContract {})
+        .unwrap();
+
+    stake_tokens(
+        &mut app,
+        staking_addr.clone(),
+        token_addr.clone(),
+        CREATOR_ADDR,
+        60,
+    );
+    stake_tokens(&mut app, staking_addr, token_addr, DAO_ADDR, 40);
+    app.update_block(next_block);
+
+    // The creator's 60 of 100 staked is capped at 50.
+    let creator_voting_power: VotingPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(
+            voting_addr.clone(),
+            &QueryMsg::VotingPowerAtHeight {
+                address: CREATOR_ADDR.to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(creator_voting_power.power, Uint128::new(50));
+
+    let total_voting_power: TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(voting_addr, &QueryMsg::TotalPowerAtHeight { height: None })
+        .unwrap();
+    assert_eq!(total_voting_power.power, Uint128::new(90));
+}
+
+#[test]
+#[should_panic(expected = "A power cap can only be set when instantiating a new staking contract")]
+fn test_power_cap_existing_staking() {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_base_contract());
+    let voting_id = app.store_code(dao_voting_cw20_staked_contract());
+
+    let token_addr = app
+        .instantiate_contract(
+            cw20_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw20_base::msg::InstantiateMsg {
+                name: "DAO DAO".to_string(),
+                symbol: "DAO".to_string(),
+                decimals: 3,
+                initial_balances: vec![Cw20Coin {
+                    address: CREATOR_ADDR.to_string(),
+                    amount: Uint128::from(2u64),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "voting token",
+            None,
+        )
+        .unwrap();
+
+    instantiate_voting(
+        &mut app,
+        voting_id,
+        InstantiateMsg {
+            token_info: crate::msg::TokenInfo::Existing {
+                address: token_addr.to_string(),
+                staking_contract: StakingInfo::Existing {
+                    staking_contract_address: "staking".to_string(),
+                },
+            },
+            active_threshold: None,
+            power_cap: Some(PowerCap::Absolute {
+                amount: Uint128::one(),
+            }),
+        },
+    );
+}
+
 #[test]
 fn test_different_heights() {
     let mut app = App::default();
@@ -737,6 +873,7 @@ fn test_different_heights() {
                 },
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -926,6 +1063,7 @@ fn test_active_threshold_absolute_count() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(100),
             }),
+            power_cap: None,
         },
     );
 
@@ -986,6 +1124,7 @@ fn test_active_threshold_percent() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(20),
             }),
+            power_cap: None,
         },
     );
 
@@ -1046,6 +1185,7 @@ fn test_active_threshold_percent_rounds_up() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(50),
             }),
+            power_cap: None,
         },
     );
 
@@ -1119,6 +1259,7 @@ fn test_active_threshold_none() {
                 initial_dao_balance: Some(Uint128::from(100u64)),
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -1157,6 +1298,7 @@ fn test_update_active_threshold() {
                 initial_dao_balance: Some(Uint128::from(100u64)),
             },
             active_threshold: None,
+            power_cap: None,
         },
     );
 
@@ -1227,6 +1369,7 @@ fn test_active_threshold_percentage_gt_100() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(120),
             }),
+            power_cap: None,
         },
     );
 }
@@ -1261,6 +1404,7 @@ fn test_active_threshold_percentage_lte_0() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(0),
             }),
+            power_cap: None,
         },
     );
 }
@@ -1295,6 +1439,7 @@ fn test_active_threshold_absolute_count_invalid() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(10000),
             }),
+            power_cap: None,
         },
     );
 }
@@ -1327,6 +1472,7 @@ fn test_migrate() {
                     initial_dao_balance: Some(Uint128::zero()),
                 },
                 active_threshold: None,
+                power_cap: None,
             },
             &[],
             "voting module",
@@ -22,8 +22,8 @@ use crate::msg::{
     QueryMsg,
 };
 use crate::state::{
-    register_staked_nft, register_unstaked_nfts, Config, ACTIVE_THRESHOLD, CONFIG, DAO, HOOKS,
-    INITIAL_NFTS, LEGACY_NFT_CLAIMS, NFT_BALANCES, NFT_CLAIMS, STAKED_NFTS_PER_OWNER,
+    register_staked_nft, register_unstaked_nfts, Config, ACTIVE_THRESHOLD, CAPPED_TOTAL, CONFIG,
+    DAO, HOOKS, INITIAL_NFTS, LEGACY_NFT_CLAIMS, NFT_BALANCES, NFT_CLAIMS, STAKED_NFTS_PER_OWNER,
     TOTAL_STAKED_NFTS,
 };
 use crate::ContractError;
@@ -83,6 +83,10 @@ pub fn instantiate(
     // Validate unstaking duration
     validate_duration(msg.unstaking_duration)?;
 
+    if let Some(power_cap) = msg.power_cap.as_ref() {
+        power_cap.validate()?;
+    }
+
     // Validate active threshold if configured
     if let Some(active_threshold) = msg.active_threshold.as_ref() {
         match active_threshold {
@@ -115,6 +119,7 @@ pub fn instantiate(
             let config = Config {
                 nft_address: deps.api.addr_validate(&address)?,
                 unstaking_duration: msg.unstaking_duration,
+                power_cap: msg.power_cap,
             };
             CONFIG.save(deps.storage, &config)?;
 
@@ -144,6 +149,7 @@ pub fn instantiate(
             let config = Config {
                 nft_address: Addr::unchecked(""),
                 unstaking_duration: msg.unstaking_duration,
+                power_cap: msg.power_cap,
             };
             CONFIG.save(deps.storage, &config)?;
 
@@ -176,6 +182,7 @@ pub fn instantiate(
                 let config = Config {
                     nft_address: Addr::unchecked(""),
                     unstaking_duration: msg.unstaking_duration,
+                    power_cap: msg.power_cap,
                 };
                 CONFIG.save(deps.storage, &config)?;
 
@@ -232,7 +239,13 @@ pub fn execute_stake(
         });
     }
     let staker = deps.api.addr_validate(&wrapper.sender)?;
-    register_staked_nft(deps.storage, env.block.height, &staker, &wrapper.token_id)?;
+    register_staked_nft(
+        deps.storage,
+        env.block.height,
+        &staker,
+        &wrapper.token_id,
+        config.power_cap.as_ref(),
+    )?;
     let hook_msgs = stake_nft_hook_msgs(
         HOOKS,
         deps.storage,
@@ -256,7 +269,14 @@ pub fn execute_unstake(
         return Err(ContractError::ZeroUnstake {});
     }
 
-    register_unstaked_nfts(deps.storage, env.block.height, &info.sender, &token_ids)?;
+    let config = CONFIG.load(deps.storage)?;
+    register_unstaked_nfts(
+        deps.storage,
+        env.block.height,
+        &info.sender,
+        &token_ids,
+        config.power_cap.as_ref(),
+    )?;
 
     // Provided that the backing cw721 contract is non-malicious:
     //
@@ -292,7 +312,6 @@ pub fn execute_unstake(
     let hook_msgs =
         unstake_nft_hook_msgs(HOOKS, deps.storage, info.sender.clone(), token_ids.clone())?;
 
-    let config = CONFIG.load(deps.storage)?;
     match config.unstaking_duration {
         None => {
             let return_messages = token_ids
@@ -613,17 +632,26 @@ pub fn query_voting_power_at_height(
 ) -> StdResult<Binary> {
     let address = deps.api.addr_validate(&address)?;
     let height = height.unwrap_or(env.block.height);
-    let power = NFT_BALANCES
+    let mut power = NFT_BALANCES
         .may_load_at_height(deps.storage, &address, height)?
         .unwrap_or_default();
+    if let Some(power_cap) = CONFIG.load(deps.storage)?.power_cap {
+        let total = TOTAL_STAKED_NFTS
+            .may_load_at_height(deps.storage, height)?
+            .unwrap_or_default();
+        power = power_cap.apply(power, total);
+    }
     to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse { power, height })
 }
 
 pub fn query_total_power_at_height(deps: Deps, env: Env, height: Option<u64>) -> StdResult<Binary> {
     let height = height.unwrap_or(env.block.height);
-    let power = TOTAL_STAKED_NFTS
-        .may_load_at_height(deps.storage, height)?
-        .unwrap_or_default();
+    let power = match CONFIG.load(deps.storage)?.power_cap {
+        Some(_) => CAPPED_TOTAL.total_at_height(deps.storage, height)?,
+        None => TOTAL_STAKED_NFTS
+            .may_load_at_height(deps.storage, height)?
+            .unwrap_or_default(),
+    };
     to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse { power, height })
 }
 
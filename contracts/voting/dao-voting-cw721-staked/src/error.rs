@@ -20,6 +20,9 @@ pub enum ContractError {
     #[error(transparent)]
     UnstakingDurationError(#[from] dao_voting::duration::UnstakingDurationError),
 
+    #[error(transparent)]
+    PowerCapError(#[from] dao_voting::power_cap::PowerCapError),
+
     #[error(transparent)]
     NftClaimError(#[from] nft_controllers::NftClaimError),
 
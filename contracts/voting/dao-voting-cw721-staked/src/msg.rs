@@ -3,6 +3,7 @@ use cosmwasm_std::Binary;
 use cw721::{Cw721ReceiveMsg, Expiration};
 use cw_utils::Duration;
 use dao_dao_macros::{active_query, voting_module_query};
+use dao_voting::power_cap::PowerCap;
 use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
 
 #[cw_serde]
@@ -42,6 +43,11 @@ pub struct InstantiateMsg {
     /// The number or percentage of tokens that must be staked
     /// for the DAO to be active
     pub active_threshold: Option<ActiveThreshold>,
+    /// The maximum voting power of any one address, as an absolute
+    /// number of NFTs or a percentage of the total staked. May not be
+    /// changed after instantiation.
+    #[serde(default)]
+    pub power_cap: Option<PowerCap>,
 }
 
 #[cw_serde]
@@ -3,6 +3,7 @@ use cosmwasm_std::{Addr, Binary, Empty, StdError, StdResult, Storage, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
+use dao_voting::power_cap::{CappedTotal, PowerCap};
 use dao_voting::threshold::ActiveThreshold;
 use nft_controllers::NftClaims;
 
@@ -12,6 +13,10 @@ use crate::ContractError;
 pub struct Config {
     pub nft_address: Addr,
     pub unstaking_duration: Option<Duration>,
+    /// The maximum voting power of any one address. Fixed at
+    /// instantiation.
+    #[serde(default)]
+    pub power_cap: Option<PowerCap>,
 }
 
 pub const ACTIVE_THRESHOLD: Item<ActiveThreshold> = Item::new("active_threshold");
@@ -42,6 +47,11 @@ pub const TOTAL_STAKED_NFTS: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
+/// The total capped voting power as a function of block height, if
+/// a power cap is configured.
+pub const CAPPED_TOTAL: CappedTotal =
+    CappedTotal::new("cs", "ct", "ct__checkpoints", "ct__changelog");
+
 /// The legacy NFT claims storage uses a non-paginatable vector, which limits
 /// the number of claims that may be outstanding. This is horrible UX,
 /// especially for large NFT collections. To allow DAOs to upgrade, we must keep
@@ -60,6 +70,7 @@ pub fn register_staked_nft(
     height: u64,
     staker: &Addr,
     token_id: &String,
+    power_cap: Option<&PowerCap>,
 ) -> StdResult<()> {
     let add_one = |prev: Option<Uint128>| -> StdResult<Uint128> {
         prev.unwrap_or_default()
@@ -68,10 +79,20 @@ pub fn register_staked_nft(
     };
 
     STAKED_NFTS_PER_OWNER.save(storage, (staker, token_id), &Empty::default())?;
-    NFT_BALANCES.update(storage, staker, height, add_one)?;
-    TOTAL_STAKED_NFTS
-        .update(storage, height, add_one)
-        .map(|_| ())
+    let balance = NFT_BALANCES.update(storage, staker, height, add_one)?;
+    let total = TOTAL_STAKED_NFTS.update(storage, height, add_one)?;
+    if let Some(power_cap) = power_cap {
+        CAPPED_TOTAL.on_balance_changed(
+            storage,
+            power_cap,
+            staker,
+            height,
+            balance - Uint128::one(),
+            balance,
+            total,
+        )?;
+    }
+    Ok(())
 }
 
 /// Registers the unstaking of TOKEN_IDs in storage. Errors if:
@@ -83,6 +104,7 @@ pub fn register_unstaked_nfts(
     height: u64,
     staker: &Addr,
     token_ids: &[String],
+    power_cap: Option<&PowerCap>,
 ) -> Result<(), ContractError> {
     let subtractor = |amount: u128| {
         move |prev: Option<Uint128>| -> StdResult<Uint128> {
@@ -106,7 +128,18 @@ pub fn register_unstaked_nfts(
     // invariant: token_ids has unique values. for loop asserts this.
 
     let sub_n = subtractor(token_ids.len() as u128);
-    TOTAL_STAKED_NFTS.update(storage, height, sub_n)?;
-    NFT_BALANCES.update(storage, staker, height, sub_n)?;
+    let total = TOTAL_STAKED_NFTS.update(storage, height, sub_n)?;
+    let balance = NFT_BALANCES.update(storage, staker, height, sub_n)?;
+    if let Some(power_cap) = power_cap {
+        CAPPED_TOTAL.on_balance_changed(
+            storage,
+            power_cap,
+            staker,
+            height,
+            balance + Uint128::new(token_ids.len() as u128),
+            balance,
+            total,
+        )?;
+    }
     Ok(())
 }
@@ -42,6 +42,7 @@ fn test_hooks() {
             &Config {
                 nft_address: Addr::unchecked("ekez-token"),
                 unstaking_duration: None,
+                power_cap: None,
             },
         )
         .unwrap();
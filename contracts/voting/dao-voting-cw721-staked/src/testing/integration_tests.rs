@@ -90,6 +90,7 @@ fn test_full_integration_with_factory() {
                 active_threshold: Some(ActiveThreshold::Percentage {
                     percent: Decimal::percent(1),
                 }),
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -111,6 +112,8 @@ fn test_full_integration_with_factory() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -117,6 +117,7 @@ impl TestEnvBuilder {
                     active_threshold: Some(ActiveThreshold::Percentage {
                         percent: Decimal::percent(1),
                     }),
+                    power_cap: None,
                 })
                 .unwrap(),
                 admin: Some(Admin::CoreModule {}),
@@ -135,6 +136,8 @@ impl TestEnvBuilder {
                     allow_revoting: false,
                     only_members_execute: true,
                     close_proposal_on_execution_failure: false,
+                    vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                    expedited: None,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
                 })
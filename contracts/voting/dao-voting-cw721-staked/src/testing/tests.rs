@@ -11,6 +11,7 @@ use dao_interface::voting::IsActiveResponse;
 use dao_testing::contracts::{
     cw721_base_contract, dao_test_custom_factory_contract, dao_voting_cw721_staked_contract,
 };
+use dao_voting::power_cap::PowerCap;
 use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
 
 use crate::testing::execute::{claim_legacy_nfts, claim_specific_nfts};
@@ -64,6 +65,7 @@ fn test_instantiate_with_new_cw721_collection() -> anyhow::Result<()> {
                 },
                 unstaking_duration: None,
                 active_threshold: None,
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -600,6 +602,7 @@ fn test_instantiate_with_invalid_duration_fails() {
                 },
                 unstaking_duration: None,
                 active_threshold: None,
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -644,6 +647,7 @@ fn test_instantiate_zero_active_threshold_count() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::zero(),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -684,6 +688,7 @@ fn test_instantiate_invalid_active_threshold_count_new_nft() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(100),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -710,6 +715,7 @@ fn test_instantiate_invalid_active_threshold_count_existing_nft() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(100),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -766,6 +772,7 @@ fn test_active_threshold_absolute_count() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(3),
                 }),
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -830,6 +837,7 @@ fn test_active_threshold_percent() {
                 active_threshold: Some(ActiveThreshold::Percentage {
                     percent: Decimal::percent(20),
                 }),
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -921,6 +929,7 @@ fn test_active_threshold_percent_rounds_up() {
                 active_threshold: Some(ActiveThreshold::Percentage {
                     percent: Decimal::percent(50),
                 }),
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -991,6 +1000,7 @@ fn test_update_active_threshold() {
                 },
                 unstaking_duration: None,
                 active_threshold: None,
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -1069,6 +1079,7 @@ fn test_active_threshold_percentage_gt_100() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(120),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -1111,6 +1122,7 @@ fn test_active_threshold_percentage_lte_0() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(0),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -1146,6 +1158,7 @@ fn test_invalid_instantiate_msg() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(1),
                 }),
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -1185,6 +1198,7 @@ fn test_invalid_initial_nft_msg() {
                 },
                 unstaking_duration: None,
                 active_threshold: None,
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -1235,6 +1249,7 @@ fn test_invalid_initial_nft_msg_wrong_absolute_count() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(2),
                 }),
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -1273,6 +1288,7 @@ fn test_no_initial_nfts_fails() {
                 active_threshold: Some(ActiveThreshold::Percentage {
                     percent: Decimal::percent(1),
                 }),
+                power_cap: None,
             },
             &[],
             "cw721_voting",
@@ -1330,6 +1346,7 @@ fn test_factory() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(1),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -1403,6 +1420,7 @@ fn test_factory_with_funds_pass_through() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(1),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -1450,6 +1468,7 @@ fn test_factory_with_funds_pass_through() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(1),
             }),
+            power_cap: None,
         },
         &funds,
         "cw721_voting",
@@ -1493,6 +1512,7 @@ fn test_unsupported_factory_msg() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(1),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -1543,6 +1563,7 @@ fn test_factory_wrong_callback() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(1),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -1591,6 +1612,7 @@ fn test_factory_no_callback() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(1),
             }),
+            power_cap: None,
         },
         &[],
         "cw721_voting",
@@ -1599,6 +1621,78 @@ fn test_factory_no_callback() {
     .unwrap();
 }
 
+// Voting power is capped per address, and the total is the sum of
+// the capped voting powers.
+#[test]
+fn test_power_cap() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let module_id = app.store_code(dao_voting_cw721_staked_contract());
+    let nft = instantiate_cw721_base(&mut app, CREATOR_ADDR, CREATOR_ADDR);
+
+    let res = app.instantiate_contract(
+        module_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &InstantiateMsg {
+            nft_contract: NftContract::Existing {
+                address: nft.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            power_cap: Some(PowerCap::Percentage {
+                percent: Decimal::zero(),
+            }),
+        },
+        &[],
+        "cw721_voting",
+        None,
+    );
+    is_error!(res => "Voting power cap percentage must be greater than 0");
+
+    let module = app.instantiate_contract(
+        module_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &InstantiateMsg {
+            nft_contract: NftContract::Existing {
+                address: nft.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            power_cap: Some(PowerCap::Absolute {
+                amount: Uint128::new(2),
+            }),
+        },
+        &[],
+        "cw721_voting",
+        None,
+    )?;
+
+    for token_id in ["1", "2", "3"] {
+        mint_and_stake_nft(&mut app, &nft, &module, CREATOR_ADDR, token_id)?;
+    }
+    mint_nft(&mut app, &nft, CREATOR_ADDR, "other", "4")?;
+    stake_nft(&mut app, &nft, &module, "other", "4")?;
+    app.update_block(next_block);
+
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(3));
+    assert_eq!(personal, Uint128::new(2));
+
+    // Unstaking NFTs over the cap does not change voting power.
+    unstake_nfts(&mut app, &module, CREATOR_ADDR, &["1"])?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(3));
+    assert_eq!(personal, Uint128::new(2));
+
+    unstake_nfts(&mut app, &module, CREATOR_ADDR, &["2"])?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(2));
+    assert_eq!(personal, Uint128::new(1));
+
+    Ok(())
+}
+
 #[test]
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
@@ -0,0 +1,367 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+
+use cosmwasm_std::{
+    coins, to_json_binary, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_storage_plus::Bound;
+use cw_utils::{may_pay, maybe_addr, must_pay};
+use dao_hooks::stake::{stake_hook_msgs, unstake_hook_msgs};
+use dao_interface::voting::{
+    DenomResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+
+use crate::error::ContractError;
+use crate::msg::{
+    ExecuteMsg, GetHooksResponse, InstantiateMsg, ListLocksResponse, LockResponse, MigrateMsg,
+    QueryMsg,
+};
+use crate::state::{
+    add_to_total, extend_in_total, total_power, Config, Lock, CONFIG, DAO, DENOM, HOOKS, LOCKS,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-token-locked";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Settings for query pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // The voting power of every lock is relative to the maximum lock
+    // duration, so it can not be changed after instantiation without
+    // rewriting history.
+    if msg.max_lock_duration == 0 || msg.min_lock_duration > msg.max_lock_duration {
+        return Err(ContractError::InvalidLockDurationConfig {});
+    }
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            max_lock_duration: msg.max_lock_duration,
+            min_lock_duration: msg.min_lock_duration,
+        },
+    )?;
+    DAO.save(deps.storage, &info.sender)?;
+    DENOM.save(deps.storage, &msg.denom)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("denom", msg.denom)
+        .add_attribute("max_lock_duration", msg.max_lock_duration.to_string())
+        .add_attribute("min_lock_duration", msg.min_lock_duration.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Lock { duration } => execute_lock(deps, env, info, duration),
+        ExecuteMsg::IncreaseAmount {} => execute_increase_amount(deps, env, info),
+        ExecuteMsg::ExtendLock { duration } => execute_extend_lock(deps, env, info, duration),
+        ExecuteMsg::Withdraw {} => execute_withdraw(deps, env, info),
+        ExecuteMsg::AddHook { addr } => execute_add_hook(deps, env, info, addr),
+        ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, env, info, addr),
+    }
+}
+
+/// Returns the height at which a lock made at `height` for `duration`
+/// blocks expires, erroring if `duration` is outside of the
+/// configured bounds.
+fn lock_end(config: &Config, height: u64, duration: u64) -> Result<u64, ContractError> {
+    if duration == 0 || duration < config.min_lock_duration || duration > config.max_lock_duration {
+        return Err(ContractError::InvalidLockDuration {
+            min: config.min_lock_duration,
+            max: config.max_lock_duration,
+        });
+    }
+    Ok(height + duration)
+}
+
+pub fn execute_lock(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let denom = DENOM.load(deps.storage)?;
+    let paid = may_pay(&info, &denom)?;
+    let end = lock_end(&config, env.block.height, duration)?;
+
+    // Tokens in an expired lock are locked again, as the sender would
+    // otherwise need to withdraw them first.
+    let amount = match LOCKS.may_load(deps.storage, &info.sender)? {
+        Some(lock) if !lock.is_expired(env.block.height) => {
+            return Err(ContractError::LockExists {})
+        }
+        Some(lock) => lock.amount.checked_add(paid).map_err(StdError::overflow)?,
+        None => paid,
+    };
+    if amount.is_zero() {
+        return Err(ContractError::NothingToLock {});
+    }
+
+    LOCKS.save(
+        deps.storage,
+        &info.sender,
+        &Lock { amount, end },
+        env.block.height,
+    )?;
+    add_to_total(deps.storage, env.block.height + 1, amount, end)?;
+
+    let hook_msgs = if paid.is_zero() {
+        vec![]
+    } else {
+        stake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), paid)?
+    };
+
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "lock")
+        .add_attribute("amount", amount)
+        .add_attribute("end", end.to_string())
+        .add_attribute("from", info.sender))
+}
+
+pub fn execute_increase_amount(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let paid = must_pay(&info, &denom)?;
+
+    let mut lock = LOCKS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoLock {})?;
+    if lock.is_expired(env.block.height) {
+        return Err(ContractError::LockExpired {});
+    }
+
+    lock.amount = lock.amount.checked_add(paid).map_err(StdError::overflow)?;
+    LOCKS.save(deps.storage, &info.sender, &lock, env.block.height)?;
+    add_to_total(deps.storage, env.block.height + 1, paid, lock.end)?;
+
+    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), paid)?;
+
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "increase_amount")
+        .add_attribute("amount", paid)
+        .add_attribute("from", info.sender))
+}
+
+pub fn execute_extend_lock(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let new_end = lock_end(&config, env.block.height, duration)?;
+
+    let mut lock = LOCKS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoLock {})?;
+    if lock.is_expired(env.block.height) {
+        return Err(ContractError::LockExpired {});
+    }
+    if new_end <= lock.end {
+        return Err(ContractError::InvalidExtension {});
+    }
+
+    extend_in_total(
+        deps.storage,
+        env.block.height + 1,
+        lock.amount,
+        lock.end,
+        new_end,
+    )?;
+    lock.end = new_end;
+    LOCKS.save(deps.storage, &info.sender, &lock, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "extend_lock")
+        .add_attribute("end", new_end.to_string())
+        .add_attribute("from", info.sender))
+}
+
+pub fn execute_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let lock = LOCKS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoLock {})?;
+    if !lock.is_expired(env.block.height) {
+        return Err(ContractError::LockNotExpired {});
+    }
+
+    // The lock's expiration was scheduled in the total when it was
+    // locked, so only the lock itself needs removing.
+    LOCKS.remove(deps.storage, &info.sender, env.block.height)?;
+
+    let denom = DENOM.load(deps.storage)?;
+    let msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(lock.amount.u128(), denom),
+    });
+
+    let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), lock.amount)?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "withdraw")
+        .add_attribute("amount", lock.amount)
+        .add_attribute("from", info.sender))
+}
+
+pub fn execute_add_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let hook = deps.api.addr_validate(&addr)?;
+    HOOKS.add_hook(deps.storage, hook)?;
+    Ok(Response::new()
+        .add_attribute("action", "add_hook")
+        .add_attribute("hook", addr))
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let hook = deps.api.addr_validate(&addr)?;
+    HOOKS.remove_hook(deps.storage, hook)?;
+    Ok(Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("hook", addr))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            to_json_binary(&query_voting_power_at_height(deps, env, address, height)?)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => {
+            to_json_binary(&query_total_power_at_height(deps, env, height)?)
+        }
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::GetConfig {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Denom {} => to_json_binary(&DenomResponse {
+            denom: DENOM.load(deps.storage)?,
+        }),
+        QueryMsg::Lock { address } => {
+            to_json_binary(&LOCKS.may_load(deps.storage, &deps.api.addr_validate(&address)?)?)
+        }
+        QueryMsg::ListLocks { start_after, limit } => query_list_locks(deps, start_after, limit),
+        QueryMsg::GetHooks {} => to_json_binary(&query_hooks(deps)?),
+    }
+}
+
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let power = LOCKS
+        .may_load_at_height(deps.storage, &address, height)?
+        .map(|lock| lock.power(height, config.max_lock_duration))
+        .unwrap_or_default();
+    Ok(VotingPowerAtHeightResponse { power, height })
+}
+
+pub fn query_total_power_at_height(
+    deps: Deps,
+    env: Env,
+    height: Option<u64>,
+) -> StdResult<TotalPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+    let config = CONFIG.load(deps.storage)?;
+    let power = total_power(deps.storage, height, config.max_lock_duration)?;
+    Ok(TotalPowerAtHeightResponse { power, height })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_json_binary(&dao_interface::voting::InfoResponse { info })
+}
+
+pub fn query_dao(deps: Deps) -> StdResult<Binary> {
+    let dao = DAO.load(deps.storage)?;
+    to_json_binary(&dao)
+}
+
+pub fn query_list_locks(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let addr = maybe_addr(deps.api, start_after)?;
+    let start = addr.as_ref().map(Bound::exclusive);
+
+    let locks = LOCKS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(address, lock)| LockResponse { address, lock }))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ListLocksResponse { locks })
+}
+
+pub fn query_hooks(deps: Deps) -> StdResult<GetHooksResponse> {
+    Ok(GetHooksResponse {
+        hooks: HOOKS.query_hooks(deps)?.hooks,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
@@ -0,0 +1,42 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    HookError(#[from] cw_hooks::HookError),
+
+    #[error(transparent)]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Lock duration must be between {min} and {max} blocks")]
+    InvalidLockDuration { min: u64, max: u64 },
+
+    #[error("Maximum lock duration must be non-zero and at least the minimum lock duration")]
+    InvalidLockDurationConfig {},
+
+    #[error("Extended lock must expire later than the current lock")]
+    InvalidExtension {},
+
+    #[error("Sender already has a lock that has not expired")]
+    LockExists {},
+
+    #[error("Lock has expired")]
+    LockExpired {},
+
+    #[error("Lock has not expired")]
+    LockNotExpired {},
+
+    #[error("Sender has no lock")]
+    NoLock {},
+
+    #[error("Nothing to lock")]
+    NothingToLock {},
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+}
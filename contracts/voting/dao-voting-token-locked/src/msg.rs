@@ -0,0 +1,80 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+use dao_dao_macros::{native_token_query, voting_module_query};
+
+use crate::state::Lock;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The native or Token Factory denom that may be locked for
+    /// voting power.
+    pub denom: String,
+    /// The maximum number of blocks tokens may be locked for. Tokens
+    /// locked for this long start with voting power equal to their
+    /// amount.
+    pub max_lock_duration: u64,
+    /// The minimum number of blocks tokens may be locked for.
+    pub min_lock_duration: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Locks the tokens sent with this message for `duration`
+    /// blocks. Errors if the sender has a lock that has not
+    /// expired. If the sender has an expired lock, its tokens are
+    /// locked again along with any tokens sent.
+    Lock { duration: u64 },
+    /// Adds the tokens sent with this message to the sender's lock
+    /// without changing when it expires.
+    IncreaseAmount {},
+    /// Extends the sender's lock so that it expires `duration` blocks
+    /// from now. Must expire later than it currently does.
+    ExtendLock { duration: u64 },
+    /// Returns the tokens of the sender's lock once it has expired.
+    Withdraw {},
+    /// Adds a hook that fires when tokens are locked or withdrawn.
+    /// Only the DAO may call this method.
+    AddHook { addr: String },
+    /// Removes a hook that fires when tokens are locked or
+    /// withdrawn. Only the DAO may call this method.
+    RemoveHook { addr: String },
+}
+
+#[native_token_query]
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    GetConfig {},
+    /// Gets an address's lock, if any.
+    #[returns(Option<crate::state::Lock>)]
+    Lock { address: String },
+    /// Lists locks in ascending order of address.
+    #[returns(ListLocksResponse)]
+    ListLocks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(GetHooksResponse)]
+    GetHooks {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub struct ListLocksResponse {
+    pub locks: Vec<LockResponse>,
+}
+
+#[cw_serde]
+pub struct LockResponse {
+    pub address: Addr,
+    pub lock: Lock,
+}
+
+#[cw_serde]
+pub struct GetHooksResponse {
+    pub hooks: Vec<String>,
+}
@@ -0,0 +1,129 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128, Uint256};
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, SnapshotMap, Strategy};
+use cw_wormhole::Wormhole;
+
+#[cw_serde]
+pub struct Config {
+    /// The maximum number of blocks tokens may be locked for.
+    pub max_lock_duration: u64,
+    /// The minimum number of blocks tokens may be locked for.
+    pub min_lock_duration: u64,
+}
+
+/// Tokens locked by an address.
+#[cw_serde]
+pub struct Lock {
+    /// The number of tokens locked.
+    pub amount: Uint128,
+    /// The block height at which the lock expires.
+    pub end: u64,
+}
+
+impl Lock {
+    /// The voting power of this lock at `height`. This is the amount
+    /// locked scaled by the fraction of `max_lock_duration` remaining
+    /// until the lock expires, and so decays linearly to zero at
+    /// `end`.
+    pub fn power(&self, height: u64, max_lock_duration: u64) -> Uint128 {
+        if height >= self.end {
+            Uint128::zero()
+        } else {
+            self.amount
+                .multiply_ratio(self.end - height, max_lock_duration)
+        }
+    }
+
+    /// Returns true if the lock has expired at `height`.
+    pub fn is_expired(&self, height: u64) -> bool {
+        height >= self.end
+    }
+}
+
+/// The configuration of this voting contract
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The address of the DAO this voting contract is connected to
+pub const DAO: Item<Addr> = Item::new("dao");
+
+/// The native denom associated with this contract
+pub const DENOM: Item<String> = Item::new("denom");
+
+/// Keeps track of locks by address over time
+pub const LOCKS: SnapshotMap<&Addr, Lock> = SnapshotMap::new(
+    "locks",
+    "locks__checkpoints",
+    "locks__changelog",
+    Strategy::EveryBlock,
+);
+
+/// locked(h) := the number of tokens in locks that have not expired
+/// at height h.
+///
+/// Lock expirations are known in advance, so they are recorded as
+/// decrements at future heights when tokens are locked.
+const TOTAL_LOCKED: Wormhole<(), Uint128> = Wormhole::new("total_locked");
+
+/// weighted_end(h) := the sum of `amount * end` over locks that have
+/// not expired at height h.
+const TOTAL_WEIGHTED_END: Wormhole<(), Uint256> = Wormhole::new("total_weighted_end");
+
+/// Hooks to contracts that will receive locking and withdrawal messages
+pub const HOOKS: Hooks = Hooks::new("hooks");
+
+/// Adds `amount` tokens locked until `end` to the total from `height`
+/// onwards.
+///
+/// The total voting power at height h is
+///
+///   sum(amount * (end - h)) / max_lock_duration
+///   = (weighted_end(h) - h * locked(h)) / max_lock_duration
+///
+/// over locks that have not expired at h, so tracking `locked` and
+/// `weighted_end` is enough to compute it at any height.
+pub fn add_to_total(
+    storage: &mut dyn Storage,
+    height: u64,
+    amount: Uint128,
+    end: u64,
+) -> StdResult<()> {
+    let weighted = amount.full_mul(end);
+    TOTAL_LOCKED.increment(storage, (), height, amount)?;
+    TOTAL_WEIGHTED_END.increment(storage, (), height, weighted)?;
+    TOTAL_LOCKED.decrement(storage, (), end, amount)?;
+    TOTAL_WEIGHTED_END.decrement(storage, (), end, weighted)?;
+    Ok(())
+}
+
+/// Moves the expiration of `amount` tokens locked until `end` to
+/// `new_end`, with the change taking effect from `height` onwards.
+pub fn extend_in_total(
+    storage: &mut dyn Storage,
+    height: u64,
+    amount: Uint128,
+    end: u64,
+    new_end: u64,
+) -> StdResult<()> {
+    TOTAL_WEIGHTED_END.increment(storage, (), height, amount.full_mul(new_end - end))?;
+    // Cancel the expiration scheduled when the tokens were locked.
+    TOTAL_LOCKED.increment(storage, (), end, amount)?;
+    TOTAL_WEIGHTED_END.increment(storage, (), end, amount.full_mul(end))?;
+    TOTAL_LOCKED.decrement(storage, (), new_end, amount)?;
+    TOTAL_WEIGHTED_END.decrement(storage, (), new_end, amount.full_mul(new_end))?;
+    Ok(())
+}
+
+/// The total voting power of all locks at `height`.
+pub fn total_power(
+    storage: &dyn Storage,
+    height: u64,
+    max_lock_duration: u64,
+) -> StdResult<Uint128> {
+    let locked = TOTAL_LOCKED.load(storage, (), height)?.unwrap_or_default();
+    let weighted_end = TOTAL_WEIGHTED_END
+        .load(storage, (), height)?
+        .unwrap_or_default();
+    let power = (weighted_end - locked.full_mul(height)) / Uint256::from(max_lock_duration);
+    Ok(power.try_into()?)
+}
@@ -0,0 +1,548 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{coins, Addr, Coin, Uint128};
+use cw_multi_test::{next_block, App, AppResponse, BankSudo, Executor, SudoMsg};
+use dao_interface::voting::{
+    DenomResponse, InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+use dao_testing::contracts::{
+    dao_proposal_hook_counter_contract, dao_voting_token_locked_contract,
+};
+
+use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::msg::{
+    ExecuteMsg, GetHooksResponse, InstantiateMsg, ListLocksResponse, LockResponse, MigrateMsg,
+    QueryMsg,
+};
+use crate::state::{Config, Lock};
+use crate::ContractError;
+
+const DAO_ADDR: &str = "dao";
+const ADDR1: &str = "addr1";
+const ADDR2: &str = "addr2";
+const DENOM: &str = "ujuno";
+const INVALID_DENOM: &str = "uinvalid";
+
+const MAX_LOCK_DURATION: u64 = 100;
+const MIN_LOCK_DURATION: u64 = 10;
+
+fn mock_app() -> App {
+    let mut app = App::default();
+    for addr in [ADDR1, ADDR2] {
+        app.sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: addr.to_string(),
+            amount: vec![Coin::new(10000, DENOM), Coin::new(10000, INVALID_DENOM)],
+        }))
+        .unwrap();
+    }
+    app
+}
+
+fn instantiate_locking(app: &mut App) -> Addr {
+    let code_id = app.store_code(dao_voting_token_locked_contract());
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(DAO_ADDR),
+        &InstantiateMsg {
+            denom: DENOM.to_string(),
+            max_lock_duration: MAX_LOCK_DURATION,
+            min_lock_duration: MIN_LOCK_DURATION,
+        },
+        &[],
+        "Locking",
+        None,
+    )
+    .unwrap()
+}
+
+fn lock_tokens(
+    app: &mut App,
+    locking_addr: &Addr,
+    sender: &str,
+    amount: u128,
+    duration: u64,
+) -> anyhow::Result<AppResponse> {
+    let funds = if amount == 0 {
+        vec![]
+    } else {
+        coins(amount, DENOM)
+    };
+    app.execute_contract(
+        Addr::unchecked(sender),
+        locking_addr.clone(),
+        &ExecuteMsg::Lock { duration },
+        &funds,
+    )
+}
+
+fn increase_amount(
+    app: &mut App,
+    locking_addr: &Addr,
+    sender: &str,
+    amount: u128,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        locking_addr.clone(),
+        &ExecuteMsg::IncreaseAmount {},
+        &coins(amount, DENOM),
+    )
+}
+
+fn extend_lock(
+    app: &mut App,
+    locking_addr: &Addr,
+    sender: &str,
+    duration: u64,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        locking_addr.clone(),
+        &ExecuteMsg::ExtendLock { duration },
+        &[],
+    )
+}
+
+fn withdraw(app: &mut App, locking_addr: &Addr, sender: &str) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        locking_addr.clone(),
+        &ExecuteMsg::Withdraw {},
+        &[],
+    )
+}
+
+fn advance_blocks(app: &mut App, blocks: u64) {
+    app.update_block(|b| {
+        b.height += blocks;
+        b.time = b.time.plus_seconds(5 * blocks);
+    });
+}
+
+fn get_voting_power(app: &App, locking_addr: &Addr, address: &str) -> Uint128 {
+    let res: VotingPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(
+            locking_addr,
+            &QueryMsg::VotingPowerAtHeight {
+                address: address.to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    res.power
+}
+
+fn get_total_power(app: &App, locking_addr: &Addr, height: Option<u64>) -> Uint128 {
+    let res: TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(locking_addr, &QueryMsg::TotalPowerAtHeight { height })
+        .unwrap();
+    res.power
+}
+
+fn get_lock(app: &App, locking_addr: &Addr, address: &str) -> Option<Lock> {
+    app.wrap()
+        .query_wasm_smart(
+            locking_addr,
+            &QueryMsg::Lock {
+                address: address.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_instantiate_invalid_durations() {
+    let mut app = mock_app();
+    let code_id = app.store_code(dao_voting_token_locked_contract());
+
+    for (max_lock_duration, min_lock_duration) in [(0, 0), (10, 11)] {
+        let err: ContractError = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(DAO_ADDR),
+                &InstantiateMsg {
+                    denom: DENOM.to_string(),
+                    max_lock_duration,
+                    min_lock_duration,
+                },
+                &[],
+                "Locking",
+                None,
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::InvalidLockDurationConfig {});
+    }
+}
+
+#[test]
+fn test_queries() {
+    let mut app = mock_app();
+    let addr = instantiate_locking(&mut app);
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetConfig {})
+        .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            max_lock_duration: MAX_LOCK_DURATION,
+            min_lock_duration: MIN_LOCK_DURATION,
+        }
+    );
+
+    let denom: DenomResponse = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::Denom {})
+        .unwrap();
+    assert_eq!(denom.denom, DENOM);
+
+    let dao: Addr = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::Dao {})
+        .unwrap();
+    assert_eq!(dao, Addr::unchecked(DAO_ADDR));
+
+    let info: InfoResponse = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::Info {})
+        .unwrap();
+    assert_eq!(info.info.contract, CONTRACT_NAME);
+
+    let height = app.block_info().height;
+    lock_tokens(&mut app, &addr, ADDR2, 200, 20).unwrap();
+    lock_tokens(&mut app, &addr, ADDR1, 100, 10).unwrap();
+
+    let locks: ListLocksResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::ListLocks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        locks.locks,
+        vec![
+            LockResponse {
+                address: Addr::unchecked(ADDR1),
+                lock: Lock {
+                    amount: Uint128::new(100),
+                    end: height + 10,
+                },
+            },
+            LockResponse {
+                address: Addr::unchecked(ADDR2),
+                lock: Lock {
+                    amount: Uint128::new(200),
+                    end: height + 20,
+                },
+            },
+        ]
+    );
+
+    let locks: ListLocksResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::ListLocks {
+                start_after: Some(ADDR1.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(locks.locks.len(), 1);
+    assert_eq!(locks.locks[0].address, Addr::unchecked(ADDR2));
+}
+
+#[test]
+fn test_voting_power_decays() {
+    let mut app = mock_app();
+    let addr = instantiate_locking(&mut app);
+    let start = app.block_info().height;
+
+    lock_tokens(&mut app, &addr, ADDR1, 1000, 100).unwrap();
+
+    // Power is not counted until the next block.
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::zero());
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::zero());
+
+    app.update_block(next_block);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(990));
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::new(990));
+
+    // Halfway through the lock, half of the power remains.
+    advance_blocks(&mut app, 49);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(500));
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::new(500));
+
+    // A lock made now for half the maximum duration expires at the
+    // same time as the first.
+    lock_tokens(&mut app, &addr, ADDR2, 1000, 50).unwrap();
+    app.update_block(next_block);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(490));
+    assert_eq!(get_voting_power(&app, &addr, ADDR2), Uint128::new(490));
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::new(980));
+
+    // Historical power is unchanged.
+    assert_eq!(
+        get_total_power(&app, &addr, Some(start + 1)),
+        Uint128::new(990)
+    );
+
+    advance_blocks(&mut app, 49);
+    assert_eq!(app.block_info().height, start + 100);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::zero());
+    assert_eq!(get_voting_power(&app, &addr, ADDR2), Uint128::zero());
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::zero());
+}
+
+#[test]
+fn test_increase_and_extend() {
+    let mut app = mock_app();
+    let addr = instantiate_locking(&mut app);
+    let start = app.block_info().height;
+
+    lock_tokens(&mut app, &addr, ADDR1, 1000, 50).unwrap();
+    app.update_block(next_block);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(490));
+
+    increase_amount(&mut app, &addr, ADDR1, 1000).unwrap();
+    app.update_block(next_block);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(960));
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::new(960));
+
+    // Can't extend to before the lock's current end.
+    let err: ContractError = extend_lock(&mut app, &addr, ADDR1, 40)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidExtension {});
+
+    extend_lock(&mut app, &addr, ADDR1, 100).unwrap();
+    assert_eq!(
+        get_lock(&app, &addr, ADDR1),
+        Some(Lock {
+            amount: Uint128::new(2000),
+            end: start + 102,
+        })
+    );
+    app.update_block(next_block);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(1980));
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::new(1980));
+
+    // Past the original end of the lock, the tokens are still
+    // counted in the total.
+    advance_blocks(&mut app, 57);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(840));
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::new(840));
+
+    advance_blocks(&mut app, 42);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::zero());
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::zero());
+
+    // Expired locks may be neither increased nor extended.
+    let err: ContractError = increase_amount(&mut app, &addr, ADDR1, 1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::LockExpired {});
+    let err: ContractError = extend_lock(&mut app, &addr, ADDR1, 100)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::LockExpired {});
+
+    // Nor may locks that don't exist.
+    let err: ContractError = increase_amount(&mut app, &addr, ADDR2, 1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoLock {});
+}
+
+#[test]
+fn test_lock_errors() {
+    let mut app = mock_app();
+    let addr = instantiate_locking(&mut app);
+
+    for duration in [0, MIN_LOCK_DURATION - 1, MAX_LOCK_DURATION + 1] {
+        let err: ContractError = lock_tokens(&mut app, &addr, ADDR1, 100, duration)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(
+            err,
+            ContractError::InvalidLockDuration {
+                min: MIN_LOCK_DURATION,
+                max: MAX_LOCK_DURATION,
+            }
+        );
+    }
+
+    let err: ContractError = lock_tokens(&mut app, &addr, ADDR1, 0, 10)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NothingToLock {});
+
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        addr.clone(),
+        &ExecuteMsg::Lock { duration: 10 },
+        &coins(100, INVALID_DENOM),
+    )
+    .unwrap_err();
+
+    lock_tokens(&mut app, &addr, ADDR1, 100, 10).unwrap();
+    let err: ContractError = lock_tokens(&mut app, &addr, ADDR1, 100, 10)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::LockExists {});
+}
+
+#[test]
+fn test_withdraw_and_relock() {
+    let mut app = mock_app();
+    let addr = instantiate_locking(&mut app);
+
+    let err: ContractError = withdraw(&mut app, &addr, ADDR1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoLock {});
+
+    lock_tokens(&mut app, &addr, ADDR1, 100, 10).unwrap();
+    let err: ContractError = withdraw(&mut app, &addr, ADDR1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::LockNotExpired {});
+
+    // Tokens in an expired lock may be locked again without being
+    // withdrawn.
+    advance_blocks(&mut app, 10);
+    lock_tokens(&mut app, &addr, ADDR1, 50, 20).unwrap();
+    let end = app.block_info().height + 20;
+    assert_eq!(
+        get_lock(&app, &addr, ADDR1),
+        Some(Lock {
+            amount: Uint128::new(150),
+            end,
+        })
+    );
+    app.update_block(next_block);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::new(28));
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::new(28));
+
+    advance_blocks(&mut app, 19);
+    withdraw(&mut app, &addr, ADDR1).unwrap();
+    assert_eq!(get_lock(&app, &addr, ADDR1), None);
+    assert_eq!(
+        app.wrap().query_balance(ADDR1, DENOM).unwrap().amount,
+        Uint128::new(10000)
+    );
+    app.update_block(next_block);
+    assert_eq!(get_voting_power(&app, &addr, ADDR1), Uint128::zero());
+    assert_eq!(get_total_power(&app, &addr, None), Uint128::zero());
+}
+
+#[test]
+fn test_hooks() {
+    let mut app = mock_app();
+    let addr = instantiate_locking(&mut app);
+
+    let hook_id = app.store_code(dao_proposal_hook_counter_contract());
+    let hook = app
+        .instantiate_contract(
+            hook_id,
+            Addr::unchecked(DAO_ADDR),
+            &dao_proposal_hook_counter::msg::InstantiateMsg {
+                should_error: false,
+            },
+            &[],
+            "hook counter".to_string(),
+            None,
+        )
+        .unwrap();
+
+    // Only the DAO may add hooks.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            addr.clone(),
+            &ExecuteMsg::AddHook {
+                addr: hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        addr.clone(),
+        &ExecuteMsg::AddHook {
+            addr: hook.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let hooks: GetHooksResponse = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetHooks {})
+        .unwrap();
+    assert_eq!(hooks.hooks, vec![hook.to_string()]);
+
+    let res = lock_tokens(&mut app, &addr, ADDR1, 100, 10).unwrap();
+    assert_eq!("stake_hook", res.events.last().unwrap().attributes[1].value);
+
+    let res = increase_amount(&mut app, &addr, ADDR1, 100).unwrap();
+    assert_eq!("stake_hook", res.events.last().unwrap().attributes[1].value);
+
+    advance_blocks(&mut app, 10);
+    let res = withdraw(&mut app, &addr, ADDR1).unwrap();
+    assert_eq!("stake_hook", res.events.last().unwrap().attributes[1].value);
+
+    let count: dao_proposal_hook_counter::msg::CountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &hook,
+            &dao_proposal_hook_counter::msg::QueryMsg::StakeCounter {},
+        )
+        .unwrap();
+    assert_eq!(count.count, 3);
+
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        addr.clone(),
+        &ExecuteMsg::RemoveHook {
+            addr: hook.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let hooks: GetHooksResponse = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetHooks {})
+        .unwrap();
+    assert!(hooks.hooks.is_empty());
+}
+
+#[test]
+pub fn test_migrate_update_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "my-contract", "1.0.0").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    let version = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(version.version, CONTRACT_VERSION);
+    assert_eq!(version.contract, CONTRACT_NAME);
+}
@@ -2,8 +2,9 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    coins, from_json, to_json_binary, BankMsg, BankQuery, Binary, Coin, CosmosMsg, Deps, DepsMut,
-    Env, MessageInfo, Order, Reply, Response, StdResult, SubMsg, Uint128, Uint256, WasmMsg,
+    coins, from_json, to_json_binary, Addr, BankMsg, BankQuery, Binary, Coin, CosmosMsg, Decimal,
+    Deps, DepsMut, Env, MessageInfo, Order, QuerierWrapper, Reply, Response, StdResult, Storage,
+    SubMsg, Uint128, Uint256, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_controllers::ClaimsResponse;
@@ -28,6 +29,7 @@ use dao_interface::{
 };
 use dao_voting::{
     duration::validate_duration,
+    stake_bonus::validate_bonus_schedule,
     threshold::{
         assert_valid_absolute_count_threshold, assert_valid_percentage_threshold, ActiveThreshold,
         ActiveThresholdResponse,
@@ -36,12 +38,14 @@ use dao_voting::{
 
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg,
-    StakerBalanceResponse, TokenInfo,
+    DerivativeResponse, ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse,
+    MigrateMsg, OracleQueryMsg, QueryMsg, RedemptionRateResponse, StakerBalanceResponse, TokenInfo,
 };
 use crate::state::{
-    Config, ACTIVE_THRESHOLD, CLAIMS, CONFIG, DAO, DENOM, HOOKS, MAX_CLAIMS, STAKED_BALANCES,
-    STAKED_TOTAL, TOKEN_INSTANTIATION_INFO, TOKEN_ISSUER_CONTRACT,
+    Config, Derivative, ACTIVE_THRESHOLD, CAPPED_TOTAL, CLAIMS, CONFIG, DAO, DENOM, DERIVATIVE,
+    DERIVATIVE_BALANCES, DERIVATIVE_CLAIMS, DERIVATIVE_HOOK_VALUES, DERIVATIVE_TOTAL, HOOKS,
+    MAX_CLAIMS, REDEMPTION_RATE, STAKED_BALANCES, STAKED_TOTAL, STAKE_BONUS,
+    TOKEN_INSTANTIATION_INFO, TOKEN_ISSUER_CONTRACT,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-token-staked";
@@ -61,16 +65,25 @@ const PRECISION_FACTOR: u128 = 10u128.pow(9);
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     validate_duration(msg.unstaking_duration)?;
+    validate_bonus_schedule(&msg.bonus_schedule)?;
+    if let Some(power_cap) = msg.power_cap.as_ref() {
+        if !msg.bonus_schedule.is_empty() || msg.derivative.is_some() {
+            return Err(ContractError::UnsupportedPowerCap {});
+        }
+        power_cap.validate()?;
+    }
 
     let config = Config {
         unstaking_duration: msg.unstaking_duration,
+        bonus_schedule: msg.bonus_schedule,
+        power_cap: msg.power_cap,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -87,8 +100,26 @@ pub fn instantiate(
         ACTIVE_THRESHOLD.save(deps.storage, active_threshold)?;
     }
 
+    if let Some(derivative) = msg.derivative {
+        let derivative = Derivative {
+            denom: derivative.denom,
+            oracle: deps.api.addr_validate(&derivative.oracle)?,
+        };
+        // Record the initial rate, which also checks that the oracle
+        // supports the derivative.
+        update_redemption_rate(deps.storage, &deps.querier, env.block.height, &derivative)?;
+        DERIVATIVE.save(deps.storage, &derivative)?;
+    }
+
     match msg.token_info {
         TokenInfo::Existing { denom } => {
+            if DERIVATIVE
+                .may_load(deps.storage)?
+                .is_some_and(|derivative| derivative.denom == denom)
+            {
+                return Err(ContractError::DerivativeDenomConflict {});
+            }
+
             // Validate active threshold absolute count if configured
             if let Some(ActiveThreshold::AbsoluteCount { count }) = msg.active_threshold {
                 let supply: Coin = deps.querier.query_supply(denom.clone())?;
@@ -168,8 +199,12 @@ pub fn execute(
     match msg {
         ExecuteMsg::Stake {} => execute_stake(deps, env, info),
         ExecuteMsg::Unstake { amount } => execute_unstake(deps, env, info, amount),
+        ExecuteMsg::UnstakeDerivative { amount } => {
+            execute_unstake_derivative(deps, env, info, amount)
+        }
         ExecuteMsg::UpdateConfig { duration } => execute_update_config(deps, info, duration),
         ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+        ExecuteMsg::UpdateRedemptionRate {} => execute_update_redemption_rate(deps, env),
         ExecuteMsg::UpdateActiveThreshold { new_threshold } => {
             execute_update_active_threshold(deps, env, info, new_threshold)
         }
@@ -183,20 +218,46 @@ pub fn execute_stake(
     env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
+    if let Some(derivative) = DERIVATIVE.may_load(deps.storage)? {
+        if info.funds.len() == 1 && info.funds[0].denom == derivative.denom {
+            return execute_stake_derivative(deps, env, info, derivative);
+        }
+    }
+
     let denom = DENOM.load(deps.storage)?;
     let amount = must_pay(&info, &denom)?;
+    let config = CONFIG.load(deps.storage)?;
 
-    STAKED_BALANCES.update(
+    let balance = STAKED_BALANCES.update(
         deps.storage,
         &info.sender,
         env.block.height,
         |balance| -> StdResult<Uint128> { Ok(balance.unwrap_or_default().checked_add(amount)?) },
     )?;
-    STAKED_TOTAL.update(
+    STAKE_BONUS.on_stake_changed(
+        deps.storage,
+        &config.bonus_schedule,
+        &info.sender,
+        env.block.height,
+        balance - amount,
+        balance,
+    )?;
+    let total = STAKED_TOTAL.update(
         deps.storage,
         env.block.height,
         |total| -> StdResult<Uint128> { Ok(total.unwrap_or_default().checked_add(amount)?) },
     )?;
+    if let Some(power_cap) = config.power_cap.as_ref() {
+        CAPPED_TOTAL.on_balance_changed(
+            deps.storage,
+            power_cap,
+            &info.sender,
+            env.block.height,
+            balance - amount,
+            balance,
+            total,
+        )?;
+    }
 
     // Add stake hook messages
     let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), amount)?;
@@ -208,6 +269,43 @@ pub fn execute_stake(
         .add_attribute("from", info.sender))
 }
 
+fn execute_stake_derivative(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    derivative: Derivative,
+) -> Result<Response, ContractError> {
+    let amount = must_pay(&info, &derivative.denom)?;
+
+    DERIVATIVE_BALANCES.update(
+        deps.storage,
+        &info.sender,
+        env.block.height,
+        |balance| -> StdResult<Uint128> { Ok(balance.unwrap_or_default().checked_add(amount)?) },
+    )?;
+    DERIVATIVE_TOTAL.update(
+        deps.storage,
+        env.block.height,
+        |total| -> StdResult<Uint128> { Ok(total.unwrap_or_default().checked_add(amount)?) },
+    )?;
+    let rate = update_redemption_rate(deps.storage, &deps.querier, env.block.height, &derivative)?;
+
+    // Hooks receive the change in voting power, so the derivative is
+    // valued in the governance token at the time it is staked.
+    let value = amount.mul_floor(rate);
+    DERIVATIVE_HOOK_VALUES.update(deps.storage, &info.sender, |total| -> StdResult<Uint128> {
+        Ok(total.unwrap_or_default().checked_add(value)?)
+    })?;
+    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), value)?;
+
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "stake")
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("denom", derivative.denom)
+        .add_attribute("from", info.sender))
+}
+
 pub fn execute_unstake(
     deps: DepsMut,
     env: Env,
@@ -218,7 +316,8 @@ pub fn execute_unstake(
         return Err(ContractError::ZeroUnstake {});
     }
 
-    STAKED_BALANCES.update(
+    let config = CONFIG.load(deps.storage)?;
+    let balance = STAKED_BALANCES.update(
         deps.storage,
         &info.sender,
         env.block.height,
@@ -229,7 +328,15 @@ pub fn execute_unstake(
                 .map_err(|_e| ContractError::InvalidUnstakeAmount {})
         },
     )?;
-    STAKED_TOTAL.update(
+    STAKE_BONUS.on_stake_changed(
+        deps.storage,
+        &config.bonus_schedule,
+        &info.sender,
+        env.block.height,
+        balance + amount,
+        balance,
+    )?;
+    let total = STAKED_TOTAL.update(
         deps.storage,
         env.block.height,
         |total| -> Result<Uint128, ContractError> {
@@ -239,11 +346,21 @@ pub fn execute_unstake(
                 .map_err(|_e| ContractError::InvalidUnstakeAmount {})
         },
     )?;
+    if let Some(power_cap) = config.power_cap.as_ref() {
+        CAPPED_TOTAL.on_balance_changed(
+            deps.storage,
+            power_cap,
+            &info.sender,
+            env.block.height,
+            balance + amount,
+            balance,
+            total,
+        )?;
+    }
 
     // Add unstake hook messages
     let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), amount)?;
 
-    let config = CONFIG.load(deps.storage)?;
     let denom = DENOM.load(deps.storage)?;
     match config.unstaking_duration {
         None => {
@@ -281,6 +398,93 @@ pub fn execute_unstake(
     }
 }
 
+pub fn execute_unstake_derivative(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let derivative = DERIVATIVE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::DerivativeNotEnabled {})?;
+    if amount.is_zero() {
+        return Err(ContractError::ZeroUnstake {});
+    }
+
+    let balance = DERIVATIVE_BALANCES.update(
+        deps.storage,
+        &info.sender,
+        env.block.height,
+        |balance| -> Result<Uint128, ContractError> {
+            balance
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
+        },
+    )?;
+    DERIVATIVE_TOTAL.update(
+        deps.storage,
+        env.block.height,
+        |total| -> Result<Uint128, ContractError> {
+            total
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
+        },
+    )?;
+    update_redemption_rate(deps.storage, &deps.querier, env.block.height, &derivative)?;
+
+    // Hooks receive the share of the value reported when the
+    // derivative was staked, so that stake and unstake hooks net to
+    // zero however the rate has changed in between.
+    let hook_value = DERIVATIVE_HOOK_VALUES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    let value = hook_value.multiply_ratio(amount, balance + amount);
+    DERIVATIVE_HOOK_VALUES.save(deps.storage, &info.sender, &(hook_value - value))?;
+
+    // Add unstake hook messages
+    let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), value)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    match config.unstaking_duration {
+        None => {
+            let msg = CosmosMsg::Bank(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(amount.u128(), derivative.denom),
+            });
+            Ok(Response::new()
+                .add_message(msg)
+                .add_submessages(hook_msgs)
+                .add_attribute("action", "unstake_derivative")
+                .add_attribute("from", info.sender)
+                .add_attribute("amount", amount)
+                .add_attribute("claim_duration", "None"))
+        }
+        Some(duration) => {
+            let outstanding_claims = DERIVATIVE_CLAIMS
+                .query_claims(deps.as_ref(), &info.sender)?
+                .claims;
+            if outstanding_claims.len() >= MAX_CLAIMS as usize {
+                return Err(ContractError::TooManyClaims {});
+            }
+
+            DERIVATIVE_CLAIMS.create_claim(
+                deps.storage,
+                &info.sender,
+                amount,
+                duration.after(&env.block),
+            )?;
+            Ok(Response::new()
+                .add_submessages(hook_msgs)
+                .add_attribute("action", "unstake_derivative")
+                .add_attribute("from", info.sender)
+                .add_attribute("amount", amount)
+                .add_attribute("claim_duration", format!("{duration}")))
+        }
+    }
+}
+
 pub fn execute_update_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -308,21 +512,69 @@ pub fn execute_claim(
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
     let release = CLAIMS.claim_tokens(deps.storage, &info.sender, &env.block, None)?;
-    if release.is_zero() {
+    let derivative_release = match DERIVATIVE.may_load(deps.storage)? {
+        Some(derivative) => Some((
+            derivative.denom,
+            DERIVATIVE_CLAIMS.claim_tokens(deps.storage, &info.sender, &env.block, None)?,
+        )),
+        None => None,
+    }
+    .filter(|(_, amount)| !amount.is_zero());
+    if release.is_zero() && derivative_release.is_none() {
         return Err(ContractError::NothingToClaim {});
     }
 
-    let denom = DENOM.load(deps.storage)?;
-    let msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: info.sender.to_string(),
-        amount: coins(release.u128(), denom),
-    });
+    let mut response = Response::new()
+        .add_attribute("action", "claim")
+        .add_attribute("from", info.sender.clone())
+        .add_attribute("amount", release);
+
+    if !release.is_zero() {
+        let denom = DENOM.load(deps.storage)?;
+        response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(release.u128(), denom),
+        }));
+    }
+    if let Some((denom, amount)) = derivative_release {
+        response = response
+            .add_attribute("derivative_amount", amount)
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(amount.u128(), denom),
+            }));
+    }
+
+    Ok(response)
+}
+
+pub fn execute_update_redemption_rate(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let derivative = DERIVATIVE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::DerivativeNotEnabled {})?;
+    let rate = update_redemption_rate(deps.storage, &deps.querier, env.block.height, &derivative)?;
 
     Ok(Response::new()
-        .add_message(msg)
-        .add_attribute("action", "claim")
-        .add_attribute("from", info.sender)
-        .add_attribute("amount", release))
+        .add_attribute("action", "update_redemption_rate")
+        .add_attribute("redemption_rate", rate.to_string()))
+}
+
+/// Queries the oracle for the derivative's redemption rate and records
+/// it at `height`.
+fn update_redemption_rate(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
+    height: u64,
+    derivative: &Derivative,
+) -> StdResult<Decimal> {
+    let RedemptionRateResponse { redemption_rate } = querier.query_wasm_smart(
+        &derivative.oracle,
+        &OracleQueryMsg::RedemptionRate {
+            denom: derivative.denom.clone(),
+        },
+    )?;
+    REDEMPTION_RATE.save(storage, &redemption_rate, height)?;
+    Ok(redemption_rate)
 }
 
 pub fn execute_update_active_threshold(
@@ -416,6 +668,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::TokenContract {} => {
             to_json_binary(&TOKEN_ISSUER_CONTRACT.may_load(deps.storage)?)
         }
+        QueryMsg::Derivative { height } => to_json_binary(&query_derivative(deps, env, height)?),
+        QueryMsg::DerivativeClaims { address } => to_json_binary(
+            &DERIVATIVE_CLAIMS.query_claims(deps, &deps.api.addr_validate(&address)?)?,
+        ),
     }
 }
 
@@ -427,9 +683,28 @@ pub fn query_voting_power_at_height(
 ) -> StdResult<VotingPowerAtHeightResponse> {
     let height = height.unwrap_or(env.block.height);
     let address = deps.api.addr_validate(&address)?;
-    let power = STAKED_BALANCES
+    let config = CONFIG.load(deps.storage)?;
+    let balance = STAKED_BALANCES
         .may_load_at_height(deps.storage, &address, height)?
         .unwrap_or_default();
+    let power = match config.power_cap {
+        // Power caps are not used with bonuses or derivatives.
+        Some(power_cap) => power_cap.apply(
+            balance,
+            STAKED_TOTAL
+                .may_load_at_height(deps.storage, height)?
+                .unwrap_or_default(),
+        ),
+        None => STAKE_BONUS
+            .power_at_height(
+                deps.storage,
+                &config.bonus_schedule,
+                &address,
+                balance,
+                height,
+            )?
+            .checked_add(derivative_power_at_height(deps, Some(&address), height)?)?,
+    };
     Ok(VotingPowerAtHeightResponse { power, height })
 }
 
@@ -439,12 +714,60 @@ pub fn query_total_power_at_height(
     height: Option<u64>,
 ) -> StdResult<TotalPowerAtHeightResponse> {
     let height = height.unwrap_or(env.block.height);
-    let power = STAKED_TOTAL
-        .may_load_at_height(deps.storage, height)?
-        .unwrap_or_default();
+    let power = match CONFIG.load(deps.storage)?.power_cap {
+        Some(_) => CAPPED_TOTAL.total_at_height(deps.storage, height)?,
+        None => STAKED_TOTAL
+            .may_load_at_height(deps.storage, height)?
+            .unwrap_or_default()
+            .checked_add(STAKE_BONUS.total_bonus_at_height(deps.storage, height)?)?
+            .checked_add(derivative_power_at_height(deps, None, height)?)?,
+    };
     Ok(TotalPowerAtHeightResponse { power, height })
 }
 
+/// The voting power of staked derivative tokens at `height`, valued at
+/// the redemption rate recorded for that height. Gives the power of
+/// `address` if provided, otherwise the total.
+fn derivative_power_at_height(
+    deps: Deps,
+    address: Option<&Addr>,
+    height: u64,
+) -> StdResult<Uint128> {
+    let staked = match address {
+        Some(address) => DERIVATIVE_BALANCES.may_load_at_height(deps.storage, address, height)?,
+        None => DERIVATIVE_TOTAL.may_load_at_height(deps.storage, height)?,
+    };
+    Ok(match staked {
+        Some(staked) => {
+            let rate = REDEMPTION_RATE
+                .may_load_at_height(deps.storage, height)?
+                .unwrap_or_default();
+            staked.mul_floor(rate)
+        }
+        None => Uint128::zero(),
+    })
+}
+
+pub fn query_derivative(
+    deps: Deps,
+    env: Env,
+    height: Option<u64>,
+) -> StdResult<Option<DerivativeResponse>> {
+    let height = height.unwrap_or(env.block.height);
+    DERIVATIVE
+        .may_load(deps.storage)?
+        .map(|derivative| {
+            Ok(DerivativeResponse {
+                denom: derivative.denom,
+                oracle: derivative.oracle,
+                redemption_rate: REDEMPTION_RATE
+                    .may_load_at_height(deps.storage, height)?
+                    .unwrap_or_default(),
+            })
+        })
+        .transpose()
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -20,9 +20,21 @@ pub enum ContractError {
     #[error(transparent)]
     ParseReplyError(#[from] ParseReplyError),
 
+    #[error(transparent)]
+    PowerCapError(#[from] dao_voting::power_cap::PowerCapError),
+
+    #[error(transparent)]
+    StakeBonusError(#[from] dao_voting::stake_bonus::StakeBonusError),
+
     #[error(transparent)]
     UnstakingDurationError(#[from] dao_voting::duration::UnstakingDurationError),
 
+    #[error("Liquid staking derivative must have a different denom than the governance token")]
+    DerivativeDenomConflict {},
+
+    #[error("No liquid staking derivative is configured")]
+    DerivativeNotEnabled {},
+
     #[error("Initial governance token balances must not be empty")]
     InitialBalancesError {},
 
@@ -38,6 +50,11 @@ pub enum ContractError {
     #[error("Too many outstanding claims. Claim some tokens before unstaking more.")]
     TooManyClaims {},
 
+    #[error(
+        "A voting power cap can not be used with a stake bonus schedule or liquid staking derivative"
+    )]
+    UnsupportedPowerCap {},
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -1,8 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
 use cw_utils::Duration;
 use dao_dao_macros::{active_query, native_token_query, voting_module_query};
 use dao_interface::token::NewTokenInfo;
+use dao_voting::power_cap::PowerCap;
+use dao_voting::stake_bonus::BonusTier;
 use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
 
 #[cw_serde]
@@ -24,6 +26,17 @@ pub enum TokenInfo {
     Factory(Binary),
 }
 
+/// A liquid staking derivative of the governance token that may be
+/// staked for voting power.
+#[cw_serde]
+pub struct DerivativeInfo {
+    /// The native denom of the derivative.
+    pub denom: String,
+    /// The contract that reports how many governance tokens one
+    /// derivative token redeems for. Must implement `OracleQueryMsg`.
+    pub oracle: String,
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// New or existing native token to use for voting power.
@@ -33,18 +46,45 @@ pub struct InstantiateMsg {
     /// The number or percentage of tokens that must be staked
     /// for the DAO to be active
     pub active_threshold: Option<ActiveThreshold>,
+    /// A liquid staking derivative of the governance token that may
+    /// also be staked. Staked derivative tokens give voting power
+    /// equal to the governance tokens they redeem for.
+    #[serde(default)]
+    pub derivative: Option<DerivativeInfo>,
+    /// Voting power multipliers for governance tokens that have been
+    /// staked for a number of blocks, ordered by `min_stake_blocks`.
+    /// Unstaking resets the age of an address's stake. May not be
+    /// changed after instantiation.
+    #[serde(default)]
+    pub bonus_schedule: Vec<BonusTier>,
+    /// The maximum voting power of any one address, as an absolute
+    /// amount or a percentage of the total staked. May not be used
+    /// with a bonus schedule or derivative, or changed after
+    /// instantiation.
+    #[serde(default)]
+    pub power_cap: Option<PowerCap>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Stakes tokens with the contract to get voting power in the DAO
+    /// Stakes tokens with the contract to get voting power in the
+    /// DAO. If a liquid staking derivative is configured, it may be
+    /// staked instead of the governance token.
     Stake {},
     /// Unstakes tokens so that they begin unbonding
     Unstake { amount: Uint128 },
+    /// Unstakes liquid staking derivative tokens so that they begin
+    /// unbonding
+    UnstakeDerivative { amount: Uint128 },
     /// Updates the contract configuration
     UpdateConfig { duration: Option<Duration> },
     /// Claims unstaked tokens that have completed the unbonding period
     Claim {},
+    /// Queries the oracle for the liquid staking derivative's
+    /// redemption rate and records it for voting power from the next
+    /// block. The rate is also recorded whenever the derivative is
+    /// staked or unstaked. Anyone may call this method.
+    UpdateRedemptionRate {},
     /// Sets the active threshold to a new value. Only the
     /// instantiator of this contract (a DAO most likely) may call this
     /// method.
@@ -78,6 +118,12 @@ pub enum QueryMsg {
     GetHooks {},
     #[returns(Option<cosmwasm_std::Addr>)]
     TokenContract {},
+    /// Returns the liquid staking derivative, if configured, along
+    /// with its redemption rate at `height`.
+    #[returns(Option<DerivativeResponse>)]
+    Derivative { height: Option<u64> },
+    #[returns(cw_controllers::ClaimsResponse)]
+    DerivativeClaims { address: String },
 }
 
 #[cw_serde]
@@ -98,3 +144,27 @@ pub struct StakerBalanceResponse {
 pub struct GetHooksResponse {
     pub hooks: Vec<String>,
 }
+
+#[cw_serde]
+pub struct DerivativeResponse {
+    pub denom: String,
+    pub oracle: Addr,
+    /// The redemption rate used for voting power at the queried
+    /// height. Zero if no rate had been recorded by then.
+    pub redemption_rate: Decimal,
+}
+
+/// The query interface redemption rate oracles must implement.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum OracleQueryMsg {
+    /// Returns the number of underlying tokens one `denom` token
+    /// redeems for.
+    #[returns(RedemptionRateResponse)]
+    RedemptionRate { denom: String },
+}
+
+#[cw_serde]
+pub struct RedemptionRateResponse {
+    pub redemption_rate: Decimal,
+}
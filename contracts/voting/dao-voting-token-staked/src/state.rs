@@ -1,9 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw_controllers::Claims;
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
+use dao_voting::power_cap::{CappedTotal, PowerCap};
+use dao_voting::stake_bonus::{BonusTier, StakeBonus};
 use dao_voting::threshold::ActiveThreshold;
 
 use crate::msg::TokenInfo;
@@ -11,6 +13,20 @@ use crate::msg::TokenInfo;
 #[cw_serde]
 pub struct Config {
     pub unstaking_duration: Option<Duration>,
+    /// Voting power multipliers for long-term stakers. Fixed at
+    /// instantiation.
+    #[serde(default)]
+    pub bonus_schedule: Vec<BonusTier>,
+    /// The maximum voting power of any one address. Fixed at
+    /// instantiation.
+    #[serde(default)]
+    pub power_cap: Option<PowerCap>,
+}
+
+#[cw_serde]
+pub struct Derivative {
+    pub denom: String,
+    pub oracle: Addr,
 }
 
 /// The configuration of this voting contract
@@ -38,6 +54,24 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
+/// Keeps track of how long addresses have staked the governance
+/// token for, and the total bonus voting power this gives over time
+pub const STAKE_BONUS: StakeBonus = StakeBonus::new(
+    "stake_bonus_since",
+    "stake_bonus_since__checkpoints",
+    "stake_bonus_since__changelog",
+    "stake_bonus_total",
+);
+
+/// Keeps track of the total capped voting power over time, if a
+/// power cap is configured
+pub const CAPPED_TOTAL: CappedTotal = CappedTotal::new(
+    "capped_stakers",
+    "capped_total",
+    "capped_total__checkpoints",
+    "capped_total__changelog",
+);
+
 /// The maximum number of claims that may be outstanding.
 pub const MAX_CLAIMS: u64 = 100;
 
@@ -54,3 +88,39 @@ pub const TOKEN_INSTANTIATION_INFO: Item<TokenInfo> = Item::new("token_instantia
 
 /// The address of the cw-tokenfactory-issuer contract
 pub const TOKEN_ISSUER_CONTRACT: Item<Addr> = Item::new("token_issuer_contract");
+
+/// The liquid staking derivative that may be staked, if any
+pub const DERIVATIVE: Item<Derivative> = Item::new("derivative");
+
+/// Keeps track of staked derivative balances by address over time
+pub const DERIVATIVE_BALANCES: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "derivative_balances",
+    "derivative_balance__checkpoints",
+    "derivative_balance__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Keeps track of the staked derivative total over time
+pub const DERIVATIVE_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
+    "derivative_total",
+    "derivative_total__checkpoints",
+    "derivative_total__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Keeps track of the derivative's redemption rate over time, so
+/// that voting power at past heights does not change with the rate
+pub const REDEMPTION_RATE: SnapshotItem<Decimal> = SnapshotItem::new(
+    "redemption_rate",
+    "redemption_rate__checkpoints",
+    "redemption_rate__changelog",
+    Strategy::EveryBlock,
+);
+
+pub const DERIVATIVE_CLAIMS: Claims = Claims::new("derivative_claims");
+
+/// The value in the governance token reported to stake hooks for each
+/// address's staked derivative. Unstake hooks report the share of
+/// this value being unstaked rather than the value at the current
+/// rate, so that an address's hooks net to zero once it has unstaked.
+pub const DERIVATIVE_HOOK_VALUES: Map<&Addr, Uint128> = Map::new("derivative_hook_values");
@@ -1,11 +1,11 @@
 use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
 use crate::msg::{
-    ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg,
-    StakerBalanceResponse, TokenInfo,
+    DerivativeInfo, DerivativeResponse, ExecuteMsg, GetHooksResponse, InstantiateMsg,
+    ListStakersResponse, MigrateMsg, QueryMsg, StakerBalanceResponse, TokenInfo,
 };
 use crate::state::Config;
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{coins, Addr, Coin, Decimal, Uint128};
+use cosmwasm_std::{coins, Addr, Coin, Decimal, Empty, Uint128};
 use cw_controllers::ClaimsResponse;
 use cw_multi_test::{next_block, App, AppResponse, BankSudo, Executor, SudoMsg};
 use cw_utils::Duration;
@@ -16,6 +16,8 @@ use dao_interface::voting::{
 use dao_testing::contracts::{
     dao_proposal_hook_counter_contract, dao_voting_token_staked_contract,
 };
+use dao_voting::power_cap::PowerCap;
+use dao_voting::stake_bonus::BonusTier;
 use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
 
 const DAO_ADDR: &str = "dao";
@@ -197,6 +199,9 @@ fn test_instantiate_existing() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -210,6 +215,9 @@ fn test_instantiate_existing() {
             },
             unstaking_duration: None,
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -244,6 +252,9 @@ fn test_instantiate_invalid_unstaking_duration_height() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(1),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 }
@@ -267,6 +278,9 @@ fn test_instantiate_invalid_unstaking_duration_time() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(1),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 }
@@ -286,6 +300,9 @@ fn test_stake_invalid_denom() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -307,6 +324,9 @@ fn test_stake_valid_denom() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -330,6 +350,9 @@ fn test_unstake_none_staked() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -351,6 +374,9 @@ fn test_unstake_zero_tokens() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -372,6 +398,9 @@ fn test_unstake_invalid_balance() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -397,6 +426,9 @@ fn test_unstake() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -434,6 +466,9 @@ fn test_unstake_no_unstaking_duration() {
             },
             unstaking_duration: None,
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -473,6 +508,9 @@ fn test_claim_no_claims() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -494,6 +532,9 @@ fn test_claim_claim_not_reached() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -523,6 +564,9 @@ fn test_claim() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -576,6 +620,9 @@ fn test_update_config_invalid_sender() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -597,6 +644,9 @@ fn test_update_config_as_owner() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -607,6 +657,8 @@ fn test_update_config_as_owner() {
     assert_eq!(
         Config {
             unstaking_duration: Some(Duration::Height(10)),
+            bonus_schedule: vec![],
+            power_cap: None,
         },
         config
     );
@@ -627,6 +679,9 @@ fn test_update_config_invalid_duration() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -648,6 +703,9 @@ fn test_query_dao() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -670,6 +728,9 @@ fn test_query_info() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -692,6 +753,9 @@ fn test_query_claims() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -730,6 +794,9 @@ fn test_query_get_config() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -738,6 +805,8 @@ fn test_query_get_config() {
         config,
         Config {
             unstaking_duration: Some(Duration::Height(5)),
+            bonus_schedule: vec![],
+            power_cap: None,
         }
     )
 }
@@ -756,6 +825,9 @@ fn test_voting_power_queries() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -864,6 +936,9 @@ fn test_query_list_stakers() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -953,6 +1028,9 @@ fn test_instantiate_zero_active_threshold_count() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::zero(),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 }
@@ -974,6 +1052,9 @@ fn test_active_threshold_absolute_count() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(100),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -1012,6 +1093,9 @@ fn test_active_threshold_percent() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(20),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -1050,6 +1134,9 @@ fn test_active_threshold_percent_rounds_up() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(50),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -1095,6 +1182,9 @@ fn test_active_threshold_none() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -1120,6 +1210,9 @@ fn test_update_active_threshold() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -1174,6 +1267,9 @@ fn test_active_threshold_percentage_gt_100() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(120),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 }
@@ -1197,6 +1293,9 @@ fn test_active_threshold_percentage_lte_0() {
             active_threshold: Some(ActiveThreshold::Percentage {
                 percent: Decimal::percent(0),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 }
@@ -1218,6 +1317,9 @@ fn test_active_threshold_absolute_count_invalid() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(30001),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 }
@@ -1237,6 +1339,9 @@ fn test_add_remove_hooks() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -1313,6 +1418,9 @@ fn test_staking_hooks() {
             },
             unstaking_duration: Some(Duration::Height(5)),
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
     );
 
@@ -1342,6 +1450,577 @@ fn test_staking_hooks() {
     assert_eq!("stake_hook", res.events.last().unwrap().attributes[1].value);
 }
 
+const DERIVATIVE_DENOM: &str = "ustjuno";
+
+/// A redemption rate oracle for `DERIVATIVE_DENOM` whose rate is set
+/// on instantiation and by executing it with a new rate.
+mod oracle {
+    use cosmwasm_std::{
+        to_json_binary, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+        StdError, StdResult,
+    };
+    use cw_multi_test::{Contract, ContractWrapper};
+    use cw_storage_plus::Item;
+
+    use crate::msg::{OracleQueryMsg, RedemptionRateResponse};
+
+    const RATE: Item<Decimal> = Item::new("rate");
+
+    fn set_rate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        rate: Decimal,
+    ) -> StdResult<Response> {
+        RATE.save(deps.storage, &rate)?;
+        Ok(Response::new())
+    }
+
+    fn query(deps: Deps, _env: Env, msg: OracleQueryMsg) -> StdResult<Binary> {
+        match msg {
+            OracleQueryMsg::RedemptionRate { denom } => {
+                if denom != super::DERIVATIVE_DENOM {
+                    return Err(StdError::generic_err("unknown denom"));
+                }
+                to_json_binary(&RedemptionRateResponse {
+                    redemption_rate: RATE.load(deps.storage)?,
+                })
+            }
+        }
+    }
+
+    pub fn contract() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(set_rate, set_rate, query))
+    }
+}
+
+/// A stake hook receiver that tracks the net amount reported by the
+/// hooks it receives. Errors if more is unstaked than was staked.
+mod hook_tracker {
+    use cosmwasm_std::{
+        to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+        Uint128,
+    };
+    use cw_multi_test::{Contract, ContractWrapper};
+    use cw_storage_plus::Item;
+    use dao_hooks::stake::{StakeChangedExecuteMsg, StakeChangedHookMsg};
+
+    const STAKED: Item<Uint128> = Item::new("staked");
+
+    fn instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        STAKED.save(deps.storage, &Uint128::zero())?;
+        Ok(Response::new())
+    }
+
+    fn execute(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: StakeChangedExecuteMsg,
+    ) -> StdResult<Response> {
+        let StakeChangedExecuteMsg::StakeChangeHook(hook) = msg;
+        let staked = STAKED.load(deps.storage)?;
+        let staked = match hook {
+            StakeChangedHookMsg::Stake { amount, .. } => staked.checked_add(amount)?,
+            StakeChangedHookMsg::Unstake { amount, .. } => staked.checked_sub(amount)?,
+        };
+        STAKED.save(deps.storage, &staked)?;
+        Ok(Response::new())
+    }
+
+    fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_json_binary(&STAKED.load(deps.storage)?)
+    }
+
+    pub fn contract() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(execute, instantiate, query))
+    }
+}
+
+/// Instantiates an oracle with `rate` and a staking contract with
+/// `DERIVATIVE_DENOM` as its derivative, and mints `DERIVATIVE_DENOM`
+/// to ADDR1 and ADDR2.
+fn setup_derivative(app: &mut App, rate: Decimal) -> (Addr, Addr) {
+    for address in [ADDR1, ADDR2] {
+        app.sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: address.to_string(),
+            amount: coins(10000, DERIVATIVE_DENOM),
+        }))
+        .unwrap();
+    }
+
+    let oracle_id = app.store_code(oracle::contract());
+    let oracle = app
+        .instantiate_contract(
+            oracle_id,
+            Addr::unchecked(DAO_ADDR),
+            &rate,
+            &[],
+            "oracle",
+            None,
+        )
+        .unwrap();
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    let staking = instantiate_staking(
+        app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+            derivative: Some(DerivativeInfo {
+                denom: DERIVATIVE_DENOM.to_string(),
+                oracle: oracle.to_string(),
+            }),
+            bonus_schedule: vec![],
+            power_cap: None,
+        },
+    );
+    (staking, oracle)
+}
+
+#[test]
+fn test_derivative_voting_power() {
+    let mut app = mock_app();
+    let (addr, oracle) = setup_derivative(&mut app, Decimal::percent(150));
+
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 100, DERIVATIVE_DENOM).unwrap();
+    app.update_block(next_block);
+    let height = app.block_info().height;
+
+    // Derivative tokens count at their underlying value.
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(100));
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(150));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(250));
+
+    // A new rate is only used once it has been recorded, and from
+    // the next block.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        oracle.clone(),
+        &Decimal::percent(200),
+        &[],
+    )
+    .unwrap();
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(150));
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        addr.clone(),
+        &ExecuteMsg::UpdateRedemptionRate {},
+        &[],
+    )
+    .unwrap();
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(150));
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(200));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(300));
+
+    // Past voting power does not change with the rate.
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), Some(height));
+    assert_eq!(resp.power, Uint128::new(150));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), Some(height));
+    assert_eq!(resp.power, Uint128::new(250));
+
+    let derivative: Option<DerivativeResponse> = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Derivative { height: None })
+        .unwrap();
+    assert_eq!(
+        derivative,
+        Some(DerivativeResponse {
+            denom: DERIVATIVE_DENOM.to_string(),
+            oracle,
+            redemption_rate: Decimal::percent(200),
+        })
+    );
+
+    // Unstake some derivative tokens and claim them.
+    app.execute_contract(
+        Addr::unchecked(ADDR2),
+        addr.clone(),
+        &ExecuteMsg::UnstakeDerivative {
+            amount: Uint128::new(50),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(100));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(200));
+
+    let claims: ClaimsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::DerivativeClaims {
+                address: ADDR2.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(claims.claims.len(), 1);
+    assert_eq!(claims.claims[0].amount, Uint128::new(50));
+
+    app.update_block(|b| b.height += 5);
+    claim(&mut app, addr, ADDR2).unwrap();
+    assert_eq!(
+        get_balance(&mut app, ADDR2, DERIVATIVE_DENOM),
+        Uint128::new(9950)
+    );
+}
+
+#[test]
+fn test_derivative_stake_hooks() {
+    let mut app = mock_app();
+    let (addr, oracle) = setup_derivative(&mut app, Decimal::percent(150));
+
+    let tracker_id = app.store_code(hook_tracker::contract());
+    let tracker = app
+        .instantiate_contract(
+            tracker_id,
+            Addr::unchecked(DAO_ADDR),
+            &Empty {},
+            &[],
+            "hook tracker",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        addr.clone(),
+        &ExecuteMsg::AddHook {
+            addr: tracker.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let tracked =
+        |app: &App| -> Uint128 { app.wrap().query_wasm_smart(&tracker, &Empty {}).unwrap() };
+
+    // Stake hooks report the derivative's value when it is staked.
+    stake_tokens(&mut app, addr.clone(), ADDR2, 100, DERIVATIVE_DENOM).unwrap();
+    assert_eq!(tracked(&app), Uint128::new(150));
+
+    // Unstake hooks report the share of that value being unstaked,
+    // even though the rate has changed since, so that the hooks net
+    // to zero.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        oracle,
+        &Decimal::percent(200),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        addr.clone(),
+        &ExecuteMsg::UpdateRedemptionRate {},
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    for (amount, remaining) in [(40, 90), (60, 0)] {
+        app.execute_contract(
+            Addr::unchecked(ADDR2),
+            addr.clone(),
+            &ExecuteMsg::UnstakeDerivative {
+                amount: Uint128::new(amount),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(tracked(&app), Uint128::new(remaining));
+    }
+}
+
+#[test]
+#[should_panic(expected = "No liquid staking derivative is configured")]
+fn test_unstake_derivative_not_enabled() {
+    let mut app = mock_app();
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
+        },
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        addr,
+        &ExecuteMsg::UnstakeDerivative {
+            amount: Uint128::new(1),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Can only unstake less than or equal to the amount you have staked")]
+fn test_unstake_derivative_invalid_balance() {
+    let mut app = mock_app();
+    let (addr, _) = setup_derivative(&mut app, Decimal::one());
+
+    // Governance tokens can not be unstaked as derivative tokens.
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    app.update_block(next_block);
+
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        addr,
+        &ExecuteMsg::UnstakeDerivative {
+            amount: Uint128::new(100),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(
+    expected = "Liquid staking derivative must have a different denom than the governance token"
+)]
+fn test_instantiate_derivative_denom_conflict() {
+    let mut app = mock_app();
+    let (_, oracle) = setup_derivative(&mut app, Decimal::one());
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DERIVATIVE_DENOM.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            derivative: Some(DerivativeInfo {
+                denom: DERIVATIVE_DENOM.to_string(),
+                oracle: oracle.to_string(),
+            }),
+            bonus_schedule: vec![],
+            power_cap: None,
+        },
+    );
+}
+
+fn bonus_schedule() -> Vec<BonusTier> {
+    vec![
+        BonusTier {
+            min_stake_blocks: 10,
+            multiplier: Decimal::percent(120),
+        },
+        BonusTier {
+            min_stake_blocks: 20,
+            multiplier: Decimal::percent(150),
+        },
+    ]
+}
+
+#[test]
+fn test_stake_bonus() {
+    let mut app = mock_app();
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            derivative: None,
+            bonus_schedule: bonus_schedule(),
+            power_cap: None,
+        },
+    );
+    assert_eq!(
+        get_config(&mut app, addr.clone()).bonus_schedule,
+        bonus_schedule()
+    );
+
+    let start = app.block_info().height;
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 100, DENOM).unwrap();
+
+    // No bonus until the first tier is reached.
+    app.update_block(|b| b.height += 9);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(100));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(200));
+
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(120));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(240));
+
+    // Unstaking resets the age of the remaining stake.
+    unstake_tokens(&mut app, addr.clone(), ADDR1, 50).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(50));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(170));
+
+    app.update_block(|b| b.height = start + 20);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(150));
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(60));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(210));
+
+    // Historical queries are unchanged.
+    let resp =
+        get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), Some(start + 10));
+    assert_eq!(resp.power, Uint128::new(120));
+    let resp = get_total_power_at_height(&mut app, addr, Some(start + 11));
+    assert_eq!(resp.power, Uint128::new(170));
+}
+
+#[test]
+#[should_panic(
+    expected = "Bonus tiers must be ordered by strictly increasing min_stake_blocks, starting above zero"
+)]
+fn test_instantiate_invalid_bonus_schedule() {
+    let mut app = mock_app();
+
+    let mut schedule = bonus_schedule();
+    schedule.reverse();
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            derivative: None,
+            bonus_schedule: schedule,
+            power_cap: None,
+        },
+    );
+}
+
+#[test]
+fn test_power_cap() {
+    let mut app = mock_app();
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: Some(PowerCap::Percentage {
+                percent: Decimal::percent(50),
+            }),
+        },
+    );
+
+    stake_tokens(&mut app, addr.clone(), ADDR1, 300, DENOM).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 100, DENOM).unwrap();
+    app.update_block(next_block);
+    let height = app.block_info().height;
+
+    // ADDR1 is capped at half of the 400 staked.
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(200));
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(100));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(300));
+
+    // As more is staked, the cap rises.
+    stake_tokens(&mut app, addr.clone(), ADDR2, 300, DENOM).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(300));
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(350));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(650));
+
+    // Historical queries are unchanged.
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), Some(height));
+    assert_eq!(resp.power, Uint128::new(200));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), Some(height));
+    assert_eq!(resp.power, Uint128::new(300));
+
+    unstake_tokens(&mut app, addr.clone(), ADDR2, 400).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(150));
+    let resp = get_total_power_at_height(&mut app, addr, None);
+    assert_eq!(resp.power, Uint128::new(150));
+}
+
+#[test]
+#[should_panic(
+    expected = "A voting power cap can not be used with a stake bonus schedule or liquid staking derivative"
+)]
+fn test_instantiate_power_cap_with_bonus() {
+    let mut app = mock_app();
+
+    let staking_id = app.store_code(dao_voting_token_staked_contract());
+    instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+            derivative: None,
+            bonus_schedule: bonus_schedule(),
+            power_cap: Some(PowerCap::Absolute {
+                amount: Uint128::new(100),
+            }),
+        },
+    );
+}
+
 #[test]
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
@@ -132,6 +132,9 @@ fn test_instantiate_no_dao_balance() {
                 }),
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             },
             dao,
         )
@@ -185,6 +188,9 @@ fn test_instantiate_no_metadata() {
             }),
             unstaking_duration: None,
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
         dao,
     )
@@ -226,6 +232,9 @@ fn test_instantiate_invalid_metadata_fails() {
             }),
             unstaking_duration: None,
             active_threshold: None,
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         },
         dao,
     )
@@ -270,6 +279,9 @@ fn test_instantiate_invalid_active_threshold_count_fails() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(1000),
                 }),
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             },
             dao,
         )
@@ -314,6 +326,9 @@ fn test_instantiate_no_initial_balances_fails() {
                 }),
                 unstaking_duration: None,
                 active_threshold: None,
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             },
             dao,
         )
@@ -377,6 +392,9 @@ fn test_factory() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(75),
                 }),
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -395,6 +413,8 @@ fn test_factory() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -487,6 +507,9 @@ fn test_factory_funds_pass_through() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(75),
                 }),
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -505,6 +528,8 @@ fn test_factory_funds_pass_through() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -561,6 +586,9 @@ fn test_factory_funds_pass_through() {
             active_threshold: Some(ActiveThreshold::AbsoluteCount {
                 count: Uint128::new(75),
             }),
+            derivative: None,
+            bonus_schedule: vec![],
+            power_cap: None,
         })
         .unwrap(),
         admin: Some(Admin::CoreModule {}),
@@ -613,6 +641,9 @@ fn test_factory_no_callback() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(75),
                 }),
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -631,6 +662,8 @@ fn test_factory_no_callback() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -694,6 +727,9 @@ fn test_factory_wrong_callback() {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(75),
                 }),
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -712,6 +748,8 @@ fn test_factory_wrong_callback() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -156,6 +156,9 @@ impl TestEnvBuilder {
                 active_threshold: Some(ActiveThreshold::AbsoluteCount {
                     count: Uint128::new(75),
                 }),
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             },
             &accounts[0],
         )
@@ -228,6 +231,9 @@ impl TestEnvBuilder {
                     active_threshold: Some(ActiveThreshold::AbsoluteCount {
                         count: Uint128::new(75),
                     }),
+                    derivative: None,
+                    bonus_schedule: vec![],
+                    power_cap: None,
                 })
                 .unwrap(),
                 admin: Some(Admin::CoreModule {}),
@@ -246,6 +252,8 @@ impl TestEnvBuilder {
                     allow_revoting: false,
                     only_members_execute: true,
                     close_proposal_on_execution_failure: false,
+                    vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                    expedited: None,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
                 })
@@ -2,14 +2,26 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, Empty, StdResult, Storage, SubMsg, WasmMsg};
 use cw_hooks::Hooks;
 use dao_voting::{
+    execution::ExecutionResult,
     pre_propose::ProposalCreationPolicy,
-    reply::{failed_pre_propose_module_hook_id, mask_proposal_hook_index},
+    reply::{
+        failed_pre_propose_module_hook_id, mask_extended_proposal_hook_index,
+        mask_proposal_hook_index,
+    },
     status::Status,
 };
 
 /// An enum representing proposal hook messages.
 /// Either a new propsoal hook, fired when a new proposal is created,
-/// or a proposal status hook, fired when a proposal changes status.
+/// a proposal status hook, fired when a proposal changes status, a
+/// proposal executed hook, fired with the outcome of executing a
+/// proposal by modules that record it, or a proposal amended hook,
+/// fired when an open proposal is amended by modules that allow it.
+///
+/// Proposal executed and amended hooks are extended hooks. They are
+/// only sent to consumers that opt in to extended proposal hooks, so
+/// that consumers written before they existed are not sent messages
+/// they can not parse.
 #[cw_serde]
 pub enum ProposalHookMsg {
     NewProposal {
@@ -21,6 +33,17 @@ pub enum ProposalHookMsg {
         old_status: String,
         new_status: String,
     },
+    ProposalExecuted {
+        id: u64,
+        result: ExecutionResult,
+    },
+    ProposalAmended {
+        id: u64,
+        amendment_id: u64,
+        /// Whether the votes cast on the proposal before it was
+        /// amended were removed.
+        votes_reset: bool,
+    },
 }
 
 /// Prepares new proposal hook messages. These messages reply on error
@@ -92,6 +115,67 @@ pub fn proposal_status_changed_hooks(
     Ok(messages)
 }
 
+/// Prepares proposal executed hook messages for consumers of extended
+/// proposal hooks. These messages reply on error with extended
+/// proposal hook reply IDs.
+pub fn proposal_executed_hooks(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    id: u64,
+    result: ExecutionResult,
+) -> StdResult<Vec<SubMsg>> {
+    let msg = to_json_binary(&ProposalHookExecuteMsg::ProposalHook(
+        ProposalHookMsg::ProposalExecuted { id, result },
+    ))?;
+    let mut index: u64 = 0;
+    let messages = hooks.prepare_hooks(storage, |a| {
+        let execute = WasmMsg::Execute {
+            contract_addr: a.to_string(),
+            msg: msg.clone(),
+            funds: vec![],
+        };
+        let masked_index = mask_extended_proposal_hook_index(index);
+        let tmp = SubMsg::reply_on_error(execute, masked_index);
+        index += 1;
+        Ok(tmp)
+    })?;
+
+    Ok(messages)
+}
+
+/// Prepares proposal amended hook messages for consumers of extended
+/// proposal hooks. These messages reply on error with extended
+/// proposal hook reply IDs.
+pub fn proposal_amended_hooks(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    id: u64,
+    amendment_id: u64,
+    votes_reset: bool,
+) -> StdResult<Vec<SubMsg>> {
+    let msg = to_json_binary(&ProposalHookExecuteMsg::ProposalHook(
+        ProposalHookMsg::ProposalAmended {
+            id,
+            amendment_id,
+            votes_reset,
+        },
+    ))?;
+    let mut index: u64 = 0;
+    let messages = hooks.prepare_hooks(storage, |a| {
+        let execute = WasmMsg::Execute {
+            contract_addr: a.to_string(),
+            msg: msg.clone(),
+            funds: vec![],
+        };
+        let masked_index = mask_extended_proposal_hook_index(index);
+        let tmp = SubMsg::reply_on_error(execute, masked_index);
+        index += 1;
+        Ok(tmp)
+    })?;
+
+    Ok(messages)
+}
+
 /// Message type used for firing hooks to a proposal module's pre-propose
 /// module, if one is installed.
 pub type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
@@ -0,0 +1,76 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_utils::{Duration, Expiration};
+
+/// A rule permitting a proposal module to have the DAO execute a
+/// class of messages. Once a proposal module has authorizations
+/// configured, every message it sends via `ExecuteProposalHook` must
+/// be permitted by at least one of them.
+#[cw_serde]
+pub enum Authorization {
+    /// Permits any message.
+    Any {},
+    /// Permits `BankMsg::Send` messages of a single denom.
+    BankSend {
+        denom: String,
+        /// If set, only these addresses may receive tokens.
+        recipients: Option<Vec<String>>,
+        /// If set, the total amount that may be sent under this
+        /// authorization each period.
+        limit: Option<SpendLimit>,
+    },
+    /// Permits `WasmMsg::Execute` messages to a single contract that
+    /// do not attach funds.
+    ///
+    /// NOTE: authorizing execution on the DAO's core contract grants
+    /// the module the same power as the DAO itself.
+    WasmExecute {
+        contract: String,
+        /// If set, only these top level message variants (for
+        /// example, `"transfer"` for a cw20 `Transfer` message) may
+        /// be executed.
+        msgs: Option<Vec<String>>,
+    },
+}
+
+/// An amount that may be spent each period. The period begins with
+/// the first spend after the previous period ended.
+#[cw_serde]
+pub struct SpendLimit {
+    pub amount: Uint128,
+    pub period: Duration,
+}
+
+/// Spending recorded against an authorization with a `SpendLimit`.
+#[cw_serde]
+pub struct SpendUsage {
+    /// The amount spent in the current period.
+    pub spent: Uint128,
+    /// When the current period ends.
+    pub period_end: Expiration,
+}
+
+/// The outcome of checking a message against a proposal module's
+/// authorizations.
+#[cw_serde]
+pub enum AuthorizationResult {
+    /// The module has no authorizations configured and may execute
+    /// any message.
+    Unrestricted {},
+    /// The message is permitted by the authorization at `index` in
+    /// the module's list of authorizations.
+    Allowed { index: u32 },
+    /// No authorization permits the message. `reasons` explains why
+    /// each of the module's authorizations did not, in order.
+    Denied { reasons: Vec<String> },
+}
+
+#[cw_serde]
+pub struct ModuleAuthorizationsResponse {
+    pub module: Addr,
+    pub authorizations: Vec<Authorization>,
+    /// Spending recorded against each authorization, in the same
+    /// order as `authorizations`. None for authorizations without a
+    /// spend limit or that have not been used.
+    pub usage: Vec<Option<SpendUsage>>,
+}
@@ -1,10 +1,15 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+pub mod authorization;
 pub mod migrate_msg;
 pub mod msg;
 pub mod nft;
 pub mod proposal;
 pub mod query;
+pub mod ratification;
+pub mod spending;
+pub mod staking;
 pub mod state;
 pub mod token;
+pub mod treasury;
 pub mod voting;
@@ -1,8 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{CosmosMsg, Empty};
-use cw_utils::Duration;
+use cosmwasm_std::{CosmosMsg, Empty, Timestamp, Uint128};
+use cw_stake_tracker::StakeTrackerQuery;
+use cw_utils::{Duration, Expiration};
 
-use crate::state::Config;
+use crate::authorization::Authorization;
+use crate::ratification::RatificationRule;
+use crate::spending::{SpendDenom, SpendingCap};
+use crate::staking::StakingConfig;
+use crate::state::{Config, PauseScope};
+use crate::treasury::TreasuryKind;
 use crate::{migrate_msg::MigrateParams, query::SubDao, state::ModuleInstantiateInfo};
 
 /// Information about an item to be stored in the items list.
@@ -14,6 +20,24 @@ pub struct InitialItem {
     pub value: String,
 }
 
+/// Messages to schedule for execution by the DAO.
+#[cw_serde]
+pub struct ScheduleInfo {
+    /// The messages may be executed once this has expired.
+    pub execute_at: Expiration,
+    /// The messages to execute.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+}
+
+/// Information about a DAO's guardians.
+#[cw_serde]
+pub struct GuardianInfo {
+    /// The addresses that may act as guardians.
+    pub guardians: Vec<String>,
+    /// When the guardians' powers expire. Must not be `Never`.
+    pub expiration: Expiration,
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Optional Admin with the ability to execute DAO messages
@@ -62,9 +86,21 @@ pub enum ExecuteMsg {
     ExecuteProposalHook { msgs: Vec<CosmosMsg<Empty>> },
     /// Pauses the DAO for a set duration.
     /// When paused the DAO is unable to execute proposals
-    Pause { duration: Duration },
-    /// Unpauses the DAO
-    Unpause {},
+    ///
+    /// If `scope` is set, only that part of the DAO is paused and
+    /// other activity continues. Pausing a scope that is already
+    /// paused replaces the existing pause.
+    ///
+    /// Callable by the core contract and active guardians. Pauses by
+    /// guardians must end before the guardians' powers expire, and
+    /// never shorten an existing pause.
+    Pause {
+        duration: Duration,
+        scope: Option<PauseScope>,
+    },
+    /// Unpauses the DAO. If `scope` is set, only the pause of that
+    /// scope is lifted.
+    Unpause { scope: Option<PauseScope> },
     /// Executed when the contract receives a cw20 token. Depending on
     /// the contract's configuration the contract will automatically
     /// add the token to its treasury.
@@ -122,15 +158,146 @@ pub enum ExecuteMsg {
         to_add: Vec<ModuleInstantiateInfo>,
         to_disable: Vec<String>,
     },
+    /// Callable by the core contract and active guardians. Disables
+    /// a proposal module.
+    DisableProposalModule { address: String },
     /// Callable by the core contract. Replaces the current
     /// voting module with a new one instantiated by the governance
     /// contract.
     UpdateVotingModule { module: ModuleInstantiateInfo },
-    /// Update the core module to add/remove SubDAOs and their charters
+    /// Update the core module to add/remove SubDAOs and their charters.
+    /// Installs or removes ratification rules on SubDAOs whose
+    /// ratification rules change.
     UpdateSubDaos {
         to_add: Vec<SubDao>,
         to_remove: Vec<String>,
     },
+    /// Callable by the core contract. Schedules messages to be
+    /// executed by the DAO at a later time. Proposals schedule
+    /// messages by including this message in the messages they
+    /// execute via `ExecuteProposalHook`.
+    Schedule {
+        /// The proposal module of the proposal scheduling the
+        /// messages. Must be the module whose messages are being
+        /// executed.
+        proposal_module: String,
+        /// The ID of the proposal scheduling the messages. Messages
+        /// scheduled by a proposal may be cancelled together with
+        /// `CancelScheduled`.
+        proposal_id: u64,
+        schedule: Vec<ScheduleInfo>,
+    },
+    /// Callable by the core contract and active guardians. Cancels
+    /// all pending messages scheduled by a proposal.
+    CancelScheduled {
+        proposal_module: String,
+        proposal_id: u64,
+    },
+    /// Callable by anyone. Executes scheduled messages once their
+    /// execution time has passed. Scheduled messages can not be
+    /// executed while the DAO is paused.
+    ExecuteScheduled { id: u64 },
+    /// Callable by the core contract. Restricts the messages a
+    /// proposal module may execute via `ExecuteProposalHook` to those
+    /// permitted by at least one of `authorizations`, replacing any
+    /// existing authorizations for the module.
+    SetModuleAuthorizations {
+        module: String,
+        authorizations: Vec<Authorization>,
+    },
+    /// Callable by the core contract. Removes a proposal module's
+    /// authorizations, allowing it to execute any message.
+    RemoveModuleAuthorizations { module: String },
+    /// Callable by the core contract. Sets or removes caps on the
+    /// amount of a token that proposal modules may spend via
+    /// `ExecuteProposalHook` over a rolling window. Caps apply to
+    /// native tokens sent, burned, transferred over IBC or attached to
+    /// contract messages, and to cw20 `Transfer`, `Send`, `Burn` and
+    /// `IncreaseAllowance` messages. While a proposal module's
+    /// messages are executing, only callable if that module is exempt
+    /// from spending caps.
+    UpdateSpendingCaps {
+        to_set: Vec<SpendingCap>,
+        to_remove: Vec<SpendDenom>,
+    },
+    /// Callable by the core contract. Updates the proposal modules
+    /// that are exempt from spending caps. Typically these are
+    /// modules with a higher passing threshold than the DAO's other
+    /// proposal modules. Spending by these modules does not count
+    /// towards caps. While a proposal module's messages are
+    /// executing, only callable if that module is exempt.
+    UpdateSpendingCapOverrideModules {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+    /// Callable by the core contract. Sets the DAO's guardians,
+    /// replacing any existing guardians. Guardian powers must expire
+    /// and may be renewed by setting them again. If `None`, removes
+    /// the DAO's guardians.
+    UpdateGuardians { guardians: Option<GuardianInfo> },
+    /// Callable by the admin, typically a parent DAO, which becomes
+    /// the ratifier. Requires that messages executed by proposals
+    /// that match any of `rules` are held until ratified by the
+    /// ratifier, replacing any existing rules.
+    SetRatification { rules: Vec<RatificationRule> },
+    /// Callable by the admin and the ratifier. Removes ratification
+    /// requirements. Messages pending ratification are dropped and
+    /// may not be ratified if ratification is required again.
+    RemoveRatification {},
+    /// Callable by the ratifier. Executes messages held for
+    /// ratification.
+    Ratify { id: u64 },
+    /// Callable by the ratifier. Drops messages held for
+    /// ratification.
+    RejectRatification { id: u64 },
+    /// Callable by the core module. Sets how the DAO stakes its
+    /// native tokens. Must be set before the DAO can stake.
+    UpdateStakingConfig { config: StakingConfig },
+    /// Callable by the core module. Delegates the chain's staking
+    /// denom to a validator. Not supported on chains without staking.
+    Delegate {
+        /// The validator to delegate to.
+        validator: String,
+        /// The amount to delegate.
+        amount: Uint128,
+    },
+    /// Callable by the core module. Redelegates tokens between
+    /// validators. Only tokens that may be redelegated immediately can
+    /// be redelegated.
+    Redelegate {
+        src_validator: String,
+        dst_validator: String,
+        amount: Uint128,
+    },
+    /// Callable by the core module. Begins unbonding tokens from a
+    /// validator. Unbonding tokens are listed by the `Unbondings`
+    /// query.
+    Undelegate {
+        /// The validator to undelegate from.
+        validator: String,
+        /// The amount to undelegate.
+        amount: Uint128,
+    },
+    /// Withdraws the DAO's staking rewards from validators. Callable
+    /// by anyone. If auto-compounding is enabled the rewards are
+    /// delegated back to the validator they were earned from.
+    ClaimStakingRewards {
+        /// The validators to withdraw rewards from.
+        validators: Vec<String>,
+    },
+    /// Callable by the core module. Registers a slash of the DAO's
+    /// bonded or unbonding tokens. The contract can not verify that
+    /// the slash occurred, so the DAO is assumed to be honest.
+    RegisterSlash {
+        /// The validator the slash occurred for.
+        validator: String,
+        /// The time the slash occurred. Must not be in the future.
+        time: Timestamp,
+        /// The number of tokens slashed.
+        amount: Uint128,
+        /// If the slashed tokens were unbonding.
+        during_unbonding: bool,
+    },
 }
 
 #[cw_serde]
@@ -229,6 +396,96 @@ pub enum QueryMsg {
     /// Returns the total voting power at a given block height.
     #[returns(crate::voting::TotalPowerAtHeightResponse)]
     TotalPowerAtHeight { height: Option<u64> },
+    /// Lists messages that have been scheduled for execution and
+    /// have not yet been executed or cancelled, in ascending order of
+    /// ID.
+    #[returns(Vec<crate::query::ScheduledMsgsResponse>)]
+    ListScheduled {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists the pending messages scheduled by a proposal, in
+    /// ascending order of ID.
+    #[returns(Vec<crate::query::ScheduledMsgsResponse>)]
+    ProposalScheduled {
+        proposal_module: String,
+        proposal_id: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the authorizations restricting the messages a proposal
+    /// module may execute. A module without authorizations may
+    /// execute any message.
+    #[returns(crate::authorization::ModuleAuthorizationsResponse)]
+    ModuleAuthorizations { module: String },
+    /// Lists the proposal modules that have authorizations.
+    #[returns(Vec<crate::authorization::ModuleAuthorizationsResponse>)]
+    ListModuleAuthorizations {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Checks whether a proposal module's authorizations would
+    /// permit it to execute `msgs`, returning the result for each
+    /// message in order. Explains which authorization permits each
+    /// message, or why none do.
+    #[returns(Vec<crate::authorization::AuthorizationResult>)]
+    CheckAuthorizations {
+        module: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
+    /// Lists spending caps and the amount spent in each cap's
+    /// current window.
+    #[returns(Vec<crate::spending::SpendingCapResponse>)]
+    SpendingCaps {
+        start_after: Option<SpendDenom>,
+        limit: Option<u32>,
+    },
+    /// Lists the proposal modules that are exempt from spending
+    /// caps.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    SpendingCapOverrideModules {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the DAO's guardians, if any. Guardians have no powers
+    /// once their expiration has passed.
+    #[returns(Option<crate::state::Guardians>)]
+    Guardians {},
+    /// Gets the ratification required of the DAO by its parent, if
+    /// any.
+    #[returns(Option<crate::ratification::Ratification>)]
+    Ratification {},
+    /// Lists messages held for ratification, in ascending order of
+    /// ID.
+    #[returns(Vec<crate::ratification::PendingRatificationResponse>)]
+    PendingRatifications {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists the DAO's holdings of one kind of asset, ordered by
+    /// `TreasuryAsset::key`.
+    #[returns(crate::treasury::TreasuryResponse)]
+    Treasury {
+        kind: TreasuryKind,
+        /// The key of the asset to start listing after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the DAO's staking config.
+    #[returns(Option<crate::staking::StakingConfig>)]
+    StakingConfig {},
+    /// Queries the DAO's bonded and unbonding native token balances
+    /// as tracked by the contract. See `StakeTrackerQuery` in
+    /// `packages/cw-stake-tracker` for query methods.
+    #[returns(::cosmwasm_std::Uint128)]
+    Stake(StakeTrackerQuery),
+    /// Lists tokens the DAO is unbonding that have not yet been
+    /// returned, in the order they were undelegated.
+    #[returns(Vec<crate::staking::UnbondingResponse>)]
+    Unbondings {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -3,7 +3,8 @@ use cosmwasm_std::{Addr, Uint128};
 use cw2::ContractVersion;
 use cw_utils::Expiration;
 
-use crate::state::{Config, ProposalModule};
+use crate::ratification::RatificationRule;
+use crate::state::{Config, ProposalModule, ScheduledMsgs, ScopedPause};
 
 /// Relevant state for the governance module. Returned by the
 /// `DumpState` query.
@@ -31,8 +32,19 @@ pub struct DumpStateResponse {
 /// Information about if the contract is currently paused.
 #[cw_serde]
 pub enum PauseInfoResponse {
-    Paused { expiration: Expiration },
-    Unpaused {},
+    /// The whole DAO is paused. `scopes` lists pauses of parts of the
+    /// DAO that will still apply once this pause ends.
+    Paused {
+        expiration: Expiration,
+        #[serde(default)]
+        scopes: Vec<ScopedPause>,
+    },
+    /// The DAO as a whole is not paused. `scopes` lists the parts of
+    /// the DAO that are.
+    Unpaused {
+        #[serde(default)]
+        scopes: Vec<ScopedPause>,
+    },
 }
 
 /// Returned by the `GetItem` query.
@@ -66,6 +78,11 @@ pub struct SubDao {
     pub addr: String,
     /// The purpose/constitution for the SubDAO
     pub charter: Option<String>,
+    /// Actions of the SubDAO that require ratification by this DAO.
+    /// If set when adding a SubDAO, this DAO must be the SubDAO's
+    /// admin and installs the rules on the SubDAO.
+    #[serde(default)]
+    pub ratification: Option<Vec<RatificationRule>>,
 }
 
 #[cw_serde]
@@ -80,3 +97,12 @@ pub struct ProposalModuleCountResponse {
     /// The total number of proposal modules.
     pub total_proposal_module_count: u32,
 }
+
+/// Returned by the `ListScheduled` and `ProposalScheduled` queries.
+#[cw_serde]
+pub struct ScheduledMsgsResponse {
+    /// The ID of the scheduled messages. Used to execute them with
+    /// `ExecuteScheduled`.
+    pub id: u64,
+    pub scheduled: ScheduledMsgs,
+}
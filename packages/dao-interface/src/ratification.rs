@@ -0,0 +1,58 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, Empty, Uint128};
+
+use crate::spending::SpendDenom;
+
+/// A kind of sub-DAO action that requires ratification by the parent
+/// DAO before it is executed.
+#[cw_serde]
+pub enum RatificationRule {
+    /// All messages executed by proposals require ratification.
+    All {},
+    /// Messages that together spend more than `threshold` of `denom`
+    /// require ratification. Spending is counted in the same way as
+    /// for spending caps.
+    SpendAbove {
+        denom: SpendDenom,
+        threshold: Uint128,
+    },
+}
+
+impl RatificationRule {
+    /// Whether messages spending `spent` of this rule's denom, as
+    /// returned by `spent`, require ratification.
+    pub fn applies(&self, spent: impl Fn(&SpendDenom) -> Uint128) -> bool {
+        match self {
+            RatificationRule::All {} => true,
+            RatificationRule::SpendAbove { denom, threshold } => spent(denom) > *threshold,
+        }
+    }
+}
+
+/// Ratification of a sub-DAO's actions required by its parent DAO.
+#[cw_serde]
+pub struct Ratification {
+    /// The parent DAO that must ratify actions.
+    pub ratifier: Addr,
+    /// The actions that require ratification. Messages require
+    /// ratification if any rule applies to them.
+    pub rules: Vec<RatificationRule>,
+}
+
+/// Messages held until they are ratified by the parent DAO.
+#[cw_serde]
+pub struct PendingRatification {
+    /// The proposal module that executed the messages.
+    pub proposal_module: Addr,
+    /// The messages to execute once ratified.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+}
+
+/// Returned by the `PendingRatifications` query.
+#[cw_serde]
+pub struct PendingRatificationResponse {
+    /// The ID of the pending messages. Used to ratify them with
+    /// `Ratify`.
+    pub id: u64,
+    pub pending: PendingRatification,
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, Timestamp, Uint128};
+use cw_utils::Duration;
+
+/// A token that a DAO may cap spending of.
+#[cw_serde]
+pub enum SpendDenom {
+    /// A native token, spent by `BankMsg::Send` and `BankMsg::Burn`
+    /// and by funds attached to contract messages.
+    Native(String),
+    /// A cw20 token, spent by cw20 `Transfer`, `Send`, `Burn` and
+    /// `IncreaseAllowance` messages.
+    Cw20(String),
+}
+
+impl SpendDenom {
+    /// The key under which state for this denom is stored.
+    pub fn key(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for SpendDenom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpendDenom::Native(denom) => write!(f, "native:{denom}"),
+            SpendDenom::Cw20(address) => write!(f, "cw20:{address}"),
+        }
+    }
+}
+
+/// A limit on the amount of a token that proposal modules may spend
+/// over a rolling window.
+#[cw_serde]
+pub struct SpendingCap {
+    pub denom: SpendDenom,
+    /// The most that may be spent in any window.
+    pub amount: Uint128,
+    /// The length of the window.
+    pub window: Duration,
+}
+
+/// An amount spent at a point in time.
+#[cw_serde]
+pub struct SpendRecord {
+    pub amount: Uint128,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+impl SpendRecord {
+    /// Returns true if this spend happened within `window` of
+    /// `block`.
+    pub fn in_window(&self, window: &Duration, block: &BlockInfo) -> bool {
+        match window {
+            Duration::Height(blocks) => block.height < self.height.saturating_add(*blocks),
+            Duration::Time(seconds) => block.time < self.time.plus_seconds(*seconds),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct SpendingCapResponse {
+    pub cap: SpendingCap,
+    /// The amount spent in the current window.
+    pub spent: Uint128,
+}
@@ -0,0 +1,29 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128};
+
+/// Configuration for staking the DAO's native tokens.
+#[cw_serde]
+pub struct StakingConfig {
+    /// The chain's unbonding period in seconds. Used to track when
+    /// undelegated tokens are returned to the DAO.
+    pub unbonding_duration_seconds: u64,
+    /// If true, rewards withdrawn with `ClaimStakingRewards` are
+    /// delegated back to the validator they were earned from.
+    pub auto_compound: bool,
+}
+
+/// Tokens being unbonded from a validator.
+#[cw_serde]
+pub struct Unbonding {
+    pub validator: String,
+    pub amount: Uint128,
+    /// When the tokens are returned to the DAO.
+    pub completes_at: Timestamp,
+}
+
+/// An entry in an `Unbondings` query response.
+#[cw_serde]
+pub struct UnbondingResponse {
+    pub id: u64,
+    pub unbonding: Unbonding,
+}
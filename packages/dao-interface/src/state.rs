@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, WasmMsg};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Empty, WasmMsg};
+use cw_utils::Expiration;
 
 /// Top level config type for core module.
 #[cw_serde]
@@ -40,6 +41,70 @@ pub enum ProposalModuleStatus {
     Disabled,
 }
 
+/// Messages that a proposal has scheduled for execution by the DAO at
+/// a later time.
+#[cw_serde]
+pub struct ScheduledMsgs {
+    /// The proposal module of the proposal that scheduled the
+    /// messages.
+    pub proposal_module: Addr,
+    /// The ID of the proposal that scheduled the messages.
+    pub proposal_id: u64,
+    /// The messages may be executed once this has expired.
+    pub execute_at: Expiration,
+    /// The messages to execute.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+}
+
+/// A part of a DAO that may be paused while other activity continues.
+#[cw_serde]
+pub enum PauseScope {
+    /// The proposal module may not execute messages, and messages it
+    /// has scheduled may not be executed.
+    ProposalModule { address: String },
+    /// Messages that move tokens out of the treasury may not be
+    /// executed. These are bank sends, IBC transfers, cw20 and cw721
+    /// transfers and sends, contract executions with funds attached,
+    /// and Stargate messages.
+    TreasuryTransfers {},
+    /// The DAO's proposal modules and voting module may not be
+    /// updated.
+    ModuleUpdates {},
+}
+
+impl PauseScope {
+    /// The key this scope is stored under.
+    pub fn key(&self) -> String {
+        match self {
+            PauseScope::ProposalModule { address } => format!("proposal_module/{address}"),
+            PauseScope::TreasuryTransfers {} => "treasury_transfers".to_string(),
+            PauseScope::ModuleUpdates {} => "module_updates".to_string(),
+        }
+    }
+}
+
+/// A pause that applies to part of a DAO.
+#[cw_serde]
+pub struct ScopedPause {
+    /// The part of the DAO that is paused.
+    pub scope: PauseScope,
+    /// The time the pause ends.
+    pub expiration: Expiration,
+}
+
+/// Addresses that may take emergency actions on behalf of a DAO
+/// without a proposal. Guardians may pause the DAO, disable proposal
+/// modules, and cancel scheduled messages, but may not move funds.
+#[cw_serde]
+pub struct Guardians {
+    /// The guardian addresses. A guardian may be another DAO, in
+    /// which case it acts according to its own proposal modules.
+    pub guardians: Vec<Addr>,
+    /// Guardians lose their powers once this expires unless renewed
+    /// by the DAO.
+    pub expiration: Expiration,
+}
+
 /// Information about the CosmWasm level admin of a contract. Used in
 /// conjunction with `ModuleInstantiateInfo` to instantiate modules.
 #[cw_serde]
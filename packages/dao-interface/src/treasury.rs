@@ -0,0 +1,80 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+
+use crate::spending::SpendDenom;
+
+/// A kind of asset held by a DAO's treasury.
+#[cw_serde]
+pub enum TreasuryKind {
+    /// Native token balances.
+    Native {},
+    /// Balances of the DAO's registered cw20 tokens.
+    Cw20 {},
+    /// NFTs owned by the DAO in its registered cw721 collections.
+    Cw721 {},
+    /// Native tokens delegated to validators. Empty on chains without
+    /// staking support.
+    Delegations {},
+    /// Vesting contracts owned by the DAO that have not fully vested,
+    /// found via the vesting contracts the DAO has instantiated with
+    /// `payroll_factory`.
+    Vesting { payroll_factory: String },
+}
+
+/// An asset held by a DAO's treasury.
+#[cw_serde]
+pub enum TreasuryAsset {
+    Native {
+        denom: String,
+    },
+    Cw20 {
+        contract: Addr,
+    },
+    Cw721 {
+        contract: Addr,
+        token_id: String,
+    },
+    Delegation {
+        denom: String,
+        validator: String,
+    },
+    /// A cw-vesting contract. The amount of the asset is the number
+    /// of tokens that have not yet vested.
+    Vesting {
+        contract: Addr,
+        denom: SpendDenom,
+    },
+}
+
+impl TreasuryAsset {
+    /// The key assets are ordered by in `Treasury` query responses.
+    pub fn key(&self) -> String {
+        match self {
+            TreasuryAsset::Native { denom } => denom.clone(),
+            TreasuryAsset::Cw20 { contract } | TreasuryAsset::Vesting { contract, .. } => {
+                contract.to_string()
+            }
+            TreasuryAsset::Cw721 { contract, token_id } => format!("{contract}/{token_id}"),
+            TreasuryAsset::Delegation { validator, .. } => validator.clone(),
+        }
+    }
+}
+
+/// An entry in a `Treasury` query response.
+#[cw_serde]
+pub struct TreasuryItem {
+    pub asset: TreasuryAsset,
+    /// The amount of the asset held. Always one for NFTs.
+    pub amount: Uint128,
+}
+
+/// A page of a DAO's holdings returned by the `Treasury` query.
+#[cw_serde]
+pub struct TreasuryResponse {
+    pub items: Vec<TreasuryItem>,
+    /// Pass as `start_after` to get the next page. None once all
+    /// holdings have been listed. As holdings that require querying
+    /// other contracts may be skipped, a page may have fewer items
+    /// than requested, or none, while there are more to come.
+    pub start_after: Option<String>,
+}
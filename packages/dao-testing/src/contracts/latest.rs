@@ -59,6 +59,28 @@ pub fn dao_proposal_condorcet_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn dao_proposal_conviction_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_conviction::contract::execute,
+        dao_proposal_conviction::contract::instantiate,
+        dao_proposal_conviction::contract::query,
+    )
+    .with_reply(dao_proposal_conviction::contract::reply)
+    .with_migrate(dao_proposal_conviction::contract::migrate);
+    Box::new(contract)
+}
+
+pub fn dao_proposal_optimistic_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_optimistic::contract::execute,
+        dao_proposal_optimistic::contract::instantiate,
+        dao_proposal_optimistic::contract::query,
+    )
+    .with_reply(dao_proposal_optimistic::contract::reply)
+    .with_migrate(dao_proposal_optimistic::contract::migrate);
+    Box::new(contract)
+}
+
 pub fn dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_proposal_single::contract::execute,
@@ -81,6 +103,17 @@ pub fn dao_proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn dao_proposal_multistage_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_multistage::contract::execute,
+        dao_proposal_multistage::contract::instantiate,
+        dao_proposal_multistage::contract::query,
+    )
+    .with_reply(dao_proposal_multistage::contract::reply)
+    .with_migrate(dao_proposal_multistage::contract::migrate);
+    Box::new(contract)
+}
+
 pub fn dao_proposal_sudo_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_proposal_sudo::contract::execute,
@@ -130,6 +163,16 @@ pub fn dao_pre_propose_approval_single_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn dao_vote_delegation_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_vote_delegation::contract::execute,
+        dao_vote_delegation::contract::instantiate,
+        dao_vote_delegation::contract::query,
+    )
+    .with_migrate(dao_vote_delegation::contract::migrate);
+    Box::new(contract)
+}
+
 pub fn dao_voting_cw4_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_voting_cw4::contract::execute,
@@ -162,6 +205,26 @@ pub fn dao_voting_cw20_balance_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn dao_voting_composite_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_voting_composite::contract::execute,
+        dao_voting_composite::contract::instantiate,
+        dao_voting_composite::contract::query,
+    )
+    .with_migrate(dao_voting_composite::contract::migrate);
+    Box::new(contract)
+}
+
+pub fn dao_voting_token_locked_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_voting_token_locked::contract::execute,
+        dao_voting_token_locked::contract::instantiate,
+        dao_voting_token_locked::contract::query,
+    )
+    .with_migrate(dao_voting_token_locked::contract::migrate);
+    Box::new(contract)
+}
+
 pub fn dao_voting_token_staked_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_voting_token_staked::contract::execute,
@@ -151,6 +151,7 @@ pub fn instantiate_with_staked_balances_governance(
                     unstaking_duration: Some(Duration::Height(6)),
                     initial_dao_balance: None,
                 },
+                power_cap: None,
             })
             .unwrap(),
             admin: None,
@@ -272,6 +273,7 @@ pub fn instantiate_with_staking_active_threshold(
                     initial_dao_balance: None,
                 },
                 active_threshold,
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
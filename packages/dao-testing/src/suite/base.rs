@@ -96,6 +96,8 @@ pub trait DaoTestingSuite<Extra = Empty> {
                     },
                 },
                 close_proposal_on_execution_failure: true,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
+                expedited: None,
                 veto: None,
             })
             .unwrap(),
@@ -133,6 +135,7 @@ pub trait DaoTestingSuite<Extra = Empty> {
                     },
                 },
                 close_proposal_on_execution_failure: true,
+                vote_weighting: dao_voting::voting::VoteWeighting::Linear {},
                 veto: None,
             })
             .unwrap(),
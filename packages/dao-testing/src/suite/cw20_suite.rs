@@ -159,6 +159,7 @@ impl<'a> DaoTestingSuite<Cw20DaoExtra> for DaoTestingSuiteCw20<'a> {
                     initial_dao_balance: Some(self.initial_dao_balance),
                 },
                 active_threshold: self.active_threshold.clone(),
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(dao_interface::state::Admin::CoreModule {}),
@@ -156,6 +156,7 @@ impl<'a> DaoTestingSuite<Cw721DaoExtra> for DaoTestingSuiteCw721<'a> {
                 },
                 unstaking_duration: self.unstaking_duration,
                 active_threshold: self.active_threshold.clone(),
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(dao_interface::state::Admin::CoreModule {}),
@@ -145,6 +145,9 @@ impl<'a> DaoTestingSuite<TokenDaoExtra> for DaoTestingSuiteToken<'a> {
                 },
                 unstaking_duration: self.unstaking_duration,
                 active_threshold: self.active_threshold.clone(),
+                derivative: None,
+                bonus_schedule: vec![],
+                power_cap: None,
             })
             .unwrap(),
             admin: Some(dao_interface::state::Admin::CoreModule {}),
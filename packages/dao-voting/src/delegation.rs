@@ -0,0 +1,70 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+
+/// The subset of a delegation registry's query interface that
+/// proposal modules rely on to count delegated voting power.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum DelegationQuery {
+    /// Returns the address (if any) that `delegator` had delegated
+    /// their voting power to at `height`. A height of None will
+    /// query for the current block height.
+    #[returns(DelegationResponse)]
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Returns the addresses that had delegated their voting power
+    /// to `delegate` at `height`. A height of None will query for the
+    /// current block height.
+    #[returns(DelegatorsResponse)]
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+    },
+}
+
+#[cw_serde]
+pub struct DelegationResponse {
+    /// The address voting power is delegated to, or None if the
+    /// delegator had not delegated at the queried height.
+    pub delegate: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct DelegatorsResponse {
+    /// The addresses that have delegated their voting power to the
+    /// queried delegate.
+    pub delegators: Vec<Addr>,
+}
+
+/// Voting power that a delegate cast on behalf of a delegator. Stored
+/// by proposal modules so that the power can be removed from the
+/// delegate's ballot if the delegator later votes directly.
+#[cw_serde]
+pub struct DelegatedVote {
+    /// The address that cast the vote on behalf of the delegator.
+    pub delegate: Addr,
+    /// The amount of the delegator's voting power that was included
+    /// in the delegate's ballot. This is the delegator's voting power
+    /// before any vote weighting is applied.
+    pub power: Uint128,
+}
+
+/// Queries `delegation_module` for the addresses that had delegated
+/// their voting power to `delegate` at `height`.
+pub fn get_delegators(
+    deps: Deps,
+    delegation_module: &Addr,
+    delegate: &Addr,
+    height: u64,
+) -> StdResult<Vec<Addr>> {
+    let response: DelegatorsResponse = deps.querier.query_wasm_smart(
+        delegation_module,
+        &DelegationQuery::Delegators {
+            delegate: delegate.to_string(),
+            height: Some(height),
+        },
+    )?;
+    Ok(response.delegators)
+}
@@ -11,4 +11,31 @@ pub enum VotingError {
 
     #[error("Min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
+
+    #[error("Quadratic vote weighting can not be used with an absolute percentage threshold as the total weight of all votes is not known")]
+    QuadraticAbsolutePercentage {},
+
+    #[error("Proposal ({id}) was not executed one message at a time")]
+    NoPartialExecution { id: u64 },
+
+    #[error("At least one message index must be provided")]
+    NoRetryIndexes {},
+
+    #[error("Message ({index}) has not failed and can not be retried")]
+    NotRetryable { index: u32 },
+
+    #[error("The window for retrying failed messages has expired")]
+    RetryWindowExpired {},
+
+    #[error("Proposals executed one message at a time may have at most {max} messages")]
+    TooManyPartialExecutionMsgs { max: usize },
+
+    #[error("Proposal ({proposal_id}) in module ({module}) does not exist in this DAO")]
+    InvalidProposalRef { module: String, proposal_id: u64 },
+
+    #[error("Proposals may depend on or conflict with at most {max} proposals")]
+    TooManyProposalRelations { max: usize },
+
+    #[error("Proposal ({proposal_id}) in module ({module}) is in another proposal module, so a conflict with it can not be enforced")]
+    CrossModuleConflict { module: String, proposal_id: u64 },
 }
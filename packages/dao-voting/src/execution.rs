@@ -0,0 +1,353 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, BlockInfo, CosmosMsg, Empty, Event, StdError, StdResult, SubMsg,
+    SubMsgResult, WasmMsg,
+};
+use cw_utils::{Duration, Expiration};
+
+use crate::error::VotingError;
+use crate::reply::{mask_partial_proposal_execution_id, MAX_PARTIAL_EXECUTION_MSGS};
+
+/// The most bytes of data, events and errors recorded for an
+/// execution. Results are truncated to this size so that the cost of
+/// recording them does not grow with the output of the messages
+/// executed.
+pub const MAX_RECORDED_RESULT_BYTES: usize = 4096;
+
+/// The outcome of executing a proposal's messages.
+#[cw_serde]
+pub enum ExecutionResult {
+    /// The messages were executed.
+    Success {
+        /// The data returned by the DAO, if any.
+        data: Option<Binary>,
+        /// The events emitted while executing the messages.
+        events: Vec<Event>,
+        /// Whether data or events were dropped as they exceeded
+        /// `MAX_RECORDED_RESULT_BYTES`.
+        #[serde(default)]
+        truncated: bool,
+    },
+    /// Execution failed and the messages had no effect.
+    Failure {
+        /// The error execution failed with, truncated to
+        /// `MAX_RECORDED_RESULT_BYTES`.
+        error: String,
+    },
+}
+
+impl ExecutionResult {
+    pub fn is_success(&self) -> bool {
+        matches!(self, ExecutionResult::Success { .. })
+    }
+}
+
+impl From<SubMsgResult> for ExecutionResult {
+    fn from(result: SubMsgResult) -> Self {
+        match result {
+            SubMsgResult::Ok(response) => {
+                let mut remaining = MAX_RECORDED_RESULT_BYTES;
+                let mut truncated = false;
+
+                let data = response.data.filter(|data| {
+                    let fits = data.len() <= remaining;
+                    truncated |= !fits;
+                    fits
+                });
+                remaining -= data.as_ref().map_or(0, |data| data.len());
+
+                let mut events = vec![];
+                for event in response.events {
+                    let size = event_size(&event);
+                    if size > remaining {
+                        truncated = true;
+                        break;
+                    }
+                    remaining -= size;
+                    events.push(event);
+                }
+
+                ExecutionResult::Success {
+                    data,
+                    events,
+                    truncated,
+                }
+            }
+            SubMsgResult::Err(mut error) => {
+                if error.len() > MAX_RECORDED_RESULT_BYTES {
+                    let mut end = MAX_RECORDED_RESULT_BYTES;
+                    while !error.is_char_boundary(end) {
+                        end -= 1;
+                    }
+                    error.truncate(end);
+                }
+                ExecutionResult::Failure { error }
+            }
+        }
+    }
+}
+
+/// The number of bytes an event counts for against
+/// `MAX_RECORDED_RESULT_BYTES`.
+fn event_size(event: &Event) -> usize {
+    event.ty.len()
+        + event
+            .attributes
+            .iter()
+            .map(|a| a.key.len() + a.value.len())
+            .sum::<usize>()
+}
+
+/// Configuration for executing proposals one message at a time.
+#[cw_serde]
+pub struct PartialExecutionConfig {
+    /// How long after a proposal is executed its failed messages may
+    /// be retried with `RetryFailed`.
+    pub retry_window: Duration,
+}
+
+/// A proposal executed one message at a time. Each message is
+/// executed by the DAO in its own submessage so that one message
+/// failing does not revert the others.
+#[cw_serde]
+pub struct PartialExecution {
+    /// The messages being executed.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The result of executing each message, by index. `None` while a
+    /// message is being executed.
+    pub results: Vec<Option<ExecutionResult>>,
+    /// Failed messages may be retried until this expires.
+    pub retry_until: Expiration,
+}
+
+impl PartialExecution {
+    pub fn new(
+        msgs: Vec<CosmosMsg<Empty>>,
+        config: &PartialExecutionConfig,
+        block: &BlockInfo,
+    ) -> Result<Self, VotingError> {
+        if msgs.len() > MAX_PARTIAL_EXECUTION_MSGS {
+            return Err(VotingError::TooManyPartialExecutionMsgs {
+                max: MAX_PARTIAL_EXECUTION_MSGS,
+            });
+        }
+        Ok(Self {
+            results: vec![None; msgs.len()],
+            msgs,
+            retry_until: config.retry_window.after(block),
+        })
+    }
+
+    /// The indexes of all messages.
+    pub fn all_indexes(&self) -> Vec<u32> {
+        (0..self.msgs.len() as u32).collect()
+    }
+
+    /// Checks that the messages at `indexes` may be retried. Returns
+    /// the indexes sorted and without duplicates.
+    pub fn retryable(
+        &self,
+        mut indexes: Vec<u32>,
+        block: &BlockInfo,
+    ) -> Result<Vec<u32>, VotingError> {
+        if indexes.is_empty() {
+            return Err(VotingError::NoRetryIndexes {});
+        }
+        if self.retry_until.is_expired(block) {
+            return Err(VotingError::RetryWindowExpired {});
+        }
+        indexes.sort_unstable();
+        indexes.dedup();
+        for &index in &indexes {
+            if !matches!(
+                self.results.get(index as usize),
+                Some(Some(ExecutionResult::Failure { .. }))
+            ) {
+                return Err(VotingError::NotRetryable { index });
+            }
+        }
+        Ok(indexes)
+    }
+
+    /// Prepares submessages executing the messages at `indexes` with
+    /// `dao`. The results of those messages are cleared until their
+    /// replies are recorded.
+    pub fn execute(
+        &mut self,
+        dao: &Addr,
+        proposal_id: u64,
+        indexes: &[u32],
+    ) -> StdResult<Vec<SubMsg>> {
+        indexes
+            .iter()
+            .map(|&index| {
+                self.results[index as usize] = None;
+                let execute = WasmMsg::Execute {
+                    contract_addr: dao.to_string(),
+                    msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+                        msgs: vec![self.msgs[index as usize].clone()],
+                    })?,
+                    funds: vec![],
+                };
+                Ok(SubMsg::reply_always(
+                    execute,
+                    mask_partial_proposal_execution_id(proposal_id, index),
+                ))
+            })
+            .collect()
+    }
+
+    /// The outcome of executing all of the messages, or `None` while
+    /// some are still being executed. Execution succeeded if any
+    /// message succeeded, in which case the events of the messages
+    /// that succeeded are recorded up to
+    /// `MAX_RECORDED_RESULT_BYTES`. Otherwise it failed with the
+    /// error of the first message.
+    pub fn outcome(&self) -> Option<ExecutionResult> {
+        let results = self.results.iter().collect::<Option<Vec<_>>>()?;
+
+        let mut succeeded = false;
+        let mut remaining = MAX_RECORDED_RESULT_BYTES;
+        let mut events = vec![];
+        let mut truncated = false;
+        for result in &results {
+            if let ExecutionResult::Success {
+                events: msg_events,
+                truncated: msg_truncated,
+                ..
+            } = result
+            {
+                succeeded = true;
+                truncated |= *msg_truncated;
+                for event in msg_events {
+                    let size = event_size(event);
+                    if size > remaining {
+                        truncated = true;
+                        break;
+                    }
+                    remaining -= size;
+                    events.push(event.clone());
+                }
+            }
+        }
+
+        if succeeded {
+            return Some(ExecutionResult::Success {
+                data: None,
+                events,
+                truncated,
+            });
+        }
+        results
+            .into_iter()
+            .find(|result| !result.is_success())
+            .cloned()
+    }
+
+    /// Records the result of executing the message at `index`.
+    pub fn record(&mut self, index: u32, result: ExecutionResult) -> StdResult<()> {
+        match self.results.get_mut(index as usize) {
+            Some(entry) => {
+                *entry = Some(result);
+                Ok(())
+            }
+            None => Err(StdError::generic_err(format!(
+                "no message with index ({index})"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::SubMsgResponse;
+
+    use super::*;
+
+    #[test]
+    fn test_execution_result_truncation() {
+        let event = |value: &str| Event::new("wasm").add_attribute("key", value);
+        let result = ExecutionResult::from(SubMsgResult::Ok(SubMsgResponse {
+            events: vec![
+                event("small"),
+                event(&"x".repeat(MAX_RECORDED_RESULT_BYTES)),
+            ],
+            data: Some(Binary::from(vec![0; 8])),
+        }));
+        assert_eq!(
+            result,
+            ExecutionResult::Success {
+                data: Some(Binary::from(vec![0; 8])),
+                events: vec![event("small")],
+                truncated: true,
+            }
+        );
+
+        let result = ExecutionResult::from(SubMsgResult::Ok(SubMsgResponse {
+            events: vec![event("small")],
+            data: Some(Binary::from(vec![0; MAX_RECORDED_RESULT_BYTES + 1])),
+        }));
+        assert_eq!(
+            result,
+            ExecutionResult::Success {
+                data: None,
+                events: vec![event("small")],
+                truncated: true,
+            }
+        );
+
+        // Errors are truncated on a character boundary.
+        let result =
+            ExecutionResult::from(SubMsgResult::Err("é".repeat(MAX_RECORDED_RESULT_BYTES)));
+        match result {
+            ExecutionResult::Failure { error } => {
+                assert_eq!(error, "é".repeat(MAX_RECORDED_RESULT_BYTES / 2))
+            }
+            result => panic!("expected failure, got {result:?}"),
+        }
+    }
+    #[test]
+    fn test_partial_execution_outcome() {
+        let msg: CosmosMsg = cosmwasm_std::BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: vec![],
+        }
+        .into();
+        let config = PartialExecutionConfig {
+            retry_window: Duration::Height(10),
+        };
+        let block = cosmwasm_std::testing::mock_env().block;
+        let mut execution = PartialExecution::new(vec![msg.clone(), msg], &config, &block).unwrap();
+
+        let failure = ExecutionResult::Failure {
+            error: "failed".to_string(),
+        };
+        execution.record(0, failure.clone()).unwrap();
+        assert_eq!(execution.outcome(), None);
+
+        // Execution fails if no message succeeded.
+        execution.record(1, failure.clone()).unwrap();
+        assert_eq!(execution.outcome(), Some(failure));
+
+        // And succeeds with the events of the messages that did.
+        let event = Event::new("transfer");
+        execution
+            .record(
+                1,
+                ExecutionResult::Success {
+                    data: Some(Binary::from(vec![0])),
+                    events: vec![event.clone()],
+                    truncated: false,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            execution.outcome(),
+            Some(ExecutionResult::Success {
+                data: None,
+                events: vec![event],
+                truncated: false,
+            })
+        );
+    }
+}
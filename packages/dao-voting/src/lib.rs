@@ -1,13 +1,18 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
 pub mod approval;
+pub mod delegation;
 pub mod deposit;
 pub mod duration;
 pub mod error;
+pub mod execution;
 pub mod multiple_choice;
+pub mod power_cap;
 pub mod pre_propose;
 pub mod proposal;
+pub mod relations;
 pub mod reply;
+pub mod stake_bonus;
 pub mod status;
 pub mod threshold;
 pub mod veto;
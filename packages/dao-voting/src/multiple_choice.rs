@@ -11,34 +11,140 @@ const NONE_OPTION_DESCRIPTION: &str = "None of the above";
 /// Determines how many choices may be selected.
 #[cw_serde]
 pub enum VotingStrategy {
-    SingleChoice { quorum: PercentageThreshold },
+    SingleChoice {
+        quorum: PercentageThreshold,
+    },
+    /// Voters rank options in order of preference and the winner is
+    /// found by instant-runoff. The option with the fewest votes is
+    /// eliminated and its ballots move to their next ranked option
+    /// until an option holds a majority of the remaining ballots.
+    RankedChoice {
+        quorum: PercentageThreshold,
+    },
+    /// Voters approve any number of options, each of which receives
+    /// the voter's full vote weight. The option with the most
+    /// approval wins.
+    Approval {
+        quorum: PercentageThreshold,
+    },
+    /// Voters score each option from zero to `max_score`. Each option
+    /// receives the voter's vote weight multiplied by its score, and
+    /// the option with the highest total wins.
+    Score {
+        quorum: PercentageThreshold,
+        max_score: u32,
+    },
 }
 
 impl VotingStrategy {
     pub fn validate(&self) -> Result<(), ThresholdError> {
         match self {
-            VotingStrategy::SingleChoice { quorum } => validate_quorum(quorum),
+            // No option can receive any weight with a max score of
+            // zero.
+            VotingStrategy::Score { max_score: 0, .. } => {
+                Err(ThresholdError::UnreachableThreshold {})
+            }
+            _ => validate_quorum(&self.get_quorum()),
         }
     }
 
     pub fn get_quorum(&self) -> PercentageThreshold {
         match self {
-            VotingStrategy::SingleChoice { quorum } => *quorum,
+            VotingStrategy::SingleChoice { quorum }
+            | VotingStrategy::RankedChoice { quorum }
+            | VotingStrategy::Approval { quorum }
+            | VotingStrategy::Score { quorum, .. } => *quorum,
+        }
+    }
+
+    pub fn is_ranked(&self) -> bool {
+        matches!(self, VotingStrategy::RankedChoice { .. })
+    }
+
+    /// Returns true if votes under this strategy score every option.
+    pub fn is_scored(&self) -> bool {
+        matches!(
+            self,
+            VotingStrategy::Approval { .. } | VotingStrategy::Score { .. }
+        )
+    }
+
+    /// The most that a vote may multiply its weight by when counted
+    /// towards a single option.
+    pub fn max_score(&self) -> u32 {
+        match self {
+            VotingStrategy::Score { max_score, .. } => *max_score,
+            _ => 1,
         }
     }
 }
 
 /// A multiple choice vote, picking the desired option
 #[cw_serde]
-#[derive(Copy)]
 pub struct MultipleChoiceVote {
     // A vote indicates which option the user has selected.
     pub option_id: u32,
+    /// Under ranked-choice voting, the options ranked below
+    /// `option_id`, most preferred first. Ballots that run out of
+    /// ranked options stop counting once those options are
+    /// eliminated. Must be unset under other voting strategies.
+    #[serde(default)]
+    pub ranking: Option<Vec<u32>>,
+    /// Under approval and score voting, the score given to each
+    /// option, indexed by option ID. Approval votes score options
+    /// zero or one. `option_id` must be one of the highest scored
+    /// options. Must be unset under other voting strategies.
+    #[serde(default)]
+    pub scores: Option<Vec<u32>>,
+}
+
+impl MultipleChoiceVote {
+    /// The options this vote selects, most preferred first.
+    pub fn preferences(&self) -> Vec<u32> {
+        let mut preferences = vec![self.option_id];
+        preferences.extend(self.ranking.iter().flatten());
+        preferences
+    }
+
+    /// Returns true if this vote may be cast on a proposal with
+    /// `num_choices` options and `voting_strategy`. Ranked options
+    /// must exist and may not be repeated, and scored votes must
+    /// score every option.
+    pub fn is_valid(&self, voting_strategy: &VotingStrategy, num_choices: usize) -> bool {
+        if self.ranking.is_some() && !voting_strategy.is_ranked() {
+            return false;
+        }
+        if self.scores.is_some() != voting_strategy.is_scored() {
+            return false;
+        }
+        if let Some(scores) = &self.scores {
+            let max_score = voting_strategy.max_score();
+            return scores.len() == num_choices
+                && scores.iter().all(|score| *score <= max_score)
+                && scores.get(self.option_id as usize) == scores.iter().max();
+        }
+        let preferences = self.preferences();
+        preferences.iter().enumerate().all(|(idx, option_id)| {
+            (*option_id as usize) < num_choices && !preferences[..idx].contains(option_id)
+        })
+    }
 }
 
 impl std::fmt::Display for MultipleChoiceVote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.option_id)
+        if let Some(scores) = &self.scores {
+            let scores: Vec<String> = scores
+                .iter()
+                .enumerate()
+                .map(|(option_id, score)| format!("{option_id}:{score}"))
+                .collect();
+            return write!(f, "{}", scores.join(","));
+        }
+        write!(f, "{}", self.option_id)?;
+        for option_id in self.ranking.iter().flatten() {
+            write!(f, ",{option_id}")?;
+        }
+        Ok(())
     }
 }
 
@@ -56,22 +162,47 @@ impl MultipleChoiceVotes {
         self.vote_weights.iter().sum()
     }
 
-    // Add a vote to the tally
-    pub fn add_vote(&mut self, vote: MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
-        self.vote_weights[vote.option_id as usize] = self.vote_weights[vote.option_id as usize]
-            .checked_add(weight)
-            .map_err(StdError::overflow)?;
+    // Add a vote to the tally. Scored votes add their weight
+    // multiplied by each option's score. Otherwise, only the vote's
+    // first preference is counted.
+    pub fn add_vote(&mut self, vote: &MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
+        for (option_id, weight) in Self::weights(vote, weight)? {
+            self.vote_weights[option_id] = self.vote_weights[option_id]
+                .checked_add(weight)
+                .map_err(StdError::overflow)?;
+        }
         Ok(())
     }
 
     // Remove a vote from the tally
-    pub fn remove_vote(&mut self, vote: MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
-        self.vote_weights[vote.option_id as usize] = self.vote_weights[vote.option_id as usize]
-            .checked_sub(weight)
-            .map_err(StdError::overflow)?;
+    pub fn remove_vote(&mut self, vote: &MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
+        for (option_id, weight) in Self::weights(vote, weight)? {
+            self.vote_weights[option_id] = self.vote_weights[option_id]
+                .checked_sub(weight)
+                .map_err(StdError::overflow)?;
+        }
         Ok(())
     }
 
+    // The weight a vote adds to each option it counts towards.
+    fn weights(vote: &MultipleChoiceVote, weight: Uint128) -> StdResult<Vec<(usize, Uint128)>> {
+        match &vote.scores {
+            Some(scores) => scores
+                .iter()
+                .enumerate()
+                .map(|(option_id, score)| {
+                    Ok((
+                        option_id,
+                        weight
+                            .checked_mul(Uint128::from(*score))
+                            .map_err(StdError::overflow)?,
+                    ))
+                })
+                .collect(),
+            None => Ok(vec![(vote.option_id as usize, weight)]),
+        }
+    }
+
     // Default tally of zero for all multiple choice options
     pub fn zero(num_choices: usize) -> Self {
         Self {
@@ -188,10 +319,102 @@ mod test {
 
     #[test]
     fn test_display_multiple_choice_vote() {
-        let vote = MultipleChoiceVote { option_id: 0 };
+        let vote = MultipleChoiceVote {
+            option_id: 0,
+            ranking: None,
+            scores: None,
+        };
         assert_eq!("0", vote.to_string())
     }
 
+    #[test]
+    fn test_ranked_vote() {
+        let vote = MultipleChoiceVote {
+            option_id: 2,
+            ranking: Some(vec![0, 1]),
+            scores: None,
+        };
+        assert_eq!("2,0,1", vote.to_string());
+        assert_eq!(vote.preferences(), vec![2, 0, 1]);
+
+        let ranked = VotingStrategy::RankedChoice {
+            quorum: PercentageThreshold::Majority {},
+        };
+        let single = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        };
+        assert!(vote.is_valid(&ranked, 3));
+        // Rankings are only accepted by ranked-choice proposals.
+        assert!(!vote.is_valid(&single, 3));
+        // Ranked options must exist.
+        assert!(!vote.is_valid(&ranked, 2));
+
+        let repeated = MultipleChoiceVote {
+            option_id: 0,
+            ranking: Some(vec![1, 0]),
+            scores: None,
+        };
+        assert!(!repeated.is_valid(&ranked, 3));
+    }
+
+    #[test]
+    fn test_scored_vote() {
+        let vote = MultipleChoiceVote {
+            option_id: 1,
+            ranking: None,
+            scores: Some(vec![2, 3, 0]),
+        };
+        assert_eq!("0:2,1:3,2:0", vote.to_string());
+
+        let score = VotingStrategy::Score {
+            quorum: PercentageThreshold::Majority {},
+            max_score: 3,
+        };
+        let approval = VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+        };
+        assert!(vote.is_valid(&score, 3));
+        // Scores may not exceed the max score.
+        assert!(!vote.is_valid(&approval, 3));
+        // Every option must be scored.
+        assert!(!vote.is_valid(&score, 4));
+        // The vote's option must be one of the highest scored.
+        let not_highest = MultipleChoiceVote {
+            option_id: 0,
+            ..vote.clone()
+        };
+        assert!(!not_highest.is_valid(&score, 3));
+        // Scored strategies require scores.
+        let unscored = MultipleChoiceVote {
+            option_id: 1,
+            ranking: None,
+            scores: None,
+        };
+        assert!(!unscored.is_valid(&score, 3));
+
+        let mut votes = MultipleChoiceVotes::zero(3);
+        votes.add_vote(&vote, Uint128::new(10)).unwrap();
+        votes.add_vote(&unscored, Uint128::new(5)).unwrap();
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::new(20), Uint128::new(35), Uint128::zero()]
+        );
+        votes.remove_vote(&vote, Uint128::new(10)).unwrap();
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::zero(), Uint128::new(5), Uint128::zero()]
+        );
+
+        assert_eq!(
+            VotingStrategy::Score {
+                quorum: PercentageThreshold::Majority {},
+                max_score: 0,
+            }
+            .validate(),
+            Err(ThresholdError::UnreachableThreshold {})
+        );
+    }
+
     #[test]
     fn test_multiple_choice_votes() {
         let mut votes = MultipleChoiceVotes {
@@ -201,16 +424,37 @@ mod test {
         assert_eq!(total, Uint128::new(110));
 
         votes
-            .add_vote(MultipleChoiceVote { option_id: 0 }, Uint128::new(10))
+            .add_vote(
+                &MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
+                Uint128::new(10),
+            )
             .unwrap();
         let total = votes.total();
         assert_eq!(total, Uint128::new(120));
 
         votes
-            .remove_vote(MultipleChoiceVote { option_id: 0 }, Uint128::new(20))
+            .remove_vote(
+                &MultipleChoiceVote {
+                    option_id: 0,
+                    ranking: None,
+                    scores: None,
+                },
+                Uint128::new(20),
+            )
             .unwrap();
         votes
-            .remove_vote(MultipleChoiceVote { option_id: 1 }, Uint128::new(100))
+            .remove_vote(
+                &MultipleChoiceVote {
+                    option_id: 1,
+                    ranking: None,
+                    scores: None,
+                },
+                Uint128::new(100),
+            )
             .unwrap();
 
         assert_eq!(votes, MultipleChoiceVotes::zero(2))
@@ -0,0 +1,371 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Empty, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Map, SnapshotItem, Strategy};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PowerCapError {
+    #[error("Voting power cap must be greater than zero")]
+    ZeroCap {},
+
+    #[error("Voting power cap percentage must be greater than 0 and less than or equal to 1")]
+    InvalidPercentage {},
+
+    #[error("Voting power cap percentage must be at least ({min})")]
+    PercentageTooSmall { min: Decimal },
+}
+
+/// The smallest percentage cap. Fewer than `1 / percent` addresses
+/// can be over a percentage cap, so this bounds the number of
+/// addresses whose capped power is recomputed when a balance changes.
+pub const MIN_POWER_CAP_PERCENT: Decimal = Decimal::percent(1);
+
+/// The maximum voting power that any one address may have.
+#[cw_serde]
+pub enum PowerCap {
+    /// An absolute amount of voting power.
+    Absolute { amount: Uint128 },
+    /// A percentage of the total amount staked, before capping.
+    Percentage { percent: Decimal },
+}
+
+impl PowerCap {
+    pub fn validate(&self) -> Result<(), PowerCapError> {
+        match self {
+            PowerCap::Absolute { amount } if amount.is_zero() => Err(PowerCapError::ZeroCap {}),
+            PowerCap::Percentage { percent } if percent.is_zero() || *percent > Decimal::one() => {
+                Err(PowerCapError::InvalidPercentage {})
+            }
+            PowerCap::Percentage { percent } if *percent < MIN_POWER_CAP_PERCENT => {
+                Err(PowerCapError::PercentageTooSmall {
+                    min: MIN_POWER_CAP_PERCENT,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The most voting power an address may have when `total` is
+    /// staked. Percentages round up, so that the cap is never zero
+    /// while anything is staked.
+    pub fn max_power(&self, total: Uint128) -> Uint128 {
+        match self {
+            PowerCap::Absolute { amount } => *amount,
+            PowerCap::Percentage { percent } => total.mul_ceil(*percent),
+        }
+    }
+
+    /// The voting power of `balance` when `total` is staked.
+    pub fn apply(&self, balance: Uint128, total: Uint128) -> Uint128 {
+        balance.min(self.max_power(total))
+    }
+}
+
+/// Tracks the sum of capped voting power over time.
+///
+/// Under an absolute cap only the power of the address whose balance
+/// changed changes, so the total is updated by the difference in its
+/// capped power.
+///
+/// A percentage cap moves with the total staked, so any change in
+/// stake may change the power of every address over the cap. To keep
+/// the total exact, stakers are indexed by balance and the excess of
+/// those over the cap is recomputed whenever a balance changes. Fewer
+/// than `1 / percent` addresses can be over a percentage cap, which
+/// `MIN_POWER_CAP_PERCENT` bounds.
+pub struct CappedTotal<'a> {
+    /// (balance, address) for every address with a staked balance.
+    /// Only maintained under a percentage cap.
+    stakers: Map<'a, (u128, &'a Addr), Empty>,
+    /// total(h) := the sum of capped voting power at height h.
+    total: SnapshotItem<'a, Uint128>,
+}
+
+impl<'a> CappedTotal<'a> {
+    pub const fn new(
+        stakers_namespace: &'a str,
+        total_namespace: &'a str,
+        total_checkpoints: &'a str,
+        total_changelog: &'a str,
+    ) -> Self {
+        Self {
+            stakers: Map::new(stakers_namespace),
+            total: SnapshotItem::new(
+                total_namespace,
+                total_checkpoints,
+                total_changelog,
+                Strategy::EveryBlock,
+            ),
+        }
+    }
+
+    /// Updates the capped total for a change in `address`'s staked
+    /// balance at `height`, after which `total` is staked in all.
+    #[allow(clippy::too_many_arguments)]
+    pub fn on_balance_changed(
+        &self,
+        storage: &mut dyn Storage,
+        cap: &PowerCap,
+        address: &Addr,
+        height: u64,
+        old_balance: Uint128,
+        new_balance: Uint128,
+        total: Uint128,
+    ) -> StdResult<()> {
+        let capped_total = match cap {
+            PowerCap::Absolute { amount } => {
+                let capped_total = self.total.may_load(storage)?.unwrap_or_default();
+                capped_total - old_balance.min(*amount) + new_balance.min(*amount)
+            }
+            PowerCap::Percentage { .. } => {
+                if !old_balance.is_zero() {
+                    self.stakers.remove(storage, (old_balance.u128(), address));
+                }
+                if !new_balance.is_zero() {
+                    self.stakers
+                        .save(storage, (new_balance.u128(), address), &Empty {})?;
+                }
+
+                let max = cap.max_power(total);
+                let mut excess = Uint128::zero();
+                for key in self.stakers.keys(storage, None, None, Order::Descending) {
+                    let balance = Uint128::new(key?.0);
+                    if balance <= max {
+                        break;
+                    }
+                    excess += balance - max;
+                }
+                total - excess
+            }
+        };
+        self.total.save(storage, &capped_total, height)
+    }
+
+    /// The sum of capped voting power at `height`.
+    pub fn total_at_height(&self, storage: &dyn Storage, height: u64) -> StdResult<Uint128> {
+        Ok(self
+            .total
+            .may_load_at_height(storage, height)?
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    const CAPPED: CappedTotal =
+        CappedTotal::new("stakers", "total", "total__check", "total__change");
+
+    #[test]
+    fn test_validate_power_cap() {
+        PowerCap::Absolute {
+            amount: Uint128::one(),
+        }
+        .validate()
+        .unwrap();
+        PowerCap::Percentage {
+            percent: Decimal::one(),
+        }
+        .validate()
+        .unwrap();
+
+        assert_eq!(
+            PowerCap::Absolute {
+                amount: Uint128::zero()
+            }
+            .validate(),
+            Err(PowerCapError::ZeroCap {})
+        );
+        for percent in [Decimal::zero(), Decimal::percent(101)] {
+            assert_eq!(
+                PowerCap::Percentage { percent }.validate(),
+                Err(PowerCapError::InvalidPercentage {})
+            );
+        }
+        assert_eq!(
+            PowerCap::Percentage {
+                percent: Decimal::permille(9)
+            }
+            .validate(),
+            Err(PowerCapError::PercentageTooSmall {
+                min: MIN_POWER_CAP_PERCENT
+            })
+        );
+    }
+
+    #[test]
+    fn test_capped_total() {
+        let storage = &mut MockStorage::default();
+        let cap = PowerCap::Percentage {
+            percent: Decimal::percent(40),
+        };
+        let a = Addr::unchecked("a");
+        let b = Addr::unchecked("b");
+        let c = Addr::unchecked("c");
+
+        // `a` stakes 60, and is capped at 40% of that.
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &a,
+                1,
+                Uint128::zero(),
+                Uint128::new(60),
+                Uint128::new(60),
+            )
+            .unwrap();
+        assert_eq!(
+            cap.apply(Uint128::new(60), Uint128::new(60)),
+            Uint128::new(24)
+        );
+        assert_eq!(
+            CAPPED.total_at_height(storage, 2).unwrap(),
+            Uint128::new(24)
+        );
+
+        // `b` and `c` stake 20 each: `a` is capped at 40.
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &b,
+                2,
+                Uint128::zero(),
+                Uint128::new(20),
+                Uint128::new(80),
+            )
+            .unwrap();
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &c,
+                2,
+                Uint128::zero(),
+                Uint128::new(20),
+                Uint128::new(100),
+            )
+            .unwrap();
+        assert_eq!(
+            CAPPED.total_at_height(storage, 2).unwrap(),
+            Uint128::new(24)
+        );
+        assert_eq!(
+            CAPPED.total_at_height(storage, 3).unwrap(),
+            Uint128::new(80)
+        );
+
+        // `a` unstakes 40, so is no longer capped.
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &a,
+                3,
+                Uint128::new(60),
+                Uint128::new(20),
+                Uint128::new(60),
+            )
+            .unwrap();
+        assert_eq!(
+            CAPPED.total_at_height(storage, 4).unwrap(),
+            Uint128::new(60)
+        );
+        assert_eq!(
+            CAPPED.total_at_height(storage, 3).unwrap(),
+            Uint128::new(80)
+        );
+        assert_eq!(CAPPED.total_at_height(storage, 0).unwrap(), Uint128::zero());
+    }
+    #[test]
+    fn test_capped_total_absolute() {
+        let storage = &mut MockStorage::default();
+        let cap = PowerCap::Absolute {
+            amount: Uint128::new(30),
+        };
+        let a = Addr::unchecked("a");
+        let b = Addr::unchecked("b");
+
+        // `a` stakes 50 and is capped at 30, `b` stakes 20.
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &a,
+                1,
+                Uint128::zero(),
+                Uint128::new(50),
+                Uint128::new(50),
+            )
+            .unwrap();
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &b,
+                1,
+                Uint128::zero(),
+                Uint128::new(20),
+                Uint128::new(70),
+            )
+            .unwrap();
+        assert_eq!(
+            CAPPED.total_at_height(storage, 2).unwrap(),
+            Uint128::new(50)
+        );
+
+        // `a` unstakes 10 and is still capped, `b` stakes 20 more and
+        // is capped.
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &a,
+                2,
+                Uint128::new(50),
+                Uint128::new(40),
+                Uint128::new(60),
+            )
+            .unwrap();
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &b,
+                2,
+                Uint128::new(20),
+                Uint128::new(40),
+                Uint128::new(80),
+            )
+            .unwrap();
+        assert_eq!(
+            CAPPED.total_at_height(storage, 3).unwrap(),
+            Uint128::new(60)
+        );
+
+        // `a` unstakes everything.
+        CAPPED
+            .on_balance_changed(
+                storage,
+                &cap,
+                &a,
+                3,
+                Uint128::new(40),
+                Uint128::zero(),
+                Uint128::new(40),
+            )
+            .unwrap();
+        assert_eq!(
+            CAPPED.total_at_height(storage, 4).unwrap(),
+            Uint128::new(30)
+        );
+        assert_eq!(
+            CAPPED.total_at_height(storage, 2).unwrap(),
+            Uint128::new(50)
+        );
+    }
+}
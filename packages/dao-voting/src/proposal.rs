@@ -1,8 +1,9 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty};
+use cosmwasm_std::{CosmosMsg, Empty, Uint128};
 
 use crate::{
     multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
+    relations::ProposalRelations,
     voting::SingleChoiceAutoVote,
 };
 
@@ -38,6 +39,16 @@ pub struct SingleChoiceProposeMsg {
     pub proposer: Option<String>,
     /// An optional vote cast by the proposer.
     pub vote: Option<SingleChoiceAutoVote>,
+    /// Proposals this proposal depends on or conflicts with, if any.
+    #[serde(default)]
+    pub relations: Option<ProposalRelations>,
+    /// Whether the proposal is expedited. Expedited proposals are
+    /// voted on under a stricter threshold and shorter voting period,
+    /// falling back to the normal ones if they do not pass in time.
+    /// Only supported if the proposal module has expedited proposals
+    /// configured.
+    #[serde(default)]
+    pub expedited: bool,
 }
 
 /// The contents of a message to create a proposal in the multiple
@@ -68,3 +79,120 @@ pub struct MultipleChoiceProposeMsg {
     /// An optional vote cast by the proposer.
     pub vote: Option<MultipleChoiceAutoVote>,
 }
+
+/// The contents of a message to create a proposal in the conviction
+/// voting proposal module.
+///
+/// As above, this type lives in this package so that pre-propose
+/// modules can get type checking on their propose messages.
+#[cw_serde]
+pub struct ConvictionProposeMsg {
+    /// The title of the proposal.
+    pub title: String,
+    /// A description of the proposal.
+    pub description: String,
+    /// The address that will receive the requested funds should this
+    /// proposal pass.
+    pub beneficiary: String,
+    /// The amount of the module's funding denomination requested
+    /// from the DAO's treasury.
+    pub amount: Uint128,
+    /// The address creating the proposal. If no pre-propose
+    /// module is attached to this module this must always be None
+    /// as the proposer is the sender of the propose message. If a
+    /// pre-propose module is attached, this must be Some and will
+    /// set the proposer of the proposal it creates.
+    pub proposer: Option<String>,
+}
+
+/// The contents of a message to create a proposal in the multistage
+/// proposal module.
+///
+/// As above, this type lives in this package so that pre-propose
+/// modules can get type checking on their propose messages.
+#[cw_serde]
+pub struct MultistageProposeMsg {
+    /// The title of the proposal.
+    pub title: String,
+    /// A description of the proposal.
+    pub description: String,
+    /// The messages that should be executed in response to this
+    /// proposal passing all of its stages.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The address creating the proposal. If no pre-propose
+    /// module is attached to this module this must always be None
+    /// as the proposer is the sender of the propose message. If a
+    /// pre-propose module is attached, this must be Some and will
+    /// set the proposer of the proposal it creates.
+    pub proposer: Option<String>,
+}
+
+/// As above, this type lives in this package so that pre-propose
+/// modules can get type checking on their propose messages.
+#[cw_serde]
+pub struct OptimisticProposeMsg {
+    /// The title of the proposal.
+    pub title: String,
+    /// A description of the proposal.
+    pub description: String,
+    /// The messages that should be executed in response to this
+    /// proposal passing.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The address creating the proposal. If no pre-propose
+    /// module is attached to this module this must always be None
+    /// as the proposer is the sender of the propose message. If a
+    /// pre-propose module is attached, this must be Some and will
+    /// set the proposer of the proposal it creates.
+    pub proposer: Option<String>,
+}
+
+/// The contents of a message to amend a proposal in the single choice
+/// proposal module. Fields that are `None` are left unchanged.
+///
+/// As above, this type lives in this package so that pre-propose
+/// modules can get type checking on their amend messages.
+#[cw_serde]
+pub struct SingleChoiceAmendMsg {
+    /// The ID of the proposal to amend.
+    pub proposal_id: u64,
+    /// The new title of the proposal.
+    pub title: Option<String>,
+    /// The new description of the proposal.
+    pub description: Option<String>,
+    /// The new messages that should be executed in response to this
+    /// proposal passing.
+    pub msgs: Option<Vec<CosmosMsg<Empty>>>,
+}
+
+/// The contents of a message to amend a proposal in the multiple
+/// choice proposal module. Fields that are `None` are left unchanged.
+#[cw_serde]
+pub struct MultipleChoiceAmendMsg {
+    /// The ID of the proposal to amend.
+    pub proposal_id: u64,
+    /// The new title of the proposal.
+    pub title: Option<String>,
+    /// The new description of the proposal.
+    pub description: Option<String>,
+    /// The new choices of the proposal.
+    pub choices: Option<MultipleChoiceOptions>,
+}
+
+/// The most votes, counting ballots and votes cast by delegates on
+/// behalf of delegators, that may be removed when amending a
+/// proposal. Proposals with more votes may only be amended in ways
+/// that preserve them.
+pub const MAX_AMENDMENT_VOTE_REMOVALS: usize = 100;
+
+/// How proposals are amended while they are open for voting.
+#[cw_serde]
+#[derive(Default)]
+pub struct AmendmentConfig {
+    /// If true, votes cast before a proposal is amended are kept,
+    /// unless the amendment changes the messages the proposal
+    /// executes, or for multiple choice proposals the choices voted
+    /// on. Otherwise amending a proposal removes all votes cast
+    /// on it, so that voters do not unknowingly support the amended
+    /// version.
+    pub preserve_votes: bool,
+}
@@ -0,0 +1,158 @@
+use cosmwasm_schema::{cw_serde, serde::Deserialize};
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+use crate::{error::VotingError, status::Status};
+
+/// The maximum number of proposals a proposal may depend on or
+/// conflict with. Each relation is checked when the proposal is
+/// executed, so this bounds the cost of execution.
+pub const MAX_PROPOSAL_RELATIONS: usize = 16;
+
+/// A proposal in one of a DAO's proposal modules.
+#[cw_serde]
+pub struct ProposalRef {
+    /// The address of the proposal module the proposal was created
+    /// in.
+    pub module: String,
+    /// The ID of the proposal in that module.
+    pub proposal_id: u64,
+}
+
+/// A proposal in one of a DAO's proposal modules, with the module's
+/// address validated.
+#[cw_serde]
+pub struct CheckedProposalRef {
+    pub module: Addr,
+    pub proposal_id: u64,
+}
+
+/// The proposals a proposal depends on and conflicts with.
+#[cw_serde]
+#[derive(Default)]
+pub struct ProposalRelations {
+    /// Proposals that must be executed before this proposal may be
+    /// executed.
+    pub depends_on: Vec<ProposalRef>,
+    /// Proposals that may not be executed alongside this proposal.
+    /// Once either is executed, the other is rejected. Must be in the
+    /// same proposal module, as a proposal in another module would not
+    /// be rejected if this one were executed first.
+    pub conflicts_with: Vec<ProposalRef>,
+}
+
+/// As above, with the proposal modules validated.
+#[cw_serde]
+#[derive(Default)]
+pub struct CheckedProposalRelations {
+    pub depends_on: Vec<CheckedProposalRef>,
+    pub conflicts_with: Vec<CheckedProposalRef>,
+}
+
+impl CheckedProposalRelations {
+    pub fn is_empty(&self) -> bool {
+        self.depends_on.is_empty() && self.conflicts_with.is_empty()
+    }
+}
+
+impl ProposalRelations {
+    /// Validates the proposals referenced by these relations.
+    /// Proposals in `module`, the proposal module creating the
+    /// proposal, must have an ID lower than `next_id`. Proposals in
+    /// other modules must exist and their module must belong to
+    /// `dao`. Conflicts must be with proposals in `module`.
+    pub fn into_checked(
+        self,
+        deps: Deps,
+        dao: &Addr,
+        module: &Addr,
+        next_id: u64,
+    ) -> Result<CheckedProposalRelations, VotingError> {
+        if self.depends_on.len() + self.conflicts_with.len() > MAX_PROPOSAL_RELATIONS {
+            return Err(VotingError::TooManyProposalRelations {
+                max: MAX_PROPOSAL_RELATIONS,
+            });
+        }
+        let check = |refs: Vec<ProposalRef>| {
+            refs.into_iter()
+                .map(|r| r.into_checked(deps, dao, module, next_id))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        for conflict in &self.conflicts_with {
+            if deps.api.addr_validate(&conflict.module)? != *module {
+                return Err(VotingError::CrossModuleConflict {
+                    module: conflict.module.clone(),
+                    proposal_id: conflict.proposal_id,
+                });
+            }
+        }
+        Ok(CheckedProposalRelations {
+            depends_on: check(self.depends_on)?,
+            conflicts_with: check(self.conflicts_with)?,
+        })
+    }
+}
+
+impl ProposalRef {
+    fn into_checked(
+        self,
+        deps: Deps,
+        dao: &Addr,
+        module: &Addr,
+        next_id: u64,
+    ) -> Result<CheckedProposalRef, VotingError> {
+        let invalid = || VotingError::InvalidProposalRef {
+            module: self.module.clone(),
+            proposal_id: self.proposal_id,
+        };
+        let checked = CheckedProposalRef {
+            module: deps.api.addr_validate(&self.module)?,
+            proposal_id: self.proposal_id,
+        };
+        if checked.module == *module {
+            if checked.proposal_id == 0 || checked.proposal_id >= next_id {
+                return Err(invalid());
+            }
+        } else {
+            let module_dao: Addr = deps
+                .querier
+                .query_wasm_smart(&checked.module, &dao_interface::proposal::Query::Dao {})
+                .map_err(|_| invalid())?;
+            if module_dao != *dao {
+                return Err(invalid());
+            }
+            query_proposal_status(deps, &checked).map_err(|_| invalid())?;
+        }
+        Ok(checked)
+    }
+}
+
+#[cw_serde]
+enum ProposalQuery {
+    Proposal { proposal_id: u64 },
+}
+
+/// The parts of a proposal module's `Proposal` query response shared
+/// by the proposal modules that use `Status`. Unknown fields are
+/// ignored so that this may be used with any of them.
+#[derive(Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde")]
+struct ProposalStatusResponse {
+    proposal: ProposalStatus,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde")]
+struct ProposalStatus {
+    status: Status,
+}
+
+/// Queries the status of a proposal in another proposal module.
+pub fn query_proposal_status(deps: Deps, proposal: &CheckedProposalRef) -> StdResult<Status> {
+    let response: ProposalStatusResponse = deps.querier.query_wasm_smart(
+        &proposal.module,
+        &ProposalQuery::Proposal {
+            proposal_id: proposal.proposal_id,
+        },
+    )?;
+    Ok(response.proposal.status)
+}
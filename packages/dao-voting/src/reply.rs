@@ -9,6 +9,13 @@ const FAILED_VOTE_HOOK_MASK: u64 = 0b010;
 /// handler is handling.)
 const PRE_PROPOSE_MODULE_INSTANTIATION_ID: u64 = 0b011;
 const FAILED_PRE_PROPOSE_MODULE_HOOK_ID: u64 = 0b100;
+const PARTIAL_PROPOSAL_EXECUTION_MASK: u64 = 0b101;
+const FAILED_EXTENDED_PROPOSAL_HOOK_MASK: u64 = 0b110;
+
+/// The number of bits of a partial proposal execution reply ID used
+/// for the index of the message being executed.
+const MSG_INDEX_BITS: u8 = 16;
+const MSG_INDEX_MASK: u64 = (1 << MSG_INDEX_BITS) - 1;
 
 const BITS_RESERVED_FOR_REPLY_TYPE: u8 = 3;
 const REPLY_TYPE_MASK: u64 = (1 << BITS_RESERVED_FOR_REPLY_TYPE) - 1;
@@ -22,7 +29,8 @@ const REPLY_TYPE_MASK: u64 = (1 << BITS_RESERVED_FOR_REPLY_TYPE) - 1;
 #[limit_variant_count(8)]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq, Eq))]
 pub enum TaggedReplyId {
-    /// Fired when a proposal's execution fails.
+    /// Fired when a proposal's execution fails. Modules that record
+    /// execution results also receive this when execution succeeds.
     FailedProposalExecution(u64),
     /// Fired when a proposal hook's execution fails.
     FailedProposalHook(u64),
@@ -32,6 +40,11 @@ pub enum TaggedReplyId {
     FailedPreProposeModuleHook,
     /// Fired when a pre-propose module is successfully instantiated.
     PreProposeModuleInstantiation,
+    /// Fired when one message of a proposal being executed one
+    /// message at a time has been executed.
+    PartialProposalExecution { proposal_id: u64, msg_index: u32 },
+    /// Fired when an extended proposal hook's execution fails.
+    FailedExtendedProposalHook(u64),
 }
 
 impl TaggedReplyId {
@@ -50,6 +63,13 @@ impl TaggedReplyId {
             FAILED_VOTE_HOOK_MASK => Ok(TaggedReplyId::FailedVoteHook(id_after_shift)),
             PRE_PROPOSE_MODULE_INSTANTIATION_ID => Ok(TaggedReplyId::PreProposeModuleInstantiation),
             FAILED_PRE_PROPOSE_MODULE_HOOK_ID => Ok(TaggedReplyId::FailedPreProposeModuleHook),
+            PARTIAL_PROPOSAL_EXECUTION_MASK => Ok(TaggedReplyId::PartialProposalExecution {
+                proposal_id: id_after_shift >> MSG_INDEX_BITS,
+                msg_index: (id_after_shift & MSG_INDEX_MASK) as u32,
+            }),
+            FAILED_EXTENDED_PROPOSAL_HOOK_MASK => {
+                Ok(TaggedReplyId::FailedExtendedProposalHook(id_after_shift))
+            }
             _ => Err(error::TagError::UnknownReplyId { id }),
         }
     }
@@ -60,6 +80,20 @@ pub const fn mask_proposal_execution_proposal_id(proposal_id: u64) -> u64 {
     FAILED_PROPOSAL_EXECUTION_MASK | (proposal_id << BITS_RESERVED_FOR_REPLY_TYPE)
 }
 
+/// This function can drop bits, if you have more than
+/// `u(64-[`BITS_RESERVED_FOR_REPLY_TYPE`]-[`MSG_INDEX_BITS`])`
+/// proposals or more than `u[`MSG_INDEX_BITS`]` messages in a
+/// proposal.
+pub const fn mask_partial_proposal_execution_id(proposal_id: u64, msg_index: u32) -> u64 {
+    PARTIAL_PROPOSAL_EXECUTION_MASK
+        | (((proposal_id << MSG_INDEX_BITS) | (msg_index as u64 & MSG_INDEX_MASK))
+            << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
+/// The maximum number of messages a proposal may have to be executed
+/// one message at a time.
+pub const MAX_PARTIAL_EXECUTION_MSGS: usize = 1 << MSG_INDEX_BITS;
+
 pub const fn mask_proposal_hook_index(index: u64) -> u64 {
     FAILED_PROPOSAL_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
 }
@@ -68,6 +102,10 @@ pub const fn mask_vote_hook_index(index: u64) -> u64 {
     FAILED_VOTE_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
 }
 
+pub const fn mask_extended_proposal_hook_index(index: u64) -> u64 {
+    FAILED_EXTENDED_PROPOSAL_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
 pub const fn pre_propose_module_instantiation_id() -> u64 {
     PRE_PROPOSE_MODULE_INSTANTIATION_ID
 }
@@ -114,8 +152,19 @@ mod test {
             TaggedReplyId::FailedVoteHook(vote_hook_idx)
         );
         assert_eq!(
-            TaggedReplyId::new(0b110).unwrap_err(),
-            error::TagError::UnknownReplyId { id: 0b110 }
+            TaggedReplyId::new(mask_partial_proposal_execution_id(1234, 7)).unwrap(),
+            TaggedReplyId::PartialProposalExecution {
+                proposal_id: 1234,
+                msg_index: 7
+            }
+        );
+        assert_eq!(
+            TaggedReplyId::new(mask_extended_proposal_hook_index(proposal_hook_idx)).unwrap(),
+            TaggedReplyId::FailedExtendedProposalHook(proposal_hook_idx)
+        );
+        assert_eq!(
+            TaggedReplyId::new(0b111).unwrap_err(),
+            error::TagError::UnknownReplyId { id: 0b111 }
         );
     }
 }
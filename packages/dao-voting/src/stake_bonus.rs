@@ -0,0 +1,350 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, StdResult, Storage, Uint128, Uint256};
+use cw_storage_plus::{SnapshotMap, Strategy};
+use cw_wormhole::Wormhole;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum StakeBonusError {
+    #[error(
+        "Bonus tiers must be ordered by strictly increasing min_stake_blocks, starting above zero"
+    )]
+    UnorderedTiers {},
+
+    #[error("Bonus multipliers must be greater than one and must not decrease between tiers")]
+    InvalidMultiplier {},
+}
+
+/// A multiplier applied to the voting power of tokens that have been
+/// staked for at least `min_stake_blocks` blocks.
+#[cw_serde]
+pub struct BonusTier {
+    pub min_stake_blocks: u64,
+    /// For example, `1.5` gives a bonus of 50%.
+    pub multiplier: Decimal,
+}
+
+/// Validates that tiers are ordered by `min_stake_blocks` and that
+/// multipliers only increase. An empty schedule gives no bonus.
+pub fn validate_bonus_schedule(schedule: &[BonusTier]) -> Result<(), StakeBonusError> {
+    let mut prev: Option<&BonusTier> = None;
+    for tier in schedule {
+        if tier.min_stake_blocks <= prev.map_or(0, |p| p.min_stake_blocks) {
+            return Err(StakeBonusError::UnorderedTiers {});
+        }
+        if tier.multiplier <= Decimal::one() || prev.is_some_and(|p| tier.multiplier < p.multiplier)
+        {
+            return Err(StakeBonusError::InvalidMultiplier {});
+        }
+        prev = Some(tier);
+    }
+    Ok(())
+}
+
+/// The bonus voting power of `balance` tokens that have been staked
+/// for `age` blocks.
+pub fn bonus(schedule: &[BonusTier], balance: Uint128, age: u64) -> Uint128 {
+    schedule
+        .iter()
+        .rev()
+        .find(|tier| tier.min_stake_blocks <= age)
+        .map(|tier| balance.mul_floor(tier.multiplier - Decimal::one()))
+        .unwrap_or_default()
+}
+
+/// Tracks how long addresses have been staked for, and the total
+/// bonus voting power this gives at every height.
+///
+/// Bonuses grow without any transaction taking place, so when an
+/// address's stake changes the heights at which it will move up a
+/// tier are known and scheduled in advance. This is only correct if
+/// the schedule passed to every method is the same, so schedules may
+/// not be changed once set.
+pub struct StakeBonus<'a> {
+    /// since(a, h) := the height from which address a's staked
+    /// tokens are aged at height h.
+    since: SnapshotMap<'a, &'a Addr, u64>,
+    /// bonus(h) := the sum of the bonus voting power of all stakers
+    /// at height h.
+    total_bonus: Wormhole<'a, (), Uint128>,
+}
+
+impl<'a> StakeBonus<'a> {
+    pub const fn new(
+        since_namespace: &'a str,
+        since_checkpoints: &'a str,
+        since_changelog: &'a str,
+        total_bonus_namespace: &'a str,
+    ) -> Self {
+        Self {
+            since: SnapshotMap::new(
+                since_namespace,
+                since_checkpoints,
+                since_changelog,
+                Strategy::EveryBlock,
+            ),
+            total_bonus: Wormhole::new(total_bonus_namespace),
+        }
+    }
+
+    /// Updates the bonus for a change in `address`'s staked balance
+    /// at `height`. Staking more tokens ages the balance from the
+    /// average of when its tokens were staked, weighted by amount, so
+    /// that topping up a stake neither resets nor inherits its age.
+    /// Unstaking resets the age of the remaining balance.
+    pub fn on_stake_changed(
+        &self,
+        storage: &mut dyn Storage,
+        schedule: &[BonusTier],
+        address: &Addr,
+        height: u64,
+        old_balance: Uint128,
+        new_balance: Uint128,
+    ) -> StdResult<()> {
+        if schedule.is_empty() {
+            return Ok(());
+        }
+        // Changes at `height` apply to voting power from the next
+        // block, as with snapshot maps.
+        let from = height + 1;
+
+        let old_since = self.since.may_load(storage, address)?;
+        let new_since = match old_since {
+            Some(old_since) if new_balance > old_balance && !old_balance.is_zero() => {
+                let weighted =
+                    old_balance.full_mul(old_since) + (new_balance - old_balance).full_mul(height);
+                // The average is at most `height`, so fits in a u64.
+                Uint128::try_from(weighted / Uint256::from(new_balance))?.u128() as u64
+            }
+            _ => height,
+        };
+
+        if let Some(old_since) = old_since {
+            self.schedule(storage, schedule, old_balance, old_since, from, false)?;
+        }
+        if new_balance.is_zero() {
+            self.since.remove(storage, address, height)?;
+        } else {
+            self.schedule(storage, schedule, new_balance, new_since, from, true)?;
+            self.since.save(storage, address, &new_since, height)?;
+        }
+        Ok(())
+    }
+
+    /// Adds (or removes) the bonus of `balance` tokens aged from
+    /// `since` to the total from height `from` onwards, including the
+    /// increases as they reach each tier.
+    fn schedule(
+        &self,
+        storage: &mut dyn Storage,
+        schedule: &[BonusTier],
+        balance: Uint128,
+        since: u64,
+        from: u64,
+        add: bool,
+    ) -> StdResult<()> {
+        let mut prev = bonus(schedule, balance, from - since);
+        let mut changes = vec![(from, prev)];
+        for tier in schedule
+            .iter()
+            .filter(|t| since + t.min_stake_blocks > from)
+        {
+            let next = bonus(schedule, balance, tier.min_stake_blocks);
+            changes.push((since + tier.min_stake_blocks, next - prev));
+            prev = next;
+        }
+
+        for (t, amount) in changes.into_iter().filter(|(_, amount)| !amount.is_zero()) {
+            if add {
+                self.total_bonus.increment(storage, (), t, amount)?;
+            } else {
+                self.total_bonus.decrement(storage, (), t, amount)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The voting power of `address` at `height`, given its staked
+    /// balance at that height.
+    pub fn power_at_height(
+        &self,
+        storage: &dyn Storage,
+        schedule: &[BonusTier],
+        address: &Addr,
+        balance: Uint128,
+        height: u64,
+    ) -> StdResult<Uint128> {
+        Ok(
+            match self.since.may_load_at_height(storage, address, height)? {
+                Some(since) => {
+                    balance.checked_add(bonus(schedule, balance, height.saturating_sub(since)))?
+                }
+                None => balance,
+            },
+        )
+    }
+
+    /// The total bonus voting power at `height`.
+    pub fn total_bonus_at_height(&self, storage: &dyn Storage, height: u64) -> StdResult<Uint128> {
+        Ok(self
+            .total_bonus
+            .load(storage, (), height)?
+            .unwrap_or_default())
+    }
+
+    /// The height from which `address`'s stake is currently aged, if
+    /// it has any bonus tracked.
+    pub fn staked_since(&self, storage: &dyn Storage, address: &Addr) -> StdResult<Option<u64>> {
+        self.since.may_load(storage, address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    const BONUS: StakeBonus = StakeBonus::new("since", "since__check", "since__change", "bonus");
+
+    fn schedule() -> Vec<BonusTier> {
+        vec![
+            BonusTier {
+                min_stake_blocks: 10,
+                multiplier: Decimal::percent(120),
+            },
+            BonusTier {
+                min_stake_blocks: 20,
+                multiplier: Decimal::percent(150),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_validate_bonus_schedule() {
+        validate_bonus_schedule(&[]).unwrap();
+        validate_bonus_schedule(&schedule()).unwrap();
+
+        let mut unordered = schedule();
+        unordered.reverse();
+        assert_eq!(
+            validate_bonus_schedule(&unordered),
+            Err(StakeBonusError::UnorderedTiers {})
+        );
+        assert_eq!(
+            validate_bonus_schedule(&[BonusTier {
+                min_stake_blocks: 0,
+                multiplier: Decimal::percent(150),
+            }]),
+            Err(StakeBonusError::UnorderedTiers {})
+        );
+        assert_eq!(
+            validate_bonus_schedule(&[BonusTier {
+                min_stake_blocks: 10,
+                multiplier: Decimal::one(),
+            }]),
+            Err(StakeBonusError::InvalidMultiplier {})
+        );
+    }
+
+    #[test]
+    fn test_stake_bonus() {
+        let storage = &mut MockStorage::default();
+        let schedule = schedule();
+        let a = Addr::unchecked("a");
+        let b = Addr::unchecked("b");
+
+        // `a` stakes 100 at height 0, `b` stakes 100 at height 5.
+        BONUS
+            .on_stake_changed(
+                storage,
+                &schedule,
+                &a,
+                0,
+                Uint128::zero(),
+                Uint128::new(100),
+            )
+            .unwrap();
+        BONUS
+            .on_stake_changed(
+                storage,
+                &schedule,
+                &b,
+                5,
+                Uint128::zero(),
+                Uint128::new(100),
+            )
+            .unwrap();
+
+        let power = |storage: &MockStorage, addr: &Addr, balance: u128, height: u64| {
+            BONUS
+                .power_at_height(storage, &schedule, addr, Uint128::new(balance), height)
+                .unwrap()
+                .u128()
+        };
+        let total_bonus = |storage: &MockStorage, height: u64| {
+            BONUS.total_bonus_at_height(storage, height).unwrap().u128()
+        };
+
+        assert_eq!(power(storage, &a, 100, 9), 100);
+        assert_eq!(power(storage, &a, 100, 10), 120);
+        assert_eq!(power(storage, &b, 100, 15), 120);
+        assert_eq!(power(storage, &a, 100, 20), 150);
+        assert_eq!(total_bonus(storage, 9), 0);
+        assert_eq!(total_bonus(storage, 10), 20);
+        assert_eq!(total_bonus(storage, 15), 40);
+        assert_eq!(total_bonus(storage, 20), 70);
+        assert_eq!(total_bonus(storage, 25), 100);
+
+        // `a` unstakes half at height 12, resetting its age.
+        BONUS
+            .on_stake_changed(
+                storage,
+                &schedule,
+                &a,
+                12,
+                Uint128::new(100),
+                Uint128::new(50),
+            )
+            .unwrap();
+        assert_eq!(power(storage, &a, 100, 12), 120);
+        assert_eq!(power(storage, &a, 50, 13), 50);
+        assert_eq!(power(storage, &a, 50, 22), 60);
+        assert_eq!(total_bonus(storage, 12), 20);
+        assert_eq!(total_bonus(storage, 13), 0);
+        assert_eq!(total_bonus(storage, 22), 30);
+        assert_eq!(total_bonus(storage, 32), 75);
+
+        // `b` doubles its stake at height 15, and its age halves.
+        BONUS
+            .on_stake_changed(
+                storage,
+                &schedule,
+                &b,
+                15,
+                Uint128::new(100),
+                Uint128::new(200),
+            )
+            .unwrap();
+        assert_eq!(BONUS.staked_since(storage, &b).unwrap(), Some(10));
+        assert_eq!(power(storage, &b, 200, 16), 200);
+        assert_eq!(power(storage, &b, 200, 20), 240);
+        assert_eq!(total_bonus(storage, 20), 40);
+        assert_eq!(total_bonus(storage, 30), 100 + 10);
+
+        // Unstaking everything removes the bonus.
+        BONUS
+            .on_stake_changed(
+                storage,
+                &schedule,
+                &b,
+                40,
+                Uint128::new(200),
+                Uint128::zero(),
+            )
+            .unwrap();
+        assert_eq!(BONUS.staked_since(storage, &b).unwrap(), None);
+        assert_eq!(total_bonus(storage, 41), 25);
+        assert_eq!(total_bonus(storage, 40), 125);
+    }
+}
@@ -3,7 +3,8 @@ use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128, Uint256};
 use cw_utils::Duration;
 use dao_interface::voting;
 
-use crate::threshold::PercentageThreshold;
+use crate::error::VotingError;
+use crate::threshold::{PercentageThreshold, Threshold};
 
 // We multiply by this when calculating needed_votes in order to round
 // up properly.
@@ -39,6 +40,76 @@ pub struct SingleChoiceAutoVote {
     pub rationale: Option<String>,
 }
 
+/// How a voter's voting power is converted into the weight of their
+/// vote.
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum VoteWeighting {
+    /// Votes are weighted by voting power.
+    #[default]
+    Linear {},
+    /// Votes are weighted by the square root of voting power. This
+    /// reduces the influence of large holders relative to small
+    /// ones. Quorum is still computed over the voting power of the
+    /// addresses that have voted so that it means the same thing
+    /// under either weighting.
+    Quadratic {},
+}
+
+impl VoteWeighting {
+    /// Returns the weight of a vote cast with `power` voting power.
+    pub fn weight(&self, power: Uint128) -> Uint128 {
+        match self {
+            VoteWeighting::Linear {} => power,
+            VoteWeighting::Quadratic {} => Uint128::new(isqrt(power.u128())),
+        }
+    }
+
+    /// Checks that this weighting may be used with `threshold`. An
+    /// absolute percentage threshold is measured against the total
+    /// weight of all votes that could be cast, which is not known
+    /// under quadratic weighting without querying every voter.
+    pub fn validate(&self, threshold: &Threshold) -> Result<(), VotingError> {
+        match (self, threshold) {
+            (VoteWeighting::Quadratic {}, Threshold::AbsolutePercentage { .. }) => {
+                Err(VotingError::QuadraticAbsolutePercentage {})
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns true if votes are weighted by the square root of
+    /// voting power.
+    pub fn is_quadratic(&self) -> bool {
+        matches!(self, VoteWeighting::Quadratic {})
+    }
+}
+
+impl std::fmt::Display for VoteWeighting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VoteWeighting::Linear {} => write!(f, "linear"),
+            VoteWeighting::Quadratic {} => write!(f, "quadratic"),
+        }
+    }
+}
+
+/// Computes the integer square root of `n`, rounded down.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method. The initial guess is larger than the root so
+    // the sequence decreases monotonically until it converges.
+    let mut x = n;
+    let mut y = x / 2 + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
 pub enum VoteCmp {
     Greater,
     Geq,
@@ -461,4 +532,43 @@ mod test {
             Decimal::percent(0)
         ))
     }
+
+    #[test]
+    fn vote_weighting() {
+        for (n, root) in [
+            (0u128, 0u128),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 2),
+            (15, 3),
+            (16, 4),
+            (17, 4),
+            (1_000_000, 1_000),
+            (u128::MAX, u64::MAX as u128),
+        ] {
+            assert_eq!(isqrt(n), root, "isqrt({n})");
+        }
+
+        let power = Uint128::new(100);
+        assert_eq!(VoteWeighting::Linear {}.weight(power), power);
+        assert_eq!(VoteWeighting::Quadratic {}.weight(power), Uint128::new(10));
+        assert_eq!(VoteWeighting::default(), VoteWeighting::Linear {});
+        assert!(VoteWeighting::Quadratic {}.is_quadratic());
+        assert!(!VoteWeighting::Linear {}.is_quadratic());
+
+        let percentage = Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        };
+        assert!(VoteWeighting::Linear {}.validate(&percentage).is_ok());
+        assert_eq!(
+            VoteWeighting::Quadratic {}.validate(&percentage),
+            Err(VotingError::QuadraticAbsolutePercentage {})
+        );
+        assert!(VoteWeighting::Quadratic {}
+            .validate(&Threshold::AbsoluteCount {
+                threshold: Uint128::new(10)
+            })
+            .is_ok());
+    }
 }